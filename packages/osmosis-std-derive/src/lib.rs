@@ -2,6 +2,7 @@ use itertools::Itertools;
 use proc_macro::TokenStream;
 use proc_macro2::TokenTree;
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::{parse_macro_input, DeriveInput};
 
 macro_rules! match_kv_attr {
@@ -22,24 +23,408 @@ macro_rules! match_kv_attr {
     };
 }
 
-#[proc_macro_derive(CosmwasmExt, attributes(proto_message, proto_query))]
+#[proc_macro_derive(CosmwasmExt, attributes(proto_message, proto_query, amino))]
 pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = input.ident;
 
+    if let syn::Data::Enum(data) = &input.data {
+        return oneof_ext(&ident, data).into();
+    }
+
     let type_url = get_type_url(&input.attrs);
 
+    let (cosmwasm_query, cosmwasm_conversions) = cosmwasm_ext(&ident, &type_url, &input.attrs);
+
+    let amino_json = amino_ext(&ident, &input.data, &input.attrs);
+    let proto_json = proto_json_ext(&ident, &input.data);
+
+    let msg_with_response = match get_response_type(&input.attrs) {
+        Some(response_type) => quote! {
+            impl crate::msg::MsgWithResponse for #ident {
+                type Response = #response_type;
+            }
+        },
+        None => quote!(),
+    };
+
+    (quote! {
+        impl #ident {
+            pub const TYPE_URL: &'static str = #type_url;
+            #cosmwasm_query
+        }
+
+        #msg_with_response
+
+        #cosmwasm_conversions
+
+        #amino_json
+
+        #proto_json
+    })
+    .into()
+}
+
+/// Amino JSON of the message, and its amino type if it has one, behind the `amino` feature of the
+/// crate deriving them.
+fn amino_ext(
+    ident: &syn::Ident,
+    data: &syn::Data,
+    attrs: &[syn::Attribute],
+) -> proc_macro2::TokenStream {
+    let insert_fields = named_fields(data).into_iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let amino_attr = match get_amino_field_attr(&field.attrs) {
+            Ok(amino_attr) => amino_attr,
+            Err(e) => return e.to_compile_error(),
+        };
+        let name = match amino_attr.name {
+            Some(name) => name.value(),
+            None => field_ident.unraw().to_string(),
+        };
+
+        if has_prost_ident(&field.attrs, "oneof") {
+            return quote! {
+                crate::amino::insert_oneof_field(&mut fields, &self.#field_ident)?;
+            };
+        }
+
+        // `Vec<u8>` is a list of numbers to serde, but a base64 string in amino JSON
+        let value = if has_prost_ident(&field.attrs, "bytes") {
+            if has_prost_ident(&field.attrs, "repeated") {
+                quote!(&self.#field_ident.iter().map(|value| crate::amino::Bytes(value)).collect::<Vec<_>>())
+            } else {
+                quote!(&crate::amino::Bytes(&self.#field_ident))
+            }
+        } else {
+            quote!(&self.#field_ident)
+        };
+
+        if amino_attr.not_omitted {
+            quote! {
+                crate::amino::insert_required_field(&mut fields, #name, #value)?;
+            }
+        } else {
+            quote! {
+                crate::amino::insert_field(&mut fields, #name, #value)?;
+            }
+        }
+    });
+
+    let amino_type = get_amino_type(attrs);
+    let (amino_type_fn, amino_msg) = match &amino_type {
+        Some(amino_type) => (
+            quote! {
+                fn amino_type(&self) -> Option<&'static str> {
+                    Some(#amino_type)
+                }
+            },
+            quote! {
+                #[cfg(feature = "amino")]
+                impl crate::amino::AminoMsg for #ident {
+                    const AMINO_TYPE: &'static str = #amino_type;
+                }
+            },
+        ),
+        None => (quote!(), quote!()),
+    };
+
+    quote! {
+        #[cfg(feature = "amino")]
+        impl crate::amino::AminoJson for #ident {
+            fn to_amino_json(&self) -> Result<serde_json::Value, crate::Error> {
+                let mut fields = serde_json::Map::new();
+                #(#insert_fields)*
+                Ok(serde_json::Value::Object(fields))
+            }
+
+            #amino_type_fn
+        }
+
+        #amino_msg
+    }
+}
+
+/// Protobuf JSON of the message, behind the `proto-json` feature of the crate deriving it: fields
+/// are named in lowerCamelCase, the ones with default values are left out, and either names are
+/// accepted when decoding.
+fn proto_json_ext(ident: &syn::Ident, data: &syn::Data) -> proc_macro2::TokenStream {
+    let fields = named_fields(data);
+
+    let insert_fields = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let json_name = to_json_name(&field_ident.unraw().to_string());
+
+        match get_prost_enumeration(&field.attrs) {
+            Some(enumeration) if !has_prost_ident(&field.attrs, "repeated") => quote! {
+                crate::proto_json::insert_enum_field(&mut fields, #json_name, self.#field_ident, |value| {
+                    #enumeration::from_i32(value).map(|value| value.as_str_name())
+                })?;
+            },
+            _ if has_prost_ident(&field.attrs, "bytes") && has_prost_ident(&field.attrs, "repeated") => quote! {
+                crate::proto_json::insert_repeated_bytes_field(&mut fields, #json_name, &self.#field_ident)?;
+            },
+            _ if has_prost_ident(&field.attrs, "bytes") => quote! {
+                crate::proto_json::insert_bytes_field(&mut fields, #json_name, &self.#field_ident)?;
+            },
+            _ if has_prost_ident(&field.attrs, "oneof") => quote! {
+                crate::proto_json::insert_oneof_field(&mut fields, &self.#field_ident)?;
+            },
+            _ => quote! {
+                crate::proto_json::insert_field(&mut fields, #json_name, &self.#field_ident)?;
+            },
+        }
+    });
+
+    let take_fields = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let name = field_ident.unraw().to_string();
+        let json_name = to_json_name(&name);
+
+        let value = match get_prost_enumeration(&field.attrs) {
+            Some(enumeration) if !has_prost_ident(&field.attrs, "repeated") => quote! {
+                crate::proto_json::take_enum_field(&mut fields, #json_name, #name, |name| {
+                    #enumeration::from_str_name(name).map(|value| value as i32)
+                })?
+            },
+            _ if has_prost_ident(&field.attrs, "bytes")
+                && has_prost_ident(&field.attrs, "repeated") =>
+            {
+                quote! {
+                    crate::proto_json::take_repeated_bytes_field(&mut fields, #json_name, #name)?
+                }
+            }
+            _ if has_prost_ident(&field.attrs, "bytes") => quote! {
+                crate::proto_json::take_bytes_field(&mut fields, #json_name, #name)?
+            },
+            _ if has_prost_ident(&field.attrs, "oneof") => quote! {
+                crate::proto_json::take_oneof_field(&mut fields)?
+            },
+            _ => quote! {
+                crate::proto_json::take_field(&mut fields, #json_name, #name)?
+            },
+        };
+
+        quote!(#field_ident: #value,)
+    });
+
+    quote! {
+        #[cfg(feature = "proto-json")]
+        impl crate::proto_json::ProtoJson for #ident {
+            fn to_proto_json(&self) -> Result<serde_json::Value, crate::Error> {
+                let mut fields = serde_json::Map::new();
+                #(#insert_fields)*
+                Ok(serde_json::Value::Object(fields))
+            }
+
+            fn from_proto_json(value: serde_json::Value) -> Result<Self, crate::Error> {
+                let mut fields = crate::proto_json::into_fields(value, stringify!(#ident))?;
+                Ok(#ident {
+                    #(#take_fields)*
+                })
+            }
+        }
+    }
+}
+
+/// The enum that prost generates for a `oneof` only gets its JSON, in which the variant that is
+/// set is a field of the message that holds the oneof, named after the variant.
+fn oneof_ext(ident: &syn::Ident, data: &syn::DataEnum) -> proc_macro2::TokenStream {
+    let amino_json = {
+        let insert_variants = data.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let name = to_field_name(&variant_ident.unraw().to_string());
+
+            let value = if has_prost_ident(&variant.attrs, "bytes") {
+                quote!(&crate::amino::Bytes(value))
+            } else {
+                quote!(value)
+            };
+
+            quote! {
+                #ident::#variant_ident(value) => {
+                    fields.insert(#name.to_string(), crate::amino::AminoJson::to_amino_json(#value)?);
+                }
+            }
+        });
+
+        quote! {
+            #[cfg(feature = "amino")]
+            impl crate::amino::AminoJsonOneof for #ident {
+                fn insert_into(&self, fields: &mut serde_json::Map<String, serde_json::Value>) -> Result<(), crate::Error> {
+                    match self {
+                        #(#insert_variants)*
+                    }
+                    Ok(())
+                }
+            }
+        }
+    };
+
+    let proto_json = {
+        let insert_variants = data.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let json_name = to_json_name(&to_field_name(&variant_ident.unraw().to_string()));
+
+            let value = match get_prost_enumeration(&variant.attrs) {
+                Some(enumeration) => quote! {
+                    crate::proto_json::enum_to_proto_json(*value, |value| {
+                        #enumeration::from_i32(value).map(|value| value.as_str_name())
+                    })
+                },
+                None if has_prost_ident(&variant.attrs, "bytes") => quote! {
+                    crate::proto_json::bytes_to_proto_json(value)
+                },
+                None => quote! {
+                    crate::proto_json::ProtoJson::to_proto_json(value)?
+                },
+            };
+
+            quote! {
+                #ident::#variant_ident(value) => {
+                    fields.insert(#json_name.to_string(), #value);
+                }
+            }
+        });
+
+        let take_variants = data.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let name = to_field_name(&variant_ident.unraw().to_string());
+            let json_name = to_json_name(&name);
+
+            let value = match get_prost_enumeration(&variant.attrs) {
+                Some(enumeration) => quote! {
+                    crate::proto_json::enum_from_proto_json(value, #name, |name| {
+                        #enumeration::from_str_name(name).map(|value| value as i32)
+                    })?
+                },
+                None if has_prost_ident(&variant.attrs, "bytes") => quote! {
+                    crate::proto_json::bytes_from_proto_json(value, #name)?
+                },
+                None => quote! {
+                    crate::proto_json::ProtoJson::from_proto_json(value)?
+                },
+            };
+
+            quote! {
+                if let Some(value) = crate::proto_json::take_value(fields, #json_name, #name) {
+                    return Ok(Some(#ident::#variant_ident(#value)));
+                }
+            }
+        });
+
+        quote! {
+            #[cfg(feature = "proto-json")]
+            impl crate::proto_json::ProtoJsonOneof for #ident {
+                fn insert_into(&self, fields: &mut serde_json::Map<String, serde_json::Value>) -> Result<(), crate::Error> {
+                    match self {
+                        #(#insert_variants)*
+                    }
+                    Ok(())
+                }
+
+                fn take_from(fields: &mut serde_json::Map<String, serde_json::Value>) -> Result<Option<Self>, crate::Error> {
+                    #(#take_variants)*
+                    Ok(None)
+                }
+            }
+        }
+    };
+
+    quote! {
+        #amino_json
+
+        #proto_json
+    }
+}
+
+fn named_fields(data: &syn::Data) -> Vec<&syn::Field> {
+    match data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => fields.named.iter().collect(),
+        _ => vec![],
+    }
+}
+
+/// `json_name` that protoc gives to a field.
+fn to_json_name(name: &str) -> String {
+    let mut json_name = String::with_capacity(name.len());
+    let mut capitalize_next = false;
+    for c in name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            json_name.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            json_name.push(c);
+        }
+    }
+    json_name
+}
+
+/// Name of the field of a oneof variant, which prost names in upper camel case.
+fn to_field_name(variant: &str) -> String {
+    let mut name = String::with_capacity(variant.len() + 4);
+    for (i, c) in variant.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Tokens inside the `prost` attribute of a field or oneof variant, e.g. `bytes = "vec", tag = "1"`.
+fn prost_attr_tokens(attrs: &[syn::Attribute]) -> Vec<TokenTree> {
+    match get_attr("prost", attrs).and_then(|attr| attr.tokens.clone().into_iter().next()) {
+        Some(TokenTree::Group(group)) => group.stream().into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn has_prost_ident(attrs: &[syn::Attribute], ident: &str) -> bool {
+    prost_attr_tokens(attrs)
+        .iter()
+        .any(|tt| matches!(tt, TokenTree::Ident(i) if i == ident))
+}
+
+/// The enum of an `enumeration = "..."` field.
+fn get_prost_enumeration(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    let key_values: Vec<Vec<TokenTree>> = prost_attr_tokens(attrs)
+        .split(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ','))
+        .map(|kv| kv.to_vec())
+        .collect();
+
+    let enumeration = key_values
+        .iter()
+        .find_map(match_kv_attr!("enumeration", Literal))?;
+    syn::parse2::<syn::LitStr>(enumeration).ok()?.parse().ok()
+}
+
+/// `query` for queries, and conversions of the message into and from the CosmWasm types, behind
+/// the `cosmwasm` feature of the crate deriving them.
+fn cosmwasm_ext(
+    ident: &syn::Ident,
+    type_url: &proc_macro2::TokenStream,
+    attrs: &[syn::Attribute],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
     // `EncodeError` always indicates that a message failed to encode because the
     // provided buffer had insufficient capacity. Message encoding is otherwise
     // infallible.
 
-    let (query_request_conversion, cosmwasm_query) = if get_attr("proto_query", &input.attrs)
-        .is_some()
-    {
-        let path = get_query_attrs(&input.attrs, match_kv_attr!("path", Literal));
-        let res = get_query_attrs(&input.attrs, match_kv_attr!("response_type", Ident));
+    let (query_request_conversion, cosmwasm_query) = if get_attr("proto_query", attrs).is_some() {
+        let path = get_query_attrs(attrs, match_kv_attr!("path", Literal));
+        let res = get_query_attrs(attrs, match_kv_attr!("response_type", Ident));
 
         let query_request_conversion = quote! {
+            #[cfg(feature = "cosmwasm")]
             impl <Q: cosmwasm_std::CustomQuery> From<#ident> for cosmwasm_std::QueryRequest<Q> {
                 fn from(msg: #ident) -> Self {
                     cosmwasm_std::QueryRequest::<Q>::Stargate {
@@ -51,6 +436,7 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
         };
 
         let cosmwasm_query = quote! {
+            #[cfg(feature = "cosmwasm")]
             pub fn query(self, querier: &cosmwasm_std::QuerierWrapper<impl cosmwasm_std::CustomQuery>) -> cosmwasm_std::StdResult<#res> {
                 querier.query::<#res>(&self.into())
             }
@@ -61,14 +447,10 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
         (quote!(), quote!())
     };
 
-    (quote! {
-        impl #ident {
-            pub const TYPE_URL: &'static str = #type_url;
-            #cosmwasm_query
-        }
-
+    let cosmwasm_conversions = quote! {
         #query_request_conversion
 
+        #[cfg(feature = "cosmwasm")]
         impl From<#ident> for cosmwasm_std::Binary {
             fn from(msg: #ident) -> Self {
                 let mut bytes = Vec::new();
@@ -79,6 +461,7 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[cfg(feature = "cosmwasm")]
         impl<T> From<#ident> for cosmwasm_std::CosmosMsg<T> {
             fn from(msg: #ident) -> Self {
                 cosmwasm_std::CosmosMsg::<T>::Stargate {
@@ -88,6 +471,7 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[cfg(feature = "cosmwasm")]
         impl TryFrom<cosmwasm_std::Binary> for #ident {
             type Error = cosmwasm_std::StdError;
 
@@ -107,6 +491,7 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[cfg(feature = "cosmwasm")]
         impl TryFrom<cosmwasm_std::SubMsgResult> for #ident {
             type Error = cosmwasm_std::StdError;
 
@@ -121,32 +506,139 @@ pub fn derive_cosmwasm_ext(input: TokenStream) -> TokenStream {
                     .try_into()
             }
         }
-    }).into()
+    };
+
+    (cosmwasm_query, cosmwasm_conversions)
 }
 
-fn get_type_url(attrs: &Vec<syn::Attribute>) -> proc_macro2::TokenStream {
-    let proto_message = get_attr("proto_message", attrs).and_then(|a| a.parse_meta().ok());
+fn get_type_url(attrs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+    let proto_message = get_attr("proto_message", attrs);
+    let args = match get_proto_message_args(attrs) {
+        Some(Ok(args)) => args,
+        Some(Err(e)) => return e.to_compile_error(),
+        None => return proto_message_attr_error(proto_message),
+    };
+
+    if let Some(unexpected) = args.iter().skip(1).find(|arg| {
+        !matches!(
+            (arg.key.to_string().as_str(), &arg.value),
+            ("amino_type", ProtoMessageValue::Str(_))
+                | ("response_type", ProtoMessageValue::Type(_))
+        )
+    }) {
+        return proto_message_attr_error(&unexpected.key);
+    }
 
-    if let Some(syn::Meta::List(meta)) = proto_message.clone() {
-        match meta.nested[0].clone() {
-            syn::NestedMeta::Meta(syn::Meta::NameValue(meta)) => {
-                if meta.path.is_ident("type_url") {
-                    match meta.lit {
-                        syn::Lit::Str(s) => quote!(#s),
-                        _ => proto_message_attr_error(meta.lit),
-                    }
-                } else {
-                    proto_message_attr_error(meta.path)
-                }
+    match args.first() {
+        Some(ProtoMessageArg {
+            key,
+            value: ProtoMessageValue::Str(type_url),
+        }) if key == "type_url" => quote!(#type_url),
+        Some(arg) => proto_message_attr_error(&arg.key),
+        None => proto_message_attr_error(proto_message),
+    }
+}
+
+/// `amino_type`, which follows `type_url` in `proto_message` for messages registered with the
+/// legacy amino codec.
+fn get_amino_type(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    get_proto_message_args(attrs)?
+        .ok()?
+        .into_iter()
+        .find_map(|arg| match arg.value {
+            ProtoMessageValue::Str(s) if arg.key == "amino_type" => Some(s),
+            _ => None,
+        })
+}
+
+/// `response_type` in `proto_message`, the response of the `Msg` service method that takes the
+/// message.
+fn get_response_type(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    get_proto_message_args(attrs)?
+        .ok()?
+        .into_iter()
+        .find_map(|arg| match arg.value {
+            ProtoMessageValue::Type(path) if arg.key == "response_type" => Some(path),
+            _ => None,
+        })
+}
+
+/// `amino(name = "...", not_omitted)` of a field, which proto-build adds to the fields that are
+/// named differently in amino JSON, or that are encoded even when empty.
+#[derive(Default)]
+struct AminoFieldAttr {
+    name: Option<syn::LitStr>,
+    not_omitted: bool,
+}
+
+fn get_amino_field_attr(attrs: &[syn::Attribute]) -> syn::Result<AminoFieldAttr> {
+    let mut amino_attr = AminoFieldAttr::default();
+    let amino = match get_attr("amino", attrs) {
+        Some(amino) => amino,
+        None => return Ok(amino_attr),
+    };
+
+    let args = amino.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+    )?;
+    for arg in args {
+        match arg {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(name),
+                ..
+            }) if path.is_ident("name") => amino_attr.name = Some(name),
+            syn::Meta::Path(path) if path.is_ident("not_omitted") => amino_attr.not_omitted = true,
+            arg => {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `amino(name = \"...\", not_omitted)`",
+                ))
             }
-            t => proto_message_attr_error(t),
         }
-    } else {
-        proto_message_attr_error(proto_message)
     }
+
+    Ok(amino_attr)
+}
+
+/// `key = value` in `proto_message`.
+struct ProtoMessageArg {
+    key: syn::Ident,
+    value: ProtoMessageValue,
+}
+
+enum ProtoMessageValue {
+    Str(syn::LitStr),
+    Type(syn::Path),
+}
+
+impl syn::parse::Parse for ProtoMessageArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let value = if input.peek(syn::LitStr) {
+            ProtoMessageValue::Str(input.parse()?)
+        } else {
+            ProtoMessageValue::Type(input.parse()?)
+        };
+
+        Ok(ProtoMessageArg { key, value })
+    }
+}
+
+fn get_proto_message_args(attrs: &[syn::Attribute]) -> Option<syn::Result<Vec<ProtoMessageArg>>> {
+    let proto_message = get_attr("proto_message", attrs)?;
+
+    Some(
+        proto_message
+            .parse_args_with(
+                syn::punctuated::Punctuated::<ProtoMessageArg, syn::Token![,]>::parse_terminated,
+            )
+            .map(|args| args.into_iter().collect()),
+    )
 }
 
-fn get_query_attrs<F>(attrs: &Vec<syn::Attribute>, f: F) -> proc_macro2::TokenStream
+fn get_query_attrs<F>(attrs: &[syn::Attribute], f: F) -> proc_macro2::TokenStream
 where
     F: FnMut(&Vec<TokenTree>) -> Option<proc_macro2::TokenStream>,
 {
@@ -185,13 +677,19 @@ where
     }
 }
 
-fn get_attr<'a>(attr_ident: &str, attrs: &'a Vec<syn::Attribute>) -> Option<&'a syn::Attribute> {
-    attrs.iter().find(|&attr| attr.path.segments.len() == 1 && attr.path.segments[0].ident == attr_ident)
+fn get_attr<'a>(attr_ident: &str, attrs: &'a [syn::Attribute]) -> Option<&'a syn::Attribute> {
+    attrs
+        .iter()
+        .find(|&attr| attr.path.segments.len() == 1 && attr.path.segments[0].ident == attr_ident)
 }
 
 fn proto_message_attr_error<T: quote::ToTokens>(tokens: T) -> proc_macro2::TokenStream {
-    syn::Error::new_spanned(tokens, "expected `proto_message(type_url = \"...\")`")
-        .to_compile_error()
+    syn::Error::new_spanned(
+        tokens,
+        "expected `proto_message(type_url = \"...\")`, optionally followed by `amino_type = \"...\"` \
+         and `response_type = ...`",
+    )
+    .to_compile_error()
 }
 
 fn proto_query_attr_error<T: quote::ToTokens>(tokens: T) -> proc_macro2::TokenStream {
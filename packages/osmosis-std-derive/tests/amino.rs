@@ -0,0 +1,64 @@
+#![cfg(feature = "amino")]
+
+use osmosis_std::amino::{AminoJson, AminoMsg};
+use osmosis_std_derive::CosmwasmExt;
+
+include!("support/crate_root.rs");
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
+#[proto_message(
+    type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+    amino_type = "osmosis/tokenfactory/create-denom"
+)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub subdenom: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
+#[proto_message(type_url = "/osmosis.gamm.v1beta1.SwapAmountInRoute")]
+pub struct SwapAmountInRoute {
+    #[prost(uint64, tag = "1")]
+    #[amino(name = "poolId", not_omitted)]
+    pub pool_id: u64,
+    #[prost(string, repeated, tag = "2")]
+    #[amino(not_omitted)]
+    pub denoms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+
+fn main() {
+    assert_eq!(
+        MsgCreateDenom::AMINO_TYPE,
+        "osmosis/tokenfactory/create-denom"
+    );
+
+    let msg = MsgCreateDenom {
+        sender: "osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk".to_string(),
+        subdenom: "uxxx".to_string(),
+        metadata: vec![1, 2, 3],
+    };
+    assert_eq!(
+        msg.to_amino_msg().unwrap(),
+        serde_json::json!({
+            "type": "osmosis/tokenfactory/create-denom",
+            "value": {
+                "sender": "osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk",
+                "subdenom": "uxxx",
+                "metadata": "AQID",
+            },
+        })
+    );
+
+    let empty = MsgCreateDenom::default();
+    assert_eq!(empty.to_amino_json().unwrap(), serde_json::json!({}));
+
+    let route = SwapAmountInRoute::default();
+    assert_eq!(
+        route.to_amino_json().unwrap(),
+        serde_json::json!({"poolId": "0", "denoms": null})
+    );
+}
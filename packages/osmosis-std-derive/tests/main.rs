@@ -3,4 +3,9 @@ fn tests() {
     let t = trybuild::TestCases::new();
     t.pass("tests/struct.rs");
     t.pass("tests/query.rs");
+    t.pass("tests/msg.rs");
+    #[cfg(feature = "amino")]
+    t.pass("tests/amino.rs");
+    #[cfg(feature = "proto-json")]
+    t.pass("tests/proto_json.rs");
 }
@@ -0,0 +1,36 @@
+use osmosis_std_derive::CosmwasmExt;
+
+include!("support/crate_root.rs");
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
+#[proto_message(
+    type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+    response_type = MsgCreateDenomResponse
+)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    /// subdenom can be up to 44 "alphanumeric" characters long.
+    #[prost(string, tag = "2")]
+    pub subdenom: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
+#[proto_message(type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenomResponse")]
+pub struct MsgCreateDenomResponse {
+    #[prost(string, tag = "1")]
+    pub new_token_denom: ::prost::alloc::string::String,
+}
+
+fn main() {
+    let res: <MsgCreateDenom as msg::MsgWithResponse>::Response = MsgCreateDenomResponse {
+        new_token_denom:
+            "factory/osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk/uxxx"
+                .to_string(),
+    };
+
+    assert_eq!(
+        res.new_token_denom,
+        "factory/osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk/uxxx"
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "proto-json")]
+
+use osmosis_std::proto_json::{decode_proto_json, encode_proto_json};
+use osmosis_std_derive::CosmwasmExt;
+
+include!("support/crate_root.rs");
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
+#[proto_message(type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom")]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub sub_denom: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub metadata: ::prost::alloc::vec::Vec<u8>,
+}
+
+fn main() {
+    let msg = MsgCreateDenom {
+        sender: "osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk".to_string(),
+        sub_denom: "uxxx".to_string(),
+        metadata: vec![1, 2, 3],
+    };
+    let json = r#"{"metadata":"AQID","sender":"osmo1sr9zm2pq3xrru7l7gz632t2rqs9caet9xulwvapcqagq9pytkcgqwfc3nk","subDenom":"uxxx"}"#;
+
+    assert_eq!(encode_proto_json(&msg).unwrap(), json);
+    assert_eq!(decode_proto_json::<MsgCreateDenom>(json).unwrap(), msg);
+    // the proto names of the fields are accepted too
+    assert_eq!(
+        decode_proto_json::<MsgCreateDenom>(r#"{"sub_denom":"uxxx"}"#).unwrap(),
+        MsgCreateDenom {
+            sub_denom: "uxxx".to_string(),
+            ..Default::default()
+        }
+    );
+    assert_eq!(encode_proto_json(&MsgCreateDenom::default()).unwrap(), "{}");
+}
@@ -1,6 +1,8 @@
 use cosmwasm_std::{Empty, QueryRequest};
 use osmosis_std_derive::CosmwasmExt;
 
+include!("support/crate_root.rs");
+
 #[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize, CosmwasmExt)]
 #[proto_message(type_url = "/osmosis.tokenfactory.v1beta1.QueryDenomsFromCreatorRequest")]
 #[proto_query(
@@ -1,6 +1,8 @@
 use cosmwasm_std::CosmosMsg;
 use osmosis_std_derive::CosmwasmExt;
 
+include!("support/crate_root.rs");
+
 #[derive(Clone, PartialEq, Eq, ::prost::Message, CosmwasmExt)]
 #[proto_message(type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom")]
 pub struct MsgCreateDenom {
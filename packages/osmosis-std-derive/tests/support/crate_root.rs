@@ -0,0 +1,20 @@
+// The derived code refers to the items of osmosis-std through `crate::`, as it is meant to be
+// derived in there. Each test `include!`s this at its root, so that those paths resolve.
+
+#[allow(unused_imports)]
+use osmosis_std::Error;
+
+#[allow(unused_imports)]
+mod amino {
+    pub use osmosis_std::amino::*;
+}
+
+#[allow(unused_imports)]
+mod msg {
+    pub use osmosis_std::msg::*;
+}
+
+#[allow(unused_imports)]
+mod proto_json {
+    pub use osmosis_std::proto_json::*;
+}
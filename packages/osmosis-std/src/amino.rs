@@ -0,0 +1,467 @@
+//! Amino JSON, for signing messages with `SIGN_MODE_LEGACY_AMINO_JSON`.
+//!
+//! Messages that are registered with the legacy amino codec implement [`AminoMsg`], which wraps
+//! their amino JSON with the type name they are registered under. The messages of a transaction
+//! go into a [`StdSignDoc`], whose [`StdSignDoc::sign_bytes`] are what the signer signs.
+//!
+//! ```
+//! use osmosis_std::amino::{AminoMsg, StdFee, StdSignDoc};
+//! use osmosis_std::types::cosmos::bank::v1beta1::MsgSend;
+//! use osmosis_std::types::cosmos::base::v1beta1::Coin;
+//!
+//! let msg = MsgSend {
+//!     from_address: "cosmos1d9h8qat57ljhcm".to_string(),
+//!     to_address: "cosmos1da6hgur4wsmpnjyg".to_string(),
+//!     amount: vec![Coin {
+//!         denom: "atom".to_string(),
+//!         amount: 10u128.into(),
+//!     }],
+//! };
+//! // `TestMsgSendGetSignBytes` of the SDK's `x/bank/types`
+//! assert_eq!(
+//!     msg.to_amino_msg().unwrap().to_string(),
+//!     r#"{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"10","denom":"atom"}],"from_address":"cosmos1d9h8qat57ljhcm","to_address":"cosmos1da6hgur4wsmpnjyg"}}"#
+//! );
+//!
+//! let fee = Coin {
+//!     denom: "atom".to_string(),
+//!     amount: 150u128.into(),
+//! };
+//! let sign_doc = StdSignDoc::builder("1234", 3, 6)
+//!     .msg(&msg)
+//!     .fee(StdFee::new(vec![fee], 100_000))
+//!     .memo("memo")
+//!     .build()
+//!     .unwrap();
+//! // `TestStdSignBytes` of the SDK's `x/auth/legacy/legacytx`, holding the message above
+//! assert_eq!(
+//!     String::from_utf8(sign_doc.sign_bytes().unwrap()).unwrap(),
+//!     r#"{"account_number":"3","chain_id":"1234","fee":{"amount":[{"amount":"150","denom":"atom"}],"gas":"100000"},"memo":"memo","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"10","denom":"atom"}],"from_address":"cosmos1d9h8qat57ljhcm","to_address":"cosmos1da6hgur4wsmpnjyg"}}],"sequence":"6"}"#
+//! );
+//! ```
+
+use serde_json::{Map, Value};
+
+use crate::shim::{Any, Dec, Duration, Int, Timestamp};
+use crate::types::cosmos::base::v1beta1::Coin;
+use crate::Error;
+
+/// A value that can be encoded as amino JSON.
+///
+/// It is implemented for every generated message. Fields are named after their JSON tags, 64 bit
+/// integers are strings, and fields holding an empty value are left out, the same way the
+/// chain's `omitempty` JSON tags leave them out. Non-nullable fields have no `omitempty` tag,
+/// so they are always there: an empty list as `null` and a missing message with its fields empty.
+///
+/// ```
+/// use osmosis_std::amino::AminoMsg;
+/// use osmosis_std::shim::Duration;
+/// use osmosis_std::types::osmosis::lockup::MsgLockTokens;
+///
+/// let msg = MsgLockTokens {
+///     owner: "osmo1owner".to_string(),
+///     duration: Some(Duration {
+///         seconds: 86400,
+///         nanos: 0,
+///     }),
+///     coins: vec![],
+/// };
+/// assert_eq!(
+///     msg.to_amino_msg().unwrap().to_string(),
+///     r#"{"type":"osmosis/lockup/lock-tokens","value":{"coins":null,"duration":"86400000000000","owner":"osmo1owner"}}"#
+/// );
+/// ```
+pub trait AminoJson {
+    /// Encode this value as amino JSON.
+    fn to_amino_json(&self) -> Result<Value, Error>;
+
+    /// Returns `true` if a field holding this value is left out of amino JSON.
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Encode this value as amino JSON of a field that is never left out, which holds Go's zero
+    /// value of the field's type when it is empty.
+    #[doc(hidden)]
+    fn to_required_amino_json(&self) -> Result<Value, Error> {
+        self.to_amino_json()
+    }
+
+    #[doc(hidden)]
+    fn amino_type(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// A message that is registered with the legacy amino codec.
+pub trait AminoMsg: AminoJson {
+    /// Name the message is registered under, e.g. `osmosis/gamm/swap-exact-amount-in`.
+    const AMINO_TYPE: &'static str;
+
+    /// Encode this message as `{"type": AMINO_TYPE, "value": ...}`, which is how it appears in
+    /// [`StdSignDoc`].
+    fn to_amino_msg(&self) -> Result<Value, Error> {
+        Ok(amino_msg(Self::AMINO_TYPE, self.to_amino_json()?))
+    }
+}
+
+fn amino_msg(amino_type: &str, value: Value) -> Value {
+    let mut msg = Map::new();
+    msg.insert("type".to_string(), Value::String(amino_type.to_string()));
+    msg.insert("value".to_string(), value);
+    Value::Object(msg)
+}
+
+/// Insert `value` into `fields` as `name`, unless it is empty.
+#[doc(hidden)]
+pub fn insert_field<T: AminoJson + ?Sized>(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: &T,
+) -> Result<(), Error> {
+    if !value.is_empty() {
+        fields.insert(name.to_string(), value.to_amino_json()?);
+    }
+    Ok(())
+}
+
+/// Insert `value` into `fields` as `name`, even if it is empty.
+#[doc(hidden)]
+pub fn insert_required_field<T: AminoJson + ?Sized>(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: &T,
+) -> Result<(), Error> {
+    fields.insert(name.to_string(), value.to_required_amino_json()?);
+    Ok(())
+}
+
+/// The enum that holds the field of a `oneof` that is set, which is a field of the message
+/// that holds the oneof.
+#[doc(hidden)]
+pub trait AminoJsonOneof {
+    /// Insert the field that is set into `fields`.
+    fn insert_into(&self, fields: &mut Map<String, Value>) -> Result<(), Error>;
+}
+
+/// Insert the field of a `oneof` that is set, if any.
+#[doc(hidden)]
+pub fn insert_oneof_field<T: AminoJsonOneof>(
+    fields: &mut Map<String, Value>,
+    value: &Option<T>,
+) -> Result<(), Error> {
+    match value {
+        Some(value) => value.insert_into(fields),
+        None => Ok(()),
+    }
+}
+
+/// `bytes` field, which is encoded as base64.
+#[doc(hidden)]
+pub struct Bytes<'a>(pub &'a [u8]);
+
+impl AminoJson for Bytes<'_> {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        Ok(Value::String(base64::encode(self.0)))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl AminoJson for String {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        Ok(Value::String(self.clone()))
+    }
+
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+impl AminoJson for bool {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        Ok(Value::Bool(*self))
+    }
+
+    fn is_empty(&self) -> bool {
+        !*self
+    }
+}
+
+macro_rules! impl_amino_json_for_int {
+    ($($t:ty => |$n:ident| $to_json:expr),* $(,)?) => {
+        $(
+            impl AminoJson for $t {
+                fn to_amino_json(&self) -> Result<Value, Error> {
+                    let $n = *self;
+                    Ok($to_json)
+                }
+
+                fn is_empty(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_amino_json_for_int! {
+    i32 => |n| Value::from(n),
+    u32 => |n| Value::from(n),
+    i64 => |n| Value::String(n.to_string()),
+    u64 => |n| Value::String(n.to_string()),
+}
+
+impl<T: AminoJson + Default> AminoJson for Option<T> {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        match self {
+            Some(value) => value.to_amino_json(),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn to_required_amino_json(&self) -> Result<Value, Error> {
+        match self {
+            Some(value) => value.to_amino_json(),
+            None => T::default().to_amino_json(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Some(value) => value.is_empty(),
+            None => true,
+        }
+    }
+}
+
+impl<T: AminoJson> AminoJson for Vec<T> {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        self.iter()
+            .map(T::to_amino_json)
+            .collect::<Result<_, _>>()
+            .map(Value::Array)
+    }
+
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+
+    /// A Go slice decoded from an empty list is `nil`, which is `null`.
+    fn to_required_amino_json(&self) -> Result<Value, Error> {
+        if self.is_empty() {
+            Ok(Value::Null)
+        } else {
+            self.to_amino_json()
+        }
+    }
+}
+
+impl AminoJson for Dec {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        Ok(Value::String(self.to_string()))
+    }
+}
+
+impl AminoJson for Int {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        Ok(Value::String(self.to_string()))
+    }
+}
+
+/// RFC 3339 with as many fractional digits as needed, which is how Go formats `time.Time`.
+impl AminoJson for Timestamp {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        let mut ts = prost_types::Timestamp::from(self.clone());
+        ts.normalize();
+
+        let ts = ts.to_string();
+        let ts = match ts.strip_suffix('Z').and_then(|ts| ts.split_once('.')) {
+            Some((seconds, fractional)) => {
+                format!("{}.{}Z", seconds, fractional.trim_end_matches('0'))
+            }
+            None => ts,
+        };
+        Ok(Value::String(ts))
+    }
+}
+
+/// Nanoseconds, which is how Go encodes `time.Duration`.
+impl AminoJson for Duration {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        let nanos = i128::from(self.seconds) * 1_000_000_000 + i128::from(self.nanos);
+        Ok(Value::String(nanos.to_string()))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.seconds == 0 && self.nanos == 0
+    }
+}
+
+/// The message held by `Any`, looked up in [`crate::registry`], as `{"type": ..., "value": ...}`.
+impl AminoJson for Any {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        let msg = crate::registry::decode(self)?;
+        let msg = msg.as_amino_json();
+        let amino_type = msg.amino_type().ok_or_else(|| {
+            Error::not_found(format!("amino type registered for `{}`", self.type_url))
+        })?;
+
+        Ok(amino_msg(amino_type, msg.to_amino_json()?))
+    }
+}
+
+/// Fee of a [`StdSignDoc`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StdFee {
+    pub amount: Vec<Coin>,
+    pub gas: u64,
+    pub payer: String,
+    pub granter: String,
+}
+
+impl StdFee {
+    pub fn new(amount: Vec<Coin>, gas: u64) -> Self {
+        StdFee {
+            amount,
+            gas,
+            ..Default::default()
+        }
+    }
+}
+
+impl AminoJson for StdFee {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        let mut fields = Map::new();
+        // always present, even without any coins
+        fields.insert("amount".to_string(), self.amount.to_amino_json()?);
+        fields.insert("gas".to_string(), Value::String(self.gas.to_string()));
+        insert_field(&mut fields, "payer", &self.payer)?;
+        insert_field(&mut fields, "granter", &self.granter)?;
+        Ok(Value::Object(fields))
+    }
+}
+
+/// The document that is signed with `SIGN_MODE_LEGACY_AMINO_JSON`.
+///
+/// `msgs` holds the messages as encoded by [`AminoMsg::to_amino_msg`].
+/// Use [`StdSignDoc::builder`] to build one out of messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StdSignDoc {
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub timeout_height: u64,
+    pub fee: StdFee,
+    pub msgs: Vec<Value>,
+    pub memo: String,
+}
+
+impl StdSignDoc {
+    pub fn builder(
+        chain_id: impl Into<String>,
+        account_number: u64,
+        sequence: u64,
+    ) -> StdSignDocBuilder {
+        StdSignDocBuilder {
+            sign_doc: StdSignDoc {
+                chain_id: chain_id.into(),
+                account_number,
+                sequence,
+                timeout_height: 0,
+                fee: StdFee::default(),
+                msgs: vec![],
+                memo: String::new(),
+            },
+            error: None,
+        }
+    }
+
+    /// Bytes to sign, which are the amino JSON of the document with sorted keys
+    /// and `&`, `<` and `>` escaped, as the chain verifies them.
+    pub fn sign_bytes(&self) -> Result<Vec<u8>, Error> {
+        let json = serde_json::to_string(&sort_keys(self.to_amino_json()?))
+            .map_err(|e| Error::parse_err("StdSignDoc", e))?;
+
+        Ok(json
+            .replace('&', "\\u0026")
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .into_bytes())
+    }
+}
+
+impl AminoJson for StdSignDoc {
+    fn to_amino_json(&self) -> Result<Value, Error> {
+        let mut fields = Map::new();
+        fields.insert(
+            "account_number".to_string(),
+            self.account_number.to_amino_json()?,
+        );
+        fields.insert("chain_id".to_string(), self.chain_id.to_amino_json()?);
+        fields.insert("fee".to_string(), self.fee.to_amino_json()?);
+        fields.insert("memo".to_string(), self.memo.to_amino_json()?);
+        fields.insert("msgs".to_string(), Value::Array(self.msgs.clone()));
+        fields.insert("sequence".to_string(), self.sequence.to_amino_json()?);
+        insert_field(&mut fields, "timeout_height", &self.timeout_height)?;
+        Ok(Value::Object(fields))
+    }
+}
+
+/// Builder of [`StdSignDoc`]. The first message that fails to encode is
+/// returned as an error by [`StdSignDocBuilder::build`].
+#[derive(Clone, Debug)]
+pub struct StdSignDocBuilder {
+    sign_doc: StdSignDoc,
+    error: Option<Error>,
+}
+
+impl StdSignDocBuilder {
+    pub fn msg<M: AminoMsg>(mut self, msg: &M) -> Self {
+        match msg.to_amino_msg() {
+            Ok(msg) => self.sign_doc.msgs.push(msg),
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+        self
+    }
+
+    pub fn fee(mut self, fee: StdFee) -> Self {
+        self.sign_doc.fee = fee;
+        self
+    }
+
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.sign_doc.memo = memo.into();
+        self
+    }
+
+    pub fn timeout_height(mut self, timeout_height: u64) -> Self {
+        self.sign_doc.timeout_height = timeout_height;
+        self
+    }
+
+    pub fn build(self) -> Result<StdSignDoc, Error> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.sign_doc),
+        }
+    }
+}
+
+/// `value` with the keys of every object in sorted order, which `serde_json` only
+/// keeps without its `preserve_order` feature.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => {
+            let mut fields: Vec<_> = fields.into_iter().collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(fields.into_iter().map(|(k, v)| (k, sort_keys(v))).collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
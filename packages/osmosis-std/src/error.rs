@@ -0,0 +1,51 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Errors of the parts of this crate that do not depend on CosmWasm.
+///
+/// The variants mirror `cosmwasm_std::StdError`, which it converts into
+/// with the `cosmwasm` feature, so `?` works the same in contract code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    ParseErr { target_type: String, msg: String },
+    NotFound { kind: String },
+}
+
+impl Error {
+    pub fn parse_err(target_type: impl Into<String>, msg: impl ToString) -> Self {
+        Error::ParseErr {
+            target_type: target_type.into(),
+            msg: msg.to_string(),
+        }
+    }
+
+    pub fn not_found(kind: impl Into<String>) -> Self {
+        Error::NotFound { kind: kind.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ParseErr { target_type, msg } => {
+                write!(f, "Error parsing into type {}: {}", target_type, msg)
+            }
+            Error::NotFound { kind } => write!(f, "{} not found", kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "cosmwasm")]
+impl From<Error> for cosmwasm_std::StdError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::ParseErr { target_type, msg } => {
+                cosmwasm_std::StdError::parse_err(target_type, msg)
+            }
+            Error::NotFound { kind } => cosmwasm_std::StdError::not_found(kind),
+        }
+    }
+}
@@ -0,0 +1,459 @@
+//! Weighted constant function market maker, ported from the balancer pool model.
+
+use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+
+use super::common::{calc_exit_pool, maximal_exact_ratio_join, sorted_coins};
+use super::fixed::SdkDec;
+use super::Amm;
+use crate::gamm::PoolI;
+use crate::types::osmosis::gamm::v1beta1::Pool;
+
+/// Precision to which the fractional part of an exponent is approximated.
+const POW_PRECISION: u128 = 10_000_000_000; // 10^-8
+
+struct Asset {
+    balance: SdkDec,
+    weight: SdkDec,
+}
+
+impl Amm for Pool {
+    fn calc_out_amt_given_in(&self, token_in: &Coin, token_out_denom: &str) -> StdResult<Coin> {
+        let swap_fee = SdkDec::from_decimal(self.swap_fee()?);
+        let asset_in = self.asset(&token_in.denom)?;
+        let asset_out = self.asset(token_out_denom)?;
+
+        let token_in_after_fee =
+            SdkDec::from_int(token_in.amount)?.checked_mul(SdkDec::one().checked_sub(swap_fee)?)?;
+        let token_out = solve_constant_function_invariant(
+            asset_in.balance,
+            asset_in.balance.checked_add(token_in_after_fee)?,
+            asset_in.weight,
+            asset_out.balance,
+            asset_out.weight,
+        )?
+        .to_uint128()?;
+
+        if token_out.is_zero() {
+            return Err(StdError::generic_err("token amount must be positive"));
+        }
+
+        Ok(Coin::new(token_out.u128(), token_out_denom))
+    }
+
+    fn calc_in_amt_given_out(&self, token_out: &Coin, token_in_denom: &str) -> StdResult<Coin> {
+        let swap_fee = SdkDec::from_decimal(self.swap_fee()?);
+        let asset_in = self.asset(token_in_denom)?;
+        let asset_out = self.asset(&token_out.denom)?;
+
+        let token_out_amount = SdkDec::from_int(token_out.amount)?;
+        if token_out_amount >= asset_out.balance {
+            return Err(StdError::generic_err(
+                "token out amount must be less than the pool reserve",
+            ));
+        }
+
+        let token_in = solve_constant_function_invariant(
+            asset_out.balance,
+            asset_out.balance.checked_sub(token_out_amount)?,
+            asset_out.weight,
+            asset_in.balance,
+            asset_in.weight,
+        )?;
+
+        // the invariant is followed by `token_in * (1 - swap_fee)`, so the fee is added back here
+        let token_in_before_fee = token_in
+            .checked_div(SdkDec::one().checked_sub(swap_fee)?)?
+            .ceil()?
+            .to_uint128()?;
+
+        Ok(Coin::new(token_in_before_fee.u128(), token_in_denom))
+    }
+
+    fn calc_join_pool_shares(&self, tokens_in: &[Coin]) -> StdResult<(Uint128, Vec<Coin>)> {
+        let tokens_in = sorted_coins(tokens_in)?;
+        let swap_fee = SdkDec::from_decimal(self.swap_fee()?);
+        let mut reserves = self.reserves()?;
+        let mut total_shares = self.total_shares()?.amount;
+
+        if tokens_in.len() == 1 {
+            let num_shares =
+                self.calc_single_asset_join(&tokens_in[0], swap_fee, &reserves, total_shares)?;
+            return Ok((num_shares, tokens_in));
+        } else if tokens_in.len() != reserves.len() {
+            return Err(StdError::generic_err(
+                "balancer pool only supports LP'ing with one asset or all assets in pool",
+            ));
+        }
+
+        let (mut num_shares, rem_coins) =
+            maximal_exact_ratio_join(&reserves, total_shares, &tokens_in)?;
+
+        add_to_reserves(&mut reserves, &tokens_in)?;
+        sub_from_reserves(&mut reserves, &rem_coins)?;
+        total_shares = total_shares.checked_add(num_shares)?;
+
+        // coins that could not be joined at the exact ratio are joined one by one
+        for coin in &rem_coins {
+            let new_shares =
+                self.calc_single_asset_join(coin, swap_fee, &reserves, total_shares)?;
+
+            add_to_reserves(&mut reserves, std::slice::from_ref(coin))?;
+            total_shares = total_shares.checked_add(new_shares)?;
+            num_shares = num_shares.checked_add(new_shares)?;
+        }
+
+        Ok((num_shares, tokens_in))
+    }
+
+    fn calc_exit_pool_coins_from_shares(&self, exiting_shares: Uint128) -> StdResult<Vec<Coin>> {
+        let mut reserves = self.reserves()?;
+        reserves.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        calc_exit_pool(
+            &reserves,
+            self.total_shares()?.amount,
+            exiting_shares,
+            self.exit_fee()?,
+        )
+    }
+}
+
+impl Pool {
+    fn asset(&self, denom: &str) -> StdResult<Asset> {
+        let (token, weight) = self
+            .pool_assets
+            .iter()
+            .find_map(|asset| {
+                asset
+                    .token
+                    .as_ref()
+                    .filter(|token| token.denom == denom)
+                    .map(|token| (token, &asset.weight))
+            })
+            .ok_or_else(|| StdError::generic_err(format!("denom {} is not in the pool", denom)))?;
+
+        Ok(Asset {
+            balance: SdkDec::from_int(Uint128::try_from(&token.amount)?)?,
+            weight: SdkDec::from_int(Uint128::try_from(weight)?)?,
+        })
+    }
+
+    /// `calcSingleAssetJoin` against the intermediary `reserves` and `total_shares`.
+    fn calc_single_asset_join(
+        &self,
+        token_in: &Coin,
+        swap_fee: SdkDec,
+        reserves: &[Coin],
+        total_shares: Uint128,
+    ) -> StdResult<Uint128> {
+        let total_weight = SdkDec::from_int(Uint128::try_from(&self.total_weight)?)?;
+        if total_weight.is_zero() {
+            return Err(StdError::generic_err(
+                "pool misconfigured, total weight = 0",
+            ));
+        }
+
+        let balance = super::common::reserve_of(reserves, &token_in.denom)?;
+        let normalized_weight = self
+            .asset(&token_in.denom)?
+            .weight
+            .checked_div(total_weight)?;
+
+        calc_pool_shares_out_given_single_asset_in(
+            SdkDec::from_int(balance)?,
+            normalized_weight,
+            SdkDec::from_int(total_shares)?,
+            SdkDec::from_int(token_in.amount)?,
+            swap_fee,
+        )?
+        .to_uint128()
+    }
+}
+
+/// `balance_unknown_before * (1 - (balance_fixed_before / balance_fixed_after) ^ (weight_fixed / weight_unknown))`
+///
+/// Only the absolute value is returned, which is the amount out of the unknown asset
+/// when the fixed balance increases, and the amount in when it decreases.
+fn solve_constant_function_invariant(
+    token_balance_fixed_before: SdkDec,
+    token_balance_fixed_after: SdkDec,
+    token_weight_fixed: SdkDec,
+    token_balance_unknown_before: SdkDec,
+    token_weight_unknown: SdkDec,
+) -> StdResult<SdkDec> {
+    let weight_ratio = token_weight_fixed.checked_div(token_weight_unknown)?;
+    let y = token_balance_fixed_before.checked_div(token_balance_fixed_after)?;
+    let y_to_weight_ratio = pow(y, weight_ratio)?;
+    let (paranthetical, _) = SdkDec::one().abs_diff(y_to_weight_ratio);
+
+    token_balance_unknown_before.checked_mul(paranthetical)
+}
+
+/// Shares minted for adding `token_amount_in` of a single asset, which is the same as swapping
+/// the part of it that is not at the pool's ratio, so swap fee is charged on that part only.
+fn calc_pool_shares_out_given_single_asset_in(
+    token_balance_in: SdkDec,
+    normalized_token_weight_in: SdkDec,
+    pool_shares: SdkDec,
+    token_amount_in: SdkDec,
+    swap_fee: SdkDec,
+) -> StdResult<SdkDec> {
+    let fee_ratio = SdkDec::one().checked_sub(
+        SdkDec::one()
+            .checked_sub(normalized_token_weight_in)?
+            .checked_mul(swap_fee)?,
+    )?;
+    let token_amount_in_after_fee = token_amount_in.checked_mul(fee_ratio)?;
+
+    solve_constant_function_invariant(
+        token_balance_in.checked_add(token_amount_in_after_fee)?,
+        token_balance_in,
+        normalized_token_weight_in,
+        pool_shares,
+        SdkDec::one(),
+    )
+}
+
+/// `osmomath.Pow`, `base ^ exp` for `0 < base < 2`, where the integer part of the exponent
+/// is computed exactly and the fractional part is approximated.
+fn pow(base: SdkDec, exp: SdkDec) -> StdResult<SdkDec> {
+    if base.is_zero() {
+        return Err(StdError::generic_err("base must be greater than 0"));
+    }
+    if base >= SdkDec::from_int(2u128)? {
+        return Err(StdError::generic_err("base must be lesser than two"));
+    }
+
+    let integer = exp.truncate();
+    let fractional = exp.checked_sub(integer)?;
+    let integer_pow = base.checked_pow(
+        u64::try_from(exp.to_uint128()?.u128())
+            .map_err(|_| StdError::generic_err("exponent is too large"))?,
+    )?;
+
+    if fractional.is_zero() {
+        return Ok(integer_pow);
+    }
+
+    let fractional_pow = pow_approx(base, fractional, SdkDec::from_atomics(POW_PRECISION))?;
+    integer_pow.checked_mul(fractional_pow)
+}
+
+/// `osmomath.PowApprox`, `base ^ exp` for `0 <= exp < 1` from the binomial series
+/// `(1 + x) ^ a = 1 + a x + a (a - 1) x^2 / 2! + ...`, until a term is below `precision`.
+fn pow_approx(base: SdkDec, exp: SdkDec, precision: SdkDec) -> StdResult<SdkDec> {
+    if exp.is_zero() {
+        return Ok(SdkDec::one());
+    }
+
+    if exp == SdkDec::one().checked_div_int(2u128)? {
+        return base.approx_sqrt();
+    }
+
+    let (x, x_negative) = base.abs_diff(SdkDec::one());
+    let mut term = SdkDec::one();
+    let mut sum = SdkDec::one();
+    let mut negative = false;
+
+    let mut i = 1u128;
+    while term >= precision {
+        let big_k = SdkDec::from_int(i)?;
+        let (c, c_negative) = exp.abs_diff(big_k.checked_sub(SdkDec::one())?);
+        term = term.checked_mul(c.checked_mul(x)?)?;
+        term = term.checked_div(big_k)?;
+
+        if term.is_zero() {
+            break;
+        }
+        if x_negative {
+            negative = !negative;
+        }
+        if c_negative {
+            negative = !negative;
+        }
+
+        sum = if negative {
+            sum.checked_sub(term)?
+        } else {
+            sum.checked_add(term)?
+        };
+        i += 1;
+    }
+
+    Ok(sum)
+}
+
+fn add_to_reserves(reserves: &mut [Coin], coins: &[Coin]) -> StdResult<()> {
+    for coin in coins {
+        let reserve = reserve_mut(reserves, &coin.denom)?;
+        reserve.amount = reserve.amount.checked_add(coin.amount)?;
+    }
+    Ok(())
+}
+
+fn sub_from_reserves(reserves: &mut [Coin], coins: &[Coin]) -> StdResult<()> {
+    for coin in coins {
+        let reserve = reserve_mut(reserves, &coin.denom)?;
+        reserve.amount = reserve.amount.checked_sub(coin.amount)?;
+    }
+    Ok(())
+}
+
+fn reserve_mut<'a>(reserves: &'a mut [Coin], denom: &str) -> StdResult<&'a mut Coin> {
+    reserves
+        .iter_mut()
+        .find(|reserve| reserve.denom == denom)
+        .ok_or_else(|| StdError::generic_err(format!("denom {} is not in the pool", denom)))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Decimal;
+
+    use super::*;
+    use crate::types::osmosis::gamm::v1beta1::{PoolAsset, PoolParams};
+
+    fn dec(value: &str) -> SdkDec {
+        SdkDec::from_decimal(value.parse().unwrap())
+    }
+
+    fn assert_approx_eq(actual: SdkDec, expected: SdkDec, tolerance: SdkDec) {
+        let (diff, _) = actual.abs_diff(expected);
+        assert!(
+            diff <= tolerance,
+            "expected {:?} to be within {:?} of {:?}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    fn pool(assets: &[(u128, &str, u128)], swap_fee: Decimal) -> Pool {
+        Pool {
+            address: "osmo1pool".to_string(),
+            id: 1,
+            pool_params: Some(PoolParams {
+                swap_fee: swap_fee.into(),
+                exit_fee: Decimal::zero().into(),
+                smooth_weight_change_params: None,
+            }),
+            future_pool_governor: "24h".to_string(),
+            total_shares: Some(Coin::new(100_000_000_000_000_000_000, "gamm/pool/1").into()),
+            pool_assets: assets
+                .iter()
+                .map(|&(amount, denom, weight)| PoolAsset {
+                    token: Some(Coin::new(amount, denom).into()),
+                    weight: weight.into(),
+                })
+                .collect(),
+            total_weight: assets
+                .iter()
+                .map(|&(_, _, weight)| weight)
+                .sum::<u128>()
+                .into(),
+        }
+    }
+
+    #[test]
+    fn pow_vectors() {
+        // `TestPow` of osmomath
+        let precision = SdkDec::from_atomics(POW_PRECISION);
+        assert_approx_eq(
+            pow(dec("1.68"), dec("0.32")).unwrap(),
+            dec("1.18058965"),
+            precision,
+        );
+
+        // integer exponents are exact
+        assert_eq!(pow(dec("1.5"), dec("3")).unwrap(), dec("3.375"));
+
+        assert!(pow(dec("0"), dec("0.5")).is_err());
+        assert!(pow(dec("2"), dec("0.5")).is_err());
+    }
+
+    #[test]
+    fn pow_approx_vectors() {
+        // `TestPowApprox` of osmomath
+        let precision = SdkDec::from_atomics(POW_PRECISION);
+        assert_approx_eq(
+            pow_approx(dec("0.8"), dec("0.32"), precision).unwrap(),
+            dec("0.93108385"),
+            precision,
+        );
+        assert_approx_eq(
+            pow_approx(dec("1.1"), dec("1.5"), precision).unwrap(),
+            dec("1.15368973"),
+            precision,
+        );
+
+        assert_eq!(
+            pow_approx(dec("0.8"), dec("0"), precision).unwrap(),
+            dec("1")
+        );
+        // a half is computed as a square root
+        assert_eq!(
+            pow_approx(dec("2"), dec("0.5"), precision).unwrap(),
+            dec("1.414213562373095049")
+        );
+    }
+
+    #[test]
+    fn calc_out_given_in() {
+        // `TestCalcOutGivenIn` of the balancer pool model
+        let token_in_after_fee = dec("40").checked_mul(dec("0.99")).unwrap();
+        let token_out = solve_constant_function_invariant(
+            dec("100"),
+            dec("100").checked_add(token_in_after_fee).unwrap(),
+            dec("0.1"),
+            dec("200"),
+            dec("0.3"),
+        )
+        .unwrap();
+
+        let tolerance = SdkDec::from_atomics(POW_PRECISION * 10_000);
+        assert_approx_eq(token_out, dec("21.0487006"), tolerance);
+    }
+
+    #[test]
+    fn calc_in_given_out() {
+        // `TestCalcInGivenOut` of the balancer pool model
+        let token_in = solve_constant_function_invariant(
+            dec("200"),
+            dec("200").checked_sub(dec("70")).unwrap(),
+            dec("0.3"),
+            dec("100"),
+            dec("0.1"),
+        )
+        .unwrap()
+        .checked_div(dec("0.99"))
+        .unwrap();
+
+        let tolerance = SdkDec::from_atomics(POW_PRECISION * 10_000);
+        assert_approx_eq(token_in, dec("266.8009177"), tolerance);
+    }
+
+    #[test]
+    fn swap_with_equal_weights() {
+        let pool = pool(
+            &[(1_000_000, "uatom", 100), (1_000_000, "uosmo", 100)],
+            Decimal::zero(),
+        );
+
+        // 1_000_000 * (1 - 1_000_000 / 1_010_000) = 9900.99..
+        assert_eq!(
+            pool.calc_out_amt_given_in(&Coin::new(10_000, "uatom"), "uosmo")
+                .unwrap(),
+            Coin::new(9_900, "uosmo")
+        );
+        // 1_000_000 * (1_000_000 / 990_100 - 1) = 9998.98.., rounded up
+        assert_eq!(
+            pool.calc_in_amt_given_out(&Coin::new(9_900, "uosmo"), "uatom")
+                .unwrap(),
+            Coin::new(9_999, "uatom")
+        );
+
+        assert!(pool
+            .calc_in_amt_given_out(&Coin::new(1_000_000, "uosmo"), "uatom")
+            .is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Join and exit calculations shared by all pool models, ported from `cfmm_common`.
+
+use cosmwasm_std::{Coin, Decimal, StdError, StdResult, Uint128};
+
+use super::fixed::SdkDec;
+
+/// `MaximalExactRatioJoin`, join with as much of `tokens_in` as can be added without changing
+/// the ratio of the reserves. Returns the number of shares and the coins that could not be joined.
+pub(super) fn maximal_exact_ratio_join(
+    reserves: &[Coin],
+    total_shares: Uint128,
+    tokens_in: &[Coin],
+) -> StdResult<(Uint128, Vec<Coin>)> {
+    let share_ratios = tokens_in
+        .iter()
+        .map(|coin| {
+            let reserve = reserve_of(reserves, &coin.denom)?;
+            SdkDec::from_int(coin.amount)?.checked_div_int(reserve)
+        })
+        .collect::<StdResult<Vec<SdkDec>>>()?;
+
+    let min_share_ratio = share_ratios
+        .iter()
+        .min()
+        .copied()
+        .ok_or_else(|| StdError::generic_err("no tokens to join with"))?;
+
+    // round down to never mint more shares than the share of liquidity added
+    let num_shares = min_share_ratio
+        .checked_mul_int(total_shares)?
+        .to_uint128()?;
+
+    let mut rem_coins = vec![];
+    for (coin, share_ratio) in tokens_in.iter().zip(share_ratios) {
+        if share_ratio == min_share_ratio {
+            continue;
+        }
+
+        let used_amount = min_share_ratio
+            .checked_mul_int(reserve_of(reserves, &coin.denom)?)?
+            .ceil()?
+            .to_uint128()?;
+        let rem_amount = coin.amount.checked_sub(used_amount)?;
+
+        if !rem_amount.is_zero() {
+            rem_coins.push(Coin::new(rem_amount.u128(), &coin.denom));
+        }
+    }
+
+    Ok((num_shares, rem_coins))
+}
+
+/// `CalcExitPool`, the coins returned for exiting `exiting_shares` after deducting the exit fee.
+pub(super) fn calc_exit_pool(
+    reserves: &[Coin],
+    total_shares: Uint128,
+    exiting_shares: Uint128,
+    exit_fee: Decimal,
+) -> StdResult<Vec<Coin>> {
+    if exiting_shares >= total_shares {
+        return Err(StdError::generic_err(
+            "cannot exit all shares in a pool, attempted to exit more than the total shares",
+        ));
+    }
+
+    let refunded_shares = SdkDec::one()
+        .checked_sub(SdkDec::from_decimal(exit_fee))?
+        .checked_mul_int(exiting_shares)?;
+    let share_out_ratio = refunded_shares.checked_div_int(total_shares)?;
+
+    let mut exited_coins = vec![];
+    for reserve in reserves {
+        // round down to never exit more than the share of liquidity
+        let exit_amount = share_out_ratio
+            .checked_mul_int(reserve.amount)?
+            .to_uint128()?;
+
+        if exit_amount.is_zero() {
+            continue;
+        }
+        if exit_amount >= reserve.amount {
+            return Err(StdError::generic_err(
+                "too many shares out, attempted to exit the entire pool reserve",
+            ));
+        }
+
+        exited_coins.push(Coin::new(exit_amount.u128(), &reserve.denom));
+    }
+
+    Ok(exited_coins)
+}
+
+pub(super) fn reserve_of(reserves: &[Coin], denom: &str) -> StdResult<Uint128> {
+    reserves
+        .iter()
+        .find(|reserve| reserve.denom == denom)
+        .map(|reserve| reserve.amount)
+        .ok_or_else(|| StdError::generic_err(format!("denom {} is not in the pool", denom)))
+}
+
+/// Sort `coins` by denom the way `sdk.Coins` are, rejecting duplicated denoms.
+pub(super) fn sorted_coins(coins: &[Coin]) -> StdResult<Vec<Coin>> {
+    let mut coins = coins.to_vec();
+    coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    if coins.windows(2).any(|pair| pair[0].denom == pair[1].denom) {
+        return Err(StdError::generic_err("duplicated denom in tokens in"));
+    }
+
+    Ok(coins)
+}
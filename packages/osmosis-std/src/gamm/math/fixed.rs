@@ -0,0 +1,295 @@
+use cosmwasm_std::{Decimal, StdError, StdResult, Uint128, Uint512};
+
+/// Non-negative fixed point decimal with `P` decimal places, which rounds the same way
+/// as `sdk.Dec` and `osmomath.BigDec` do, so that results match the chain to the last digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct Fixed<const P: u32>(Uint512);
+
+/// `sdk.Dec`
+pub(super) type SdkDec = Fixed<18>;
+
+/// `osmomath.BigDec`
+pub(super) type BigDec = Fixed<36>;
+
+impl<const P: u32> Fixed<P> {
+    fn precision() -> Uint512 {
+        Uint512::from(10u128.pow(P))
+    }
+
+    pub fn zero() -> Self {
+        Fixed(Uint512::zero())
+    }
+
+    pub fn one() -> Self {
+        Fixed(Self::precision())
+    }
+
+    /// Decimal from its raw representation, i.e. scaled by `10^P`.
+    pub fn from_atomics(atomics: impl Into<Uint512>) -> Self {
+        Fixed(atomics.into())
+    }
+
+    pub fn from_int(int: impl Into<Uint512>) -> StdResult<Self> {
+        Ok(Fixed(int.into().checked_mul(Self::precision())?))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn checked_add(self, other: Self) -> StdResult<Self> {
+        Ok(Fixed(self.0.checked_add(other.0)?))
+    }
+
+    pub fn checked_sub(self, other: Self) -> StdResult<Self> {
+        Ok(Fixed(self.0.checked_sub(other.0)?))
+    }
+
+    /// `|self - other|` and whether `self < other`.
+    pub fn abs_diff(self, other: Self) -> (Self, bool) {
+        if self < other {
+            (Fixed(other.0 - self.0), true)
+        } else {
+            (Fixed(self.0 - other.0), false)
+        }
+    }
+
+    /// `Mul`, rounds half to even.
+    pub fn checked_mul(self, other: Self) -> StdResult<Self> {
+        let mul = self.0.checked_mul(other.0)?;
+        Ok(Fixed(chop_precision_and_round(mul, Self::precision())))
+    }
+
+    /// `Quo`, rounds half to even.
+    pub fn checked_div(self, other: Self) -> StdResult<Self> {
+        let mul = self
+            .0
+            .checked_mul(Self::precision())?
+            .checked_mul(Self::precision())?;
+        let quo = mul.checked_div(other.0)?;
+        Ok(Fixed(chop_precision_and_round(quo, Self::precision())))
+    }
+
+    /// `MulInt`, which is exact.
+    pub fn checked_mul_int(self, int: impl Into<Uint512>) -> StdResult<Self> {
+        Ok(Fixed(self.0.checked_mul(int.into())?))
+    }
+
+    /// `QuoInt`, which truncates.
+    pub fn checked_div_int(self, int: impl Into<Uint512>) -> StdResult<Self> {
+        Ok(Fixed(self.0.checked_div(int.into())?))
+    }
+
+    /// `TruncateDec`
+    pub fn truncate(self) -> Self {
+        Fixed(self.truncate_int() * Self::precision())
+    }
+
+    /// `TruncateInt`
+    pub fn truncate_int(self) -> Uint512 {
+        self.0 / Self::precision()
+    }
+
+    /// `Ceil`
+    pub fn ceil(self) -> StdResult<Self> {
+        let truncated = self.truncate();
+        if truncated == self {
+            Ok(self)
+        } else {
+            truncated.checked_add(Self::one())
+        }
+    }
+
+    /// `Power`, exponentiation by squaring, rounding on every multiplication.
+    pub fn checked_pow(self, power: u64) -> StdResult<Self> {
+        if power == 0 {
+            return Ok(Self::one());
+        }
+
+        let mut base = self;
+        let mut tmp = Self::one();
+        let mut i = power;
+        while i > 1 {
+            if i & 1 == 1 {
+                tmp = tmp.checked_mul(base)?;
+            }
+            i /= 2;
+            base = base.checked_mul(base)?;
+        }
+        base.checked_mul(tmp)
+    }
+
+    /// `ApproxSqrt`, Newton's method as implemented by `ApproxRoot`.
+    pub fn approx_sqrt(self) -> StdResult<Self> {
+        const MAX_ITERATIONS: usize = 100;
+
+        if self.is_zero() || self == Self::one() {
+            return Ok(self);
+        }
+
+        let two = Uint512::from(2u128);
+        let mut guess = Self::one();
+        let mut delta = Self::one();
+        let mut iterations = 0;
+
+        while delta.0 > Uint512::one() && iterations < MAX_ITERATIONS {
+            // guess ^ (root - 1) is just `guess` for square root, but `Power` still rounds it
+            let prev = guess.checked_mul(Self::one())?;
+            let (diff, negative) = self.checked_div(prev)?.abs_diff(guess);
+            delta = diff.checked_div_int(two)?;
+            guess = if negative {
+                guess.checked_sub(delta)?
+            } else {
+                guess.checked_add(delta)?
+            };
+            iterations += 1;
+        }
+
+        Ok(guess)
+    }
+
+    pub fn to_uint128(self) -> StdResult<Uint128> {
+        Ok(Uint128::try_from(self.truncate_int())?)
+    }
+}
+
+impl SdkDec {
+    pub fn from_decimal(decimal: Decimal) -> Self {
+        Fixed::from_atomics(decimal.atomics())
+    }
+}
+
+impl BigDec {
+    /// `BigDecFromSDKDec`
+    pub fn from_sdk_dec(dec: SdkDec) -> StdResult<Self> {
+        Ok(Fixed(dec.0.checked_mul(SdkDec::precision())?))
+    }
+
+    /// `SDKDec`, which truncates the additional decimal places.
+    pub fn to_sdk_dec(self) -> SdkDec {
+        Fixed(self.0 / SdkDec::precision())
+    }
+
+    /// `DivIntByU64ToBigDec`, rounding either up or down.
+    pub fn from_int_div(int: Uint128, divisor: u64, round_up: bool) -> StdResult<Self> {
+        if divisor == 0 {
+            return Err(StdError::generic_err("scaling factor must not be zero"));
+        }
+
+        let numerator = Uint512::from(int).checked_mul(Self::precision())?;
+        let divisor = Uint512::from(divisor);
+        let quo = numerator / divisor;
+        if round_up && !(numerator % divisor).is_zero() {
+            Ok(Fixed(quo + Uint512::one()))
+        } else {
+            Ok(Fixed(quo))
+        }
+    }
+}
+
+/// Remove the decimal places added by a multiplication, rounding half to even.
+fn chop_precision_and_round(value: Uint512, precision: Uint512) -> Uint512 {
+    let quo = value / precision;
+    let rem = value % precision;
+    let half = precision / Uint512::from(2u128);
+
+    if rem.is_zero() || rem < half {
+        quo
+    } else if rem > half || !(quo % Uint512::from(2u128)).is_zero() {
+        quo + Uint512::one()
+    } else {
+        quo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> SdkDec {
+        SdkDec::from_decimal(value.parse().unwrap())
+    }
+
+    #[test]
+    fn approx_sqrt() {
+        // `TestApproxSqrt` of `sdk.Dec`
+        let cases = [
+            ("1", "1"),
+            ("0.25", "0.5"),
+            ("0.04", "0.2"),
+            ("9", "3"),
+            ("2", "1.414213562373095049"),
+            ("0.000000000000000001", "0.000000001"),
+            ("0.000000000000000003", "0.000000001732050808"),
+            ("0.004", "0.063245553203367587"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                dec(input).approx_sqrt().unwrap(),
+                dec(expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn checked_pow() {
+        // `TestPower` of `sdk.Dec`
+        let cases = [
+            ("3", 0, "1"),
+            ("3", 1, "3"),
+            ("3", 2, "9"),
+            ("0.2", 2, "0.04"),
+            ("3", 10, "59049"),
+            ("0.3", 10, "0.0000059049"),
+        ];
+
+        for (base, power, expected) in cases {
+            assert_eq!(dec(base).checked_pow(power).unwrap(), dec(expected));
+        }
+    }
+
+    #[test]
+    fn checked_mul_rounds_half_to_even() {
+        let half = dec("0.5");
+
+        assert_eq!(
+            dec("0.000000000000000001").checked_mul(half).unwrap(),
+            dec("0")
+        );
+        assert_eq!(
+            dec("0.000000000000000003").checked_mul(half).unwrap(),
+            dec("0.000000000000000002")
+        );
+        assert_eq!(
+            dec("0.000000000000000005").checked_mul(half).unwrap(),
+            dec("0.000000000000000002")
+        );
+        assert_eq!(
+            dec("0.000000000000000007").checked_mul(half).unwrap(),
+            dec("0.000000000000000004")
+        );
+    }
+
+    #[test]
+    fn from_int_div_rounds_in_the_given_direction() {
+        let down = BigDec::from_int_div(Uint128::new(10), 3, false).unwrap();
+        let up = BigDec::from_int_div(Uint128::new(10), 3, true).unwrap();
+
+        assert_eq!(
+            down,
+            BigDec::from_atomics(3_333_333_333_333_333_333_333_333_333_333_333_333u128)
+        );
+        assert_eq!(
+            up,
+            BigDec::from_atomics(3_333_333_333_333_333_333_333_333_333_333_333_334u128)
+        );
+        assert_eq!(
+            BigDec::from_int_div(Uint128::new(10), 2, true).unwrap(),
+            BigDec::from_int(5u128).unwrap()
+        );
+        assert!(BigDec::from_int_div(Uint128::new(10), 0, false).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Off-chain implementation of the gamm AMM math, which gives the same results as the chain
+//! without the gas cost of the estimation queries.
+
+mod balancer;
+mod common;
+mod fixed;
+mod stableswap;
+
+use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+
+use super::{Pool, PoolI};
+use crate::types::osmosis::gamm::v1beta1::{SwapAmountInRoute, SwapAmountOutRoute};
+
+/// Swap, join and exit calculations of a pool, using the pool's own swap fee and exit fee.
+pub trait Amm: PoolI {
+    /// Amount of `token_out_denom` out for swapping in `token_in`.
+    fn calc_out_amt_given_in(&self, token_in: &Coin, token_out_denom: &str) -> StdResult<Coin>;
+
+    /// Amount of `token_in_denom` that has to be swapped in to get `token_out` out.
+    fn calc_in_amt_given_out(&self, token_out: &Coin, token_in_denom: &str) -> StdResult<Coin>;
+
+    /// Number of shares minted for joining the pool with `tokens_in`, along with the tokens joined.
+    fn calc_join_pool_shares(&self, tokens_in: &[Coin]) -> StdResult<(Uint128, Vec<Coin>)>;
+
+    /// Coins out for exiting the pool with `exiting_shares`, after deducting the exit fee.
+    fn calc_exit_pool_coins_from_shares(&self, exiting_shares: Uint128) -> StdResult<Vec<Coin>>;
+}
+
+impl Amm for Pool {
+    fn calc_out_amt_given_in(&self, token_in: &Coin, token_out_denom: &str) -> StdResult<Coin> {
+        match self {
+            Pool::Balancer(pool) => pool.calc_out_amt_given_in(token_in, token_out_denom),
+            Pool::StableSwap(pool) => pool.calc_out_amt_given_in(token_in, token_out_denom),
+        }
+    }
+
+    fn calc_in_amt_given_out(&self, token_out: &Coin, token_in_denom: &str) -> StdResult<Coin> {
+        match self {
+            Pool::Balancer(pool) => pool.calc_in_amt_given_out(token_out, token_in_denom),
+            Pool::StableSwap(pool) => pool.calc_in_amt_given_out(token_out, token_in_denom),
+        }
+    }
+
+    fn calc_join_pool_shares(&self, tokens_in: &[Coin]) -> StdResult<(Uint128, Vec<Coin>)> {
+        match self {
+            Pool::Balancer(pool) => pool.calc_join_pool_shares(tokens_in),
+            Pool::StableSwap(pool) => pool.calc_join_pool_shares(tokens_in),
+        }
+    }
+
+    fn calc_exit_pool_coins_from_shares(&self, exiting_shares: Uint128) -> StdResult<Vec<Coin>> {
+        match self {
+            Pool::Balancer(pool) => pool.calc_exit_pool_coins_from_shares(exiting_shares),
+            Pool::StableSwap(pool) => pool.calc_exit_pool_coins_from_shares(exiting_shares),
+        }
+    }
+}
+
+/// Amount out of `MsgSwapExactAmountIn` with `token_in` and `routes`, looking up the pools by id in `pools`.
+///
+/// The swap fee discount that the chain gives to two hop routes through OSMO
+/// between incentivized pools is not taken into account.
+pub fn quote_swap_exact_amount_in(
+    pools: &[Pool],
+    token_in: &Coin,
+    routes: &[SwapAmountInRoute],
+) -> StdResult<Coin> {
+    routes.iter().try_fold(token_in.clone(), |token_in, route| {
+        find_pool(pools, route.pool_id)?.calc_out_amt_given_in(&token_in, &route.token_out_denom)
+    })
+}
+
+/// Amount in of `MsgSwapExactAmountOut` with `routes` and `token_out`, looking up the pools by id in `pools`.
+///
+/// The swap fee discount that the chain gives to two hop routes through OSMO
+/// between incentivized pools is not taken into account.
+pub fn quote_swap_exact_amount_out(
+    pools: &[Pool],
+    routes: &[SwapAmountOutRoute],
+    token_out: &Coin,
+) -> StdResult<Coin> {
+    routes
+        .iter()
+        .rev()
+        .try_fold(token_out.clone(), |token_out, route| {
+            find_pool(pools, route.pool_id)?
+                .calc_in_amt_given_out(&token_out, &route.token_in_denom)
+        })
+}
+
+fn find_pool(pools: &[Pool], pool_id: u64) -> StdResult<&Pool> {
+    pools
+        .iter()
+        .find(|pool| pool.id() == pool_id)
+        .ok_or_else(|| StdError::not_found(format!("pool {}", pool_id)))
+}
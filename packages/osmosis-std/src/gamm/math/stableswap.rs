@@ -0,0 +1,392 @@
+//! Solidly style stableswap market maker with scaling factors, ported from the stableswap pool model.
+//!
+//! The CFMM is `x * y * (x^2 + y^2 + w) = k`, where `x` and `y` are the scaled reserves of the assets
+//! being swapped and `w` is the sum of the squares of the other scaled reserves.
+//! It has no closed form solution, so the reserve after the swap is found by binary search.
+
+use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+
+use super::common::{calc_exit_pool, maximal_exact_ratio_join, sorted_coins};
+use super::fixed::{BigDec, SdkDec};
+use super::Amm;
+use crate::gamm::PoolI;
+use crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::Pool;
+
+const MAX_ITERATIONS: usize = 256;
+
+impl Amm for Pool {
+    fn calc_out_amt_given_in(&self, token_in: &Coin, token_out_denom: &str) -> StdResult<Coin> {
+        let one_minus_swap_fee = self.one_minus_swap_fee()?;
+
+        // round liquidity down, and token in down
+        let (token_in_supply, token_out_supply, rem_reserves) =
+            self.scaled_sorted_reserves(&token_in.denom, token_out_denom, false)?;
+        let token_in_amount = self.scale(&token_in.denom, token_in.amount, false)?;
+
+        let token_in_after_fee = token_in_amount.checked_mul(one_minus_swap_fee)?;
+        let cfmm_out = solve_cfmm_out(
+            token_out_supply,
+            token_in_supply,
+            &rem_reserves,
+            token_in_after_fee,
+        )?;
+
+        // round token out down
+        let token_out = self.descale(token_out_denom, cfmm_out)?.to_uint128()?;
+        if token_out.is_zero() {
+            return Err(StdError::generic_err("token amount must be positive"));
+        }
+
+        Ok(Coin::new(token_out.u128(), token_out_denom))
+    }
+
+    fn calc_in_amt_given_out(&self, token_out: &Coin, token_in_denom: &str) -> StdResult<Coin> {
+        let one_minus_swap_fee = self.one_minus_swap_fee()?;
+
+        // round liquidity down, and token out up
+        let (token_in_supply, token_out_supply, rem_reserves) =
+            self.scaled_sorted_reserves(token_in_denom, &token_out.denom, false)?;
+        let token_out_amount = self.scale(&token_out.denom, token_out.amount, true)?;
+
+        let cfmm_in = solve_cfmm_in(
+            token_in_supply,
+            token_out_supply,
+            &rem_reserves,
+            token_out_amount,
+        )?;
+        let token_in_before_fee = cfmm_in.checked_div(one_minus_swap_fee)?;
+
+        // round token in up
+        let token_in = self
+            .descale(token_in_denom, token_in_before_fee)?
+            .ceil()?
+            .to_uint128()?;
+
+        Ok(Coin::new(token_in.u128(), token_in_denom))
+    }
+
+    /// Only joins at the exact ratio of the reserves are supported. Joining with
+    /// a remainder requires single asset joins, which are not implemented for stableswap pools.
+    fn calc_join_pool_shares(&self, tokens_in: &[Coin]) -> StdResult<(Uint128, Vec<Coin>)> {
+        let tokens_in = sorted_coins(tokens_in)?;
+        let (num_shares, rem_coins) =
+            maximal_exact_ratio_join(&self.reserves()?, self.total_shares()?.amount, &tokens_in)?;
+
+        if !rem_coins.is_empty() {
+            return Err(StdError::generic_err(
+                "joining stableswap pool with tokens that are not at the ratio of the reserves is not supported",
+            ));
+        }
+
+        Ok((num_shares, tokens_in))
+    }
+
+    fn calc_exit_pool_coins_from_shares(&self, exiting_shares: Uint128) -> StdResult<Vec<Coin>> {
+        calc_exit_pool(
+            &self.reserves()?,
+            self.total_shares()?.amount,
+            exiting_shares,
+            self.exit_fee()?,
+        )
+    }
+}
+
+impl Pool {
+    fn one_minus_swap_fee(&self) -> StdResult<BigDec> {
+        BigDec::one().checked_sub(BigDec::from_sdk_dec(SdkDec::from_decimal(
+            self.swap_fee()?,
+        ))?)
+    }
+
+    fn scaling_factor(&self, denom: &str) -> StdResult<u64> {
+        self.pool_liquidity
+            .iter()
+            .position(|coin| coin.denom == denom)
+            .and_then(|i| self.scaling_factors.get(i))
+            .copied()
+            .ok_or_else(|| {
+                StdError::generic_err(format!("no scaling factor for denom {} in the pool", denom))
+            })
+    }
+
+    /// `scaleCoin`, `amount / scaling_factor` rounded in the given direction.
+    fn scale(&self, denom: &str, amount: Uint128, round_up: bool) -> StdResult<BigDec> {
+        BigDec::from_int_div(amount, self.scaling_factor(denom)?, round_up)
+    }
+
+    /// `getDescaledPoolAmt`, `amount * scaling_factor` truncated to `sdk.Dec`.
+    fn descale(&self, denom: &str, amount: BigDec) -> StdResult<SdkDec> {
+        Ok(amount
+            .checked_mul_int(self.scaling_factor(denom)?)?
+            .to_sdk_dec())
+    }
+
+    /// `scaledSortedPoolReserves`, scaled reserves of `first` and `second`, followed by the rest.
+    fn scaled_sorted_reserves(
+        &self,
+        first: &str,
+        second: &str,
+        round_up: bool,
+    ) -> StdResult<(BigDec, BigDec, Vec<BigDec>)> {
+        let reserves = self.reserves()?;
+        let scaled = |denom: &str| {
+            let amount = super::common::reserve_of(&reserves, denom)?;
+            self.scale(denom, amount, round_up)
+        };
+
+        let rem_reserves = reserves
+            .iter()
+            .filter(|reserve| reserve.denom != first && reserve.denom != second)
+            .map(|reserve| scaled(&reserve.denom))
+            .collect::<StdResult<Vec<BigDec>>>()?;
+
+        Ok((scaled(first)?, scaled(second)?, rem_reserves))
+    }
+}
+
+/// `x * y * (x^2 + y^2 + w)`
+fn cfmm_constant_multi_no_v(
+    x_reserve: BigDec,
+    y_reserve: BigDec,
+    w_sum_squares: BigDec,
+) -> StdResult<BigDec> {
+    let xy = x_reserve.checked_mul(y_reserve)?;
+    let x2 = x_reserve.checked_mul(x_reserve)?;
+    let y2 = y_reserve.checked_mul(y_reserve)?;
+    xy.checked_mul(x2.checked_add(y2)?.checked_add(w_sum_squares)?)
+}
+
+fn sum_squares(reserves: &[BigDec]) -> StdResult<BigDec> {
+    reserves.iter().try_fold(BigDec::zero(), |sum, reserve| {
+        sum.checked_add(reserve.checked_mul(*reserve)?)
+    })
+}
+
+/// Amount of `x` out for `y_in` of `y` added to the pool.
+fn solve_cfmm_out(
+    x_reserve: BigDec,
+    y_reserve: BigDec,
+    rem_reserves: &[BigDec],
+    y_in: BigDec,
+) -> StdResult<BigDec> {
+    let x_final = solve_cfmm_x_final(x_reserve, y_reserve, rem_reserves, y_in, true)?;
+
+    let x_out = x_reserve.checked_sub(x_final)?;
+    if x_out >= x_reserve {
+        return Err(StdError::generic_err(
+            "invalid output: greater than full pool reserves",
+        ));
+    }
+
+    Ok(x_out)
+}
+
+/// Amount of `x` in required for `y_out` of `y` to be taken out of the pool.
+fn solve_cfmm_in(
+    x_reserve: BigDec,
+    y_reserve: BigDec,
+    rem_reserves: &[BigDec],
+    y_out: BigDec,
+) -> StdResult<BigDec> {
+    let x_final = solve_cfmm_x_final(x_reserve, y_reserve, rem_reserves, y_out, false)?;
+
+    let x_in = x_final.checked_sub(x_reserve)?;
+    if x_in >= x_reserve {
+        return Err(StdError::generic_err(
+            "invalid output: greater than full pool reserves",
+        ));
+    }
+
+    Ok(x_in)
+}
+
+/// `solveCFMMBinarySearchMulti`, the `x` reserve that keeps the CFMM constant after
+/// `y_change` of `y` is added to the pool, or taken out of it if `y_added` is false.
+///
+/// Like on chain, `x` is searched for in `[0, 2 * x_reserve]`, which also covers
+/// the `x` reserve increasing when `y` is taken out.
+fn solve_cfmm_x_final(
+    x_reserve: BigDec,
+    y_reserve: BigDec,
+    rem_reserves: &[BigDec],
+    y_change: BigDec,
+    y_added: bool,
+) -> StdResult<BigDec> {
+    if x_reserve.is_zero() || y_reserve.is_zero() {
+        return Err(StdError::generic_err("reserves must be positive"));
+    }
+    if y_change >= y_reserve {
+        return Err(StdError::generic_err(
+            "cannot input more than pool reserves",
+        ));
+    }
+
+    let w_sum_squares = sum_squares(rem_reserves)?;
+    let k = cfmm_constant_multi_no_v(x_reserve, y_reserve, w_sum_squares)?;
+    let y_final = if y_added {
+        y_reserve.checked_add(y_change)?
+    } else {
+        y_reserve.checked_sub(y_change)?
+    };
+
+    binary_search(
+        |x| cfmm_constant_multi_no_v(x, y_final, w_sum_squares),
+        BigDec::zero(),
+        x_reserve.checked_add(x_reserve)?,
+        k,
+    )
+}
+
+/// `BinarySearchBigDec` for the `x` where `f(x)` is within an additive tolerance of 1 from `target`.
+/// `f` must be increasing in `[lower_bound, upper_bound]`.
+fn binary_search(
+    f: impl Fn(BigDec) -> StdResult<BigDec>,
+    mut lower_bound: BigDec,
+    mut upper_bound: BigDec,
+    target: BigDec,
+) -> StdResult<BigDec> {
+    let two = BigDec::from_int(2u128)?;
+    let tolerance = BigDec::one();
+
+    let mut estimate = lower_bound.checked_add(upper_bound)?.checked_div(two)?;
+    for _ in 0..MAX_ITERATIONS {
+        let output = f(estimate)?;
+        let (diff, target_below_output) = target.abs_diff(output);
+
+        if diff <= tolerance {
+            return Ok(estimate);
+        } else if target_below_output {
+            upper_bound = estimate;
+        } else {
+            lower_bound = estimate;
+        }
+
+        estimate = lower_bound.checked_add(upper_bound)?.checked_div(two)?;
+    }
+
+    Err(StdError::generic_err(
+        "hit maximum iterations, did not converge fast enough",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Decimal;
+
+    use super::*;
+    use crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::PoolParams;
+
+    fn pool(liquidity: &[(u128, &str)], scaling_factors: &[u64], swap_fee: Decimal) -> Pool {
+        Pool {
+            address: "osmo1pool".to_string(),
+            id: 1,
+            pool_params: Some(PoolParams {
+                swap_fee: swap_fee.into(),
+                exit_fee: Decimal::zero().into(),
+            }),
+            future_pool_governor: "24h".to_string(),
+            total_shares: Some(Coin::new(100_000_000_000_000_000_000, "gamm/pool/1").into()),
+            pool_liquidity: liquidity
+                .iter()
+                .map(|&(amount, denom)| Coin::new(amount, denom).into())
+                .collect(),
+            scaling_factors: scaling_factors.to_vec(),
+            scaling_factor_controller: "".to_string(),
+        }
+    }
+
+    // expected amounts are the exact solutions of the CFMM, rounded the way the chain rounds them
+
+    #[test]
+    fn even_pool_swap() {
+        let pool = pool(
+            &[(1_000_000_000, "bar"), (1_000_000_000, "foo")],
+            &[1, 1],
+            Decimal::zero(),
+        );
+
+        // 99.9999999999999999999500..
+        assert_eq!(
+            pool.calc_out_amt_given_in(&Coin::new(100, "foo"), "bar")
+                .unwrap(),
+            Coin::new(99, "bar")
+        );
+    }
+
+    #[test]
+    fn multi_asset_pool_with_scaling_factors() {
+        let pool = pool(
+            &[(2_000_000, "bar"), (500_000, "baz"), (1_000_000, "foo")],
+            &[2, 1, 1],
+            Decimal::permille(3),
+        );
+
+        // 9964.1516980225.. scaled bar, or 19928.3033960451.. bar
+        assert_eq!(
+            pool.calc_out_amt_given_in(&Coin::new(10_000, "foo"), "bar")
+                .unwrap(),
+            Coin::new(19_928, "bar")
+        );
+        // 5001.4713137373.. foo before the swap fee, 5016.5208763664.. after
+        assert_eq!(
+            pool.calc_in_amt_given_out(&Coin::new(10_000, "bar"), "foo")
+                .unwrap(),
+            Coin::new(5_017, "foo")
+        );
+    }
+
+    #[test]
+    fn swap_in_is_bounded_by_twice_the_reserve() {
+        let pool = pool(&[(100, "bar"), (100, "foo")], &[1, 1], Decimal::zero());
+
+        // the foo reserve becomes 153.4924111738.., within the `[0, 2 * 100]` bracket
+        assert_eq!(
+            pool.calc_in_amt_given_out(&Coin::new(50, "bar"), "foo")
+                .unwrap(),
+            Coin::new(54, "foo")
+        );
+
+        // the foo reserve would have to become 584.8029776505.., which the chain rejects
+        assert!(pool
+            .calc_in_amt_given_out(&Coin::new(99, "bar"), "foo")
+            .is_err());
+        assert!(pool
+            .calc_in_amt_given_out(&Coin::new(100, "bar"), "foo")
+            .is_err());
+    }
+
+    #[test]
+    fn solve_cfmm_keeps_the_constant() {
+        // two asset cases of `TestCFMMInvariantTwoAssets` of the stableswap pool model
+        let cases = [
+            (100u128, 100u128, 1u128),
+            (100, 100, 99),
+            (1_000_000_000, 1_000_000_000, 1),
+            (1_000_000_000, 1_000_000_000, 100_000_000),
+            (1_000_000_000, 100, 1),
+            (100, 1_000_000_000, 1_000_000),
+        ];
+
+        for (x, y, y_in) in cases {
+            let x = BigDec::from_int(x).unwrap();
+            let y = BigDec::from_int(y).unwrap();
+            let y_in = BigDec::from_int(y_in).unwrap();
+
+            let x_out = solve_cfmm_out(x, y, &[], y_in).unwrap();
+            let k = cfmm_constant_multi_no_v(x, y, BigDec::zero()).unwrap();
+            let k_after = cfmm_constant_multi_no_v(
+                x.checked_sub(x_out).unwrap(),
+                y.checked_add(y_in).unwrap(),
+                BigDec::zero(),
+            )
+            .unwrap();
+
+            let (diff, _) = k.abs_diff(k_after);
+            assert!(diff <= BigDec::one(), "{:?} {:?} {:?}", x, y, y_in);
+        }
+
+        let one = BigDec::one();
+        assert!(solve_cfmm_out(one, one, &[], one).is_err());
+        assert!(solve_cfmm_out(BigDec::zero(), one, &[], one).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! Abstractions over the gamm pool types, so that pools can be handled uniformly
+//! regardless of their pool model, along with the AMM math to quote swaps, joins and exits,
+//! and a router to find the best swap route through them.
+
+mod math;
+mod pool;
+mod router;
+
+pub use math::{quote_swap_exact_amount_in, quote_swap_exact_amount_out, Amm};
+pub use pool::{Pool, PoolI};
+pub use router::{Router, SwapAmountInQuote, SwapAmountOutQuote};
@@ -0,0 +1,200 @@
+use cosmwasm_std::{Coin, Decimal, StdError, StdResult};
+
+use crate::registry;
+use crate::shim::Any;
+use crate::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use crate::types::osmosis::gamm::poolmodels::stableswap;
+use crate::types::osmosis::gamm::v1beta1::{self as balancer, QueryPoolResponse};
+
+/// Common interface of gamm pools, modeled after `PoolI` of the osmosis gamm module.
+pub trait PoolI {
+    /// Id of the pool.
+    fn id(&self) -> u64;
+
+    /// Address of the module account holding the pool's assets.
+    fn address(&self) -> &str;
+
+    /// Total amount of pool shares that have been issued.
+    fn total_shares(&self) -> StdResult<Coin>;
+
+    /// Amount of every asset held by the pool.
+    fn reserves(&self) -> StdResult<Vec<Coin>>;
+
+    /// Fee charged on the token in of a swap.
+    fn swap_fee(&self) -> StdResult<Decimal>;
+
+    /// Fee charged on the shares when exiting the pool.
+    fn exit_fee(&self) -> StdResult<Decimal>;
+}
+
+/// A pool of any of the supported pool models.
+///
+/// This can be decoded straight from the `Any` in `QueryPoolResponse`:
+///
+/// ```
+/// use cosmwasm_std::{Deps, StdResult};
+/// use osmosis_std::gamm::{Pool, PoolI};
+/// use osmosis_std::types::osmosis::gamm::v1beta1::GammQuerier;
+///
+/// fn query_swap_fee(deps: Deps, pool_id: u64) -> StdResult<cosmwasm_std::Decimal> {
+///     let pool: Pool = GammQuerier::new(&deps.querier).pool(pool_id)?.try_into()?;
+///     pool.swap_fee()
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pool {
+    Balancer(balancer::Pool),
+    StableSwap(stableswap::v1beta1::Pool),
+}
+
+impl TryFrom<Any> for Pool {
+    type Error = StdError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        let pool = match registry::decode(&value)?.downcast::<balancer::Pool>() {
+            Ok(pool) => return Ok(Pool::Balancer(*pool)),
+            Err(other) => other,
+        };
+
+        match pool.downcast::<stableswap::v1beta1::Pool>() {
+            Ok(pool) => Ok(Pool::StableSwap(*pool)),
+            Err(other) => Err(StdError::parse_err(
+                "Pool",
+                format!("unsupported pool type: {}", other.type_url()),
+            )),
+        }
+    }
+}
+
+impl TryFrom<QueryPoolResponse> for Pool {
+    type Error = StdError;
+
+    fn try_from(value: QueryPoolResponse) -> Result<Self, Self::Error> {
+        value
+            .pool
+            .ok_or_else(|| StdError::not_found("pool"))?
+            .try_into()
+    }
+}
+
+impl PoolI for Pool {
+    fn id(&self) -> u64 {
+        match self {
+            Pool::Balancer(pool) => pool.id(),
+            Pool::StableSwap(pool) => pool.id(),
+        }
+    }
+
+    fn address(&self) -> &str {
+        match self {
+            Pool::Balancer(pool) => pool.address(),
+            Pool::StableSwap(pool) => pool.address(),
+        }
+    }
+
+    fn total_shares(&self) -> StdResult<Coin> {
+        match self {
+            Pool::Balancer(pool) => pool.total_shares(),
+            Pool::StableSwap(pool) => pool.total_shares(),
+        }
+    }
+
+    fn reserves(&self) -> StdResult<Vec<Coin>> {
+        match self {
+            Pool::Balancer(pool) => pool.reserves(),
+            Pool::StableSwap(pool) => pool.reserves(),
+        }
+    }
+
+    fn swap_fee(&self) -> StdResult<Decimal> {
+        match self {
+            Pool::Balancer(pool) => pool.swap_fee(),
+            Pool::StableSwap(pool) => pool.swap_fee(),
+        }
+    }
+
+    fn exit_fee(&self) -> StdResult<Decimal> {
+        match self {
+            Pool::Balancer(pool) => pool.exit_fee(),
+            Pool::StableSwap(pool) => pool.exit_fee(),
+        }
+    }
+}
+
+impl PoolI for balancer::Pool {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn total_shares(&self) -> StdResult<Coin> {
+        total_shares(&self.total_shares)
+    }
+
+    fn reserves(&self) -> StdResult<Vec<Coin>> {
+        self.pool_assets
+            .iter()
+            .map(|asset| {
+                asset
+                    .token
+                    .clone()
+                    .ok_or_else(|| StdError::not_found("pool asset token"))?
+                    .try_into()
+            })
+            .collect()
+    }
+
+    fn swap_fee(&self) -> StdResult<Decimal> {
+        Decimal::try_from(&pool_params(&self.pool_params)?.swap_fee)
+    }
+
+    fn exit_fee(&self) -> StdResult<Decimal> {
+        Decimal::try_from(&pool_params(&self.pool_params)?.exit_fee)
+    }
+}
+
+impl PoolI for stableswap::v1beta1::Pool {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn total_shares(&self) -> StdResult<Coin> {
+        total_shares(&self.total_shares)
+    }
+
+    fn reserves(&self) -> StdResult<Vec<Coin>> {
+        self.pool_liquidity
+            .iter()
+            .cloned()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    fn swap_fee(&self) -> StdResult<Decimal> {
+        Decimal::try_from(&pool_params(&self.pool_params)?.swap_fee)
+    }
+
+    fn exit_fee(&self) -> StdResult<Decimal> {
+        Decimal::try_from(&pool_params(&self.pool_params)?.exit_fee)
+    }
+}
+
+fn total_shares(total_shares: &Option<ProtoCoin>) -> StdResult<Coin> {
+    total_shares
+        .clone()
+        .ok_or_else(|| StdError::not_found("pool total shares"))?
+        .try_into()
+}
+
+fn pool_params<P>(pool_params: &Option<P>) -> StdResult<&P> {
+    pool_params
+        .as_ref()
+        .ok_or_else(|| StdError::not_found("pool params"))
+}
@@ -0,0 +1,454 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_std::{Coin, Decimal, StdError, StdResult, Uint128, Uint256};
+
+use super::{Amm, Pool, PoolI};
+use crate::types::osmosis::gamm::v1beta1::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountOut, QueryPoolsResponse, SwapAmountInRoute,
+    SwapAmountOutRoute,
+};
+
+/// Finds the swap route with the best price through a set of pools, and builds
+/// swap messages for it with slippage bounded minimum and maximum amounts.
+///
+/// Every path of at most `max_hops` pools between the two denoms is quoted with [`Amm`],
+/// so the number of pools should be kept to the ones worth routing through.
+///
+/// ```
+/// use cosmwasm_std::{Coin, Decimal, Deps, StdResult};
+/// use osmosis_std::gamm::Router;
+/// use osmosis_std::types::osmosis::gamm::v1beta1::{GammQuerier, MsgSwapExactAmountIn};
+///
+/// fn swap_to_uosmo(deps: Deps, sender: String, token_in: &Coin) -> StdResult<MsgSwapExactAmountIn> {
+///     let router: Router = GammQuerier::new(&deps.querier).pools(None)?.try_into()?;
+///     router.swap_exact_amount_in_msg(sender, token_in, "uosmo", 3, Decimal::percent(1))
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Router {
+    pools: Vec<Pool>,
+    /// Denoms of the reserves of each pool in `pools`.
+    pool_denoms: Vec<Vec<String>>,
+    /// Pools, by index into `pools`, that hold each denom.
+    pools_by_denom: BTreeMap<String, Vec<usize>>,
+}
+
+/// A route for `MsgSwapExactAmountIn`, along with the quoted amount out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapAmountInQuote {
+    pub routes: Vec<SwapAmountInRoute>,
+    pub token_out: Coin,
+}
+
+/// A route for `MsgSwapExactAmountOut`, along with the quoted amount in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapAmountOutQuote {
+    pub routes: Vec<SwapAmountOutRoute>,
+    pub token_in: Coin,
+}
+
+/// A pool along a path, and the denom it swaps into.
+type Hop<'a> = (&'a Pool, &'a str);
+
+impl Router {
+    pub fn new(pools: Vec<Pool>) -> StdResult<Self> {
+        let pool_denoms = pools
+            .iter()
+            .map(|pool| {
+                let reserves = pool.reserves()?;
+                Ok(reserves.into_iter().map(|reserve| reserve.denom).collect())
+            })
+            .collect::<StdResult<Vec<Vec<String>>>>()?;
+
+        let mut pools_by_denom: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, denoms) in pool_denoms.iter().enumerate() {
+            for denom in denoms {
+                pools_by_denom.entry(denom.clone()).or_default().push(i);
+            }
+        }
+
+        Ok(Router {
+            pools,
+            pool_denoms,
+            pools_by_denom,
+        })
+    }
+
+    pub fn pools(&self) -> &[Pool] {
+        &self.pools
+    }
+
+    /// Route with the largest amount out for swapping `token_in` to `token_out_denom`
+    /// through at most `max_hops` pools.
+    pub fn best_swap_exact_amount_in(
+        &self,
+        token_in: &Coin,
+        token_out_denom: &str,
+        max_hops: usize,
+    ) -> StdResult<SwapAmountInQuote> {
+        let mut best: Option<SwapAmountInQuote> = None;
+        self.visit_paths(&token_in.denom, token_out_denom, max_hops, &mut |hops| {
+            let mut token = token_in.clone();
+            for (pool, denom_out) in hops {
+                token = match pool.calc_out_amt_given_in(&token, denom_out) {
+                    Ok(token_out) => token_out,
+                    // not enough liquidity on this path
+                    Err(_) => return,
+                };
+            }
+
+            let is_better = match &best {
+                Some(best) => token.amount > best.token_out.amount,
+                None => true,
+            };
+            if is_better {
+                best = Some(SwapAmountInQuote {
+                    routes: hops
+                        .iter()
+                        .map(|(pool, denom_out)| SwapAmountInRoute {
+                            pool_id: pool.id(),
+                            token_out_denom: denom_out.to_string(),
+                        })
+                        .collect(),
+                    token_out: token,
+                });
+            }
+        });
+
+        best.ok_or_else(|| no_route(&token_in.denom, token_out_denom, max_hops))
+    }
+
+    /// Route with the smallest amount in for swapping `token_in_denom` to `token_out`
+    /// through at most `max_hops` pools.
+    pub fn best_swap_exact_amount_out(
+        &self,
+        token_in_denom: &str,
+        token_out: &Coin,
+        max_hops: usize,
+    ) -> StdResult<SwapAmountOutQuote> {
+        let mut best: Option<SwapAmountOutQuote> = None;
+        // paths are walked backwards from the token out, since amounts in are quoted from the end
+        self.visit_paths(&token_out.denom, token_in_denom, max_hops, &mut |hops| {
+            let mut token = token_out.clone();
+            for (pool, denom_in) in hops {
+                token = match pool.calc_in_amt_given_out(&token, denom_in) {
+                    Ok(token_in) => token_in,
+                    // not enough liquidity on this path
+                    Err(_) => return,
+                };
+            }
+
+            let is_better = match &best {
+                Some(best) => token.amount < best.token_in.amount,
+                None => true,
+            };
+            if is_better {
+                best = Some(SwapAmountOutQuote {
+                    routes: hops
+                        .iter()
+                        .rev()
+                        .map(|(pool, denom_in)| SwapAmountOutRoute {
+                            pool_id: pool.id(),
+                            token_in_denom: denom_in.to_string(),
+                        })
+                        .collect(),
+                    token_in: token,
+                });
+            }
+        });
+
+        best.ok_or_else(|| no_route(token_in_denom, &token_out.denom, max_hops))
+    }
+
+    /// `MsgSwapExactAmountIn` through the best route, which fails if the amount out
+    /// is more than `max_slippage` below the quote.
+    pub fn swap_exact_amount_in_msg(
+        &self,
+        sender: impl Into<String>,
+        token_in: &Coin,
+        token_out_denom: &str,
+        max_hops: usize,
+        max_slippage: Decimal,
+    ) -> StdResult<MsgSwapExactAmountIn> {
+        let quote = self.best_swap_exact_amount_in(token_in, token_out_denom, max_hops)?;
+        let token_out_min_amount = quote
+            .token_out
+            .amount
+            .checked_multiply_ratio(
+                Decimal::one().checked_sub(max_slippage)?.atomics(),
+                Decimal::one().atomics(),
+            )
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        Ok(MsgSwapExactAmountIn {
+            sender: sender.into(),
+            routes: quote.routes,
+            token_in: Some(token_in.clone().into()),
+            token_out_min_amount: token_out_min_amount.into(),
+        })
+    }
+
+    /// `MsgSwapExactAmountOut` through the best route, which fails if the amount in
+    /// is more than `max_slippage` above the quote.
+    pub fn swap_exact_amount_out_msg(
+        &self,
+        sender: impl Into<String>,
+        token_in_denom: &str,
+        token_out: &Coin,
+        max_hops: usize,
+        max_slippage: Decimal,
+    ) -> StdResult<MsgSwapExactAmountOut> {
+        let quote = self.best_swap_exact_amount_out(token_in_denom, token_out, max_hops)?;
+        let token_in_max_amount = mul_ceil(
+            quote.token_in.amount,
+            Decimal::one().checked_add(max_slippage)?,
+        )?;
+
+        Ok(MsgSwapExactAmountOut {
+            sender: sender.into(),
+            routes: quote.routes,
+            token_in_max_amount: token_in_max_amount.into(),
+            token_out: Some(token_out.clone().into()),
+        })
+    }
+
+    /// Calls `visit` with every path from `from` to `to` of at most `max_hops` pools,
+    /// as the pools along the path and the denom each one swaps into.
+    /// No pool or denom is visited twice in a path.
+    fn visit_paths<'a>(
+        &'a self,
+        from: &'a str,
+        to: &str,
+        max_hops: usize,
+        visit: &mut dyn FnMut(&[Hop<'a>]),
+    ) {
+        let mut visited_denoms = BTreeSet::from([from]);
+        let mut hops = vec![];
+        self.visit_paths_from(from, to, max_hops, &mut visited_denoms, &mut hops, visit);
+    }
+
+    fn visit_paths_from<'a>(
+        &'a self,
+        denom: &'a str,
+        to: &str,
+        max_hops: usize,
+        visited_denoms: &mut BTreeSet<&'a str>,
+        hops: &mut Vec<Hop<'a>>,
+        visit: &mut dyn FnMut(&[Hop<'a>]),
+    ) {
+        if hops.len() >= max_hops {
+            return;
+        }
+
+        for &i in self.pools_by_denom.get(denom).into_iter().flatten() {
+            let pool = &self.pools[i];
+            if hops.iter().any(|(visited, _)| visited.id() == pool.id()) {
+                continue;
+            }
+
+            for next_denom in &self.pool_denoms[i] {
+                let next_denom = next_denom.as_str();
+                if visited_denoms.contains(next_denom) {
+                    continue;
+                }
+
+                hops.push((pool, next_denom));
+                if next_denom == to {
+                    visit(hops);
+                } else {
+                    visited_denoms.insert(next_denom);
+                    self.visit_paths_from(next_denom, to, max_hops, visited_denoms, hops, visit);
+                    visited_denoms.remove(next_denom);
+                }
+                hops.pop();
+            }
+        }
+    }
+}
+
+impl TryFrom<QueryPoolsResponse> for Router {
+    type Error = StdError;
+
+    fn try_from(value: QueryPoolsResponse) -> Result<Self, Self::Error> {
+        Router::new(
+            value
+                .pools
+                .into_iter()
+                .map(Pool::try_from)
+                .collect::<StdResult<_>>()?,
+        )
+    }
+}
+
+fn no_route(denom_in: &str, denom_out: &str, max_hops: usize) -> StdError {
+    StdError::not_found(format!(
+        "route from {} to {} within {} hops",
+        denom_in, denom_out, max_hops
+    ))
+}
+
+/// `amount * ratio`, rounded up.
+fn mul_ceil(amount: Uint128, ratio: Decimal) -> StdResult<Uint128> {
+    let precision = Uint256::from(Decimal::one().atomics());
+    let numerator = Uint256::from(amount).checked_mul(Uint256::from(ratio.atomics()))?;
+    let quo = numerator / precision;
+    let quo = if (numerator % precision).is_zero() {
+        quo
+    } else {
+        quo + Uint256::one()
+    };
+
+    Ok(Uint128::try_from(quo)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::osmosis::gamm::v1beta1::{self as balancer, PoolAsset, PoolParams};
+
+    /// Balancer pool with equal weights and no swap fee.
+    fn pool(id: u64, reserves: [(u128, &str); 2]) -> Pool {
+        Pool::Balancer(balancer::Pool {
+            address: format!("osmo1pool{}", id),
+            id,
+            pool_params: Some(PoolParams {
+                swap_fee: Decimal::zero().into(),
+                exit_fee: Decimal::zero().into(),
+                smooth_weight_change_params: None,
+            }),
+            future_pool_governor: "24h".to_string(),
+            total_shares: Some(
+                Coin::new(100_000_000_000_000_000_000, format!("gamm/pool/{}", id)).into(),
+            ),
+            pool_assets: reserves
+                .iter()
+                .map(|&(amount, denom)| PoolAsset {
+                    token: Some(Coin::new(amount, denom).into()),
+                    weight: 1_000_000u128.into(),
+                })
+                .collect(),
+            total_weight: 2_000_000u128.into(),
+        })
+    }
+
+    /// The direct pool 3 is ten times shallower than the route through uosmo.
+    fn router() -> Router {
+        Router::new(vec![
+            pool(1, [(1_000_000, "uatom"), (1_000_000, "uosmo")]),
+            pool(2, [(1_000_000, "uion"), (1_000_000, "uosmo")]),
+            pool(3, [(100_000, "uatom"), (100_000, "uion")]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn best_swap_exact_amount_in() {
+        let router = router();
+
+        assert_eq!(
+            router
+                .best_swap_exact_amount_in(&Coin::new(10_000, "uatom"), "uion", 3)
+                .unwrap(),
+            SwapAmountInQuote {
+                routes: vec![
+                    SwapAmountInRoute {
+                        pool_id: 1,
+                        token_out_denom: "uosmo".to_string(),
+                    },
+                    SwapAmountInRoute {
+                        pool_id: 2,
+                        token_out_denom: "uion".to_string(),
+                    },
+                ],
+                token_out: Coin::new(9_802, "uion"),
+            }
+        );
+
+        assert_eq!(
+            router
+                .best_swap_exact_amount_in(&Coin::new(10_000, "uatom"), "uion", 1)
+                .unwrap(),
+            SwapAmountInQuote {
+                routes: vec![SwapAmountInRoute {
+                    pool_id: 3,
+                    token_out_denom: "uion".to_string(),
+                }],
+                token_out: Coin::new(9_090, "uion"),
+            }
+        );
+
+        assert!(router
+            .best_swap_exact_amount_in(&Coin::new(10_000, "uatom"), "uusdc", 3)
+            .is_err());
+        assert!(router
+            .best_swap_exact_amount_in(&Coin::new(10_000, "uatom"), "uion", 0)
+            .is_err());
+    }
+
+    #[test]
+    fn best_swap_exact_amount_out() {
+        let router = router();
+
+        // the direct pool would take 9891 uatom
+        assert_eq!(
+            router
+                .best_swap_exact_amount_out("uatom", &Coin::new(9_000, "uion"), 3)
+                .unwrap(),
+            SwapAmountOutQuote {
+                routes: vec![
+                    SwapAmountOutRoute {
+                        pool_id: 1,
+                        token_in_denom: "uatom".to_string(),
+                    },
+                    SwapAmountOutRoute {
+                        pool_id: 2,
+                        token_in_denom: "uosmo".to_string(),
+                    },
+                ],
+                token_in: Coin::new(9_166, "uatom"),
+            }
+        );
+
+        // more than the direct pool holds
+        assert_eq!(
+            router
+                .best_swap_exact_amount_out("uatom", &Coin::new(100_000, "uion"), 1)
+                .unwrap_err(),
+            no_route("uatom", "uion", 1)
+        );
+    }
+
+    #[test]
+    fn swap_msgs_bound_slippage() {
+        let router = router();
+
+        let msg = router
+            .swap_exact_amount_in_msg(
+                "osmo1sender",
+                &Coin::new(10_000, "uatom"),
+                "uion",
+                3,
+                Decimal::percent(1),
+            )
+            .unwrap();
+        assert_eq!(msg.sender, "osmo1sender");
+        assert_eq!(msg.routes.len(), 2);
+        assert_eq!(msg.token_in, Some(Coin::new(10_000, "uatom").into()));
+        // 9802 * 0.99 = 9703.98, rounded down
+        assert_eq!(msg.token_out_min_amount, Uint128::new(9_703).into());
+
+        let msg = router
+            .swap_exact_amount_out_msg(
+                "osmo1sender",
+                "uatom",
+                &Coin::new(9_000, "uion"),
+                3,
+                Decimal::percent(1),
+            )
+            .unwrap();
+        assert_eq!(msg.routes.len(), 2);
+        assert_eq!(msg.token_out, Some(Coin::new(9_000, "uion").into()));
+        // 9166 * 1.01 = 9257.66, rounded up
+        assert_eq!(msg.token_in_max_amount, Uint128::new(9_258).into());
+    }
+}
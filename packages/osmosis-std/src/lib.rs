@@ -1,11 +1,31 @@
-#![doc = include_str!("../README.md")]
+// the examples in the readme are for CosmWasm contracts
+#![cfg_attr(feature = "cosmwasm", doc = include_str!("../README.md"))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(trivial_casts, trivial_numeric_casts, unused_import_braces)]
 
+extern crate alloc;
+
 /// The version (commit hash) of the Cosmos SDK used when generating this library.
 pub const OSMOSISD_VERSION: &str = include_str!("types/OSMOSIS_COMMIT");
 
+#[cfg(feature = "amino")]
+#[cfg_attr(docsrs, doc(cfg(feature = "amino")))]
+pub mod amino;
+mod error;
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub mod gamm;
+pub mod msg;
+#[cfg(feature = "proto-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proto-json")))]
+pub mod proto_json;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod registry;
 mod serde;
 pub mod shim;
 pub mod types;
+
+pub use error::Error;
@@ -0,0 +1,25 @@
+//! Messages of the modules' `Msg` services.
+
+/// A message of a `Msg` service, along with the response the service returns for it, e.g.
+/// `MsgCreateDenomResponse` for `MsgCreateDenom`. It lets code that executes messages or
+/// parses the replies to them infer the type to decode the result into:
+///
+/// ```
+/// use osmosis_std::msg::MsgWithResponse;
+/// use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgCreateDenomResponse};
+///
+/// fn decode_response<M: MsgWithResponse>(data: &[u8]) -> Result<M::Response, prost::DecodeError> {
+///     prost::Message::decode(data)
+/// }
+///
+/// let res = MsgCreateDenomResponse {
+///     new_token_denom: "factory/osmo1.../uxxx".to_string(),
+/// };
+/// let data = prost::Message::encode_to_vec(&res);
+///
+/// assert_eq!(decode_response::<MsgCreateDenom>(&data).unwrap(), res);
+/// ```
+pub trait MsgWithResponse: prost::Message {
+    /// The response of the `Msg` service method that takes the message
+    type Response: prost::Message + Default;
+}
@@ -0,0 +1,465 @@
+//! Protobuf JSON, the canonical JSON mapping of protobuf messages.
+//!
+//! This is the JSON that gRPC gateways, such as the REST endpoints of a node, speak. Fields are
+//! named in lowerCamelCase, 64 bit integers are strings, enums are the names of their values,
+//! bytes are base64, and fields holding a default value are left out. Decoding accepts the
+//! original field names as well, so responses with snake_case fields are decoded too, and
+//! ignores fields it does not know about.
+//!
+//! ```
+//! use osmosis_std::proto_json::{decode_proto_json, encode_proto_json};
+//! use osmosis_std::types::osmosis::lockup::{LockQueryType, QueryCondition};
+//!
+//! let condition = QueryCondition {
+//!     lock_query_type: LockQueryType::ByTime.into(),
+//!     denom: "gamm/pool/1".to_string(),
+//!     duration: None,
+//!     timestamp: Some(osmosis_std::shim::Timestamp {
+//!         seconds: 1_600_000_000,
+//!         nanos: 0,
+//!     }),
+//! };
+//! let json = r#"{"denom":"gamm/pool/1","lockQueryType":"ByTime","timestamp":"2020-09-13T12:26:40Z"}"#;
+//!
+//! assert_eq!(encode_proto_json(&condition).unwrap(), json);
+//! assert_eq!(decode_proto_json::<QueryCondition>(json).unwrap(), condition);
+//!
+//! // as returned by the REST endpoints of a node
+//! let json = r#"{"lock_query_type":"ByTime","denom":"gamm/pool/1","duration":null,"timestamp":"2020-09-13T12:26:40Z"}"#;
+//! assert_eq!(decode_proto_json::<QueryCondition>(json).unwrap(), condition);
+//! ```
+
+use serde_json::{Map, Number, Value};
+
+use crate::shim::{Any, Dec, Duration, Int, Timestamp};
+use crate::Error;
+
+/// A value that can be encoded as, and decoded from, protobuf JSON.
+///
+/// It is implemented for every generated message.
+pub trait ProtoJson {
+    /// Encode this value as protobuf JSON.
+    fn to_proto_json(&self) -> Result<Value, Error>;
+
+    /// Decode a value from protobuf JSON.
+    fn from_proto_json(value: Value) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Returns `true` if this is the default value, which is left out of protobuf JSON.
+    fn is_default(&self) -> bool {
+        false
+    }
+}
+
+/// The enum that holds the field of a `oneof` that is set, which is a field of the message
+/// that holds the oneof.
+#[doc(hidden)]
+pub trait ProtoJsonOneof: Sized {
+    /// Insert the field that is set into `fields`, even if it holds the default value.
+    fn insert_into(&self, fields: &mut Map<String, Value>) -> Result<(), Error>;
+
+    /// Take the field that is set out of `fields`, if any.
+    fn take_from(fields: &mut Map<String, Value>) -> Result<Option<Self>, Error>;
+}
+
+/// Encode `msg` as a protobuf JSON string.
+pub fn encode_proto_json<T: ProtoJson + ?Sized>(msg: &T) -> Result<String, Error> {
+    Ok(msg.to_proto_json()?.to_string())
+}
+
+/// Decode a message from a protobuf JSON string.
+pub fn decode_proto_json<T: ProtoJson>(json: &str) -> Result<T, Error> {
+    let value = serde_json::from_str(json).map_err(|e| parse_err::<T>(e))?;
+    T::from_proto_json(value)
+}
+
+fn parse_err<T: ?Sized>(msg: impl ToString) -> Error {
+    Error::parse_err(core::any::type_name::<T>(), msg)
+}
+
+fn invalid<T: ?Sized>(value: &Value) -> Error {
+    parse_err::<T>(format!("invalid protobuf JSON: {}", value))
+}
+
+/// Fields of a message, given as `value`.
+#[doc(hidden)]
+pub fn into_fields(value: Value, message: &str) -> Result<Map<String, Value>, Error> {
+    match value {
+        Value::Object(fields) => Ok(fields),
+        value => Err(Error::parse_err(
+            message,
+            format!("expected a JSON object, got: {}", value),
+        )),
+    }
+}
+
+/// Insert `value` into `fields` as `name`, unless it is the default value.
+#[doc(hidden)]
+pub fn insert_field<T: ProtoJson + ?Sized>(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: &T,
+) -> Result<(), Error> {
+    if !value.is_default() {
+        fields.insert(name.to_string(), value.to_proto_json()?);
+    }
+    Ok(())
+}
+
+/// Take the field `json_name`, or `name` as it is in the proto file, out of `fields`.
+/// It is the default value if it is missing or `null`.
+#[doc(hidden)]
+pub fn take_field<T: ProtoJson + Default>(
+    fields: &mut Map<String, Value>,
+    json_name: &str,
+    name: &str,
+) -> Result<T, Error> {
+    match take_value(fields, json_name, name) {
+        Some(value) => T::from_proto_json(value),
+        None => Ok(T::default()),
+    }
+}
+
+/// Take the value of the field `json_name`, or `name`, out of `fields`, unless it is `null`.
+#[doc(hidden)]
+pub fn take_value(fields: &mut Map<String, Value>, json_name: &str, name: &str) -> Option<Value> {
+    fields
+        .remove(json_name)
+        .or_else(|| fields.remove(name))
+        .filter(|value| !value.is_null())
+}
+
+/// Insert an enum field, as the name of its value if it is known, or else as the number.
+#[doc(hidden)]
+pub fn insert_enum_field(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: i32,
+    as_str_name: fn(i32) -> Option<&'static str>,
+) -> Result<(), Error> {
+    if value != 0 {
+        fields.insert(name.to_string(), enum_to_proto_json(value, as_str_name));
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn enum_to_proto_json(value: i32, as_str_name: fn(i32) -> Option<&'static str>) -> Value {
+    match as_str_name(value) {
+        Some(name) => Value::String(name.to_string()),
+        None => Value::from(value),
+    }
+}
+
+/// Take an enum field, given as either the name or the number of its value.
+#[doc(hidden)]
+pub fn take_enum_field(
+    fields: &mut Map<String, Value>,
+    json_name: &str,
+    name: &str,
+    from_str_name: fn(&str) -> Option<i32>,
+) -> Result<i32, Error> {
+    match take_value(fields, json_name, name) {
+        Some(value) => enum_from_proto_json(value, name, from_str_name),
+        None => Ok(0),
+    }
+}
+
+#[doc(hidden)]
+pub fn enum_from_proto_json(
+    value: Value,
+    name: &str,
+    from_str_name: fn(&str) -> Option<i32>,
+) -> Result<i32, Error> {
+    match value {
+        Value::String(s) => from_str_name(&s)
+            .ok_or_else(|| Error::parse_err(name, format!("unknown enum value: {}", s))),
+        value => i32::from_proto_json(value),
+    }
+}
+
+#[doc(hidden)]
+pub fn insert_bytes_field(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: &[u8],
+) -> Result<(), Error> {
+    if !value.is_empty() {
+        fields.insert(name.to_string(), bytes_to_proto_json(value));
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn bytes_to_proto_json(value: &[u8]) -> Value {
+    Value::String(base64::encode(value))
+}
+
+/// Take a bytes field, given as either standard or URL-safe base64.
+#[doc(hidden)]
+pub fn take_bytes_field(
+    fields: &mut Map<String, Value>,
+    json_name: &str,
+    name: &str,
+) -> Result<Vec<u8>, Error> {
+    match take_value(fields, json_name, name) {
+        Some(value) => bytes_from_proto_json(value, name),
+        None => Ok(vec![]),
+    }
+}
+
+/// Bytes given as either standard or URL-safe base64.
+#[doc(hidden)]
+pub fn bytes_from_proto_json(value: Value, name: &str) -> Result<Vec<u8>, Error> {
+    match value {
+        Value::String(s) => base64::decode(&s)
+            .or_else(|_| base64::decode_config(&s, base64::URL_SAFE))
+            .map_err(|e| Error::parse_err(name, e)),
+        value => Err(invalid::<Vec<u8>>(&value)),
+    }
+}
+
+#[doc(hidden)]
+pub fn insert_repeated_bytes_field(
+    fields: &mut Map<String, Value>,
+    name: &str,
+    value: &[Vec<u8>],
+) -> Result<(), Error> {
+    if !value.is_empty() {
+        let values = value
+            .iter()
+            .map(|value| bytes_to_proto_json(value))
+            .collect();
+        fields.insert(name.to_string(), Value::Array(values));
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn take_repeated_bytes_field(
+    fields: &mut Map<String, Value>,
+    json_name: &str,
+    name: &str,
+) -> Result<Vec<Vec<u8>>, Error> {
+    match take_value(fields, json_name, name) {
+        Some(Value::Array(values)) => values
+            .into_iter()
+            .map(|value| bytes_from_proto_json(value, name))
+            .collect(),
+        Some(value) => Err(invalid::<Vec<Vec<u8>>>(&value)),
+        None => Ok(vec![]),
+    }
+}
+
+/// Insert the field of a `oneof` that is set, if any.
+#[doc(hidden)]
+pub fn insert_oneof_field<T: ProtoJsonOneof>(
+    fields: &mut Map<String, Value>,
+    value: &Option<T>,
+) -> Result<(), Error> {
+    match value {
+        Some(value) => value.insert_into(fields),
+        None => Ok(()),
+    }
+}
+
+/// Take the field of a `oneof` that is set, if any.
+#[doc(hidden)]
+pub fn take_oneof_field<T: ProtoJsonOneof>(
+    fields: &mut Map<String, Value>,
+) -> Result<Option<T>, Error> {
+    T::take_from(fields)
+}
+
+impl ProtoJson for String {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        Ok(Value::String(self.clone()))
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::String(s) => Ok(s),
+            value => Err(invalid::<Self>(&value)),
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl ProtoJson for bool {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        Ok(Value::Bool(*self))
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Bool(b) => Ok(b),
+            value => Err(invalid::<Self>(&value)),
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        !*self
+    }
+}
+
+/// 32 bit integers are numbers and 64 bit ones are strings, but either form is accepted.
+macro_rules! impl_proto_json_for_int {
+    ($($t:ty => |$n:ident| $to_json:expr),* $(,)?) => {
+        $(
+            impl ProtoJson for $t {
+                fn to_proto_json(&self) -> Result<Value, Error> {
+                    let $n = *self;
+                    Ok($to_json)
+                }
+
+                fn from_proto_json(value: Value) -> Result<Self, Error> {
+                    match &value {
+                        Value::String(s) => s.parse().map_err(parse_err::<Self>),
+                        Value::Number(n) => n
+                            .as_i64()
+                            .and_then(|n| n.try_into().ok())
+                            .or_else(|| n.as_u64().and_then(|n| n.try_into().ok()))
+                            .ok_or_else(|| invalid::<Self>(&value)),
+                        _ => Err(invalid::<Self>(&value)),
+                    }
+                }
+
+                fn is_default(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_proto_json_for_int! {
+    i32 => |n| Value::Number(Number::from(n)),
+    u32 => |n| Value::Number(Number::from(n)),
+    i64 => |n| Value::String(n.to_string()),
+    u64 => |n| Value::String(n.to_string()),
+}
+
+impl<T: ProtoJson> ProtoJson for Option<T> {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        match self {
+            Some(value) => value.to_proto_json(),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_proto_json(value).map(Some),
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T: ProtoJson> ProtoJson for Vec<T> {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        self.iter()
+            .map(T::to_proto_json)
+            .collect::<Result<_, _>>()
+            .map(Value::Array)
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Array(values) => values.into_iter().map(T::from_proto_json).collect(),
+            value => Err(invalid::<Self>(&value)),
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// `Dec` and `Int` are in the notation that the chain uses for JSON, e.g. `"0.010000000000000000"`.
+macro_rules! impl_proto_json_for_custom_type {
+    ($($t:ty),*) => {
+        $(
+            impl ProtoJson for $t {
+                fn to_proto_json(&self) -> Result<Value, Error> {
+                    Ok(Value::String(self.to_string()))
+                }
+
+                fn from_proto_json(value: Value) -> Result<Self, Error> {
+                    match value {
+                        Value::String(s) => s.parse(),
+                        value => Err(invalid::<Self>(&value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_proto_json_for_custom_type!(Dec, Int);
+
+/// RFC 3339, e.g. `"1972-01-01T10:00:20.021Z"`.
+impl ProtoJson for Timestamp {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        let mut ts = prost_types::Timestamp::from(self.clone());
+        ts.normalize();
+        Ok(Value::String(ts.to_string()))
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::String(s) => s
+                .parse::<prost_types::Timestamp>()
+                .map(Into::into)
+                .map_err(parse_err::<Self>),
+            value => Err(invalid::<Self>(&value)),
+        }
+    }
+}
+
+/// Seconds with an `s` suffix, e.g. `"1.000340012s"`.
+impl ProtoJson for Duration {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        let mut d = prost_types::Duration::from(self.clone());
+        d.normalize();
+        Ok(Value::String(d.to_string()))
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::String(s) => s
+                .parse::<prost_types::Duration>()
+                .map(Into::into)
+                .map_err(parse_err::<Self>),
+            value => Err(invalid::<Self>(&value)),
+        }
+    }
+}
+
+/// The fields of the message held by `Any`, looked up in [`crate::registry`],
+/// along with its type url as `@type`.
+impl ProtoJson for Any {
+    fn to_proto_json(&self) -> Result<Value, Error> {
+        let msg = crate::registry::decode(self)?;
+        let mut fields = into_fields(msg.as_proto_json().to_proto_json()?, &self.type_url)?;
+        fields.insert("@type".to_string(), Value::String(self.type_url.clone()));
+        Ok(Value::Object(fields))
+    }
+
+    fn from_proto_json(value: Value) -> Result<Self, Error> {
+        let mut fields = into_fields(value, "Any")?;
+        let type_url = match fields.remove("@type") {
+            Some(Value::String(type_url)) => type_url,
+            _ => return Err(Error::parse_err("Any", "missing `@type`")),
+        };
+
+        crate::registry::from_proto_json(&type_url, Value::Object(fields))
+    }
+}
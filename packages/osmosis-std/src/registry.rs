@@ -0,0 +1,284 @@
+//! Decoding [`Any`] without knowing its type in advance.
+//!
+//! Every generated message that has a type url is registered here, so an [`Any`] such as
+//! `QueryPoolResponse.pool`, which can hold either a balancer or a stableswap pool,
+//! can be decoded by its type url and then printed, serialized or downcast to the concrete type.
+//! The response type of each `Query` method is registered by its path as well, so that a
+//! response can be decoded with [`decode_query_response`].
+//!
+//! ```
+//! use osmosis_std::registry;
+//! use osmosis_std::shim::Any;
+//! use osmosis_std::types::osmosis::gamm::v1beta1::Pool;
+//!
+//! let pool = Pool {
+//!     id: 1,
+//!     ..Default::default()
+//! };
+//! let any = Any {
+//!     type_url: Pool::TYPE_URL.to_string(),
+//!     value: prost::Message::encode_to_vec(&pool),
+//! };
+//!
+//! let msg = registry::decode(&any).unwrap();
+//! assert_eq!(msg.type_url(), Pool::TYPE_URL);
+//! assert_eq!(msg.downcast_ref::<Pool>(), Some(&pool));
+//! ```
+
+use std::any::Any as StdAny;
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::{ser, Serialize};
+use serde_cw_value::{DeserializerError, SerializerError, Value, ValueDeserializer};
+
+use crate::shim::Any;
+use crate::Error;
+
+/// A message that can be packed into [`Any`], decoded through the registry.
+pub trait AnyMessage: Debug + StdAny {
+    /// Type url identifying this message in [`Any`].
+    fn type_url(&self) -> &'static str;
+
+    /// Encode this message as protobuf.
+    fn encode_to_vec(&self) -> Vec<u8>;
+
+    /// Pack this message into [`Any`].
+    fn to_any(&self) -> Any {
+        Any {
+            type_url: self.type_url().to_string(),
+            value: self.encode_to_vec(),
+        }
+    }
+
+    #[doc(hidden)]
+    fn to_value(&self) -> Result<Value, SerializerError>;
+
+    #[cfg(feature = "amino")]
+    #[doc(hidden)]
+    fn as_amino_json(&self) -> &dyn crate::amino::AminoJson;
+
+    #[cfg(feature = "proto-json")]
+    #[doc(hidden)]
+    fn as_proto_json(&self) -> &dyn crate::proto_json::ProtoJson;
+
+    #[doc(hidden)]
+    fn as_std_any(&self) -> &dyn StdAny;
+
+    #[doc(hidden)]
+    fn into_std_any(self: Box<Self>) -> Box<dyn StdAny>;
+}
+
+impl dyn AnyMessage {
+    /// Returns `true` if the decoded message is of type `T`.
+    pub fn is<T: AnyMessage>(&self) -> bool {
+        self.as_std_any().is::<T>()
+    }
+
+    /// Returns a reference to the decoded message if it is of type `T`.
+    pub fn downcast_ref<T: AnyMessage>(&self) -> Option<&T> {
+        self.as_std_any().downcast_ref::<T>()
+    }
+
+    /// Downcast the decoded message to `T`, giving it back as is if it is of another type.
+    pub fn downcast<T: AnyMessage>(self: Box<Self>) -> Result<Box<T>, Box<Self>> {
+        if self.is::<T>() {
+            Ok(self
+                .into_std_any()
+                .downcast::<T>()
+                .expect("type has already been checked"))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Serialize for dyn AnyMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_value()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Decode `any` as the message registered for its type url.
+pub fn decode(any: &Any) -> Result<Box<dyn AnyMessage>, Error> {
+    let registration = lookup(&any.type_url)?;
+
+    (registration.decode)(any.value.as_slice())
+        .map_err(|e| Error::parse_err(any.type_url.as_str(), e))
+}
+
+/// Decode `bytes`, the response of the `Query` method at `path`,
+/// e.g. `/osmosis.gamm.v1beta1.Query/Pool`.
+///
+/// ```
+/// use osmosis_std::registry;
+/// use osmosis_std::types::cosmos::bank::v1beta1::QuerySupplyOfResponse;
+/// use osmosis_std::types::cosmos::base::v1beta1::Coin;
+///
+/// let res = QuerySupplyOfResponse {
+///     amount: Some(Coin {
+///         denom: "uosmo".to_string(),
+///         amount: 1000u128.into(),
+///     }),
+/// };
+/// let bytes = prost::Message::encode_to_vec(&res);
+///
+/// let msg = registry::decode_query_response("/cosmos.bank.v1beta1.Query/SupplyOf", &bytes).unwrap();
+/// assert_eq!(msg.downcast_ref::<QuerySupplyOfResponse>(), Some(&res));
+/// ```
+pub fn decode_query_response(path: &str, bytes: &[u8]) -> Result<Box<dyn AnyMessage>, Error> {
+    let type_url = query_response_type_url(path)
+        .ok_or_else(|| Error::not_found(format!("response registered for `{}`", path)))?;
+
+    decode(&Any {
+        type_url: type_url.to_string(),
+        value: bytes.to_vec(),
+    })
+}
+
+/// Type url of the response of the `Query` method at `path`, if it is registered.
+pub fn query_response_type_url(path: &str) -> Option<&'static str> {
+    crate::types::registry::query_response_type_url(path)
+}
+
+/// Returns `true` if there is a message registered for `type_url`.
+pub fn is_registered(type_url: &str) -> bool {
+    crate::types::registry::lookup(type_url).is_some()
+}
+
+/// Type urls of all registered messages.
+pub fn type_urls() -> &'static [&'static str] {
+    crate::types::registry::TYPE_URLS
+}
+
+impl TryFrom<Any> for Box<dyn AnyMessage> {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        decode(&value)
+    }
+}
+
+/// Deserialize `value` as the message registered for `type_url` and pack it into [`Any`].
+pub(crate) fn deserialize_any(type_url: &str, value: Value) -> Result<Any, Error> {
+    let registration = lookup(type_url)?;
+
+    (registration.deserialize_any)(value).map_err(|e| Error::parse_err(type_url, e))
+}
+
+/// Decode the protobuf JSON `value` as the message registered for `type_url` and pack it into [`Any`].
+#[cfg(feature = "proto-json")]
+pub(crate) fn from_proto_json(type_url: &str, value: serde_json::Value) -> Result<Any, Error> {
+    crate::types::registry::from_proto_json(type_url, value)
+        .ok_or_else(|| Error::not_found(format!("message registered for `{}`", type_url)))?
+}
+
+fn lookup(type_url: &str) -> Result<Registration, Error> {
+    crate::types::registry::lookup(type_url)
+        .ok_or_else(|| Error::not_found(format!("message registered for `{}`", type_url)))
+}
+
+type DecodeFn = fn(&[u8]) -> Result<Box<dyn AnyMessage>, prost::DecodeError>;
+type DeserializeAnyFn = fn(Value) -> Result<Any, DeserializerError>;
+
+/// Type erased operations for a registered message.
+#[derive(Clone, Copy)]
+pub(crate) struct Registration {
+    decode: DecodeFn,
+    deserialize_any: DeserializeAnyFn,
+}
+
+impl Registration {
+    pub(crate) fn of<T>() -> Self
+    where
+        T: AnyMessage + prost::Message + Default + DeserializeOwned,
+    {
+        Registration {
+            decode: |bytes| Ok(Box::new(T::decode(bytes)?)),
+            deserialize_any: |value| {
+                T::deserialize(ValueDeserializer::<DeserializerError>::new(value))
+                    .map(|msg| msg.to_any())
+            },
+        }
+    }
+}
+
+/// Implement [`AnyMessage`] for each of the given types and register them by their type url,
+/// and register the response type of each `Query` method by its path.
+macro_rules! register_types {
+    (
+        types: { $($ty:path,)* }
+        queries: { $($query_path:literal => $response_type:path,)* }
+    ) => {
+        $(
+            impl $crate::registry::AnyMessage for $ty {
+                fn type_url(&self) -> &'static str {
+                    <$ty>::TYPE_URL
+                }
+
+                fn encode_to_vec(&self) -> Vec<u8> {
+                    prost::Message::encode_to_vec(self)
+                }
+
+                fn to_value(&self) -> Result<serde_cw_value::Value, serde_cw_value::SerializerError> {
+                    serde_cw_value::to_value(self)
+                }
+
+                #[cfg(feature = "amino")]
+                fn as_amino_json(&self) -> &dyn $crate::amino::AminoJson {
+                    self
+                }
+
+                #[cfg(feature = "proto-json")]
+                fn as_proto_json(&self) -> &dyn $crate::proto_json::ProtoJson {
+                    self
+                }
+
+                fn as_std_any(&self) -> &dyn std::any::Any {
+                    self
+                }
+
+                fn into_std_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+                    self
+                }
+            }
+        )*
+
+        pub(crate) const TYPE_URLS: &[&str] = &[$(<$ty>::TYPE_URL,)*];
+
+        pub(crate) fn lookup(type_url: &str) -> Option<$crate::registry::Registration> {
+            match type_url {
+                $(<$ty>::TYPE_URL => Some($crate::registry::Registration::of::<$ty>()),)*
+                _ => None,
+            }
+        }
+
+        pub(crate) fn query_response_type_url(path: &str) -> Option<&'static str> {
+            match path {
+                $($query_path => Some(<$response_type>::TYPE_URL),)*
+                _ => None,
+            }
+        }
+
+        #[cfg(feature = "proto-json")]
+        pub(crate) fn from_proto_json(
+            type_url: &str,
+            value: serde_json::Value,
+        ) -> Option<Result<$crate::shim::Any, $crate::Error>> {
+            match type_url {
+                $(<$ty>::TYPE_URL => Some(
+                    <$ty as $crate::proto_json::ProtoJson>::from_proto_json(value)
+                        .map(|msg| $crate::registry::AnyMessage::to_any(&msg)),
+                ),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+pub(crate) use register_types;
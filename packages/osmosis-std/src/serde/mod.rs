@@ -1,6 +1,7 @@
 pub mod as_str {
+    use alloc::string::{String, ToString};
+    use core::{fmt::Display, str::FromStr};
     use serde::{de, Deserialize, Deserializer, Serializer};
-    use std::{fmt::Display, str::FromStr};
 
     pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
     where
@@ -20,3 +21,76 @@ pub mod as_str {
         serializer.serialize_str(&value.to_string())
     }
 }
+
+/// (De)serialize an `i32` field that holds a protobuf enum as the name of its value,
+/// rejecting numbers that are not a value of the enum.
+///
+/// Numbers, and numbers as strings, are still accepted when deserializing.
+pub mod enumeration {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, value::StrDeserializer, DeserializeOwned, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// A protobuf enum, which messages hold as `i32`.
+    pub trait Enumeration: Copy + Into<i32> + Serialize + DeserializeOwned {
+        fn from_i32(value: i32) -> Option<Self>;
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<i32, D::Error>
+    where
+        T: Enumeration,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(EnumerationVisitor::<T>(PhantomData))
+    }
+
+    pub fn serialize<T, S>(value: &i32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Enumeration,
+        S: Serializer,
+    {
+        T::from_i32(*value)
+            .ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "invalid value for {}: {}",
+                    core::any::type_name::<T>(),
+                    value
+                ))
+            })?
+            .serialize(serializer)
+    }
+
+    struct EnumerationVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Enumeration> Visitor<'de> for EnumerationVisitor<T> {
+        type Value = i32;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a value of {}", core::any::type_name::<T>())
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<i32, E> {
+            match v.parse() {
+                Ok(n) => self.visit_i64(n),
+                Err(_) => T::deserialize(StrDeserializer::<E>::new(v)).map(Into::into),
+            }
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<i32, E> {
+            i32::try_from(v)
+                .ok()
+                .and_then(T::from_i32)
+                .map(Into::into)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<i32, E> {
+            i32::try_from(v)
+                .ok()
+                .and_then(T::from_i32)
+                .map(Into::into)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+        }
+    }
+}
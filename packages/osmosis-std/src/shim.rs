@@ -1,14 +1,16 @@
 use ::serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
-use chrono::{DateTime, NaiveDateTime, Utc};
+use alloc::format;
+use alloc::string::{String, ToString};
 use serde::de;
 use serde::de::Visitor;
 
-use std::fmt;
-use std::str::FromStr;
+use core::fmt;
+use core::str::FromStr;
 
-use prost::Message;
+use crate::Error;
 
-#[derive(Clone, PartialEq, Eq, ::prost::Message, schemars::JsonSchema)]
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 pub struct Timestamp {
     /// Represents seconds of UTC time since Unix epoch
     /// 1970-01-01T00:00:00Z. Must be from 0001-01-01T00:00:00Z to
@@ -28,14 +30,10 @@ impl Serialize for Timestamp {
     where
         S: Serializer,
     {
-        let mut ts = prost_types::Timestamp {
-            seconds: self.seconds,
-            nanos: self.nanos,
-        };
+        let mut ts = prost_types::Timestamp::from(self.clone());
         ts.normalize();
-        let dt = NaiveDateTime::from_timestamp(ts.seconds, ts.nanos as u32);
-        let dt: DateTime<Utc> = DateTime::from_utc(dt, Utc);
-        serializer.serialize_str(format!("{:?}", dt).as_str())
+
+        serializer.serialize_str(ts.to_string().as_str())
     }
 }
 
@@ -57,29 +55,32 @@ impl<'de> Deserialize<'de> for Timestamp {
             where
                 E: de::Error,
             {
-                let utc: DateTime<Utc> = chrono::DateTime::from_str(value).map_err(|err| {
-                    serde::de::Error::custom(format!(
-                        "Failed to parse {} as datetime: {:?}",
-                        value, err
-                    ))
-                })?;
-                let ts = Timestamp::from(utc);
-                Ok(ts)
+                value
+                    .parse::<prost_types::Timestamp>()
+                    .map(Into::into)
+                    .map_err(|err| {
+                        serde::de::Error::custom(format!(
+                            "Failed to parse {} as datetime: {:?}",
+                            value, err
+                        ))
+                    })
             }
         }
         deserializer.deserialize_str(TimestampVisitor)
     }
 }
 
-impl From<DateTime<Utc>> for Timestamp {
-    fn from(dt: DateTime<Utc>) -> Self {
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
         Timestamp {
             seconds: dt.timestamp(),
             nanos: dt.timestamp_subsec_nanos() as i32,
         }
     }
 }
-#[derive(Clone, PartialEq, Eq, ::prost::Message, schemars::JsonSchema)]
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 pub struct Duration {
     /// Signed seconds of the span of time. Must be from -315,576,000,000
     /// to +315,576,000,000 inclusive. Note: these bounds are computed from:
@@ -101,7 +102,7 @@ impl Serialize for Duration {
     where
         S: Serializer,
     {
-        let mut d = prost_types::Duration::from(self.to_owned());
+        let mut d = prost_types::Duration::from(self.clone());
         d.normalize();
 
         serializer.serialize_str(d.to_string().as_str())
@@ -136,7 +137,8 @@ impl<'de> Deserialize<'de> for Duration {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, ::prost::Message, schemars::JsonSchema)]
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 pub struct Any {
     /// A URL/resource name that uniquely identifies the type of the serialized
     /// protocol buffer message. This string must contain at least
@@ -173,35 +175,56 @@ pub struct Any {
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
 
-macro_rules! expand_as_any {
-    ($($ty:path,)*) => {
+// TODO: make serialized data contains `@type` (https://github.com/osmosis-labs/osmosis-rust/issues/43)
+// `Any` is (de)serialized as the message it holds, which is looked up in `crate::registry`,
+// so without `std` it can only be handled as protobuf.
+impl Serialize for Any {
+    #[cfg(feature = "std")]
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as ::serde::Serializer>::Ok, <S as ::serde::Serializer>::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        crate::registry::decode(self)
+            .map_err(|_| {
+                ser::Error::custom(
+                    "data did not match any type that supports serialization as `Any`",
+                )
+            })?
+            .serialize(serializer)
+    }
 
-        // TODO: make serialized data contains `@type` (https://github.com/osmosis-labs/osmosis-rust/issues/43)
-        impl Serialize for Any {
-            fn serialize<S>(
-                &self,
-                serializer: S,
-            ) -> Result<<S as ::serde::Serializer>::Ok, <S as ::serde::Serializer>::Error>
-            where
-                S: ::serde::Serializer,
-            {
-                $(
-                    if self.type_url == <$ty>::TYPE_URL {
-                        let value: Result<$ty, <S as ::serde::Serializer>::Error> =
-                            prost::Message::decode(self.value.as_slice()).map_err(ser::Error::custom);
-
-                        if let Ok(value) = value {
-                            return value.serialize(serializer);
-                        }
-                    }
-                )*
+    #[cfg(not(feature = "std"))]
+    fn serialize<S>(
+        &self,
+        _serializer: S,
+    ) -> Result<<S as ::serde::Serializer>::Ok, <S as ::serde::Serializer>::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        Err(ser::Error::custom(
+            "serializing `Any` requires the `std` feature",
+        ))
+    }
+}
 
-                Err(serde::ser::Error::custom(
-                    "data did not match any type that supports serialization as `Any`",
-                ))
-            }
-        }
+#[cfg(not(feature = "std"))]
+impl<'de> Deserialize<'de> for Any {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Err(de::Error::custom(
+            "deserializing `Any` requires the `std` feature",
+        ))
+    }
+}
 
+macro_rules! expand_as_any {
+    ($($ty:path,)*) => {
+        #[cfg(feature = "std")]
         impl<'de> Deserialize<'de> for Any {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -229,20 +252,8 @@ macro_rules! expand_as_any {
                 match type_url {
                     // @type found
                     Some(t) => {
-                        $(
-                            if t == <$ty>::TYPE_URL {
-                                return <$ty>::deserialize(
-                                    serde_cw_value::ValueDeserializer::<serde_cw_value::DeserializerError>::new(
-                                        value.clone(),
-                                    ),
-                                )
-                                .map(|v| Any {
-                                    type_url: <$ty>::TYPE_URL.to_string(),
-                                    value: v.encode_to_vec(),
-                                })
-                                .map_err(serde::de::Error::custom);
-                            }
-                        )*
+                        return crate::registry::deserialize_any(&t, value)
+                            .map_err(serde::de::Error::custom);
                     }
                     // @type not found, try match the type structure
                     None => {
@@ -254,7 +265,7 @@ macro_rules! expand_as_any {
                             ) {
                                 return Ok(Any {
                                     type_url: <$ty>::TYPE_URL.to_string(),
-                                    value: v.encode_to_vec(),
+                                    value: prost::Message::encode_to_vec(&v),
                                 });
                             }
                         )*
@@ -279,7 +290,8 @@ macro_rules! expand_as_any {
     };
 }
 
-// [HACK] Register all types that can serde as Any manually for now.
+// [HACK] Register types that can be deserialized as Any without `@type` manually for now.
+// Any with `@type` is deserialized through `crate::registry` instead.
 // must order by type that has more information for Any deserialization to
 // work correctly. Since after serialization, it currently loses @type tag.
 // And deserialization works by trying to iteratively match the structure.
@@ -320,6 +332,7 @@ impl_prost_types_exact_conversion! { Timestamp | seconds, nanos }
 impl_prost_types_exact_conversion! { Duration | seconds, nanos }
 impl_prost_types_exact_conversion! { Any | type_url, value }
 
+#[cfg(feature = "cosmwasm")]
 impl From<cosmwasm_std::Coin> for crate::types::cosmos::base::v1beta1::Coin {
     fn from(cosmwasm_std::Coin { denom, amount }: cosmwasm_std::Coin) -> Self {
         crate::types::cosmos::base::v1beta1::Coin {
@@ -329,6 +342,7 @@ impl From<cosmwasm_std::Coin> for crate::types::cosmos::base::v1beta1::Coin {
     }
 }
 
+#[cfg(feature = "cosmwasm")]
 impl TryFrom<crate::types::cosmos::base::v1beta1::Coin> for cosmwasm_std::Coin {
     type Error = cosmwasm_std::StdError;
 
@@ -341,3 +355,226 @@ impl TryFrom<crate::types::cosmos::base::v1beta1::Coin> for cosmwasm_std::Coin {
         })
     }
 }
+
+/// Number of fractional digits of [`Dec`].
+const DEC_PRECISION: usize = 18;
+
+/// `sdk.Dec`, a signed fixed-point decimal with 18 fractional digits.
+///
+/// In protobuf it is encoded as a string holding the value scaled by 10^18, e.g. `"10000000000000000"`
+/// for 0.01, and that is what `Dec` dereferences to. serde uses the decimal notation that the chain
+/// uses for JSON, e.g. `"0.010000000000000000"`.
+///
+/// ```
+/// use osmosis_std::shim::Dec;
+///
+/// let dec: Dec = "0.01".parse().unwrap();
+/// assert_eq!(dec, Dec::from_raw("10000000000000000"));
+/// assert_eq!(dec.to_string(), "0.010000000000000000");
+/// ```
+///
+/// Generated fields of this type are tagged as `required` with a default of `"0"`, so that they are
+/// always encoded, the same way gogoproto encodes non-nullable custom types.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Dec(String);
+
+/// `sdk.Int`, a signed integer of up to 256 bits, encoded as a decimal string.
+///
+/// Generated fields of this type are tagged as `required` with a default of `"0"`, so that they are
+/// always encoded, the same way gogoproto encodes non-nullable custom types.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Int(String);
+
+macro_rules! impl_custom_type {
+    ($t:ident) => {
+        impl Default for $t {
+            fn default() -> Self {
+                $t("0".to_string())
+            }
+        }
+
+        impl $t {
+            /// Wrap `raw`, its protobuf representation, as is, without checking it.
+            /// Use [`FromStr`] to parse a value in its usual notation.
+            pub fn from_raw(raw: impl Into<String>) -> Self {
+                $t(raw.into())
+            }
+        }
+
+        /// Wraps the protobuf representation as is, which is how prost sets field defaults.
+        /// Hidden since it reads like parsing, use [`Self::from_raw`] instead.
+        #[doc(hidden)]
+        impl From<&str> for $t {
+            fn from(raw: &str) -> Self {
+                Self::from_raw(raw)
+            }
+        }
+
+        impl core::ops::Deref for $t {
+            type Target = String;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $t {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($t), self)
+            }
+        }
+
+        impl Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        #[cfg(feature = "cosmwasm")]
+        impl schemars::JsonSchema for $t {
+            fn schema_name() -> String {
+                stringify!($t).to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                String::json_schema(gen)
+            }
+        }
+    };
+}
+
+/// Lossless conversion into `$t`, and checked conversion back
+/// from its protobuf representation `$raw`.
+macro_rules! impl_custom_type_conversion {
+    ($t:ident, $other:ty, |$value:ident| $to_raw:expr, |$raw:ident| $from_raw:expr) => {
+        #[cfg(feature = "cosmwasm")]
+        impl From<$other> for $t {
+            fn from($value: $other) -> Self {
+                $t($to_raw.to_string())
+            }
+        }
+
+        #[cfg(feature = "cosmwasm")]
+        impl TryFrom<&$t> for $other {
+            type Error = cosmwasm_std::StdError;
+
+            fn try_from(value: &$t) -> cosmwasm_std::StdResult<Self> {
+                let $raw = value.0.parse()?;
+                Ok($from_raw)
+            }
+        }
+
+        #[cfg(feature = "cosmwasm")]
+        impl TryFrom<$t> for $other {
+            type Error = cosmwasm_std::StdError;
+
+            fn try_from(value: $t) -> cosmwasm_std::StdResult<Self> {
+                <$other>::try_from(&value)
+            }
+        }
+    };
+}
+
+impl_custom_type!(Dec);
+impl_custom_type!(Int);
+
+impl_custom_type_conversion!(Dec, cosmwasm_std::Decimal, |d| d.atomics(), |raw| {
+    cosmwasm_std::Decimal::new(raw)
+});
+impl_custom_type_conversion!(Dec, cosmwasm_std::Decimal256, |d| d.atomics(), |raw| {
+    cosmwasm_std::Decimal256::new(raw)
+});
+impl_custom_type_conversion!(Int, cosmwasm_std::Uint128, |n| n, |raw| raw);
+impl_custom_type_conversion!(Int, cosmwasm_std::Uint256, |n| n, |raw| raw);
+
+impl From<u128> for Int {
+    fn from(value: u128) -> Self {
+        Int(value.to_string())
+    }
+}
+
+impl fmt::Display for Dec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, digits) = match self.0.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", self.0.as_str()),
+        };
+        // not a valid `Dec`, e.g. decoded from a malformed message
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return f.write_str(&self.0);
+        }
+        let digits = format!("{:0>width$}", digits, width = DEC_PRECISION + 1);
+        let (whole, fractional) = digits.split_at(digits.len() - DEC_PRECISION);
+
+        write!(f, "{}{}.{}", sign, whole, fractional)
+    }
+}
+
+impl FromStr for Dec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::parse_err("Dec", format!("invalid decimal: {}", s));
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        let (whole, fractional) = unsigned.split_once('.').unwrap_or((unsigned, "0"));
+        if !is_digits(whole) || !is_digits(fractional) || fractional.len() > DEC_PRECISION {
+            return Err(invalid());
+        }
+
+        let atomics = format!("{}{:0<width$}", whole, fractional, width = DEC_PRECISION);
+        let atomics = match atomics.trim_start_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        };
+
+        Ok(if negative && atomics != "0" {
+            Dec(format!("-{}", atomics))
+        } else {
+            Dec(atomics.to_string())
+        })
+    }
+}
+
+impl fmt::Display for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Int {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unsigned = s.strip_prefix('-').unwrap_or(s);
+        if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::parse_err("Int", format!("invalid integer: {}", s)));
+        }
+
+        Ok(Int(s.to_string()))
+    }
+}
@@ -9,9 +9,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.auth.v1beta1.BaseAccount")]
 pub struct BaseAccount {
     #[prost(string, tag = "1")]
@@ -39,9 +39,9 @@ pub struct BaseAccount {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.auth.v1beta1.ModuleAccount")]
 pub struct ModuleAccount {
     #[prost(message, optional, tag = "1")]
@@ -59,9 +59,9 @@ pub struct ModuleAccount {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.auth.v1beta1.Params")]
 pub struct Params {
     #[prost(uint64, tag = "1")]
@@ -7,9 +7,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Params")]
 pub struct Params {
     #[prost(message, repeated, tag = "1")]
@@ -26,9 +26,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.SendEnabled")]
 pub struct SendEnabled {
     #[prost(string, tag = "1")]
@@ -44,14 +44,15 @@ pub struct SendEnabled {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Input")]
 pub struct Input {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
 /// Output models transaction outputs.
@@ -62,14 +63,15 @@ pub struct Input {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Output")]
 pub struct Output {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
 /// Supply represents a struct that passively keeps track of the total supply
@@ -82,9 +84,9 @@ pub struct Output {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Supply")]
 pub struct Supply {
     #[prost(message, repeated, tag = "1")]
@@ -99,9 +101,9 @@ pub struct Supply {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.DenomUnit")]
 pub struct DenomUnit {
     /// denom represents the string name of the given denom unit (e.g uatom).
@@ -131,9 +133,9 @@ pub struct DenomUnit {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Metadata")]
 pub struct Metadata {
     #[prost(string, tag = "1")]
@@ -168,16 +170,21 @@ pub struct Metadata {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/cosmos.bank.v1beta1.MsgSend")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.bank.v1beta1.MsgSend",
+    amino_type = "cosmos-sdk/MsgSend",
+    response_type = MsgSendResponse
+)]
 pub struct MsgSend {
     #[prost(string, tag = "1")]
     pub from_address: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub to_address: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
 /// MsgSendResponse defines the Msg/Send response type.
@@ -188,9 +195,9 @@ pub struct MsgSend {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.MsgSendResponse")]
 pub struct MsgSendResponse {}
 /// MsgMultiSend represents an arbitrary multi-in, multi-out send message.
@@ -201,14 +208,20 @@ pub struct MsgSendResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/cosmos.bank.v1beta1.MsgMultiSend")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.bank.v1beta1.MsgMultiSend",
+    amino_type = "cosmos-sdk/MsgMultiSend",
+    response_type = MsgMultiSendResponse
+)]
 pub struct MsgMultiSend {
     #[prost(message, repeated, tag = "1")]
+    #[amino(not_omitted)]
     pub inputs: ::prost::alloc::vec::Vec<Input>,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub outputs: ::prost::alloc::vec::Vec<Output>,
 }
 /// MsgMultiSendResponse defines the Msg/MultiSend response type.
@@ -219,9 +232,9 @@ pub struct MsgMultiSend {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.MsgMultiSendResponse")]
 pub struct MsgMultiSendResponse {}
 /// QueryBalanceRequest is the request type for the Query/Balance RPC method.
@@ -232,9 +245,9 @@ pub struct MsgMultiSendResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryBalanceRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/Balance",
@@ -256,9 +269,9 @@ pub struct QueryBalanceRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryBalanceResponse")]
 pub struct QueryBalanceResponse {
     /// balance is the balance of the coin.
@@ -273,9 +286,9 @@ pub struct QueryBalanceResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryAllBalancesRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/AllBalances",
@@ -298,9 +311,9 @@ pub struct QueryAllBalancesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryAllBalancesResponse")]
 pub struct QueryAllBalancesResponse {
     /// balances is the balances of all the coins.
@@ -319,9 +332,9 @@ pub struct QueryAllBalancesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryTotalSupplyRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/TotalSupply",
@@ -343,9 +356,9 @@ pub struct QueryTotalSupplyRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryTotalSupplyResponse")]
 pub struct QueryTotalSupplyResponse {
     /// supply is the supply of the coins
@@ -365,9 +378,9 @@ pub struct QueryTotalSupplyResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QuerySupplyOfRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/SupplyOf",
@@ -386,9 +399,9 @@ pub struct QuerySupplyOfRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QuerySupplyOfResponse")]
 pub struct QuerySupplyOfResponse {
     /// amount is the supply of the coin.
@@ -404,9 +417,9 @@ pub struct QuerySupplyOfResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryTotalSupplyWithoutOffsetRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/TotalSupplyWithoutOffset",
@@ -428,9 +441,9 @@ pub struct QueryTotalSupplyWithoutOffsetRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryTotalSupplyWithoutOffsetResponse")]
 pub struct QueryTotalSupplyWithoutOffsetResponse {
     /// supply is the supply of the coins
@@ -450,9 +463,9 @@ pub struct QueryTotalSupplyWithoutOffsetResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QuerySupplyOfWithoutOffsetRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/SupplyOfWithoutOffset",
@@ -471,9 +484,9 @@ pub struct QuerySupplyOfWithoutOffsetRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QuerySupplyOfWithoutOffsetResponse")]
 pub struct QuerySupplyOfWithoutOffsetResponse {
     /// amount is the supply of the coin.
@@ -488,9 +501,9 @@ pub struct QuerySupplyOfWithoutOffsetResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryParamsRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/Params",
@@ -505,9 +518,9 @@ pub struct QueryParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryParamsResponse")]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
@@ -521,9 +534,9 @@ pub struct QueryParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryDenomsMetadataRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/DenomsMetadata",
@@ -543,9 +556,9 @@ pub struct QueryDenomsMetadataRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryDenomsMetadataResponse")]
 pub struct QueryDenomsMetadataResponse {
     /// metadata provides the client information for all the registered tokens.
@@ -563,9 +576,9 @@ pub struct QueryDenomsMetadataResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryDenomMetadataRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/DenomMetadata",
@@ -585,9 +598,9 @@ pub struct QueryDenomMetadataRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryDenomMetadataResponse")]
 pub struct QueryDenomMetadataResponse {
     /// metadata describes and provides all the client information for the requested token.
@@ -602,9 +615,9 @@ pub struct QueryDenomMetadataResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryBaseDenomRequest")]
 #[proto_query(
     path = "/cosmos.bank.v1beta1.Query/BaseDenom",
@@ -622,9 +635,9 @@ pub struct QueryBaseDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.QueryBaseDenomResponse")]
 pub struct QueryBaseDenomResponse {
     #[prost(string, tag = "1")]
@@ -641,9 +654,9 @@ pub struct QueryBaseDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.SendAuthorization")]
 pub struct SendAuthorization {
     #[prost(message, repeated, tag = "1")]
@@ -657,9 +670,9 @@ pub struct SendAuthorization {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.GenesisState")]
 pub struct GenesisState {
     /// params defines all the paramaters of the module.
@@ -688,9 +701,9 @@ pub struct GenesisState {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.Balance")]
 pub struct Balance {
     /// address is the address of the balance holder.
@@ -709,9 +722,9 @@ pub struct Balance {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.bank.v1beta1.GenesisSupplyOffset")]
 pub struct GenesisSupplyOffset {
     /// Denom
@@ -721,9 +734,335 @@ pub struct GenesisSupplyOffset {
     #[prost(string, tag = "2")]
     pub offset: ::prost::alloc::string::String,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn balance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryBalanceRequest>,
+        ) -> Result<tonic::Response<super::QueryBalanceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/Balance");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn all_balances(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryAllBalancesRequest>,
+        ) -> Result<tonic::Response<super::QueryAllBalancesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/AllBalances");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_supply(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalSupplyRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalSupplyResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/TotalSupply");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn supply_of(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySupplyOfRequest>,
+        ) -> Result<tonic::Response<super::QuerySupplyOfResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/SupplyOf");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_supply_without_offset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalSupplyWithoutOffsetRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalSupplyWithoutOffsetResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.bank.v1beta1.Query/TotalSupplyWithoutOffset",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn supply_of_without_offset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySupplyOfWithoutOffsetRequest>,
+        ) -> Result<tonic::Response<super::QuerySupplyOfWithoutOffsetResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.bank.v1beta1.Query/SupplyOfWithoutOffset",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn denom_metadata(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDenomMetadataRequest>,
+        ) -> Result<tonic::Response<super::QueryDenomMetadataResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/DenomMetadata");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn denoms_metadata(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDenomsMetadataRequest>,
+        ) -> Result<tonic::Response<super::QueryDenomsMetadataResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/DenomsMetadata");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn base_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryBaseDenomRequest>,
+        ) -> Result<tonic::Response<super::QueryBaseDenomResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Query/BaseDenom");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn send(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSend>,
+        ) -> Result<tonic::Response<super::MsgSendResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Msg/Send");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn multi_send(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgMultiSend>,
+        ) -> Result<tonic::Response<super::MsgMultiSendResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.bank.v1beta1.Msg/MultiSend");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct BankQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> BankQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -792,3 +1131,26 @@ impl<'a, Q: cosmwasm_std::CustomQuery> BankQuerier<'a, Q> {
         QueryBaseDenomRequest { denom }.query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct BankMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl BankMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn send(
+        &self,
+        to_address: ::prost::alloc::string::String,
+        amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSend {
+            from_address: self.sender.to_string(),
+            to_address,
+            amount,
+        }
+        .into()
+    }
+}
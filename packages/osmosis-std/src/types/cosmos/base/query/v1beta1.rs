@@ -13,9 +13,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.query.v1beta1.PageRequest")]
 pub struct PageRequest {
     /// key is a value returned in PageResponse.next_key to begin
@@ -66,9 +66,9 @@ pub struct PageRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.query.v1beta1.PageResponse")]
 pub struct PageResponse {
     /// next_key is the key to be passed to PageRequest.key to
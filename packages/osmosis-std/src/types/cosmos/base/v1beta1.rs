@@ -10,15 +10,16 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.v1beta1.Coin")]
 pub struct Coin {
     #[prost(string, tag = "1")]
     pub denom: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub amount: crate::shim::Int,
 }
 /// DecCoin defines a token with a denomination and a decimal amount.
 ///
@@ -31,15 +32,16 @@ pub struct Coin {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.v1beta1.DecCoin")]
 pub struct DecCoin {
     #[prost(string, tag = "1")]
     pub denom: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub amount: crate::shim::Dec,
 }
 /// IntProto defines a Protobuf wrapper around an Int object.
 #[derive(
@@ -49,13 +51,13 @@ pub struct DecCoin {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.v1beta1.IntProto")]
 pub struct IntProto {
-    #[prost(string, tag = "1")]
-    pub int: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub int: crate::shim::Int,
 }
 /// DecProto defines a Protobuf wrapper around a Dec object.
 #[derive(
@@ -65,11 +67,11 @@ pub struct IntProto {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.base.v1beta1.DecProto")]
 pub struct DecProto {
-    #[prost(string, tag = "1")]
-    pub dec: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub dec: crate::shim::Dec,
 }
@@ -0,0 +1,1500 @@
+use osmosis_std_derive::CosmwasmExt;
+/// MsgSetWithdrawAddress sets the withdraw address for
+/// a delegator (or validator self-delegation).
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+    amino_type = "cosmos-sdk/MsgModifyWithdrawAddress",
+    response_type = MsgSetWithdrawAddressResponse
+)]
+pub struct MsgSetWithdrawAddress {
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub withdraw_address: ::prost::alloc::string::String,
+}
+/// MsgSetWithdrawAddressResponse defines the Msg/SetWithdrawAddress response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.MsgSetWithdrawAddressResponse")]
+pub struct MsgSetWithdrawAddressResponse {}
+/// MsgWithdrawDelegatorReward represents delegation withdrawal to a delegator
+/// from a single validator.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+    amino_type = "cosmos-sdk/MsgWithdrawDelegationReward",
+    response_type = MsgWithdrawDelegatorRewardResponse
+)]
+pub struct MsgWithdrawDelegatorReward {
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub validator_address: ::prost::alloc::string::String,
+}
+/// MsgWithdrawDelegatorRewardResponse defines the Msg/WithdrawDelegatorReward response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorRewardResponse")]
+pub struct MsgWithdrawDelegatorRewardResponse {}
+/// MsgWithdrawValidatorCommission withdraws the full commission to the validator
+/// address.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+    amino_type = "cosmos-sdk/MsgWithdrawValidatorCommission",
+    response_type = MsgWithdrawValidatorCommissionResponse
+)]
+pub struct MsgWithdrawValidatorCommission {
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+}
+/// MsgWithdrawValidatorCommissionResponse defines the Msg/WithdrawValidatorCommission response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommissionResponse")]
+pub struct MsgWithdrawValidatorCommissionResponse {}
+/// MsgFundCommunityPool allows an account to directly
+/// fund the community pool.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.distribution.v1beta1.MsgFundCommunityPool",
+    amino_type = "cosmos-sdk/MsgFundCommunityPool",
+    response_type = MsgFundCommunityPoolResponse
+)]
+pub struct MsgFundCommunityPool {
+    #[prost(message, repeated, tag = "1")]
+    #[amino(not_omitted)]
+    pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    #[prost(string, tag = "2")]
+    pub depositor: ::prost::alloc::string::String,
+}
+/// MsgFundCommunityPoolResponse defines the Msg/FundCommunityPool response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.MsgFundCommunityPoolResponse")]
+pub struct MsgFundCommunityPoolResponse {}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Msg defines the distribution Msg service.
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// SetWithdrawAddress defines a method to change the withdraw address
+        /// for a delegator (or validator self-delegation).
+        pub async fn set_withdraw_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSetWithdrawAddress>,
+        ) -> Result<tonic::Response<super::MsgSetWithdrawAddressResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Msg/SetWithdrawAddress",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// WithdrawDelegatorReward defines a method to withdraw rewards of delegator
+        /// from a single validator.
+        pub async fn withdraw_delegator_reward(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgWithdrawDelegatorReward>,
+        ) -> Result<tonic::Response<super::MsgWithdrawDelegatorRewardResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Msg/WithdrawDelegatorReward",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// WithdrawValidatorCommission defines a method to withdraw the
+        /// full commission to the validator address.
+        pub async fn withdraw_validator_commission(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgWithdrawValidatorCommission>,
+        ) -> Result<tonic::Response<super::MsgWithdrawValidatorCommissionResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Msg/WithdrawValidatorCommission",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// FundCommunityPool defines a method to allow an account to directly
+        /// fund the community pool.
+        pub async fn fund_community_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgFundCommunityPool>,
+        ) -> Result<tonic::Response<super::MsgFundCommunityPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Msg/FundCommunityPool",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Params defines the set of params for the distribution module.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.Params")]
+pub struct Params {
+    #[prost(string, required, tag = "1", default = "0")]
+    pub community_tax: crate::shim::Dec,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub base_proposer_reward: crate::shim::Dec,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub bonus_proposer_reward: crate::shim::Dec,
+    #[prost(bool, tag = "4")]
+    pub withdraw_addr_enabled: bool,
+}
+/// ValidatorHistoricalRewards represents historical rewards for a validator.
+/// Height is implicit within the store key.
+/// Cumulative reward ratio is the sum from the zeroeth period
+/// until this period of rewards / tokens, per the spec.
+/// The reference count indicates the number of objects
+/// which might need to reference this historical entry at any point.
+/// ReferenceCount =
+///     number of outstanding delegations which ended the associated period (and
+///     might need to read that record)
+///   + number of slashes which ended the associated period (and might need to
+///     read that record)
+///   + one per validator for the zeroeth period, set on initialization
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorHistoricalRewards")]
+pub struct ValidatorHistoricalRewards {
+    #[prost(message, repeated, tag = "1")]
+    pub cumulative_reward_ratio: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+    #[prost(uint32, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub reference_count: u32,
+}
+/// ValidatorCurrentRewards represents current rewards and current
+/// period for a validator kept as a running counter and incremented
+/// each block as long as the validator's tokens remain constant.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorCurrentRewards")]
+pub struct ValidatorCurrentRewards {
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub period: u64,
+}
+/// ValidatorAccumulatedCommission represents accumulated commission
+/// for a validator kept as a running counter, can be withdrawn at any time.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorAccumulatedCommission")]
+pub struct ValidatorAccumulatedCommission {
+    #[prost(message, repeated, tag = "1")]
+    pub commission: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// ValidatorOutstandingRewards represents outstanding (un-withdrawn) rewards
+/// for a validator inexpensive to track, allows simple sanity checks.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorOutstandingRewards")]
+pub struct ValidatorOutstandingRewards {
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// ValidatorSlashEvent represents a validator slash event.
+/// Height is implicit within the store key.
+/// This is needed to calculate appropriate amount of staking tokens
+/// for delegations which are withdrawn after a slash has occurred.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorSlashEvent")]
+pub struct ValidatorSlashEvent {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub validator_period: u64,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub fraction: crate::shim::Dec,
+}
+/// ValidatorSlashEvents is a collection of ValidatorSlashEvent messages.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorSlashEvents")]
+pub struct ValidatorSlashEvents {
+    #[prost(message, repeated, tag = "1")]
+    pub validator_slash_events: ::prost::alloc::vec::Vec<ValidatorSlashEvent>,
+}
+/// FeePool is the global fee pool for distribution.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.FeePool")]
+pub struct FeePool {
+    #[prost(message, repeated, tag = "1")]
+    pub community_pool: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// CommunityPoolSpendProposal details a proposal for use of community funds,
+/// together with how many coins are proposed to be spent, and to which
+/// recipient account.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal",
+    amino_type = "cosmos-sdk/CommunityPoolSpendProposal"
+)]
+pub struct CommunityPoolSpendProposal {
+    #[prost(string, tag = "1")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    #[amino(not_omitted)]
+    pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+}
+/// DelegatorStartingInfo represents the starting info for a delegator reward
+/// period. It tracks the previous validator period, the delegation's amount of
+/// staking token, and the creation height (to check later on if any slashes have
+/// occurred). NOTE: Even though validators are slashed to whole staking tokens,
+/// the delegators within the validator may be left with less than a full token,
+/// thus sdk.Dec is used.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.DelegatorStartingInfo")]
+pub struct DelegatorStartingInfo {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub previous_period: u64,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub stake: crate::shim::Dec,
+    #[prost(uint64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: u64,
+}
+/// DelegationDelegatorReward represents the properties
+/// of a delegator's delegation reward.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.DelegationDelegatorReward")]
+pub struct DelegationDelegatorReward {
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub reward: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// CommunityPoolSpendProposalWithDeposit defines a CommunityPoolSpendProposal
+/// with a deposit
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.CommunityPoolSpendProposalWithDeposit")]
+pub struct CommunityPoolSpendProposalWithDeposit {
+    #[prost(string, tag = "1")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub deposit: ::prost::alloc::string::String,
+}
+/// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryParamsRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/Params",
+    response_type = QueryParamsResponse
+)]
+pub struct QueryParamsRequest {}
+/// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryParamsResponse")]
+pub struct QueryParamsResponse {
+    /// params defines the parameters of the module.
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<Params>,
+}
+/// QueryValidatorOutstandingRewardsRequest is the request type for the
+/// Query/ValidatorOutstandingRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards",
+    response_type = QueryValidatorOutstandingRewardsResponse
+)]
+pub struct QueryValidatorOutstandingRewardsRequest {
+    /// validator_address defines the validator address to query for.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+}
+/// QueryValidatorOutstandingRewardsResponse is the response type for the
+/// Query/ValidatorOutstandingRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsResponse")]
+pub struct QueryValidatorOutstandingRewardsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub rewards: ::core::option::Option<ValidatorOutstandingRewards>,
+}
+/// QueryValidatorCommissionRequest is the request type for the
+/// Query/ValidatorCommission RPC method
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorCommissionRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/ValidatorCommission",
+    response_type = QueryValidatorCommissionResponse
+)]
+pub struct QueryValidatorCommissionRequest {
+    /// validator_address defines the validator address to query for.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+}
+/// QueryValidatorCommissionResponse is the response type for the
+/// Query/ValidatorCommission RPC method
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorCommissionResponse")]
+pub struct QueryValidatorCommissionResponse {
+    /// commission defines the commision the validator received.
+    #[prost(message, optional, tag = "1")]
+    pub commission: ::core::option::Option<ValidatorAccumulatedCommission>,
+}
+/// QueryValidatorSlashesRequest is the request type for the
+/// Query/ValidatorSlashes RPC method
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorSlashesRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/ValidatorSlashes",
+    response_type = QueryValidatorSlashesResponse
+)]
+pub struct QueryValidatorSlashesRequest {
+    /// validator_address defines the validator address to query for.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// starting_height defines the optional starting height to query the slashes.
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub starting_height: u64,
+    /// starting_height defines the optional ending height to query the slashes.
+    #[prost(uint64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub ending_height: u64,
+    /// pagination defines an optional pagination for the request.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+}
+/// QueryValidatorSlashesResponse is the response type for the
+/// Query/ValidatorSlashes RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryValidatorSlashesResponse")]
+pub struct QueryValidatorSlashesResponse {
+    /// slashes defines the slashes the validator received.
+    #[prost(message, repeated, tag = "1")]
+    pub slashes: ::prost::alloc::vec::Vec<ValidatorSlashEvent>,
+    /// pagination defines the pagination in the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageResponse>,
+}
+/// QueryDelegationRewardsRequest is the request type for the
+/// Query/DelegationRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegationRewardsRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+    response_type = QueryDelegationRewardsResponse
+)]
+pub struct QueryDelegationRewardsRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    /// validator_address defines the validator address to query for.
+    #[prost(string, tag = "2")]
+    pub validator_address: ::prost::alloc::string::String,
+}
+/// QueryDelegationRewardsResponse is the response type for the
+/// Query/DelegationRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegationRewardsResponse")]
+pub struct QueryDelegationRewardsResponse {
+    /// rewards defines the rewards accrued by a delegation.
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// QueryDelegationTotalRewardsRequest is the request type for the
+/// Query/DelegationTotalRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegationTotalRewardsRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards",
+    response_type = QueryDelegationTotalRewardsResponse
+)]
+pub struct QueryDelegationTotalRewardsRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+}
+/// QueryDelegationTotalRewardsResponse is the response type for the
+/// Query/DelegationTotalRewards RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegationTotalRewardsResponse")]
+pub struct QueryDelegationTotalRewardsResponse {
+    /// rewards defines all the rewards accrued by a delegator.
+    #[prost(message, repeated, tag = "1")]
+    pub rewards: ::prost::alloc::vec::Vec<DelegationDelegatorReward>,
+    /// total defines the sum of all the rewards.
+    #[prost(message, repeated, tag = "2")]
+    pub total: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// QueryDelegatorValidatorsRequest is the request type for the
+/// Query/DelegatorValidators RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegatorValidatorsRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/DelegatorValidators",
+    response_type = QueryDelegatorValidatorsResponse
+)]
+pub struct QueryDelegatorValidatorsRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+}
+/// QueryDelegatorValidatorsResponse is the response type for the
+/// Query/DelegatorValidators RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegatorValidatorsResponse")]
+pub struct QueryDelegatorValidatorsResponse {
+    /// validators defines the validators a delegator is delegating for.
+    #[prost(string, repeated, tag = "1")]
+    pub validators: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// QueryDelegatorWithdrawAddressRequest is the request type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress",
+    response_type = QueryDelegatorWithdrawAddressResponse
+)]
+pub struct QueryDelegatorWithdrawAddressRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+}
+/// QueryDelegatorWithdrawAddressResponse is the response type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressResponse")]
+pub struct QueryDelegatorWithdrawAddressResponse {
+    /// withdraw_address defines the delegator address to query for.
+    #[prost(string, tag = "1")]
+    pub withdraw_address: ::prost::alloc::string::String,
+}
+/// QueryCommunityPoolRequest is the request type for the Query/CommunityPool RPC
+/// method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryCommunityPoolRequest")]
+#[proto_query(
+    path = "/cosmos.distribution.v1beta1.Query/CommunityPool",
+    response_type = QueryCommunityPoolResponse
+)]
+pub struct QueryCommunityPoolRequest {}
+/// QueryCommunityPoolResponse is the response type for the Query/CommunityPool
+/// RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.QueryCommunityPoolResponse")]
+pub struct QueryCommunityPoolResponse {
+    /// pool defines community pool's coins.
+    #[prost(message, repeated, tag = "1")]
+    pub pool: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Query defines the gRPC querier service for distribution module.
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Params queries params of the distribution module.
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.distribution.v1beta1.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// ValidatorOutstandingRewards queries rewards of a validator address.
+        pub async fn validator_outstanding_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryValidatorOutstandingRewardsRequest>,
+        ) -> Result<tonic::Response<super::QueryValidatorOutstandingRewardsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// ValidatorCommission queries accumulated commission for a validator.
+        pub async fn validator_commission(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryValidatorCommissionRequest>,
+        ) -> Result<tonic::Response<super::QueryValidatorCommissionResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/ValidatorCommission",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// ValidatorSlashes queries slash events of a validator.
+        pub async fn validator_slashes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryValidatorSlashesRequest>,
+        ) -> Result<tonic::Response<super::QueryValidatorSlashesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/ValidatorSlashes",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// DelegationRewards queries the total rewards accrued by a delegation.
+        pub async fn delegation_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDelegationRewardsRequest>,
+        ) -> Result<tonic::Response<super::QueryDelegationRewardsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// DelegationTotalRewards queries the total rewards accrued by a each
+        /// validator.
+        pub async fn delegation_total_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDelegationTotalRewardsRequest>,
+        ) -> Result<tonic::Response<super::QueryDelegationTotalRewardsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// DelegatorValidators queries the validators of a delegator.
+        pub async fn delegator_validators(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDelegatorValidatorsRequest>,
+        ) -> Result<tonic::Response<super::QueryDelegatorValidatorsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/DelegatorValidators",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// DelegatorWithdrawAddress queries withdraw address of a delegator.
+        pub async fn delegator_withdraw_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDelegatorWithdrawAddressRequest>,
+        ) -> Result<tonic::Response<super::QueryDelegatorWithdrawAddressResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// CommunityPool queries the community pool coins.
+        pub async fn community_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryCommunityPoolRequest>,
+        ) -> Result<tonic::Response<super::QueryCommunityPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.distribution.v1beta1.Query/CommunityPool",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// DelegatorWithdrawInfo is the address for where distributions rewards are
+/// withdrawn to by default this struct is only used at genesis to feed in
+/// default withdraw addresses.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.DelegatorWithdrawInfo")]
+pub struct DelegatorWithdrawInfo {
+    /// delegator_address is the address of the delegator.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    /// withdraw_address is the address to withdraw the delegation rewards to.
+    #[prost(string, tag = "2")]
+    pub withdraw_address: ::prost::alloc::string::String,
+}
+/// ValidatorOutstandingRewardsRecord is used for import/export via genesis json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorOutstandingRewardsRecord")]
+pub struct ValidatorOutstandingRewardsRecord {
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// outstanding_rewards represents the oustanding rewards of a validator.
+    #[prost(message, repeated, tag = "2")]
+    pub outstanding_rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
+}
+/// ValidatorAccumulatedCommissionRecord is used for import / export via genesis
+/// json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorAccumulatedCommissionRecord")]
+pub struct ValidatorAccumulatedCommissionRecord {
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// accumulated is the accumulated commission of a validator.
+    #[prost(message, optional, tag = "2")]
+    pub accumulated: ::core::option::Option<ValidatorAccumulatedCommission>,
+}
+/// ValidatorHistoricalRewardsRecord is used for import / export via genesis
+/// json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorHistoricalRewardsRecord")]
+pub struct ValidatorHistoricalRewardsRecord {
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// period defines the period the historical rewards apply to.
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub period: u64,
+    /// rewards defines the historical rewards of a validator.
+    #[prost(message, optional, tag = "3")]
+    pub rewards: ::core::option::Option<ValidatorHistoricalRewards>,
+}
+/// ValidatorCurrentRewardsRecord is used for import / export via genesis json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorCurrentRewardsRecord")]
+pub struct ValidatorCurrentRewardsRecord {
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// rewards defines the current rewards of a validator.
+    #[prost(message, optional, tag = "2")]
+    pub rewards: ::core::option::Option<ValidatorCurrentRewards>,
+}
+/// DelegatorStartingInfoRecord used for import / export via genesis json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.DelegatorStartingInfoRecord")]
+pub struct DelegatorStartingInfoRecord {
+    /// delegator_address is the address of the delegator.
+    #[prost(string, tag = "1")]
+    pub delegator_address: ::prost::alloc::string::String,
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "2")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// starting_info defines the starting info of a delegator.
+    #[prost(message, optional, tag = "3")]
+    pub starting_info: ::core::option::Option<DelegatorStartingInfo>,
+}
+/// ValidatorSlashEventRecord is used for import / export via genesis json.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.ValidatorSlashEventRecord")]
+pub struct ValidatorSlashEventRecord {
+    /// validator_address is the address of the validator.
+    #[prost(string, tag = "1")]
+    pub validator_address: ::prost::alloc::string::String,
+    /// height defines the block height at which the slash event occured.
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: u64,
+    /// period is the period of the slash event.
+    #[prost(uint64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub period: u64,
+    /// validator_slash_event describes the slash event.
+    #[prost(message, optional, tag = "4")]
+    pub validator_slash_event: ::core::option::Option<ValidatorSlashEvent>,
+}
+/// GenesisState defines the distribution module's genesis state.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.distribution.v1beta1.GenesisState")]
+pub struct GenesisState {
+    /// params defines all the paramaters of the module.
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<Params>,
+    /// fee_pool defines the fee pool at genesis.
+    #[prost(message, optional, tag = "2")]
+    pub fee_pool: ::core::option::Option<FeePool>,
+    /// fee_pool defines the delegator withdraw infos at genesis.
+    #[prost(message, repeated, tag = "3")]
+    pub delegator_withdraw_infos: ::prost::alloc::vec::Vec<DelegatorWithdrawInfo>,
+    /// fee_pool defines the previous proposer at genesis.
+    #[prost(string, tag = "4")]
+    pub previous_proposer: ::prost::alloc::string::String,
+    /// fee_pool defines the outstanding rewards of all validators at genesis.
+    #[prost(message, repeated, tag = "5")]
+    pub outstanding_rewards: ::prost::alloc::vec::Vec<ValidatorOutstandingRewardsRecord>,
+    /// fee_pool defines the accumulated commisions of all validators at genesis.
+    #[prost(message, repeated, tag = "6")]
+    pub validator_accumulated_commissions:
+        ::prost::alloc::vec::Vec<ValidatorAccumulatedCommissionRecord>,
+    /// fee_pool defines the historical rewards of all validators at genesis.
+    #[prost(message, repeated, tag = "7")]
+    pub validator_historical_rewards: ::prost::alloc::vec::Vec<ValidatorHistoricalRewardsRecord>,
+    /// fee_pool defines the current rewards of all validators at genesis.
+    #[prost(message, repeated, tag = "8")]
+    pub validator_current_rewards: ::prost::alloc::vec::Vec<ValidatorCurrentRewardsRecord>,
+    /// fee_pool defines the delegator starting infos at genesis.
+    #[prost(message, repeated, tag = "9")]
+    pub delegator_starting_infos: ::prost::alloc::vec::Vec<DelegatorStartingInfoRecord>,
+    /// fee_pool defines the validator slash events at genesis.
+    #[prost(message, repeated, tag = "10")]
+    pub validator_slash_events: ::prost::alloc::vec::Vec<ValidatorSlashEventRecord>,
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct DistributionQuerier<'a, Q: cosmwasm_std::CustomQuery> {
+    querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
+}
+#[cfg(feature = "cosmwasm")]
+impl<'a, Q: cosmwasm_std::CustomQuery> DistributionQuerier<'a, Q> {
+    pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
+        Self { querier }
+    }
+    pub fn params(&self) -> Result<QueryParamsResponse, cosmwasm_std::StdError> {
+        QueryParamsRequest {}.query(self.querier)
+    }
+    pub fn validator_outstanding_rewards(
+        &self,
+        validator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryValidatorOutstandingRewardsResponse, cosmwasm_std::StdError> {
+        QueryValidatorOutstandingRewardsRequest { validator_address }.query(self.querier)
+    }
+    pub fn validator_commission(
+        &self,
+        validator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryValidatorCommissionResponse, cosmwasm_std::StdError> {
+        QueryValidatorCommissionRequest { validator_address }.query(self.querier)
+    }
+    pub fn validator_slashes(
+        &self,
+        validator_address: ::prost::alloc::string::String,
+        starting_height: u64,
+        ending_height: u64,
+        pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+    ) -> Result<QueryValidatorSlashesResponse, cosmwasm_std::StdError> {
+        QueryValidatorSlashesRequest {
+            validator_address,
+            starting_height,
+            ending_height,
+            pagination,
+        }
+        .query(self.querier)
+    }
+    pub fn delegation_rewards(
+        &self,
+        delegator_address: ::prost::alloc::string::String,
+        validator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryDelegationRewardsResponse, cosmwasm_std::StdError> {
+        QueryDelegationRewardsRequest {
+            delegator_address,
+            validator_address,
+        }
+        .query(self.querier)
+    }
+    pub fn delegation_total_rewards(
+        &self,
+        delegator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryDelegationTotalRewardsResponse, cosmwasm_std::StdError> {
+        QueryDelegationTotalRewardsRequest { delegator_address }.query(self.querier)
+    }
+    pub fn delegator_validators(
+        &self,
+        delegator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryDelegatorValidatorsResponse, cosmwasm_std::StdError> {
+        QueryDelegatorValidatorsRequest { delegator_address }.query(self.querier)
+    }
+    pub fn delegator_withdraw_address(
+        &self,
+        delegator_address: ::prost::alloc::string::String,
+    ) -> Result<QueryDelegatorWithdrawAddressResponse, cosmwasm_std::StdError> {
+        QueryDelegatorWithdrawAddressRequest { delegator_address }.query(self.querier)
+    }
+    pub fn community_pool(&self) -> Result<QueryCommunityPoolResponse, cosmwasm_std::StdError> {
+        QueryCommunityPoolRequest {}.query(self.querier)
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct DistributionMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl DistributionMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn set_withdraw_address(
+        &self,
+        withdraw_address: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSetWithdrawAddress {
+            delegator_address: self.sender.to_string(),
+            withdraw_address,
+        }
+        .into()
+    }
+    pub fn withdraw_delegator_reward(
+        &self,
+        validator_address: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgWithdrawDelegatorReward {
+            delegator_address: self.sender.to_string(),
+            validator_address,
+        }
+        .into()
+    }
+    pub fn fund_community_pool(
+        &self,
+        amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgFundCommunityPool {
+            depositor: self.sender.to_string(),
+            amount,
+        }
+        .into()
+    }
+}
@@ -0,0 +1,553 @@
+use osmosis_std_derive::CosmwasmExt;
+/// MsgGrantAllowance adds permission for Grantee to spend up to Allowance
+/// of fees from the account of Granter.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.feegrant.v1beta1.MsgGrantAllowance",
+    response_type = MsgGrantAllowanceResponse
+)]
+pub struct MsgGrantAllowance {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[prost(string, tag = "1")]
+    pub granter: ::prost::alloc::string::String,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    #[prost(string, tag = "2")]
+    pub grantee: ::prost::alloc::string::String,
+    /// allowance can be any of basic and filtered fee allowance.
+    #[prost(message, optional, tag = "3")]
+    pub allowance: ::core::option::Option<crate::shim::Any>,
+}
+/// MsgGrantAllowanceResponse defines the Msg/GrantAllowanceResponse response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.MsgGrantAllowanceResponse")]
+pub struct MsgGrantAllowanceResponse {}
+/// MsgRevokeAllowance removes any existing Allowance from Granter to Grantee.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance",
+    response_type = MsgRevokeAllowanceResponse
+)]
+pub struct MsgRevokeAllowance {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[prost(string, tag = "1")]
+    pub granter: ::prost::alloc::string::String,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    #[prost(string, tag = "2")]
+    pub grantee: ::prost::alloc::string::String,
+}
+/// MsgRevokeAllowanceResponse defines the Msg/RevokeAllowanceResponse response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.MsgRevokeAllowanceResponse")]
+pub struct MsgRevokeAllowanceResponse {}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Msg defines the feegrant msg service.
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// GrantAllowance grants fee allowance to the grantee on the granter's
+        /// account with the provided expiration time.
+        pub async fn grant_allowance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgGrantAllowance>,
+        ) -> Result<tonic::Response<super::MsgGrantAllowanceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.feegrant.v1beta1.Msg/GrantAllowance");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// RevokeAllowance revokes any fee allowance of granter's account that
+        /// has been granted to the grantee.
+        pub async fn revoke_allowance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgRevokeAllowance>,
+        ) -> Result<tonic::Response<super::MsgRevokeAllowanceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.feegrant.v1beta1.Msg/RevokeAllowance",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// BasicAllowance implements Allowance with a one-time grant of tokens
+/// that optionally expires. The grantee can use up to SpendLimit to cover fees.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.BasicAllowance")]
+pub struct BasicAllowance {
+    /// spend_limit specifies the maximum amount of tokens that can be spent
+    /// by this allowance and will be updated as tokens are spent. If it is
+    /// empty, there is no spend limit and any amount of coins can be spent.
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    /// expiration specifies an optional time when this allowance expires
+    #[prost(message, optional, tag = "2")]
+    pub expiration: ::core::option::Option<crate::shim::Timestamp>,
+}
+/// PeriodicAllowance extends Allowance to allow for both a maximum cap,
+/// as well as a limit per time period.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.PeriodicAllowance")]
+pub struct PeriodicAllowance {
+    /// basic specifies a struct of `BasicAllowance`
+    #[prost(message, optional, tag = "1")]
+    pub basic: ::core::option::Option<BasicAllowance>,
+    /// period specifies the time duration in which period_spend_limit coins can
+    /// be spent before that allowance is reset
+    #[prost(message, optional, tag = "2")]
+    pub period: ::core::option::Option<crate::shim::Duration>,
+    /// period_spend_limit specifies the maximum number of coins that can be spent
+    /// in the period
+    #[prost(message, repeated, tag = "3")]
+    pub period_spend_limit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    /// period_can_spend is the number of coins left to be spent before the period_reset time
+    #[prost(message, repeated, tag = "4")]
+    pub period_can_spend: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    /// period_reset is the time at which this period resets and a new one begins,
+    /// it is calculated from the start time of the first transaction after the
+    /// last period ended
+    #[prost(message, optional, tag = "5")]
+    pub period_reset: ::core::option::Option<crate::shim::Timestamp>,
+}
+/// AllowedMsgAllowance creates allowance only for specified message types.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance")]
+pub struct AllowedMsgAllowance {
+    /// allowance can be any of basic and filtered fee allowance.
+    #[prost(message, optional, tag = "1")]
+    pub allowance: ::core::option::Option<crate::shim::Any>,
+    /// allowed_messages are the messages for which the grantee has the access.
+    #[prost(string, repeated, tag = "2")]
+    pub allowed_messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Grant is stored in the KVStore to record a grant with full context
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.Grant")]
+pub struct Grant {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[prost(string, tag = "1")]
+    pub granter: ::prost::alloc::string::String,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    #[prost(string, tag = "2")]
+    pub grantee: ::prost::alloc::string::String,
+    /// allowance can be any of basic and filtered fee allowance.
+    #[prost(message, optional, tag = "3")]
+    pub allowance: ::core::option::Option<crate::shim::Any>,
+}
+/// QueryAllowanceRequest is the request type for the Query/Allowance RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.QueryAllowanceRequest")]
+#[proto_query(
+    path = "/cosmos.feegrant.v1beta1.Query/Allowance",
+    response_type = QueryAllowanceResponse
+)]
+pub struct QueryAllowanceRequest {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[prost(string, tag = "1")]
+    pub granter: ::prost::alloc::string::String,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    #[prost(string, tag = "2")]
+    pub grantee: ::prost::alloc::string::String,
+}
+/// QueryAllowanceResponse is the response type for the Query/Allowance RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.QueryAllowanceResponse")]
+pub struct QueryAllowanceResponse {
+    /// allowance is a allowance granted for grantee by granter.
+    #[prost(message, optional, tag = "1")]
+    pub allowance: ::core::option::Option<Grant>,
+}
+/// QueryAllowancesRequest is the request type for the Query/Allowances RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.QueryAllowancesRequest")]
+#[proto_query(
+    path = "/cosmos.feegrant.v1beta1.Query/Allowances",
+    response_type = QueryAllowancesResponse
+)]
+pub struct QueryAllowancesRequest {
+    #[prost(string, tag = "1")]
+    pub grantee: ::prost::alloc::string::String,
+    /// pagination defines an pagination for the request.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+}
+/// QueryAllowancesResponse is the response type for the Query/Allowances RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.QueryAllowancesResponse")]
+pub struct QueryAllowancesResponse {
+    /// allowances are allowance's granted for grantee by granter.
+    #[prost(message, repeated, tag = "1")]
+    pub allowances: ::prost::alloc::vec::Vec<Grant>,
+    /// pagination defines an pagination for the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageResponse>,
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Query defines the gRPC querier service.
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Allowance returns fee granted to the grantee by the granter.
+        pub async fn allowance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryAllowanceRequest>,
+        ) -> Result<tonic::Response<super::QueryAllowanceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.feegrant.v1beta1.Query/Allowance");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Allowances returns all the grants for address.
+        pub async fn allowances(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryAllowancesRequest>,
+        ) -> Result<tonic::Response<super::QueryAllowancesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/cosmos.feegrant.v1beta1.Query/Allowances");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// GenesisState contains a set of fee allowances, persisted from the store
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.feegrant.v1beta1.GenesisState")]
+pub struct GenesisState {
+    #[prost(message, repeated, tag = "1")]
+    pub allowances: ::prost::alloc::vec::Vec<Grant>,
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct FeegrantQuerier<'a, Q: cosmwasm_std::CustomQuery> {
+    querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
+}
+#[cfg(feature = "cosmwasm")]
+impl<'a, Q: cosmwasm_std::CustomQuery> FeegrantQuerier<'a, Q> {
+    pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
+        Self { querier }
+    }
+    pub fn allowance(
+        &self,
+        granter: ::prost::alloc::string::String,
+        grantee: ::prost::alloc::string::String,
+    ) -> Result<QueryAllowanceResponse, cosmwasm_std::StdError> {
+        QueryAllowanceRequest { granter, grantee }.query(self.querier)
+    }
+    pub fn allowances(
+        &self,
+        grantee: ::prost::alloc::string::String,
+        pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+    ) -> Result<QueryAllowancesResponse, cosmwasm_std::StdError> {
+        QueryAllowancesRequest {
+            grantee,
+            pagination,
+        }
+        .query(self.querier)
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct FeegrantMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl FeegrantMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn grant_allowance(
+        &self,
+        grantee: ::prost::alloc::string::String,
+        allowance: ::core::option::Option<crate::shim::Any>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgGrantAllowance {
+            granter: self.sender.to_string(),
+            grantee,
+            allowance,
+        }
+        .into()
+    }
+    pub fn revoke_allowance(
+        &self,
+        grantee: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgRevokeAllowance {
+            granter: self.sender.to_string(),
+            grantee,
+        }
+        .into()
+    }
+}
@@ -0,0 +1,1455 @@
+use osmosis_std_derive::CosmwasmExt;
+/// WeightedVoteOption defines a unit of vote for vote split.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.WeightedVoteOption")]
+pub struct WeightedVoteOption {
+    #[prost(enumeration = "VoteOption", tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<VoteOption, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<VoteOption, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "VoteOption"))]
+    pub option: i32,
+    #[prost(string, tag = "2")]
+    pub weight: ::prost::alloc::string::String,
+}
+/// Deposit defines an amount deposited by an account address to an active
+/// proposal.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.Deposit")]
+pub struct Deposit {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+}
+/// Proposal defines the core field members of a governance proposal.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.Proposal")]
+pub struct Proposal {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub id: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub messages: ::prost::alloc::vec::Vec<crate::shim::Any>,
+    #[prost(enumeration = "ProposalStatus", tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<ProposalStatus, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<ProposalStatus, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "ProposalStatus"))]
+    pub status: i32,
+    /// final_tally_result is the final tally result of the proposal. When
+    /// querying a proposal via gRPC, this field is not populated until the
+    /// proposal's voting period has ended.
+    #[prost(message, optional, tag = "4")]
+    pub final_tally_result: ::core::option::Option<TallyResult>,
+    #[prost(message, optional, tag = "5")]
+    pub submit_time: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(message, optional, tag = "6")]
+    pub deposit_end_time: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(message, repeated, tag = "7")]
+    pub total_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    #[prost(message, optional, tag = "8")]
+    pub voting_start_time: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(message, optional, tag = "9")]
+    pub voting_end_time: ::core::option::Option<crate::shim::Timestamp>,
+    /// metadata is any arbitrary metadata attached to the proposal.
+    #[prost(string, tag = "10")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// TallyResult defines a standard tally for a governance proposal.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.TallyResult")]
+pub struct TallyResult {
+    #[prost(string, tag = "1")]
+    pub yes_count: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub abstain_count: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub no_count: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub no_with_veto_count: ::prost::alloc::string::String,
+}
+/// Vote defines a vote on a governance proposal.
+/// A Vote consists of a proposal ID, the voter, and the vote option.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.Vote")]
+pub struct Vote {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub options: ::prost::alloc::vec::Vec<WeightedVoteOption>,
+    /// metadata is any  arbitrary metadata to attached to the vote.
+    #[prost(string, tag = "5")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// DepositParams defines the params for deposits on governance proposals.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.DepositParams")]
+pub struct DepositParams {
+    ///   Minimum deposit for a proposal to enter voting period.
+    #[prost(message, repeated, tag = "1")]
+    pub min_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    ///   Maximum period for Atom holders to deposit on a proposal. Initial value: 2
+    ///   months.
+    #[prost(message, optional, tag = "2")]
+    pub max_deposit_period: ::core::option::Option<crate::shim::Duration>,
+}
+/// VotingParams defines the params for voting on governance proposals.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.VotingParams")]
+pub struct VotingParams {
+    ///   Length of the voting period.
+    #[prost(message, optional, tag = "1")]
+    pub voting_period: ::core::option::Option<crate::shim::Duration>,
+}
+/// TallyParams defines the params for tallying votes on governance proposals.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.TallyParams")]
+pub struct TallyParams {
+    ///   Minimum percentage of total stake needed to vote for a result to be
+    ///   considered valid.
+    #[prost(string, tag = "1")]
+    pub quorum: ::prost::alloc::string::String,
+    ///   Minimum proportion of Yes votes for proposal to pass. Default value: 0.5.
+    #[prost(string, tag = "2")]
+    pub threshold: ::prost::alloc::string::String,
+    ///   Minimum value of Veto votes to Total votes ratio for proposal to be
+    ///   vetoed. Default value: 1/3.
+    #[prost(string, tag = "3")]
+    pub veto_threshold: ::prost::alloc::string::String,
+}
+/// VoteOption enumerates the valid vote options for a given governance proposal.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[repr(i32)]
+pub enum VoteOption {
+    /// VOTE_OPTION_UNSPECIFIED defines a no-op vote option.
+    #[serde(rename = "VOTE_OPTION_UNSPECIFIED")]
+    Unspecified = 0,
+    /// VOTE_OPTION_YES defines a yes vote option.
+    #[serde(rename = "VOTE_OPTION_YES")]
+    Yes = 1,
+    /// VOTE_OPTION_ABSTAIN defines an abstain vote option.
+    #[serde(rename = "VOTE_OPTION_ABSTAIN")]
+    Abstain = 2,
+    /// VOTE_OPTION_NO defines a no vote option.
+    #[serde(rename = "VOTE_OPTION_NO")]
+    No = 3,
+    /// VOTE_OPTION_NO_WITH_VETO defines a no with veto vote option.
+    #[serde(rename = "VOTE_OPTION_NO_WITH_VETO")]
+    NoWithVeto = 4,
+}
+impl VoteOption {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VoteOption::Unspecified => "VOTE_OPTION_UNSPECIFIED",
+            VoteOption::Yes => "VOTE_OPTION_YES",
+            VoteOption::Abstain => "VOTE_OPTION_ABSTAIN",
+            VoteOption::No => "VOTE_OPTION_NO",
+            VoteOption::NoWithVeto => "VOTE_OPTION_NO_WITH_VETO",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VOTE_OPTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "VOTE_OPTION_YES" => Some(Self::Yes),
+            "VOTE_OPTION_ABSTAIN" => Some(Self::Abstain),
+            "VOTE_OPTION_NO" => Some(Self::No),
+            "VOTE_OPTION_NO_WITH_VETO" => Some(Self::NoWithVeto),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for VoteOption {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        VoteOption::from_i32(value)
+    }
+}
+/// ProposalStatus enumerates the valid statuses of a proposal.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[repr(i32)]
+pub enum ProposalStatus {
+    /// PROPOSAL_STATUS_UNSPECIFIED defines the default proposal status.
+    #[serde(rename = "PROPOSAL_STATUS_UNSPECIFIED")]
+    Unspecified = 0,
+    /// PROPOSAL_STATUS_DEPOSIT_PERIOD defines a proposal status during the deposit
+    /// period.
+    #[serde(rename = "PROPOSAL_STATUS_DEPOSIT_PERIOD")]
+    DepositPeriod = 1,
+    /// PROPOSAL_STATUS_VOTING_PERIOD defines a proposal status during the voting
+    /// period.
+    #[serde(rename = "PROPOSAL_STATUS_VOTING_PERIOD")]
+    VotingPeriod = 2,
+    /// PROPOSAL_STATUS_PASSED defines a proposal status of a proposal that has
+    /// passed.
+    #[serde(rename = "PROPOSAL_STATUS_PASSED")]
+    Passed = 3,
+    /// PROPOSAL_STATUS_REJECTED defines a proposal status of a proposal that has
+    /// been rejected.
+    #[serde(rename = "PROPOSAL_STATUS_REJECTED")]
+    Rejected = 4,
+    /// PROPOSAL_STATUS_FAILED defines a proposal status of a proposal that has
+    /// failed.
+    #[serde(rename = "PROPOSAL_STATUS_FAILED")]
+    Failed = 5,
+}
+impl ProposalStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ProposalStatus::Unspecified => "PROPOSAL_STATUS_UNSPECIFIED",
+            ProposalStatus::DepositPeriod => "PROPOSAL_STATUS_DEPOSIT_PERIOD",
+            ProposalStatus::VotingPeriod => "PROPOSAL_STATUS_VOTING_PERIOD",
+            ProposalStatus::Passed => "PROPOSAL_STATUS_PASSED",
+            ProposalStatus::Rejected => "PROPOSAL_STATUS_REJECTED",
+            ProposalStatus::Failed => "PROPOSAL_STATUS_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROPOSAL_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROPOSAL_STATUS_DEPOSIT_PERIOD" => Some(Self::DepositPeriod),
+            "PROPOSAL_STATUS_VOTING_PERIOD" => Some(Self::VotingPeriod),
+            "PROPOSAL_STATUS_PASSED" => Some(Self::Passed),
+            "PROPOSAL_STATUS_REJECTED" => Some(Self::Rejected),
+            "PROPOSAL_STATUS_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for ProposalStatus {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        ProposalStatus::from_i32(value)
+    }
+}
+/// GenesisState defines the gov module's genesis state.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.GenesisState")]
+pub struct GenesisState {
+    /// starting_proposal_id is the ID of the starting proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub starting_proposal_id: u64,
+    /// deposits defines all the deposits present at genesis.
+    #[prost(message, repeated, tag = "2")]
+    pub deposits: ::prost::alloc::vec::Vec<Deposit>,
+    /// votes defines all the votes present at genesis.
+    #[prost(message, repeated, tag = "3")]
+    pub votes: ::prost::alloc::vec::Vec<Vote>,
+    /// proposals defines all the proposals present at genesis.
+    #[prost(message, repeated, tag = "4")]
+    pub proposals: ::prost::alloc::vec::Vec<Proposal>,
+    /// params defines all the paramaters of related to deposit.
+    #[prost(message, optional, tag = "5")]
+    pub deposit_params: ::core::option::Option<DepositParams>,
+    /// params defines all the paramaters of related to voting.
+    #[prost(message, optional, tag = "6")]
+    pub voting_params: ::core::option::Option<VotingParams>,
+    /// params defines all the paramaters of related to tally.
+    #[prost(message, optional, tag = "7")]
+    pub tally_params: ::core::option::Option<TallyParams>,
+}
+/// QueryProposalRequest is the request type for the Query/Proposal RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryProposalRequest")]
+#[proto_query(
+    path = "/cosmos.gov.v1.Query/Proposal",
+    response_type = QueryProposalResponse
+)]
+pub struct QueryProposalRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+}
+/// QueryProposalResponse is the response type for the Query/Proposal RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryProposalResponse")]
+pub struct QueryProposalResponse {
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<Proposal>,
+}
+/// QueryProposalsRequest is the request type for the Query/Proposals RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryProposalsRequest")]
+#[proto_query(
+    path = "/cosmos.gov.v1.Query/Proposals",
+    response_type = QueryProposalsResponse
+)]
+pub struct QueryProposalsRequest {
+    /// proposal_status defines the status of the proposals.
+    #[prost(enumeration = "ProposalStatus", tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<ProposalStatus, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<ProposalStatus, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "ProposalStatus"))]
+    pub proposal_status: i32,
+    /// voter defines the voter address for the proposals.
+    #[prost(string, tag = "2")]
+    pub voter: ::prost::alloc::string::String,
+    /// depositor defines the deposit addresses from the proposals.
+    #[prost(string, tag = "3")]
+    pub depositor: ::prost::alloc::string::String,
+    /// pagination defines an optional pagination for the request.
+    #[prost(message, optional, tag = "4")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+}
+/// QueryProposalsResponse is the response type for the Query/Proposals RPC
+/// method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryProposalsResponse")]
+pub struct QueryProposalsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: ::prost::alloc::vec::Vec<Proposal>,
+    /// pagination defines the pagination in the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageResponse>,
+}
+/// QueryVoteRequest is the request type for the Query/Vote RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryVoteRequest")]
+#[proto_query(path = "/cosmos.gov.v1.Query/Vote", response_type = QueryVoteResponse)]
+pub struct QueryVoteRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    /// voter defines the voter address for the proposals.
+    #[prost(string, tag = "2")]
+    pub voter: ::prost::alloc::string::String,
+}
+/// QueryVoteResponse is the response type for the Query/Vote RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryVoteResponse")]
+pub struct QueryVoteResponse {
+    /// vote defined the queried vote.
+    #[prost(message, optional, tag = "1")]
+    pub vote: ::core::option::Option<Vote>,
+}
+/// QueryVotesRequest is the request type for the Query/Votes RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryVotesRequest")]
+#[proto_query(path = "/cosmos.gov.v1.Query/Votes", response_type = QueryVotesResponse)]
+pub struct QueryVotesRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    /// pagination defines an optional pagination for the request.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+}
+/// QueryVotesResponse is the response type for the Query/Votes RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryVotesResponse")]
+pub struct QueryVotesResponse {
+    /// votes defined the queried votes.
+    #[prost(message, repeated, tag = "1")]
+    pub votes: ::prost::alloc::vec::Vec<Vote>,
+    /// pagination defines the pagination in the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageResponse>,
+}
+/// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryParamsRequest")]
+#[proto_query(path = "/cosmos.gov.v1.Query/Params", response_type = QueryParamsResponse)]
+pub struct QueryParamsRequest {
+    /// params_type defines which parameters to query for, can be one of "voting",
+    /// "tallying" or "deposit".
+    #[prost(string, tag = "1")]
+    pub params_type: ::prost::alloc::string::String,
+}
+/// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryParamsResponse")]
+pub struct QueryParamsResponse {
+    /// voting_params defines the parameters related to voting.
+    #[prost(message, optional, tag = "1")]
+    pub voting_params: ::core::option::Option<VotingParams>,
+    /// deposit_params defines the parameters related to deposit.
+    #[prost(message, optional, tag = "2")]
+    pub deposit_params: ::core::option::Option<DepositParams>,
+    /// tally_params defines the parameters related to tally.
+    #[prost(message, optional, tag = "3")]
+    pub tally_params: ::core::option::Option<TallyParams>,
+}
+/// QueryDepositRequest is the request type for the Query/Deposit RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryDepositRequest")]
+#[proto_query(
+    path = "/cosmos.gov.v1.Query/Deposit",
+    response_type = QueryDepositResponse
+)]
+pub struct QueryDepositRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    /// depositor defines the deposit addresses from the proposals.
+    #[prost(string, tag = "2")]
+    pub depositor: ::prost::alloc::string::String,
+}
+/// QueryDepositResponse is the response type for the Query/Deposit RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryDepositResponse")]
+pub struct QueryDepositResponse {
+    /// deposit defines the requested deposit.
+    #[prost(message, optional, tag = "1")]
+    pub deposit: ::core::option::Option<Deposit>,
+}
+/// QueryDepositsRequest is the request type for the Query/Deposits RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryDepositsRequest")]
+#[proto_query(
+    path = "/cosmos.gov.v1.Query/Deposits",
+    response_type = QueryDepositsResponse
+)]
+pub struct QueryDepositsRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    /// pagination defines an optional pagination for the request.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+}
+/// QueryDepositsResponse is the response type for the Query/Deposits RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryDepositsResponse")]
+pub struct QueryDepositsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: ::prost::alloc::vec::Vec<Deposit>,
+    /// pagination defines the pagination in the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::base::query::v1beta1::PageResponse>,
+}
+/// QueryTallyResultRequest is the request type for the Query/Tally RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryTallyResultRequest")]
+#[proto_query(
+    path = "/cosmos.gov.v1.Query/TallyResult",
+    response_type = QueryTallyResultResponse
+)]
+pub struct QueryTallyResultRequest {
+    /// proposal_id defines the unique id of the proposal.
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+}
+/// QueryTallyResultResponse is the response type for the Query/Tally RPC method.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.QueryTallyResultResponse")]
+pub struct QueryTallyResultResponse {
+    /// tally defines the requested tally.
+    #[prost(message, optional, tag = "1")]
+    pub tally: ::core::option::Option<TallyResult>,
+}
+/// MsgSubmitProposal defines an sdk.Msg type that supports submitting arbitrary
+/// proposal Content.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.gov.v1.MsgSubmitProposal",
+    amino_type = "cosmos-sdk/v1/MsgSubmitProposal",
+    response_type = MsgSubmitProposalResponse
+)]
+pub struct MsgSubmitProposal {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<crate::shim::Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    #[prost(string, tag = "3")]
+    pub proposer: ::prost::alloc::string::String,
+    /// metadata is any arbitrary metadata attached to the proposal.
+    #[prost(string, tag = "4")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// MsgSubmitProposalResponse defines the Msg/SubmitProposal response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.MsgSubmitProposalResponse")]
+pub struct MsgSubmitProposalResponse {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+}
+/// MsgExecLegacyContent is used to wrap the legacy content field into a message.
+/// This ensures backwards compatibility with v1beta1.MsgSubmitProposal.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.gov.v1.MsgExecLegacyContent",
+    amino_type = "cosmos-sdk/v1/MsgExecLegacyContent",
+    response_type = MsgExecLegacyContentResponse
+)]
+pub struct MsgExecLegacyContent {
+    /// content is the proposal's content.
+    #[prost(message, optional, tag = "1")]
+    pub content: ::core::option::Option<crate::shim::Any>,
+    /// authority must be the gov module address.
+    #[prost(string, tag = "2")]
+    pub authority: ::prost::alloc::string::String,
+}
+/// MsgExecLegacyContentResponse defines the Msg/ExecLegacyContent response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.MsgExecLegacyContentResponse")]
+pub struct MsgExecLegacyContentResponse {}
+/// MsgVote defines a message to cast a vote.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.gov.v1.MsgVote",
+    amino_type = "cosmos-sdk/v1/MsgVote",
+    response_type = MsgVoteResponse
+)]
+pub struct MsgVote {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(enumeration = "VoteOption", tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<VoteOption, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<VoteOption, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "VoteOption"))]
+    pub option: i32,
+    #[prost(string, tag = "4")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// MsgVoteResponse defines the Msg/Vote response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.MsgVoteResponse")]
+pub struct MsgVoteResponse {}
+/// MsgVoteWeighted defines a message to cast a vote.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.gov.v1.MsgVoteWeighted",
+    amino_type = "cosmos-sdk/v1/MsgVoteWeighted",
+    response_type = MsgVoteWeightedResponse
+)]
+pub struct MsgVoteWeighted {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub options: ::prost::alloc::vec::Vec<WeightedVoteOption>,
+    #[prost(string, tag = "4")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// MsgVoteWeightedResponse defines the Msg/VoteWeighted response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.MsgVoteWeightedResponse")]
+pub struct MsgVoteWeightedResponse {}
+/// MsgDeposit defines a message to submit a deposit to an existing proposal.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/cosmos.gov.v1.MsgDeposit",
+    amino_type = "cosmos-sdk/v1/MsgDeposit",
+    response_type = MsgDepositResponse
+)]
+pub struct MsgDeposit {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+}
+/// MsgDepositResponse defines the Msg/Deposit response type.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.gov.v1.MsgDepositResponse")]
+pub struct MsgDepositResponse {}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Query defines the gRPC querier service for gov module
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Proposal queries proposal details based on ProposalID.
+        pub async fn proposal(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryProposalRequest>,
+        ) -> Result<tonic::Response<super::QueryProposalResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Proposal");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Proposals queries all proposals based on given status.
+        pub async fn proposals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryProposalsRequest>,
+        ) -> Result<tonic::Response<super::QueryProposalsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Proposals");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Vote queries voted information based on proposalID, voterAddr.
+        pub async fn vote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryVoteRequest>,
+        ) -> Result<tonic::Response<super::QueryVoteResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Vote");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Votes queries votes of a given proposal.
+        pub async fn votes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryVotesRequest>,
+        ) -> Result<tonic::Response<super::QueryVotesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Votes");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Params queries all parameters of the gov module.
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Deposit queries single deposit information based proposalID, depositAddr.
+        pub async fn deposit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDepositRequest>,
+        ) -> Result<tonic::Response<super::QueryDepositResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Deposit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Deposits queries all deposits of a single proposal.
+        pub async fn deposits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDepositsRequest>,
+        ) -> Result<tonic::Response<super::QueryDepositsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Deposits");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// TallyResult queries the tally of a proposal vote.
+        pub async fn tally_result(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTallyResultRequest>,
+        ) -> Result<tonic::Response<super::QueryTallyResultResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/TallyResult");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Msg defines the gov Msg service.
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// SubmitProposal defines a method to create new proposal given a content.
+        pub async fn submit_proposal(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSubmitProposal>,
+        ) -> Result<tonic::Response<super::MsgSubmitProposalResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Msg/SubmitProposal");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// ExecLegacyContent defines a Msg to be in included in a MsgSubmitProposal
+        /// to execute a legacy content-based proposal.
+        pub async fn exec_legacy_content(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgExecLegacyContent>,
+        ) -> Result<tonic::Response<super::MsgExecLegacyContentResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Msg/ExecLegacyContent");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Vote defines a method to add a vote on a specific proposal.
+        pub async fn vote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgVote>,
+        ) -> Result<tonic::Response<super::MsgVoteResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Msg/Vote");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// VoteWeighted defines a method to add a weighted vote on a specific proposal.
+        pub async fn vote_weighted(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgVoteWeighted>,
+        ) -> Result<tonic::Response<super::MsgVoteWeightedResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Msg/VoteWeighted");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Deposit defines a method to add deposit on a specific proposal.
+        pub async fn deposit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgDeposit>,
+        ) -> Result<tonic::Response<super::MsgDepositResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Msg/Deposit");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct GovQuerier<'a, Q: cosmwasm_std::CustomQuery> {
+    querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
+}
+#[cfg(feature = "cosmwasm")]
+impl<'a, Q: cosmwasm_std::CustomQuery> GovQuerier<'a, Q> {
+    pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
+        Self { querier }
+    }
+    pub fn proposal(
+        &self,
+        proposal_id: u64,
+    ) -> Result<QueryProposalResponse, cosmwasm_std::StdError> {
+        QueryProposalRequest { proposal_id }.query(self.querier)
+    }
+    pub fn proposals(
+        &self,
+        proposal_status: i32,
+        voter: ::prost::alloc::string::String,
+        depositor: ::prost::alloc::string::String,
+        pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+    ) -> Result<QueryProposalsResponse, cosmwasm_std::StdError> {
+        QueryProposalsRequest {
+            proposal_status,
+            voter,
+            depositor,
+            pagination,
+        }
+        .query(self.querier)
+    }
+    pub fn vote(
+        &self,
+        proposal_id: u64,
+        voter: ::prost::alloc::string::String,
+    ) -> Result<QueryVoteResponse, cosmwasm_std::StdError> {
+        QueryVoteRequest { proposal_id, voter }.query(self.querier)
+    }
+    pub fn votes(
+        &self,
+        proposal_id: u64,
+        pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+    ) -> Result<QueryVotesResponse, cosmwasm_std::StdError> {
+        QueryVotesRequest {
+            proposal_id,
+            pagination,
+        }
+        .query(self.querier)
+    }
+    pub fn params(
+        &self,
+        params_type: ::prost::alloc::string::String,
+    ) -> Result<QueryParamsResponse, cosmwasm_std::StdError> {
+        QueryParamsRequest { params_type }.query(self.querier)
+    }
+    pub fn deposit(
+        &self,
+        proposal_id: u64,
+        depositor: ::prost::alloc::string::String,
+    ) -> Result<QueryDepositResponse, cosmwasm_std::StdError> {
+        QueryDepositRequest {
+            proposal_id,
+            depositor,
+        }
+        .query(self.querier)
+    }
+    pub fn deposits(
+        &self,
+        proposal_id: u64,
+        pagination: ::core::option::Option<super::super::base::query::v1beta1::PageRequest>,
+    ) -> Result<QueryDepositsResponse, cosmwasm_std::StdError> {
+        QueryDepositsRequest {
+            proposal_id,
+            pagination,
+        }
+        .query(self.querier)
+    }
+    pub fn tally_result(
+        &self,
+        proposal_id: u64,
+    ) -> Result<QueryTallyResultResponse, cosmwasm_std::StdError> {
+        QueryTallyResultRequest { proposal_id }.query(self.querier)
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct GovMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl GovMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn submit_proposal(
+        &self,
+        messages: ::prost::alloc::vec::Vec<crate::shim::Any>,
+        initial_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+        metadata: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSubmitProposal {
+            proposer: self.sender.to_string(),
+            messages,
+            initial_deposit,
+            metadata,
+        }
+        .into()
+    }
+    pub fn vote(
+        &self,
+        proposal_id: u64,
+        option: i32,
+        metadata: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgVote {
+            voter: self.sender.to_string(),
+            proposal_id,
+            option,
+            metadata,
+        }
+        .into()
+    }
+    pub fn vote_weighted(
+        &self,
+        proposal_id: u64,
+        options: ::prost::alloc::vec::Vec<WeightedVoteOption>,
+        metadata: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgVoteWeighted {
+            voter: self.sender.to_string(),
+            proposal_id,
+            options,
+            metadata,
+        }
+        .into()
+    }
+    pub fn deposit(
+        &self,
+        proposal_id: u64,
+        amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgDeposit {
+            depositor: self.sender.to_string(),
+            proposal_id,
+            amount,
+        }
+        .into()
+    }
+}
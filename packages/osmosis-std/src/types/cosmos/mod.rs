@@ -1,4 +1,10 @@
 pub mod auth;
+pub mod authz;
 pub mod bank;
 pub mod base;
+pub mod distribution;
+pub mod feegrant;
+pub mod gov;
+pub mod slashing;
 pub mod staking;
+pub mod upgrade;
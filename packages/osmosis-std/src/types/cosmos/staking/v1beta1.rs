@@ -1,9 +1,25 @@
 use osmosis_std_derive::CosmwasmExt;
-/// NOTE: The following type is not implemented due to current limitations of code generator
-/// which currently has issue with tendermint_proto.
-/// This will be fixed in the upcoming release.
-#[allow(dead_code)]
-struct HistoricalInfo {}
+/// HistoricalInfo contains header and validator information for a given block.
+/// It is stored as part of staking module's state, which persists the `n` most
+/// recent HistoricalInfo
+/// (`n` is set by the staking module's `historical_entries` parameter).
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/cosmos.staking.v1beta1.HistoricalInfo")]
+pub struct HistoricalInfo {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<super::super::super::tendermint::types::Header>,
+    #[prost(message, repeated, tag = "2")]
+    pub valset: ::prost::alloc::vec::Vec<Validator>,
+}
 /// CommissionRates defines the initial commission rates to be used for creating
 /// a validator.
 #[derive(
@@ -13,20 +29,20 @@ struct HistoricalInfo {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.CommissionRates")]
 pub struct CommissionRates {
     /// rate is the commission rate charged to delegators, as a fraction.
-    #[prost(string, tag = "1")]
-    pub rate: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub rate: crate::shim::Dec,
     /// max_rate defines the maximum commission rate which validator can ever charge, as a fraction.
-    #[prost(string, tag = "2")]
-    pub max_rate: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub max_rate: crate::shim::Dec,
     /// max_change_rate defines the maximum daily increase of the validator commission, as a fraction.
-    #[prost(string, tag = "3")]
-    pub max_change_rate: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub max_change_rate: crate::shim::Dec,
 }
 /// Commission defines commission parameters for a given validator.
 #[derive(
@@ -36,9 +52,9 @@ pub struct CommissionRates {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Commission")]
 pub struct Commission {
     /// commission_rates defines the initial commission rates to be used for creating a validator.
@@ -56,9 +72,9 @@ pub struct Commission {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Description")]
 pub struct Description {
     /// moniker defines a human-readable name for the validator.
@@ -92,9 +108,9 @@ pub struct Description {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Validator")]
 pub struct Validator {
     /// operator_address defines the address of the validator's operator; bech encoded in JSON.
@@ -109,16 +125,17 @@ pub struct Validator {
     /// status is the validator status (bonded/unbonding/unbonded).
     #[prost(enumeration = "BondStatus", tag = "4")]
     #[serde(
-        serialize_with = "crate::serde::as_str::serialize",
-        deserialize_with = "crate::serde::as_str::deserialize"
+        serialize_with = "crate::serde::enumeration::serialize::<BondStatus, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<BondStatus, _>"
     )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "BondStatus"))]
     pub status: i32,
     /// tokens define the delegated tokens (incl. self-delegation).
-    #[prost(string, tag = "5")]
-    pub tokens: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "5", default = "0")]
+    pub tokens: crate::shim::Int,
     /// delegator_shares defines total shares issued to a validator's delegators.
-    #[prost(string, tag = "6")]
-    pub delegator_shares: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "6", default = "0")]
+    pub delegator_shares: crate::shim::Dec,
     /// description defines the description terms for the validator.
     #[prost(message, optional, tag = "7")]
     pub description: ::core::option::Option<Description>,
@@ -136,8 +153,8 @@ pub struct Validator {
     #[prost(message, optional, tag = "10")]
     pub commission: ::core::option::Option<Commission>,
     /// min_self_delegation is the validator's self declared minimum self delegation.
-    #[prost(string, tag = "11")]
-    pub min_self_delegation: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "11", default = "0")]
+    pub min_self_delegation: crate::shim::Int,
 }
 /// ValAddresses defines a repeated set of validator addresses.
 #[derive(
@@ -147,9 +164,9 @@ pub struct Validator {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.ValAddresses")]
 pub struct ValAddresses {
     #[prost(string, repeated, tag = "1")]
@@ -165,9 +182,9 @@ pub struct ValAddresses {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.DVPair")]
 pub struct DvPair {
     #[prost(string, tag = "1")]
@@ -183,9 +200,9 @@ pub struct DvPair {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.DVPairs")]
 pub struct DvPairs {
     #[prost(message, repeated, tag = "1")]
@@ -202,9 +219,9 @@ pub struct DvPairs {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.DVVTriplet")]
 pub struct DvvTriplet {
     #[prost(string, tag = "1")]
@@ -222,9 +239,9 @@ pub struct DvvTriplet {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.DVVTriplets")]
 pub struct DvvTriplets {
     #[prost(message, repeated, tag = "1")]
@@ -240,9 +257,9 @@ pub struct DvvTriplets {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Delegation")]
 pub struct Delegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -252,8 +269,8 @@ pub struct Delegation {
     #[prost(string, tag = "2")]
     pub validator_address: ::prost::alloc::string::String,
     /// shares define the delegation shares received.
-    #[prost(string, tag = "3")]
-    pub shares: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub shares: crate::shim::Dec,
 }
 /// UnbondingDelegation stores all of a single delegator's unbonding bonds
 /// for a single validator in an time-ordered list.
@@ -264,9 +281,9 @@ pub struct Delegation {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.UnbondingDelegation")]
 pub struct UnbondingDelegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -289,9 +306,9 @@ pub struct UnbondingDelegation {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.UnbondingDelegationEntry")]
 pub struct UnbondingDelegationEntry {
     /// creation_height is the height which the unbonding took place.
@@ -305,11 +322,11 @@ pub struct UnbondingDelegationEntry {
     #[prost(message, optional, tag = "2")]
     pub completion_time: ::core::option::Option<crate::shim::Timestamp>,
     /// initial_balance defines the tokens initially scheduled to receive at completion.
-    #[prost(string, tag = "3")]
-    pub initial_balance: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub initial_balance: crate::shim::Int,
     /// balance defines the tokens to receive at completion.
-    #[prost(string, tag = "4")]
-    pub balance: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    pub balance: crate::shim::Int,
 }
 /// RedelegationEntry defines a redelegation object with relevant metadata.
 #[derive(
@@ -319,9 +336,9 @@ pub struct UnbondingDelegationEntry {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.RedelegationEntry")]
 pub struct RedelegationEntry {
     /// creation_height  defines the height which the redelegation took place.
@@ -335,11 +352,11 @@ pub struct RedelegationEntry {
     #[prost(message, optional, tag = "2")]
     pub completion_time: ::core::option::Option<crate::shim::Timestamp>,
     /// initial_balance defines the initial balance when redelegation started.
-    #[prost(string, tag = "3")]
-    pub initial_balance: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub initial_balance: crate::shim::Int,
     /// shares_dst is the amount of destination-validator shares created by redelegation.
-    #[prost(string, tag = "4")]
-    pub shares_dst: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    pub shares_dst: crate::shim::Dec,
 }
 /// Redelegation contains the list of a particular delegator's redelegating bonds
 /// from a particular source validator to a particular destination validator.
@@ -350,9 +367,9 @@ pub struct RedelegationEntry {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Redelegation")]
 pub struct Redelegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -378,9 +395,9 @@ pub struct Redelegation {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Params")]
 pub struct Params {
     /// unbonding_time is the time duration of unbonding.
@@ -411,8 +428,8 @@ pub struct Params {
     #[prost(string, tag = "5")]
     pub bond_denom: ::prost::alloc::string::String,
     /// min_commission_rate is the chain-wide minimum commission rate that a validator can charge their delegators
-    #[prost(string, tag = "6")]
-    pub min_commission_rate: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "6", default = "0")]
+    pub min_commission_rate: crate::shim::Dec,
     /// min_self_delegation is the chain-wide minimum amount that a validator has to self delegate
     #[prost(string, tag = "7")]
     pub min_self_delegation: ::prost::alloc::string::String,
@@ -426,9 +443,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.DelegationResponse")]
 pub struct DelegationResponse {
     #[prost(message, optional, tag = "1")]
@@ -446,15 +463,15 @@ pub struct DelegationResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.RedelegationEntryResponse")]
 pub struct RedelegationEntryResponse {
     #[prost(message, optional, tag = "1")]
     pub redelegation_entry: ::core::option::Option<RedelegationEntry>,
-    #[prost(string, tag = "4")]
-    pub balance: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    pub balance: crate::shim::Int,
 }
 /// RedelegationResponse is equivalent to a Redelegation except that its entries
 /// contain a balance in addition to shares which is more suitable for client
@@ -466,9 +483,9 @@ pub struct RedelegationEntryResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.RedelegationResponse")]
 pub struct RedelegationResponse {
     #[prost(message, optional, tag = "1")]
@@ -485,26 +502,72 @@ pub struct RedelegationResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/cosmos.staking.v1beta1.Pool")]
 pub struct Pool {
-    #[prost(string, tag = "1")]
-    pub not_bonded_tokens: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub bonded_tokens: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub not_bonded_tokens: crate::shim::Int,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub bonded_tokens: crate::shim::Int,
 }
 /// BondStatus is the status of a validator.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[repr(i32)]
 pub enum BondStatus {
     /// UNSPECIFIED defines an invalid validator status.
+    #[serde(rename = "BOND_STATUS_UNSPECIFIED")]
     Unspecified = 0,
     /// UNBONDED defines a validator that is not bonded.
+    #[serde(rename = "BOND_STATUS_UNBONDED")]
     Unbonded = 1,
     /// UNBONDING defines a validator that is unbonding.
+    #[serde(rename = "BOND_STATUS_UNBONDING")]
     Unbonding = 2,
     /// BONDED defines a validator that is bonded.
+    #[serde(rename = "BOND_STATUS_BONDED")]
     Bonded = 3,
 }
+impl BondStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BondStatus::Unspecified => "BOND_STATUS_UNSPECIFIED",
+            BondStatus::Unbonded => "BOND_STATUS_UNBONDED",
+            BondStatus::Unbonding => "BOND_STATUS_UNBONDING",
+            BondStatus::Bonded => "BOND_STATUS_BONDED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BOND_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "BOND_STATUS_UNBONDED" => Some(Self::Unbonded),
+            "BOND_STATUS_UNBONDING" => Some(Self::Unbonding),
+            "BOND_STATUS_BONDED" => Some(Self::Bonded),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for BondStatus {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        BondStatus::from_i32(value)
+    }
+}
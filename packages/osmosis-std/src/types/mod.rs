@@ -1,2 +1,6 @@
 pub mod cosmos;
+pub mod ibc;
 pub mod osmosis;
+#[cfg(feature = "std")]
+pub(crate) mod registry;
+pub mod tendermint;
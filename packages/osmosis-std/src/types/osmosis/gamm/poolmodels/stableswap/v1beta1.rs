@@ -10,15 +10,17 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.PoolParams")]
 pub struct PoolParams {
-    #[prost(string, tag = "1")]
-    pub swap_fee: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub exit_fee: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    #[amino(not_omitted)]
+    pub swap_fee: crate::shim::Dec,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub exit_fee: crate::shim::Dec,
 }
 /// Pool is the stableswap Pool struct
 #[derive(
@@ -28,9 +30,9 @@ pub struct PoolParams {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.Pool")]
 pub struct Pool {
     #[prost(string, tag = "1")]
@@ -76,16 +78,21 @@ pub struct Pool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgCreateStableswapPool")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgCreateStableswapPool",
+    amino_type = "osmosis/gamm/create-stableswap-pool",
+    response_type = MsgCreateStableswapPoolResponse
+)]
 pub struct MsgCreateStableswapPool {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub pool_params: ::core::option::Option<PoolParams>,
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub initial_pool_liquidity:
         ::prost::alloc::vec::Vec<super::super::super::super::super::cosmos::base::v1beta1::Coin>,
     #[prost(uint64, repeated, packed = "false", tag = "4")]
@@ -103,9 +110,9 @@ pub struct MsgCreateStableswapPool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(
     type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgCreateStableswapPoolResponse"
 )]
@@ -126,11 +133,13 @@ pub struct MsgCreateStableswapPoolResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(
-    type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgStableSwapAdjustScalingFactors"
+    type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgStableSwapAdjustScalingFactors",
+    amino_type = "osmosis/gamm/stableswap-adjust-scaling-factors",
+    response_type = MsgStableSwapAdjustScalingFactorsResponse
 )]
 pub struct MsgStableSwapAdjustScalingFactors {
     #[prost(string, tag = "1")]
@@ -151,10 +160,158 @@ pub struct MsgStableSwapAdjustScalingFactors {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(
     type_url = "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgStableSwapAdjustScalingFactorsResponse"
 )]
 pub struct MsgStableSwapAdjustScalingFactorsResponse {}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn create_stableswap_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgCreateStableswapPool>,
+        ) -> Result<tonic::Response<super::MsgCreateStableswapPoolResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.poolmodels.stableswap.v1beta1.Msg/CreateStableswapPool",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn stable_swap_adjust_scaling_factors(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgStableSwapAdjustScalingFactors>,
+        ) -> Result<tonic::Response<super::MsgStableSwapAdjustScalingFactorsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.poolmodels.stableswap.v1beta1.Msg/StableSwapAdjustScalingFactors",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct StableswapMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl StableswapMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn create_stableswap_pool(
+        &self,
+        pool_params: ::core::option::Option<PoolParams>,
+        initial_pool_liquidity: ::prost::alloc::vec::Vec<
+            super::super::super::super::super::cosmos::base::v1beta1::Coin,
+        >,
+        scaling_factors: ::prost::alloc::vec::Vec<u64>,
+        future_pool_governor: ::prost::alloc::string::String,
+        scaling_factor_controller: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgCreateStableswapPool {
+            sender: self.sender.to_string(),
+            pool_params,
+            initial_pool_liquidity,
+            scaling_factors,
+            future_pool_governor,
+            scaling_factor_controller,
+        }
+        .into()
+    }
+    pub fn stable_swap_adjust_scaling_factors(
+        &self,
+        pool_id: u64,
+        scaling_factors: ::prost::alloc::vec::Vec<u64>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgStableSwapAdjustScalingFactors {
+            sender: self.sender.to_string(),
+            pool_id,
+            scaling_factors,
+        }
+        .into()
+    }
+}
@@ -17,18 +17,20 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.SmoothWeightChangeParams")]
 pub struct SmoothWeightChangeParams {
     /// The start time for beginning the weight change.
     /// If a parameter change / pool instantiation leaves this blank,
     /// it should be generated by the state_machine as the current time.
     #[prost(message, optional, tag = "1")]
+    #[amino(not_omitted)]
     pub start_time: ::core::option::Option<crate::shim::Timestamp>,
     /// Duration for the weights to change over
     #[prost(message, optional, tag = "2")]
+    #[amino(not_omitted)]
     pub duration: ::core::option::Option<crate::shim::Duration>,
     /// The initial pool weights. These are copied from the pool's settings
     /// at the time of weight change instantiation.
@@ -36,6 +38,7 @@ pub struct SmoothWeightChangeParams {
     /// future type refactorings should just have a type with the denom & weight
     /// here.
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub initial_pool_weights: ::prost::alloc::vec::Vec<PoolAsset>,
     /// The target pool weights. The pool weights will change linearly with respect
     /// to time between start_time, and start_time + duration. The amount
@@ -50,6 +53,7 @@ pub struct SmoothWeightChangeParams {
     ///  (gogoproto.nullable) = false
     /// ];
     #[prost(message, repeated, tag = "4")]
+    #[amino(not_omitted)]
     pub target_pool_weights: ::prost::alloc::vec::Vec<PoolAsset>,
 }
 /// PoolParams defined the parameters that will be managed by the pool
@@ -63,15 +67,17 @@ pub struct SmoothWeightChangeParams {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.PoolParams")]
 pub struct PoolParams {
-    #[prost(string, tag = "1")]
-    pub swap_fee: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub exit_fee: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    #[amino(not_omitted)]
+    pub swap_fee: crate::shim::Dec,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub exit_fee: crate::shim::Dec,
     #[prost(message, optional, tag = "3")]
     pub smooth_weight_change_params: ::core::option::Option<SmoothWeightChangeParams>,
 }
@@ -86,18 +92,20 @@ pub struct PoolParams {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.PoolAsset")]
 pub struct PoolAsset {
     /// Coins we are talking about,
     /// the denomination must be unique amongst all PoolAssets for this pool.
     #[prost(message, optional, tag = "1")]
+    #[amino(not_omitted)]
     pub token: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
     /// Weight that is not normalized. This weight must be less than 2^50
-    #[prost(string, tag = "2")]
-    pub weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub weight: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -106,9 +114,9 @@ pub struct PoolAsset {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.Pool")]
 pub struct Pool {
     #[prost(string, tag = "1")]
@@ -140,8 +148,8 @@ pub struct Pool {
     #[prost(message, repeated, tag = "6")]
     pub pool_assets: ::prost::alloc::vec::Vec<PoolAsset>,
     /// sum of all non-normalized pool weights
-    #[prost(string, tag = "7")]
-    pub total_weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "7", default = "0")]
+    pub total_weight: crate::shim::Int,
 }
 /// ===================== MsgJoinPool
 /// This is really MsgJoinPoolNoSwap
@@ -152,22 +160,29 @@ pub struct Pool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinPool")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgJoinPool",
+    amino_type = "osmosis/gamm/join-pool",
+    response_type = MsgJoinPoolResponse
+)]
 pub struct MsgJoinPool {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
-    #[prost(string, tag = "3")]
-    pub share_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    #[amino(name = "shareOutAmount", not_omitted)]
+    pub share_out_amount: crate::shim::Int,
     #[prost(message, repeated, tag = "4")]
+    #[amino(name = "tokenInMaxs", not_omitted)]
     pub token_in_maxs: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -177,13 +192,13 @@ pub struct MsgJoinPool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinPoolResponse")]
 pub struct MsgJoinPoolResponse {
-    #[prost(string, tag = "1")]
-    pub share_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub share_out_amount: crate::shim::Int,
     #[prost(message, repeated, tag = "2")]
     pub token_in: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
 }
@@ -195,22 +210,29 @@ pub struct MsgJoinPoolResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitPool")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgExitPool",
+    amino_type = "osmosis/gamm/exit-pool",
+    response_type = MsgExitPoolResponse
+)]
 pub struct MsgExitPool {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
-    #[prost(string, tag = "3")]
-    pub share_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    #[amino(name = "shareInAmount", not_omitted)]
+    pub share_in_amount: crate::shim::Int,
     #[prost(message, repeated, tag = "4")]
+    #[amino(name = "tokenOutMins", not_omitted)]
     pub token_out_mins: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -220,9 +242,9 @@ pub struct MsgExitPool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitPoolResponse")]
 pub struct MsgExitPoolResponse {
     #[prost(message, repeated, tag = "1")]
@@ -236,18 +258,20 @@ pub struct MsgExitPoolResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.SwapAmountInRoute")]
 pub struct SwapAmountInRoute {
     #[prost(uint64, tag = "1")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(string, tag = "2")]
+    #[amino(name = "tokenOutDenom")]
     pub token_out_denom: ::prost::alloc::string::String,
 }
 #[derive(
@@ -257,19 +281,26 @@ pub struct SwapAmountInRoute {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn",
+    amino_type = "osmosis/gamm/swap-exact-amount-in",
+    response_type = MsgSwapExactAmountInResponse
+)]
 pub struct MsgSwapExactAmountIn {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub routes: ::prost::alloc::vec::Vec<SwapAmountInRoute>,
     #[prost(message, optional, tag = "3")]
+    #[amino(name = "tokenIn", not_omitted)]
     pub token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
-    #[prost(string, tag = "4")]
-    pub token_out_min_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    #[amino(name = "tokenOutMinAmount", not_omitted)]
+    pub token_out_min_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -278,13 +309,13 @@ pub struct MsgSwapExactAmountIn {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountInResponse")]
 pub struct MsgSwapExactAmountInResponse {
-    #[prost(string, tag = "1")]
-    pub token_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_out_amount: crate::shim::Int,
 }
 /// ===================== MsgSwapExactAmountOut
 #[derive(
@@ -294,18 +325,20 @@ pub struct MsgSwapExactAmountInResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.SwapAmountOutRoute")]
 pub struct SwapAmountOutRoute {
     #[prost(uint64, tag = "1")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(string, tag = "2")]
+    #[amino(name = "tokenInDenom")]
     pub token_in_denom: ::prost::alloc::string::String,
 }
 #[derive(
@@ -315,18 +348,25 @@ pub struct SwapAmountOutRoute {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountOut")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountOut",
+    amino_type = "osmosis/gamm/swap-exact-amount-out",
+    response_type = MsgSwapExactAmountOutResponse
+)]
 pub struct MsgSwapExactAmountOut {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub routes: ::prost::alloc::vec::Vec<SwapAmountOutRoute>,
-    #[prost(string, tag = "3")]
-    pub token_in_max_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    #[amino(name = "tokenInMaxAmount", not_omitted)]
+    pub token_in_max_amount: crate::shim::Int,
     #[prost(message, optional, tag = "4")]
+    #[amino(name = "tokenOut", not_omitted)]
     pub token_out: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -336,13 +376,13 @@ pub struct MsgSwapExactAmountOut {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgSwapExactAmountOutResponse")]
 pub struct MsgSwapExactAmountOutResponse {
-    #[prost(string, tag = "1")]
-    pub token_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_in_amount: crate::shim::Int,
 }
 /// ===================== MsgJoinSwapExternAmountIn
 /// TODO: Rename to MsgJoinSwapExactAmountIn
@@ -353,27 +393,34 @@ pub struct MsgSwapExactAmountOutResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapExternAmountIn")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapExternAmountIn",
+    amino_type = "osmosis/gamm/join-swap-extern-amount-in",
+    response_type = MsgJoinSwapExternAmountInResponse
+)]
 pub struct MsgJoinSwapExternAmountIn {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(message, optional, tag = "3")]
+    #[amino(name = "tokenIn", not_omitted)]
     pub token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
     /// repeated cosmos.base.v1beta1.Coin tokensIn = 5 [
     ///   (gogoproto.moretags) = "yaml:\"tokens_in\"",
     ///   (gogoproto.nullable) = false
     /// ];
-    #[prost(string, tag = "4")]
-    pub share_out_min_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    #[amino(name = "shareOutMinAmount", not_omitted)]
+    pub share_out_min_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -382,13 +429,13 @@ pub struct MsgJoinSwapExternAmountIn {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapExternAmountInResponse")]
 pub struct MsgJoinSwapExternAmountInResponse {
-    #[prost(string, tag = "1")]
-    pub share_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub share_out_amount: crate::shim::Int,
 }
 /// ===================== MsgJoinSwapShareAmountOut
 #[derive(
@@ -398,25 +445,33 @@ pub struct MsgJoinSwapExternAmountInResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapShareAmountOut")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapShareAmountOut",
+    amino_type = "osmosis/gamm/join-swap-share-amount-out",
+    response_type = MsgJoinSwapShareAmountOutResponse
+)]
 pub struct MsgJoinSwapShareAmountOut {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(string, tag = "3")]
+    #[amino(name = "tokenInDenom")]
     pub token_in_denom: ::prost::alloc::string::String,
-    #[prost(string, tag = "4")]
-    pub share_out_amount: ::prost::alloc::string::String,
-    #[prost(string, tag = "5")]
-    pub token_in_max_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    #[amino(name = "shareOutAmount", not_omitted)]
+    pub share_out_amount: crate::shim::Int,
+    #[prost(string, required, tag = "5", default = "0")]
+    #[amino(name = "tokenInMaxAmount", not_omitted)]
+    pub token_in_max_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -425,13 +480,13 @@ pub struct MsgJoinSwapShareAmountOut {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgJoinSwapShareAmountOutResponse")]
 pub struct MsgJoinSwapShareAmountOutResponse {
-    #[prost(string, tag = "1")]
-    pub token_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_in_amount: crate::shim::Int,
 }
 /// ===================== MsgExitSwapShareAmountIn
 #[derive(
@@ -441,25 +496,33 @@ pub struct MsgJoinSwapShareAmountOutResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitSwapShareAmountIn")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgExitSwapShareAmountIn",
+    amino_type = "osmosis/gamm/exit-swap-share-amount-in",
+    response_type = MsgExitSwapShareAmountInResponse
+)]
 pub struct MsgExitSwapShareAmountIn {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(string, tag = "3")]
+    #[amino(name = "tokenOutDenom")]
     pub token_out_denom: ::prost::alloc::string::String,
-    #[prost(string, tag = "4")]
-    pub share_in_amount: ::prost::alloc::string::String,
-    #[prost(string, tag = "5")]
-    pub token_out_min_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    #[amino(name = "shareInAmount", not_omitted)]
+    pub share_in_amount: crate::shim::Int,
+    #[prost(string, required, tag = "5", default = "0")]
+    #[amino(name = "tokenOutMinAmount", not_omitted)]
+    pub token_out_min_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -468,13 +531,13 @@ pub struct MsgExitSwapShareAmountIn {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitSwapShareAmountInResponse")]
 pub struct MsgExitSwapShareAmountInResponse {
-    #[prost(string, tag = "1")]
-    pub token_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_out_amount: crate::shim::Int,
 }
 /// ===================== MsgExitSwapExternAmountOut
 #[derive(
@@ -484,23 +547,30 @@ pub struct MsgExitSwapShareAmountInResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitSwapExternAmountOut")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.gamm.v1beta1.MsgExitSwapExternAmountOut",
+    amino_type = "osmosis/gamm/exit-swap-extern-amount-out",
+    response_type = MsgExitSwapExternAmountOutResponse
+)]
 pub struct MsgExitSwapExternAmountOut {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "poolId")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
     #[prost(message, optional, tag = "3")]
+    #[amino(name = "tokenOut", not_omitted)]
     pub token_out: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
-    #[prost(string, tag = "4")]
-    pub share_in_max_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    #[amino(name = "shareInMaxAmount", not_omitted)]
+    pub share_in_max_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -509,13 +579,13 @@ pub struct MsgExitSwapExternAmountOut {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.MsgExitSwapExternAmountOutResponse")]
 pub struct MsgExitSwapExternAmountOutResponse {
-    #[prost(string, tag = "1")]
-    pub share_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub share_in_amount: crate::shim::Int,
 }
 ///=============================== Pool
 #[derive(
@@ -525,9 +595,9 @@ pub struct MsgExitSwapExternAmountOutResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/Pool",
@@ -548,9 +618,9 @@ pub struct QueryPoolRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolResponse")]
 pub struct QueryPoolResponse {
     #[prost(message, optional, tag = "1")]
@@ -564,9 +634,9 @@ pub struct QueryPoolResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolsRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/Pools",
@@ -585,9 +655,9 @@ pub struct QueryPoolsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolsResponse")]
 pub struct QueryPoolsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -605,9 +675,9 @@ pub struct QueryPoolsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryNumPoolsRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/NumPools",
@@ -621,9 +691,9 @@ pub struct QueryNumPoolsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryNumPoolsResponse")]
 pub struct QueryNumPoolsResponse {
     #[prost(uint64, tag = "1")]
@@ -641,9 +711,9 @@ pub struct QueryNumPoolsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolTypeRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/PoolType",
@@ -664,9 +734,9 @@ pub struct QueryPoolTypeRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolTypeResponse")]
 pub struct QueryPoolTypeResponse {
     #[prost(string, tag = "1")]
@@ -680,9 +750,9 @@ pub struct QueryPoolTypeResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcJoinPoolSharesRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares",
@@ -705,13 +775,13 @@ pub struct QueryCalcJoinPoolSharesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcJoinPoolSharesResponse")]
 pub struct QueryCalcJoinPoolSharesResponse {
-    #[prost(string, tag = "1")]
-    pub share_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub share_out_amount: crate::shim::Int,
     #[prost(message, repeated, tag = "2")]
     pub tokens_out: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
 }
@@ -723,9 +793,9 @@ pub struct QueryCalcJoinPoolSharesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcExitPoolCoinsFromSharesRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/CalcExitPoolCoinsFromShares",
@@ -738,8 +808,8 @@ pub struct QueryCalcExitPoolCoinsFromSharesRequest {
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub pool_id: u64,
-    #[prost(string, tag = "2")]
-    pub share_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub share_in_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -748,9 +818,9 @@ pub struct QueryCalcExitPoolCoinsFromSharesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcExitPoolCoinsFromSharesResponse")]
 pub struct QueryCalcExitPoolCoinsFromSharesResponse {
     #[prost(message, repeated, tag = "1")]
@@ -764,9 +834,9 @@ pub struct QueryCalcExitPoolCoinsFromSharesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolParamsRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/PoolParams",
@@ -787,9 +857,9 @@ pub struct QueryPoolParamsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolParamsResponse")]
 pub struct QueryPoolParamsResponse {
     #[prost(message, optional, tag = "1")]
@@ -803,9 +873,9 @@ pub struct QueryPoolParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalPoolLiquidityRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/TotalPoolLiquidity",
@@ -826,9 +896,9 @@ pub struct QueryTotalPoolLiquidityRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalPoolLiquidityResponse")]
 pub struct QueryTotalPoolLiquidityResponse {
     #[prost(message, repeated, tag = "1")]
@@ -842,9 +912,9 @@ pub struct QueryTotalPoolLiquidityResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalSharesRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/TotalShares",
@@ -865,9 +935,9 @@ pub struct QueryTotalSharesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalSharesResponse")]
 pub struct QueryTotalSharesResponse {
     #[prost(message, optional, tag = "1")]
@@ -881,9 +951,9 @@ pub struct QueryTotalSharesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcJoinPoolNoSwapSharesRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/CalcJoinPoolNoSwapShares",
@@ -906,15 +976,15 @@ pub struct QueryCalcJoinPoolNoSwapSharesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryCalcJoinPoolNoSwapSharesResponse")]
 pub struct QueryCalcJoinPoolNoSwapSharesResponse {
     #[prost(message, repeated, tag = "1")]
     pub tokens_out: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
-    #[prost(string, tag = "2")]
-    pub shares_out: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub shares_out: crate::shim::Int,
 }
 /// QuerySpotPriceRequest defines the gRPC request structure for a SpotPrice
 /// query.
@@ -925,9 +995,9 @@ pub struct QueryCalcJoinPoolNoSwapSharesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySpotPriceRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/SpotPrice",
@@ -952,9 +1022,9 @@ pub struct QuerySpotPriceRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolsWithFilterRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/PoolsWithFilter",
@@ -973,9 +1043,9 @@ pub struct QueryPoolsWithFilterRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryPoolsWithFilterResponse")]
 pub struct QueryPoolsWithFilterResponse {
     #[prost(message, repeated, tag = "1")]
@@ -990,9 +1060,9 @@ pub struct QueryPoolsWithFilterResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySpotPriceResponse")]
 pub struct QuerySpotPriceResponse {
     /// String of the Dec. Ex) 10.203uatom
@@ -1007,9 +1077,9 @@ pub struct QuerySpotPriceResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySwapExactAmountInRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountIn",
@@ -1036,13 +1106,13 @@ pub struct QuerySwapExactAmountInRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySwapExactAmountInResponse")]
 pub struct QuerySwapExactAmountInResponse {
-    #[prost(string, tag = "1")]
-    pub token_out_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_out_amount: crate::shim::Int,
 }
 ///=============================== EstimateSwapExactAmountOut
 #[derive(
@@ -1052,9 +1122,9 @@ pub struct QuerySwapExactAmountInResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySwapExactAmountOutRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountOut",
@@ -1081,13 +1151,13 @@ pub struct QuerySwapExactAmountOutRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QuerySwapExactAmountOutResponse")]
 pub struct QuerySwapExactAmountOutResponse {
-    #[prost(string, tag = "1")]
-    pub token_in_amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub token_in_amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -1096,9 +1166,9 @@ pub struct QuerySwapExactAmountOutResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalLiquidityRequest")]
 #[proto_query(
     path = "/osmosis.gamm.v1beta1.Query/TotalLiquidity",
@@ -1112,9 +1182,9 @@ pub struct QueryTotalLiquidityRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.QueryTotalLiquidityResponse")]
 pub struct QueryTotalLiquidityResponse {
     #[prost(message, repeated, tag = "1")]
@@ -1128,9 +1198,9 @@ pub struct QueryTotalLiquidityResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.Params")]
 pub struct Params {
     #[prost(message, repeated, tag = "1")]
@@ -1145,9 +1215,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.gamm.v1beta1.GenesisState")]
 pub struct GenesisState {
     #[prost(message, repeated, tag = "1")]
@@ -1162,9 +1232,516 @@ pub struct GenesisState {
     #[prost(message, optional, tag = "3")]
     pub params: ::core::option::Option<Params>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn pools(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPoolsRequest>,
+        ) -> Result<tonic::Response<super::QueryPoolsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/Pools");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn num_pools(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryNumPoolsRequest>,
+        ) -> Result<tonic::Response<super::QueryNumPoolsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/NumPools");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_liquidity(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalLiquidityRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalLiquidityResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/TotalLiquidity");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn pools_with_filter(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPoolsWithFilterRequest>,
+        ) -> Result<tonic::Response<super::QueryPoolsWithFilterResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/PoolsWithFilter");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPoolRequest>,
+        ) -> Result<tonic::Response<super::QueryPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/Pool");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn pool_type(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPoolTypeRequest>,
+        ) -> Result<tonic::Response<super::QueryPoolTypeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/PoolType");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn calc_join_pool_no_swap_shares(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryCalcJoinPoolNoSwapSharesRequest>,
+        ) -> Result<tonic::Response<super::QueryCalcJoinPoolNoSwapSharesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/CalcJoinPoolNoSwapShares",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn calc_join_pool_shares(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryCalcJoinPoolSharesRequest>,
+        ) -> Result<tonic::Response<super::QueryCalcJoinPoolSharesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn calc_exit_pool_coins_from_shares(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryCalcExitPoolCoinsFromSharesRequest>,
+        ) -> Result<tonic::Response<super::QueryCalcExitPoolCoinsFromSharesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/CalcExitPoolCoinsFromShares",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn pool_params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryPoolParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryPoolParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/PoolParams");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_pool_liquidity(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalPoolLiquidityRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalPoolLiquidityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/TotalPoolLiquidity",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_shares(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalSharesRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalSharesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/TotalShares");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn spot_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySpotPriceRequest>,
+        ) -> Result<tonic::Response<super::QuerySpotPriceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Query/SpotPrice");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn estimate_swap_exact_amount_in(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySwapExactAmountInRequest>,
+        ) -> Result<tonic::Response<super::QuerySwapExactAmountInResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountIn",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn estimate_swap_exact_amount_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySwapExactAmountOutRequest>,
+        ) -> Result<tonic::Response<super::QuerySwapExactAmountOutResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountOut",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn join_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgJoinPool>,
+        ) -> Result<tonic::Response<super::MsgJoinPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Msg/JoinPool");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn exit_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgExitPool>,
+        ) -> Result<tonic::Response<super::MsgExitPoolResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Msg/ExitPool");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn swap_exact_amount_in(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSwapExactAmountIn>,
+        ) -> Result<tonic::Response<super::MsgSwapExactAmountInResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.gamm.v1beta1.Msg/SwapExactAmountIn");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn swap_exact_amount_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSwapExactAmountOut>,
+        ) -> Result<tonic::Response<super::MsgSwapExactAmountOutResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Msg/SwapExactAmountOut",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn join_swap_extern_amount_in(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgJoinSwapExternAmountIn>,
+        ) -> Result<tonic::Response<super::MsgJoinSwapExternAmountInResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Msg/JoinSwapExternAmountIn",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn join_swap_share_amount_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgJoinSwapShareAmountOut>,
+        ) -> Result<tonic::Response<super::MsgJoinSwapShareAmountOutResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Msg/JoinSwapShareAmountOut",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn exit_swap_share_amount_in(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgExitSwapShareAmountIn>,
+        ) -> Result<tonic::Response<super::MsgExitSwapShareAmountInResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Msg/ExitSwapShareAmountIn",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn exit_swap_extern_amount_out(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgExitSwapExternAmountOut>,
+        ) -> Result<tonic::Response<super::MsgExitSwapExternAmountOutResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.gamm.v1beta1.Msg/ExitSwapExternAmountOut",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct GammQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> GammQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -1217,7 +1794,7 @@ impl<'a, Q: cosmwasm_std::CustomQuery> GammQuerier<'a, Q> {
     pub fn calc_exit_pool_coins_from_shares(
         &self,
         pool_id: u64,
-        share_in_amount: ::prost::alloc::string::String,
+        share_in_amount: crate::shim::Int,
     ) -> Result<QueryCalcExitPoolCoinsFromSharesResponse, cosmwasm_std::StdError> {
         QueryCalcExitPoolCoinsFromSharesRequest {
             pool_id,
@@ -1287,3 +1864,130 @@ impl<'a, Q: cosmwasm_std::CustomQuery> GammQuerier<'a, Q> {
         .query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct GammMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl GammMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn join_pool(
+        &self,
+        pool_id: u64,
+        share_out_amount: crate::shim::Int,
+        token_in_maxs: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgJoinPool {
+            sender: self.sender.to_string(),
+            pool_id,
+            share_out_amount,
+            token_in_maxs,
+        }
+        .into()
+    }
+    pub fn exit_pool(
+        &self,
+        pool_id: u64,
+        share_in_amount: crate::shim::Int,
+        token_out_mins: ::prost::alloc::vec::Vec<super::super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgExitPool {
+            sender: self.sender.to_string(),
+            pool_id,
+            share_in_amount,
+            token_out_mins,
+        }
+        .into()
+    }
+    pub fn swap_exact_amount_in(
+        &self,
+        routes: ::prost::alloc::vec::Vec<SwapAmountInRoute>,
+        token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+        token_out_min_amount: crate::shim::Int,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSwapExactAmountIn {
+            sender: self.sender.to_string(),
+            routes,
+            token_in,
+            token_out_min_amount,
+        }
+        .into()
+    }
+    pub fn swap_exact_amount_out(
+        &self,
+        routes: ::prost::alloc::vec::Vec<SwapAmountOutRoute>,
+        token_in_max_amount: crate::shim::Int,
+        token_out: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSwapExactAmountOut {
+            sender: self.sender.to_string(),
+            routes,
+            token_in_max_amount,
+            token_out,
+        }
+        .into()
+    }
+    pub fn join_swap_extern_amount_in(
+        &self,
+        pool_id: u64,
+        token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+        share_out_min_amount: crate::shim::Int,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgJoinSwapExternAmountIn {
+            sender: self.sender.to_string(),
+            pool_id,
+            token_in,
+            share_out_min_amount,
+        }
+        .into()
+    }
+    pub fn join_swap_share_amount_out(
+        &self,
+        pool_id: u64,
+        token_in_denom: ::prost::alloc::string::String,
+        share_out_amount: crate::shim::Int,
+        token_in_max_amount: crate::shim::Int,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgJoinSwapShareAmountOut {
+            sender: self.sender.to_string(),
+            pool_id,
+            token_in_denom,
+            share_out_amount,
+            token_in_max_amount,
+        }
+        .into()
+    }
+    pub fn exit_swap_share_amount_in(
+        &self,
+        pool_id: u64,
+        token_out_denom: ::prost::alloc::string::String,
+        share_in_amount: crate::shim::Int,
+        token_out_min_amount: crate::shim::Int,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgExitSwapShareAmountIn {
+            sender: self.sender.to_string(),
+            pool_id,
+            token_out_denom,
+            share_in_amount,
+            token_out_min_amount,
+        }
+        .into()
+    }
+    pub fn exit_swap_extern_amount_out(
+        &self,
+        pool_id: u64,
+        token_out: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+        share_in_max_amount: crate::shim::Int,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgExitSwapExternAmountOut {
+            sender: self.sender.to_string(),
+            pool_id,
+            token_out,
+            share_in_max_amount,
+        }
+        .into()
+    }
+}
@@ -9,9 +9,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.Gauge")]
 pub struct Gauge {
     /// id is the unique ID of a Gauge
@@ -66,9 +66,9 @@ pub struct Gauge {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.LockableDurationsInfo")]
 pub struct LockableDurationsInfo {
     /// List of incentivised durations that gauges will pay out to
@@ -83,10 +83,14 @@ pub struct LockableDurationsInfo {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.incentives.MsgCreateGauge")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.incentives.MsgCreateGauge",
+    amino_type = "osmosis/incentives/create-gauge",
+    response_type = MsgCreateGaugeResponse
+)]
 pub struct MsgCreateGauge {
     /// is_perpetual shows if it's a perpetual or non-perpetual gauge
     /// Non-perpetual gauges distribute their tokens equally per epoch while the
@@ -101,12 +105,15 @@ pub struct MsgCreateGauge {
     /// distribute_to show which lock the gauge should distribute to by time
     /// duration or by timestamp
     #[prost(message, optional, tag = "3")]
+    #[amino(not_omitted)]
     pub distribute_to: ::core::option::Option<super::lockup::QueryCondition>,
     /// coins are coin(s) to be distributed by the gauge
     #[prost(message, repeated, tag = "4")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
     /// start_time is the distribution start time
     #[prost(message, optional, tag = "5")]
+    #[amino(not_omitted)]
     pub start_time: ::core::option::Option<crate::shim::Timestamp>,
     /// num_epochs_paid_over is the number of epochs distribution will be completed
     /// over
@@ -124,9 +131,9 @@ pub struct MsgCreateGauge {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.MsgCreateGaugeResponse")]
 pub struct MsgCreateGaugeResponse {}
 /// MsgAddToGauge adds coins to a previously created gauge
@@ -137,10 +144,14 @@ pub struct MsgCreateGaugeResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.incentives.MsgAddToGauge")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.incentives.MsgAddToGauge",
+    amino_type = "osmosis/incentives/add-to-gauge",
+    response_type = MsgAddToGaugeResponse
+)]
 pub struct MsgAddToGauge {
     /// owner is the gauge owner's address
     #[prost(string, tag = "1")]
@@ -154,6 +165,7 @@ pub struct MsgAddToGauge {
     pub gauge_id: u64,
     /// rewards are the coin(s) to add to gauge
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub rewards: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -163,9 +175,9 @@ pub struct MsgAddToGauge {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.MsgAddToGaugeResponse")]
 pub struct MsgAddToGaugeResponse {}
 #[derive(
@@ -175,9 +187,9 @@ pub struct MsgAddToGaugeResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ModuleToDistributeCoinsRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/ModuleToDistributeCoins",
@@ -191,9 +203,9 @@ pub struct ModuleToDistributeCoinsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ModuleToDistributeCoinsResponse")]
 pub struct ModuleToDistributeCoinsResponse {
     /// Coins that have yet to be distributed
@@ -207,9 +219,9 @@ pub struct ModuleToDistributeCoinsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.GaugeByIDRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/GaugeByID",
@@ -231,9 +243,9 @@ pub struct GaugeByIdRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.GaugeByIDResponse")]
 pub struct GaugeByIdResponse {
     /// Gauge that corresponds to provided gague ID
@@ -247,9 +259,9 @@ pub struct GaugeByIdResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.GaugesRequest")]
 #[proto_query(path = "/osmosis.incentives.Query/Gauges", response_type = GaugesResponse)]
 pub struct GaugesRequest {
@@ -264,9 +276,9 @@ pub struct GaugesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.GaugesResponse")]
 pub struct GaugesResponse {
     /// Upcoming and active gauges
@@ -284,9 +296,9 @@ pub struct GaugesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ActiveGaugesRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/ActiveGauges",
@@ -304,9 +316,9 @@ pub struct ActiveGaugesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ActiveGaugesResponse")]
 pub struct ActiveGaugesResponse {
     /// Active gagues only
@@ -324,9 +336,9 @@ pub struct ActiveGaugesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ActiveGaugesPerDenomRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/ActiveGaugesPerDenom",
@@ -347,9 +359,9 @@ pub struct ActiveGaugesPerDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.ActiveGaugesPerDenomResponse")]
 pub struct ActiveGaugesPerDenomResponse {
     /// Active gagues that match denom in query
@@ -367,9 +379,9 @@ pub struct ActiveGaugesPerDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.UpcomingGaugesRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/UpcomingGauges",
@@ -387,9 +399,9 @@ pub struct UpcomingGaugesRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.UpcomingGaugesResponse")]
 pub struct UpcomingGaugesResponse {
     /// Gauges whose distribution is upcoming
@@ -407,9 +419,9 @@ pub struct UpcomingGaugesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.UpcomingGaugesPerDenomRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/UpcomingGaugesPerDenom",
@@ -430,9 +442,9 @@ pub struct UpcomingGaugesPerDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.UpcomingGaugesPerDenomResponse")]
 pub struct UpcomingGaugesPerDenomResponse {
     /// Upcoming gagues that match denom in query
@@ -450,9 +462,9 @@ pub struct UpcomingGaugesPerDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.RewardsEstRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/RewardsEst",
@@ -481,9 +493,9 @@ pub struct RewardsEstRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.RewardsEstResponse")]
 pub struct RewardsEstResponse {
     /// Estimated coin rewards that will be recieved at provided address
@@ -498,9 +510,9 @@ pub struct RewardsEstResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.QueryLockableDurationsRequest")]
 #[proto_query(
     path = "/osmosis.incentives.Query/LockableDurations",
@@ -514,9 +526,9 @@ pub struct QueryLockableDurationsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.QueryLockableDurationsResponse")]
 pub struct QueryLockableDurationsResponse {
     /// Time durations that users can lock coins for in order to recieve rewards
@@ -531,9 +543,9 @@ pub struct QueryLockableDurationsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.Params")]
 pub struct Params {
     /// distr_epoch_identifier is what epoch type distribution will be triggered by
@@ -550,9 +562,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.incentives.GenesisState")]
 pub struct GenesisState {
     /// params are all the parameters of the module
@@ -574,9 +586,321 @@ pub struct GenesisState {
     )]
     pub last_gauge_id: u64,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn module_to_distribute_coins(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModuleToDistributeCoinsRequest>,
+        ) -> Result<tonic::Response<super::ModuleToDistributeCoinsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.incentives.Query/ModuleToDistributeCoins",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn gauge_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GaugeByIdRequest>,
+        ) -> Result<tonic::Response<super::GaugeByIdResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/GaugeByID");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn gauges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GaugesRequest>,
+        ) -> Result<tonic::Response<super::GaugesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/Gauges");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn active_gauges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ActiveGaugesRequest>,
+        ) -> Result<tonic::Response<super::ActiveGaugesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/ActiveGauges");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn active_gauges_per_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ActiveGaugesPerDenomRequest>,
+        ) -> Result<tonic::Response<super::ActiveGaugesPerDenomResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.incentives.Query/ActiveGaugesPerDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn upcoming_gauges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpcomingGaugesRequest>,
+        ) -> Result<tonic::Response<super::UpcomingGaugesResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/UpcomingGauges");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn upcoming_gauges_per_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpcomingGaugesPerDenomRequest>,
+        ) -> Result<tonic::Response<super::UpcomingGaugesPerDenomResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.incentives.Query/UpcomingGaugesPerDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn rewards_est(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RewardsEstRequest>,
+        ) -> Result<tonic::Response<super::RewardsEstResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/RewardsEst");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn lockable_durations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryLockableDurationsRequest>,
+        ) -> Result<tonic::Response<super::QueryLockableDurationsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.incentives.Query/LockableDurations");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn create_gauge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgCreateGauge>,
+        ) -> Result<tonic::Response<super::MsgCreateGaugeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.incentives.Msg/CreateGauge");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn add_to_gauge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgAddToGauge>,
+        ) -> Result<tonic::Response<super::MsgAddToGaugeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.incentives.Msg/AddToGauge");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct IncentivesQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> IncentivesQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -640,3 +964,44 @@ impl<'a, Q: cosmwasm_std::CustomQuery> IncentivesQuerier<'a, Q> {
         QueryLockableDurationsRequest {}.query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct IncentivesMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl IncentivesMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn create_gauge(
+        &self,
+        is_perpetual: bool,
+        distribute_to: ::core::option::Option<super::lockup::QueryCondition>,
+        coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+        start_time: ::core::option::Option<crate::shim::Timestamp>,
+        num_epochs_paid_over: u64,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgCreateGauge {
+            owner: self.sender.to_string(),
+            is_perpetual,
+            distribute_to,
+            coins,
+            start_time,
+            num_epochs_paid_over,
+        }
+        .into()
+    }
+    pub fn add_to_gauge(
+        &self,
+        gauge_id: u64,
+        rewards: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgAddToGauge {
+            owner: self.sender.to_string(),
+            gauge_id,
+            rewards,
+        }
+        .into()
+    }
+}
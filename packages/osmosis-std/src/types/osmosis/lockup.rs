@@ -11,9 +11,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.PeriodLock")]
 pub struct PeriodLock {
     /// ID is the unique id of the lock.
@@ -52,17 +52,18 @@ pub struct PeriodLock {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.QueryCondition")]
 pub struct QueryCondition {
     /// LockQueryType is a type of lock query, ByLockDuration | ByLockTime
     #[prost(enumeration = "LockQueryType", tag = "1")]
     #[serde(
-        serialize_with = "crate::serde::as_str::serialize",
-        deserialize_with = "crate::serde::as_str::deserialize"
+        serialize_with = "crate::serde::enumeration::serialize::<LockQueryType, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<LockQueryType, _>"
     )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "LockQueryType"))]
     pub lock_query_type: i32,
     /// Denom represents the token denomination we are looking to lock up
     #[prost(string, tag = "2")]
@@ -71,11 +72,13 @@ pub struct QueryCondition {
     /// duration. Duration field must not be nil when the lock query type is
     /// `ByLockDuration`.
     #[prost(message, optional, tag = "3")]
+    #[amino(not_omitted)]
     pub duration: ::core::option::Option<crate::shim::Duration>,
     /// Timestamp is used by locks started before the specified duration.
     /// Timestamp field must not be nil when the lock query type is `ByLockTime`.
     /// Querying locks with timestamp is currently not implemented.
     #[prost(message, optional, tag = "4")]
+    #[amino(not_omitted)]
     pub timestamp: ::core::option::Option<crate::shim::Timestamp>,
 }
 /// SyntheticLock is creating virtual lockup where new denom is combination of
@@ -89,9 +92,9 @@ pub struct QueryCondition {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.SyntheticLock")]
 pub struct SyntheticLock {
     /// Underlying Lock ID is the underlying native lock's id for this synthetic
@@ -117,12 +120,52 @@ pub struct SyntheticLock {
 }
 /// LockQueryType defines the type of the lock query that can
 /// either be by duration or start time of the lock.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[repr(i32)]
 pub enum LockQueryType {
+    #[serde(rename = "ByDuration")]
     ByDuration = 0,
+    #[serde(rename = "ByTime")]
     ByTime = 1,
 }
+impl LockQueryType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LockQueryType::ByDuration => "ByDuration",
+            LockQueryType::ByTime => "ByTime",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ByDuration" => Some(Self::ByDuration),
+            "ByTime" => Some(Self::ByTime),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for LockQueryType {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        LockQueryType::from_i32(value)
+    }
+}
 #[derive(
     Clone,
     PartialEq,
@@ -130,16 +173,22 @@ pub enum LockQueryType {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.lockup.MsgLockTokens")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.lockup.MsgLockTokens",
+    amino_type = "osmosis/lockup/lock-tokens",
+    response_type = MsgLockTokensResponse
+)]
 pub struct MsgLockTokens {
     #[prost(string, tag = "1")]
     pub owner: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
+    #[amino(not_omitted)]
     pub duration: ::core::option::Option<crate::shim::Duration>,
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -149,9 +198,9 @@ pub struct MsgLockTokens {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.MsgLockTokensResponse")]
 pub struct MsgLockTokensResponse {
     #[prost(uint64, tag = "1")]
@@ -168,10 +217,14 @@ pub struct MsgLockTokensResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.lockup.MsgBeginUnlockingAll")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.lockup.MsgBeginUnlockingAll",
+    amino_type = "osmosis/lockup/begin-unlock-tokens",
+    response_type = MsgBeginUnlockingAllResponse
+)]
 pub struct MsgBeginUnlockingAll {
     #[prost(string, tag = "1")]
     pub owner: ::prost::alloc::string::String,
@@ -183,9 +236,9 @@ pub struct MsgBeginUnlockingAll {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.MsgBeginUnlockingAllResponse")]
 pub struct MsgBeginUnlockingAllResponse {
     #[prost(message, repeated, tag = "1")]
@@ -198,14 +251,19 @@ pub struct MsgBeginUnlockingAllResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.lockup.MsgBeginUnlocking")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.lockup.MsgBeginUnlocking",
+    amino_type = "osmosis/lockup/begin-unlock-period-lock",
+    response_type = MsgBeginUnlockingResponse
+)]
 pub struct MsgBeginUnlocking {
     #[prost(string, tag = "1")]
     pub owner: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "ID")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
@@ -213,6 +271,7 @@ pub struct MsgBeginUnlocking {
     pub id: u64,
     /// Amount of unlocking coins. Unlock all if not set.
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -222,9 +281,9 @@ pub struct MsgBeginUnlocking {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.MsgBeginUnlockingResponse")]
 pub struct MsgBeginUnlockingResponse {
     #[prost(bool, tag = "1")]
@@ -239,14 +298,19 @@ pub struct MsgBeginUnlockingResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.lockup.MsgExtendLockup")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.lockup.MsgExtendLockup",
+    amino_type = "osmosis/lockup/extend-lockup",
+    response_type = MsgExtendLockupResponse
+)]
 pub struct MsgExtendLockup {
     #[prost(string, tag = "1")]
     pub owner: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "ID")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
@@ -255,6 +319,7 @@ pub struct MsgExtendLockup {
     /// duration to be set. fails if lower than the current duration, or is
     /// unlocking
     #[prost(message, optional, tag = "3")]
+    #[amino(not_omitted)]
     pub duration: ::core::option::Option<crate::shim::Duration>,
 }
 #[derive(
@@ -264,9 +329,9 @@ pub struct MsgExtendLockup {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.MsgExtendLockupResponse")]
 pub struct MsgExtendLockupResponse {
     #[prost(bool, tag = "1")]
@@ -281,14 +346,19 @@ pub struct MsgExtendLockupResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.lockup.MsgForceUnlock")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.lockup.MsgForceUnlock",
+    amino_type = "osmosis/lockup/force-unlock-tokens",
+    response_type = MsgForceUnlockResponse
+)]
 pub struct MsgForceUnlock {
     #[prost(string, tag = "1")]
     pub owner: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[amino(name = "ID")]
     #[serde(
         serialize_with = "crate::serde::as_str::serialize",
         deserialize_with = "crate::serde::as_str::deserialize"
@@ -296,6 +366,7 @@ pub struct MsgForceUnlock {
     pub id: u64,
     /// Amount of unlocking coins. Unlock all if not set.
     #[prost(message, repeated, tag = "3")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -305,9 +376,9 @@ pub struct MsgForceUnlock {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.MsgForceUnlockResponse")]
 pub struct MsgForceUnlockResponse {
     #[prost(bool, tag = "1")]
@@ -320,9 +391,9 @@ pub struct MsgForceUnlockResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.Params")]
 pub struct Params {
     #[prost(string, repeated, tag = "1")]
@@ -335,9 +406,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.ModuleBalanceRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/ModuleBalance",
@@ -351,9 +422,9 @@ pub struct ModuleBalanceRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.ModuleBalanceResponse")]
 pub struct ModuleBalanceResponse {
     #[prost(message, repeated, tag = "1")]
@@ -366,9 +437,9 @@ pub struct ModuleBalanceResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.ModuleLockedAmountRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/ModuleLockedAmount",
@@ -382,9 +453,9 @@ pub struct ModuleLockedAmountRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.ModuleLockedAmountResponse")]
 pub struct ModuleLockedAmountResponse {
     #[prost(message, repeated, tag = "1")]
@@ -397,9 +468,9 @@ pub struct ModuleLockedAmountResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockableCoinsRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountUnlockableCoins",
@@ -416,9 +487,9 @@ pub struct AccountUnlockableCoinsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockableCoinsResponse")]
 pub struct AccountUnlockableCoinsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -431,9 +502,9 @@ pub struct AccountUnlockableCoinsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockingCoinsRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountUnlockingCoins",
@@ -450,9 +521,9 @@ pub struct AccountUnlockingCoinsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockingCoinsResponse")]
 pub struct AccountUnlockingCoinsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -465,9 +536,9 @@ pub struct AccountUnlockingCoinsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedCoinsRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedCoins",
@@ -484,9 +555,9 @@ pub struct AccountLockedCoinsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedCoinsResponse")]
 pub struct AccountLockedCoinsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -499,9 +570,9 @@ pub struct AccountLockedCoinsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedPastTime",
@@ -520,9 +591,9 @@ pub struct AccountLockedPastTimeRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeResponse")]
 pub struct AccountLockedPastTimeResponse {
     #[prost(message, repeated, tag = "1")]
@@ -535,9 +606,9 @@ pub struct AccountLockedPastTimeResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeNotUnlockingOnlyRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedPastTimeNotUnlockingOnly",
@@ -556,9 +627,9 @@ pub struct AccountLockedPastTimeNotUnlockingOnlyRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeNotUnlockingOnlyResponse")]
 pub struct AccountLockedPastTimeNotUnlockingOnlyResponse {
     #[prost(message, repeated, tag = "1")]
@@ -571,9 +642,9 @@ pub struct AccountLockedPastTimeNotUnlockingOnlyResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockedBeforeTimeRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountUnlockedBeforeTime",
@@ -592,9 +663,9 @@ pub struct AccountUnlockedBeforeTimeRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountUnlockedBeforeTimeResponse")]
 pub struct AccountUnlockedBeforeTimeResponse {
     #[prost(message, repeated, tag = "1")]
@@ -607,9 +678,9 @@ pub struct AccountUnlockedBeforeTimeResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeDenomRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedPastTimeDenom",
@@ -630,9 +701,9 @@ pub struct AccountLockedPastTimeDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedPastTimeDenomResponse")]
 pub struct AccountLockedPastTimeDenomResponse {
     #[prost(message, repeated, tag = "1")]
@@ -645,9 +716,9 @@ pub struct AccountLockedPastTimeDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.LockedDenomRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/LockedDenom",
@@ -666,13 +737,13 @@ pub struct LockedDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.LockedDenomResponse")]
 pub struct LockedDenomResponse {
-    #[prost(string, tag = "1")]
-    pub amount: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub amount: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -681,9 +752,9 @@ pub struct LockedDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.LockedRequest")]
 #[proto_query(path = "/osmosis.lockup.Query/LockedByID", response_type = LockedResponse)]
 pub struct LockedRequest {
@@ -701,9 +772,9 @@ pub struct LockedRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.LockedResponse")]
 pub struct LockedResponse {
     #[prost(message, optional, tag = "1")]
@@ -716,9 +787,9 @@ pub struct LockedResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.SyntheticLockupsByLockupIDRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/SyntheticLockupsByLockupID",
@@ -739,9 +810,9 @@ pub struct SyntheticLockupsByLockupIdRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.SyntheticLockupsByLockupIDResponse")]
 pub struct SyntheticLockupsByLockupIdResponse {
     #[prost(message, repeated, tag = "1")]
@@ -754,9 +825,9 @@ pub struct SyntheticLockupsByLockupIdResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedLongerDuration",
@@ -775,9 +846,9 @@ pub struct AccountLockedLongerDurationRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationResponse")]
 pub struct AccountLockedLongerDurationResponse {
     #[prost(message, repeated, tag = "1")]
@@ -790,9 +861,9 @@ pub struct AccountLockedLongerDurationResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedDurationRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedDuration",
@@ -811,9 +882,9 @@ pub struct AccountLockedDurationRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedDurationResponse")]
 pub struct AccountLockedDurationResponse {
     #[prost(message, repeated, tag = "1")]
@@ -826,9 +897,9 @@ pub struct AccountLockedDurationResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationNotUnlockingOnlyRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedLongerDurationNotUnlockingOnly",
@@ -847,9 +918,9 @@ pub struct AccountLockedLongerDurationNotUnlockingOnlyRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationNotUnlockingOnlyResponse")]
 pub struct AccountLockedLongerDurationNotUnlockingOnlyResponse {
     #[prost(message, repeated, tag = "1")]
@@ -862,9 +933,9 @@ pub struct AccountLockedLongerDurationNotUnlockingOnlyResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationDenomRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/AccountLockedLongerDurationDenom",
@@ -885,9 +956,9 @@ pub struct AccountLockedLongerDurationDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.AccountLockedLongerDurationDenomResponse")]
 pub struct AccountLockedLongerDurationDenomResponse {
     #[prost(message, repeated, tag = "1")]
@@ -900,9 +971,9 @@ pub struct AccountLockedLongerDurationDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.QueryParamsRequest")]
 #[proto_query(
     path = "/osmosis.lockup.Query/Params",
@@ -916,9 +987,9 @@ pub struct QueryParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.QueryParamsResponse")]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
@@ -932,9 +1003,9 @@ pub struct QueryParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.lockup.GenesisState")]
 pub struct GenesisState {
     #[prost(uint64, tag = "1")]
@@ -948,9 +1019,498 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "3")]
     pub synthetic_locks: ::prost::alloc::vec::Vec<SyntheticLock>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn module_balance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModuleBalanceRequest>,
+        ) -> Result<tonic::Response<super::ModuleBalanceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/ModuleBalance");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn module_locked_amount(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModuleLockedAmountRequest>,
+        ) -> Result<tonic::Response<super::ModuleLockedAmountResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/ModuleLockedAmount");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_unlockable_coins(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountUnlockableCoinsRequest>,
+        ) -> Result<tonic::Response<super::AccountUnlockableCoinsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountUnlockableCoins",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_unlocking_coins(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountUnlockingCoinsRequest>,
+        ) -> Result<tonic::Response<super::AccountUnlockingCoinsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/AccountUnlockingCoins");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_coins(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedCoinsRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedCoinsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/AccountLockedCoins");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_past_time(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedPastTimeRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedPastTimeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/AccountLockedPastTime");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_past_time_not_unlocking_only(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedPastTimeNotUnlockingOnlyRequest>,
+        ) -> Result<
+            tonic::Response<super::AccountLockedPastTimeNotUnlockingOnlyResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountLockedPastTimeNotUnlockingOnly",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_unlocked_before_time(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountUnlockedBeforeTimeRequest>,
+        ) -> Result<tonic::Response<super::AccountUnlockedBeforeTimeResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountUnlockedBeforeTime",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_past_time_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedPastTimeDenomRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedPastTimeDenomResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountLockedPastTimeDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn locked_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LockedDenomRequest>,
+        ) -> Result<tonic::Response<super::LockedDenomResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/LockedDenom");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn locked_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LockedRequest>,
+        ) -> Result<tonic::Response<super::LockedResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/LockedByID");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn synthetic_lockups_by_lockup_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SyntheticLockupsByLockupIdRequest>,
+        ) -> Result<tonic::Response<super::SyntheticLockupsByLockupIdResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/SyntheticLockupsByLockupID",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_longer_duration(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedLongerDurationRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedLongerDurationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountLockedLongerDuration",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_duration(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedDurationRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedDurationResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/AccountLockedDuration");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_longer_duration_not_unlocking_only(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedLongerDurationNotUnlockingOnlyRequest>,
+        ) -> Result<
+            tonic::Response<super::AccountLockedLongerDurationNotUnlockingOnlyResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountLockedLongerDurationNotUnlockingOnly",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn account_locked_longer_duration_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AccountLockedLongerDurationDenomRequest>,
+        ) -> Result<tonic::Response<super::AccountLockedLongerDurationDenomResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.lockup.Query/AccountLockedLongerDurationDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn lock_tokens(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgLockTokens>,
+        ) -> Result<tonic::Response<super::MsgLockTokensResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Msg/LockTokens");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn begin_unlocking_all(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgBeginUnlockingAll>,
+        ) -> Result<tonic::Response<super::MsgBeginUnlockingAllResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.lockup.Msg/BeginUnlockingAll");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn begin_unlocking(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgBeginUnlocking>,
+        ) -> Result<tonic::Response<super::MsgBeginUnlockingResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Msg/BeginUnlocking");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn extend_lockup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgExtendLockup>,
+        ) -> Result<tonic::Response<super::MsgExtendLockupResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Msg/ExtendLockup");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn force_unlock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgForceUnlock>,
+        ) -> Result<tonic::Response<super::MsgForceUnlockResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.lockup.Msg/ForceUnlock");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct LockupQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> LockupQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -1069,3 +1629,68 @@ impl<'a, Q: cosmwasm_std::CustomQuery> LockupQuerier<'a, Q> {
         QueryParamsRequest {}.query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct LockupMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl LockupMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn lock_tokens(
+        &self,
+        duration: ::core::option::Option<crate::shim::Duration>,
+        coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgLockTokens {
+            owner: self.sender.to_string(),
+            duration,
+            coins,
+        }
+        .into()
+    }
+    pub fn begin_unlocking_all(&self) -> cosmwasm_std::CosmosMsg {
+        MsgBeginUnlockingAll {
+            owner: self.sender.to_string(),
+        }
+        .into()
+    }
+    pub fn begin_unlocking(
+        &self,
+        id: u64,
+        coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgBeginUnlocking {
+            owner: self.sender.to_string(),
+            id,
+            coins,
+        }
+        .into()
+    }
+    pub fn extend_lockup(
+        &self,
+        id: u64,
+        duration: ::core::option::Option<crate::shim::Duration>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgExtendLockup {
+            owner: self.sender.to_string(),
+            id,
+            duration,
+        }
+        .into()
+    }
+    pub fn force_unlock(
+        &self,
+        id: u64,
+        coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgForceUnlock {
+            owner: self.sender.to_string(),
+            id,
+            coins,
+        }
+        .into()
+    }
+}
@@ -7,14 +7,14 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.Minter")]
 pub struct Minter {
     /// epoch_provisions represent rewards for the current epoch.
-    #[prost(string, tag = "1")]
-    pub epoch_provisions: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub epoch_provisions: crate::shim::Dec,
 }
 /// WeightedAddress represents an address with a weight assigned to it.
 /// The weight is used to determine the proportion of the total minted
@@ -26,15 +26,15 @@ pub struct Minter {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.WeightedAddress")]
 pub struct WeightedAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub weight: crate::shim::Dec,
 }
 /// DistributionProportions defines the distribution proportions of the minted
 /// denom. In other words, defines which stakeholders will receive the minted
@@ -46,27 +46,27 @@ pub struct WeightedAddress {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.DistributionProportions")]
 pub struct DistributionProportions {
     /// staking defines the proportion of the minted mint_denom that is to be
     /// allocated as staking rewards.
-    #[prost(string, tag = "1")]
-    pub staking: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub staking: crate::shim::Dec,
     /// pool_incentives defines the proportion of the minted mint_denom that is
     /// to be allocated as pool incentives.
-    #[prost(string, tag = "2")]
-    pub pool_incentives: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub pool_incentives: crate::shim::Dec,
     /// developer_rewards defines the proportion of the minted mint_denom that is
     /// to be allocated to developer rewards address.
-    #[prost(string, tag = "3")]
-    pub developer_rewards: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub developer_rewards: crate::shim::Dec,
     /// community_pool defines the proportion of the minted mint_denom that is
     /// to be allocated to the community pool.
-    #[prost(string, tag = "4")]
-    pub community_pool: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "4", default = "0")]
+    pub community_pool: crate::shim::Dec,
 }
 /// Params holds parameters for the x/mint module.
 #[derive(
@@ -76,17 +76,17 @@ pub struct DistributionProportions {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.Params")]
 pub struct Params {
     /// mint_denom is the denom of the coin to mint.
     #[prost(string, tag = "1")]
     pub mint_denom: ::prost::alloc::string::String,
     /// genesis_epoch_provisions epoch provisions from the first epoch.
-    #[prost(string, tag = "2")]
-    pub genesis_epoch_provisions: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub genesis_epoch_provisions: crate::shim::Dec,
     /// epoch_identifier mint epoch identifier e.g. (day, week).
     #[prost(string, tag = "3")]
     pub epoch_identifier: ::prost::alloc::string::String,
@@ -100,8 +100,8 @@ pub struct Params {
     pub reduction_period_in_epochs: i64,
     /// reduction_factor is the reduction multiplier to execute
     /// at the end of each period set by reduction_period_in_epochs.
-    #[prost(string, tag = "5")]
-    pub reduction_factor: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "5", default = "0")]
+    pub reduction_factor: crate::shim::Dec,
     /// distribution_proportions defines the distribution proportions of the minted
     /// denom. In other words, defines which stakeholders will receive the minted
     /// denoms and how much.
@@ -130,9 +130,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.QueryParamsRequest")]
 #[proto_query(
     path = "/osmosis.mint.v1beta1.Query/Params",
@@ -147,9 +147,9 @@ pub struct QueryParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.QueryParamsResponse")]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
@@ -165,9 +165,9 @@ pub struct QueryParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.QueryEpochProvisionsRequest")]
 #[proto_query(
     path = "/osmosis.mint.v1beta1.Query/EpochProvisions",
@@ -183,9 +183,9 @@ pub struct QueryEpochProvisionsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.QueryEpochProvisionsResponse")]
 pub struct QueryEpochProvisionsResponse {
     /// epoch_provisions is the current minting per epoch provisions value.
@@ -200,9 +200,9 @@ pub struct QueryEpochProvisionsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.mint.v1beta1.GenesisState")]
 pub struct GenesisState {
     /// minter is an abstraction for holding current rewards information.
@@ -220,9 +220,115 @@ pub struct GenesisState {
     )]
     pub reduction_started_epoch: i64,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.mint.v1beta1.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn epoch_provisions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryEpochProvisionsRequest>,
+        ) -> Result<tonic::Response<super::QueryEpochProvisionsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.mint.v1beta1.Query/EpochProvisions");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct MintQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> MintQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -6,9 +6,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.Params")]
 pub struct Params {
     /// minted_denom is the denomination of the coin expected to be minted by the
@@ -25,9 +25,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.LockableDurationsInfo")]
 pub struct LockableDurationsInfo {
     #[prost(message, repeated, tag = "1")]
@@ -40,13 +40,13 @@ pub struct LockableDurationsInfo {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.DistrInfo")]
 pub struct DistrInfo {
-    #[prost(string, tag = "1")]
-    pub total_weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub total_weight: crate::shim::Int,
     #[prost(message, repeated, tag = "2")]
     pub records: ::prost::alloc::vec::Vec<DistrRecord>,
 }
@@ -57,9 +57,9 @@ pub struct DistrInfo {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.DistrRecord")]
 pub struct DistrRecord {
     #[prost(uint64, tag = "1")]
@@ -68,8 +68,8 @@ pub struct DistrRecord {
         deserialize_with = "crate::serde::as_str::deserialize"
     )]
     pub gauge_id: u64,
-    #[prost(string, tag = "2")]
-    pub weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    pub weight: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -78,9 +78,9 @@ pub struct DistrRecord {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.PoolToGauge")]
 pub struct PoolToGauge {
     #[prost(uint64, tag = "1")]
@@ -105,9 +105,9 @@ pub struct PoolToGauge {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.PoolToGauges")]
 pub struct PoolToGauges {
     #[prost(message, repeated, tag = "2")]
@@ -127,9 +127,9 @@ pub struct PoolToGauges {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.ReplacePoolIncentivesProposal")]
 pub struct ReplacePoolIncentivesProposal {
     #[prost(string, tag = "1")]
@@ -153,9 +153,9 @@ pub struct ReplacePoolIncentivesProposal {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.UpdatePoolIncentivesProposal")]
 pub struct UpdatePoolIncentivesProposal {
     #[prost(string, tag = "1")]
@@ -172,9 +172,9 @@ pub struct UpdatePoolIncentivesProposal {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryGaugeIdsRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/GaugeIds",
@@ -195,9 +195,9 @@ pub struct QueryGaugeIdsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryGaugeIdsResponse")]
 pub struct QueryGaugeIdsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -214,9 +214,9 @@ pub mod query_gauge_ids_response {
         ::prost::Message,
         serde::Serialize,
         serde::Deserialize,
-        schemars::JsonSchema,
         CosmwasmExt,
     )]
+    #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
     #[proto_message(
         type_url = "/osmosis.poolincentives.v1beta1.QueryGaugeIdsResponse.GaugeIdWithDuration"
     )]
@@ -240,9 +240,9 @@ pub mod query_gauge_ids_response {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryDistrInfoRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/DistrInfo",
@@ -256,9 +256,9 @@ pub struct QueryDistrInfoRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryDistrInfoResponse")]
 pub struct QueryDistrInfoResponse {
     #[prost(message, optional, tag = "1")]
@@ -271,9 +271,9 @@ pub struct QueryDistrInfoResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryParamsRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/Params",
@@ -287,9 +287,9 @@ pub struct QueryParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryParamsResponse")]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
@@ -302,9 +302,9 @@ pub struct QueryParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryLockableDurationsRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/LockableDurations",
@@ -318,9 +318,9 @@ pub struct QueryLockableDurationsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryLockableDurationsResponse")]
 pub struct QueryLockableDurationsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -333,9 +333,9 @@ pub struct QueryLockableDurationsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryIncentivizedPoolsRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/IncentivizedPools",
@@ -349,9 +349,9 @@ pub struct QueryIncentivizedPoolsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.IncentivizedPool")]
 pub struct IncentivizedPool {
     #[prost(uint64, tag = "1")]
@@ -376,9 +376,9 @@ pub struct IncentivizedPool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryIncentivizedPoolsResponse")]
 pub struct QueryIncentivizedPoolsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -391,9 +391,9 @@ pub struct QueryIncentivizedPoolsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryExternalIncentiveGaugesRequest")]
 #[proto_query(
     path = "/osmosis.poolincentives.v1beta1.Query/ExternalIncentiveGauges",
@@ -407,9 +407,9 @@ pub struct QueryExternalIncentiveGaugesRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.QueryExternalIncentiveGaugesResponse")]
 pub struct QueryExternalIncentiveGaugesResponse {
     #[prost(message, repeated, tag = "1")]
@@ -423,9 +423,9 @@ pub struct QueryExternalIncentiveGaugesResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.poolincentives.v1beta1.GenesisState")]
 pub struct GenesisState {
     /// params defines all the paramaters of the module.
@@ -438,9 +438,183 @@ pub struct GenesisState {
     #[prost(message, optional, tag = "4")]
     pub pool_to_gauges: ::core::option::Option<PoolToGauges>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn gauge_ids(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryGaugeIdsRequest>,
+        ) -> Result<tonic::Response<super::QueryGaugeIdsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/GaugeIds",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn distr_info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDistrInfoRequest>,
+        ) -> Result<tonic::Response<super::QueryDistrInfoResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/DistrInfo",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/Params",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn lockable_durations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryLockableDurationsRequest>,
+        ) -> Result<tonic::Response<super::QueryLockableDurationsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/LockableDurations",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn incentivized_pools(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryIncentivizedPoolsRequest>,
+        ) -> Result<tonic::Response<super::QueryIncentivizedPoolsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/IncentivizedPools",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn external_incentive_gauges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryExternalIncentiveGaugesRequest>,
+        ) -> Result<tonic::Response<super::QueryExternalIncentiveGaugesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.poolincentives.v1beta1.Query/ExternalIncentiveGauges",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct PoolincentivesQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> PoolincentivesQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -6,9 +6,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.store.v1beta1.Node")]
 pub struct Node {
     #[prost(message, repeated, tag = "1")]
@@ -21,9 +21,9 @@ pub struct Node {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.store.v1beta1.Child")]
 pub struct Child {
     #[prost(bytes = "vec", tag = "1")]
@@ -38,9 +38,9 @@ pub struct Child {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.store.v1beta1.Leaf")]
 pub struct Leaf {
     #[prost(message, optional, tag = "1")]
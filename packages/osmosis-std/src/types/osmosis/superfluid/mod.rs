@@ -8,9 +8,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidAsset")]
 pub struct SuperfluidAsset {
     #[prost(string, tag = "1")]
@@ -19,9 +19,10 @@ pub struct SuperfluidAsset {
     /// share
     #[prost(enumeration = "SuperfluidAssetType", tag = "2")]
     #[serde(
-        serialize_with = "crate::serde::as_str::serialize",
-        deserialize_with = "crate::serde::as_str::deserialize"
+        serialize_with = "crate::serde::enumeration::serialize::<SuperfluidAssetType, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<SuperfluidAssetType, _>"
     )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "SuperfluidAssetType"))]
     pub asset_type: i32,
 }
 /// SuperfluidIntermediaryAccount takes the role of intermediary between LP token
@@ -34,9 +35,9 @@ pub struct SuperfluidAsset {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidIntermediaryAccount")]
 pub struct SuperfluidIntermediaryAccount {
     /// Denom indicates the denom of the superfluid asset.
@@ -66,9 +67,9 @@ pub struct SuperfluidIntermediaryAccount {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.OsmoEquivalentMultiplierRecord")]
 pub struct OsmoEquivalentMultiplierRecord {
     #[prost(int64, tag = "1")]
@@ -80,8 +81,8 @@ pub struct OsmoEquivalentMultiplierRecord {
     /// superfluid asset denom, can be LP token or native token
     #[prost(string, tag = "2")]
     pub denom: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub multiplier: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "3", default = "0")]
+    pub multiplier: crate::shim::Dec,
 }
 /// SuperfluidDelegationRecord is a struct used to indicate superfluid
 /// delegations of an account in the state machine in a user friendly form.
@@ -92,9 +93,9 @@ pub struct OsmoEquivalentMultiplierRecord {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationRecord")]
 pub struct SuperfluidDelegationRecord {
     #[prost(string, tag = "1")]
@@ -116,9 +117,9 @@ pub struct SuperfluidDelegationRecord {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.LockIdIntermediaryAccountConnection")]
 pub struct LockIdIntermediaryAccountConnection {
     #[prost(uint64, tag = "1")]
@@ -137,9 +138,9 @@ pub struct LockIdIntermediaryAccountConnection {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.UnpoolWhitelistedPools")]
 pub struct UnpoolWhitelistedPools {
     #[prost(uint64, repeated, tag = "1")]
@@ -147,13 +148,53 @@ pub struct UnpoolWhitelistedPools {
 }
 /// SuperfluidAssetType indicates whether the superfluid asset is
 /// a native token itself or the lp share of a pool.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[repr(i32)]
 pub enum SuperfluidAssetType {
+    #[serde(rename = "SuperfluidAssetTypeNative")]
     Native = 0,
     /// SuperfluidAssetTypeLendingShare = 2; // for now not exist
+    #[serde(rename = "SuperfluidAssetTypeLPShare")]
     LpShare = 1,
 }
+impl SuperfluidAssetType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SuperfluidAssetType::Native => "SuperfluidAssetTypeNative",
+            SuperfluidAssetType::LpShare => "SuperfluidAssetTypeLPShare",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SuperfluidAssetTypeNative" => Some(Self::Native),
+            "SuperfluidAssetTypeLPShare" => Some(Self::LpShare),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for SuperfluidAssetType {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        SuperfluidAssetType::from_i32(value)
+    }
+}
 #[derive(
     Clone,
     PartialEq,
@@ -161,10 +202,14 @@ pub enum SuperfluidAssetType {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidDelegate")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.superfluid.MsgSuperfluidDelegate",
+    amino_type = "osmosis/superfluid-delegate",
+    response_type = MsgSuperfluidDelegateResponse
+)]
 pub struct MsgSuperfluidDelegate {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
@@ -184,9 +229,9 @@ pub struct MsgSuperfluidDelegate {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidDelegateResponse")]
 pub struct MsgSuperfluidDelegateResponse {}
 #[derive(
@@ -196,10 +241,14 @@ pub struct MsgSuperfluidDelegateResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidUndelegate")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.superfluid.MsgSuperfluidUndelegate",
+    amino_type = "osmosis/superfluid-undelegate",
+    response_type = MsgSuperfluidUndelegateResponse
+)]
 pub struct MsgSuperfluidUndelegate {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
@@ -217,9 +266,9 @@ pub struct MsgSuperfluidUndelegate {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidUndelegateResponse")]
 pub struct MsgSuperfluidUndelegateResponse {}
 #[derive(
@@ -229,10 +278,14 @@ pub struct MsgSuperfluidUndelegateResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidUnbondLock")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.superfluid.MsgSuperfluidUnbondLock",
+    amino_type = "osmosis/superfluid-unbond-lock",
+    response_type = MsgSuperfluidUnbondLockResponse
+)]
 pub struct MsgSuperfluidUnbondLock {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
@@ -250,9 +303,9 @@ pub struct MsgSuperfluidUnbondLock {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.MsgSuperfluidUnbondLockResponse")]
 pub struct MsgSuperfluidUnbondLockResponse {}
 /// MsgLockAndSuperfluidDelegate locks coins with the unbonding period duration,
@@ -265,14 +318,19 @@ pub struct MsgSuperfluidUnbondLockResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.superfluid.MsgLockAndSuperfluidDelegate")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.superfluid.MsgLockAndSuperfluidDelegate",
+    amino_type = "osmosis/lock-and-superfluid-delegate",
+    response_type = MsgLockAndSuperfluidDelegateResponse
+)]
 pub struct MsgLockAndSuperfluidDelegate {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
     #[prost(string, tag = "3")]
     pub val_addr: ::prost::alloc::string::String,
@@ -284,9 +342,9 @@ pub struct MsgLockAndSuperfluidDelegate {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.MsgLockAndSuperfluidDelegateResponse")]
 pub struct MsgLockAndSuperfluidDelegateResponse {
     #[prost(uint64, tag = "1")]
@@ -311,10 +369,14 @@ pub struct MsgLockAndSuperfluidDelegateResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.superfluid.MsgUnPoolWhitelistedPool")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.superfluid.MsgUnPoolWhitelistedPool",
+    amino_type = "osmosis/unpool-whitelisted-pool",
+    response_type = MsgUnPoolWhitelistedPoolResponse
+)]
 pub struct MsgUnPoolWhitelistedPool {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
@@ -332,9 +394,9 @@ pub struct MsgUnPoolWhitelistedPool {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.MsgUnPoolWhitelistedPoolResponse")]
 pub struct MsgUnPoolWhitelistedPoolResponse {
     #[prost(uint64, repeated, tag = "1")]
@@ -348,17 +410,17 @@ pub struct MsgUnPoolWhitelistedPoolResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.Params")]
 pub struct Params {
     /// minimum_risk_factor is to be cut on OSMO equivalent value of lp tokens for
     /// superfluid staking, default: 5%. The minimum risk factor works
     /// to counter-balance the staked amount on chain's exposure to various asset
     /// volatilities, and have base staking be 'resistant' to volatility.
-    #[prost(string, tag = "1")]
-    pub minimum_risk_factor: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub minimum_risk_factor: crate::shim::Dec,
 }
 #[derive(
     Clone,
@@ -367,9 +429,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryParamsRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/Params",
@@ -383,9 +445,9 @@ pub struct QueryParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryParamsResponse")]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
@@ -399,9 +461,9 @@ pub struct QueryParamsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AssetTypeRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/AssetType",
@@ -418,16 +480,17 @@ pub struct AssetTypeRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AssetTypeResponse")]
 pub struct AssetTypeResponse {
     #[prost(enumeration = "SuperfluidAssetType", tag = "1")]
     #[serde(
-        serialize_with = "crate::serde::as_str::serialize",
-        deserialize_with = "crate::serde::as_str::deserialize"
+        serialize_with = "crate::serde::enumeration::serialize::<SuperfluidAssetType, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<SuperfluidAssetType, _>"
     )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "SuperfluidAssetType"))]
     pub asset_type: i32,
 }
 #[derive(
@@ -437,9 +500,9 @@ pub struct AssetTypeResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AllAssetsRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/AllAssets",
@@ -453,9 +516,9 @@ pub struct AllAssetsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AllAssetsResponse")]
 pub struct AllAssetsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -468,9 +531,9 @@ pub struct AllAssetsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AssetMultiplierRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/AssetMultiplier",
@@ -487,9 +550,9 @@ pub struct AssetMultiplierRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AssetMultiplierResponse")]
 pub struct AssetMultiplierResponse {
     #[prost(message, optional, tag = "1")]
@@ -502,9 +565,9 @@ pub struct AssetMultiplierResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidIntermediaryAccountInfo")]
 pub struct SuperfluidIntermediaryAccountInfo {
     #[prost(string, tag = "1")]
@@ -527,9 +590,9 @@ pub struct SuperfluidIntermediaryAccountInfo {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AllIntermediaryAccountsRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/AllIntermediaryAccounts",
@@ -546,9 +609,9 @@ pub struct AllIntermediaryAccountsRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.AllIntermediaryAccountsResponse")]
 pub struct AllIntermediaryAccountsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -564,9 +627,9 @@ pub struct AllIntermediaryAccountsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.ConnectedIntermediaryAccountRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/ConnectedIntermediaryAccount",
@@ -587,9 +650,9 @@ pub struct ConnectedIntermediaryAccountRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.ConnectedIntermediaryAccountResponse")]
 pub struct ConnectedIntermediaryAccountResponse {
     #[prost(message, optional, tag = "1")]
@@ -602,9 +665,9 @@ pub struct ConnectedIntermediaryAccountResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryTotalDelegationByValidatorForDenomRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/TotalDelegationByValidatorForDenom",
@@ -621,9 +684,9 @@ pub struct QueryTotalDelegationByValidatorForDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryTotalDelegationByValidatorForDenomResponse")]
 pub struct QueryTotalDelegationByValidatorForDenomResponse {
     #[prost(message, repeated, tag = "1")]
@@ -636,9 +699,9 @@ pub struct QueryTotalDelegationByValidatorForDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.Delegations")]
 pub struct Delegations {
     #[prost(string, tag = "1")]
@@ -655,9 +718,9 @@ pub struct Delegations {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.TotalSuperfluidDelegationsRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/TotalSuperfluidDelegations",
@@ -671,13 +734,13 @@ pub struct TotalSuperfluidDelegationsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.TotalSuperfluidDelegationsResponse")]
 pub struct TotalSuperfluidDelegationsResponse {
-    #[prost(string, tag = "1")]
-    pub total_delegations: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub total_delegations: crate::shim::Int,
 }
 #[derive(
     Clone,
@@ -686,9 +749,9 @@ pub struct TotalSuperfluidDelegationsResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationAmountRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/SuperfluidDelegationAmount",
@@ -709,9 +772,9 @@ pub struct SuperfluidDelegationAmountRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationAmountResponse")]
 pub struct SuperfluidDelegationAmountResponse {
     #[prost(message, repeated, tag = "1")]
@@ -724,9 +787,9 @@ pub struct SuperfluidDelegationAmountResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationsByDelegatorRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/SuperfluidDelegationsByDelegator",
@@ -743,9 +806,9 @@ pub struct SuperfluidDelegationsByDelegatorRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationsByDelegatorResponse")]
 pub struct SuperfluidDelegationsByDelegatorResponse {
     #[prost(message, repeated, tag = "1")]
@@ -763,9 +826,9 @@ pub struct SuperfluidDelegationsByDelegatorResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidUndelegationsByDelegatorRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/SuperfluidUndelegationsByDelegator",
@@ -784,9 +847,9 @@ pub struct SuperfluidUndelegationsByDelegatorRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidUndelegationsByDelegatorResponse")]
 pub struct SuperfluidUndelegationsByDelegatorResponse {
     #[prost(message, repeated, tag = "1")]
@@ -804,9 +867,9 @@ pub struct SuperfluidUndelegationsByDelegatorResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationsByValidatorDenomRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/SuperfluidDelegationsByValidatorDenom",
@@ -825,9 +888,9 @@ pub struct SuperfluidDelegationsByValidatorDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.SuperfluidDelegationsByValidatorDenomResponse")]
 pub struct SuperfluidDelegationsByValidatorDenomResponse {
     #[prost(message, repeated, tag = "1")]
@@ -840,9 +903,9 @@ pub struct SuperfluidDelegationsByValidatorDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(
     type_url = "/osmosis.superfluid.EstimateSuperfluidDelegatedAmountByValidatorDenomRequest"
 )]
@@ -863,9 +926,9 @@ pub struct EstimateSuperfluidDelegatedAmountByValidatorDenomRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(
     type_url = "/osmosis.superfluid.EstimateSuperfluidDelegatedAmountByValidatorDenomResponse"
 )]
@@ -880,9 +943,9 @@ pub struct EstimateSuperfluidDelegatedAmountByValidatorDenomResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryTotalDelegationByDelegatorRequest")]
 #[proto_query(
     path = "/osmosis.superfluid.Query/TotalDelegationByDelegator",
@@ -899,9 +962,9 @@ pub struct QueryTotalDelegationByDelegatorRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.QueryTotalDelegationByDelegatorResponse")]
 pub struct QueryTotalDelegationByDelegatorResponse {
     #[prost(message, repeated, tag = "1")]
@@ -923,9 +986,9 @@ pub struct QueryTotalDelegationByDelegatorResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.superfluid.GenesisState")]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
@@ -946,9 +1009,475 @@ pub struct GenesisState {
     pub intemediary_account_connections:
         ::prost::alloc::vec::Vec<LockIdIntermediaryAccountConnection>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryParamsRequest>,
+        ) -> Result<tonic::Response<super::QueryParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.superfluid.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn asset_type(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetTypeRequest>,
+        ) -> Result<tonic::Response<super::AssetTypeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.superfluid.Query/AssetType");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn all_assets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AllAssetsRequest>,
+        ) -> Result<tonic::Response<super::AllAssetsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.superfluid.Query/AllAssets");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn asset_multiplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetMultiplierRequest>,
+        ) -> Result<tonic::Response<super::AssetMultiplierResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.superfluid.Query/AssetMultiplier");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn all_intermediary_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AllIntermediaryAccountsRequest>,
+        ) -> Result<tonic::Response<super::AllIntermediaryAccountsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/AllIntermediaryAccounts",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn connected_intermediary_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConnectedIntermediaryAccountRequest>,
+        ) -> Result<tonic::Response<super::ConnectedIntermediaryAccountResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/ConnectedIntermediaryAccount",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_delegation_by_validator_for_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalDelegationByValidatorForDenomRequest>,
+        ) -> Result<
+            tonic::Response<super::QueryTotalDelegationByValidatorForDenomResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/TotalDelegationByValidatorForDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_superfluid_delegations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TotalSuperfluidDelegationsRequest>,
+        ) -> Result<tonic::Response<super::TotalSuperfluidDelegationsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/TotalSuperfluidDelegations",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_delegation_amount(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SuperfluidDelegationAmountRequest>,
+        ) -> Result<tonic::Response<super::SuperfluidDelegationAmountResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/SuperfluidDelegationAmount",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_delegations_by_delegator(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SuperfluidDelegationsByDelegatorRequest>,
+        ) -> Result<tonic::Response<super::SuperfluidDelegationsByDelegatorResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/SuperfluidDelegationsByDelegator",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_undelegations_by_delegator(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SuperfluidUndelegationsByDelegatorRequest>,
+        ) -> Result<tonic::Response<super::SuperfluidUndelegationsByDelegatorResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/SuperfluidUndelegationsByDelegator",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_delegations_by_validator_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SuperfluidDelegationsByValidatorDenomRequest>,
+        ) -> Result<
+            tonic::Response<super::SuperfluidDelegationsByValidatorDenomResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/SuperfluidDelegationsByValidatorDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn estimate_superfluid_delegated_amount_by_validator_denom(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::EstimateSuperfluidDelegatedAmountByValidatorDenomRequest,
+            >,
+        ) -> Result<
+            tonic::Response<super::EstimateSuperfluidDelegatedAmountByValidatorDenomResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/EstimateSuperfluidDelegatedAmountByValidatorDenom",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn total_delegation_by_delegator(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryTotalDelegationByDelegatorRequest>,
+        ) -> Result<tonic::Response<super::QueryTotalDelegationByDelegatorResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Query/TotalDelegationByDelegator",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn superfluid_delegate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSuperfluidDelegate>,
+        ) -> Result<tonic::Response<super::MsgSuperfluidDelegateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.superfluid.Msg/SuperfluidDelegate");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_undelegate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSuperfluidUndelegate>,
+        ) -> Result<tonic::Response<super::MsgSuperfluidUndelegateResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Msg/SuperfluidUndelegate",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn superfluid_unbond_lock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSuperfluidUnbondLock>,
+        ) -> Result<tonic::Response<super::MsgSuperfluidUnbondLockResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Msg/SuperfluidUnbondLock",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn lock_and_superfluid_delegate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgLockAndSuperfluidDelegate>,
+        ) -> Result<tonic::Response<super::MsgLockAndSuperfluidDelegateResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Msg/LockAndSuperfluidDelegate",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn un_pool_whitelisted_pool(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgUnPoolWhitelistedPool>,
+        ) -> Result<tonic::Response<super::MsgUnPoolWhitelistedPoolResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.superfluid.Msg/UnPoolWhitelistedPool",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct SuperfluidQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> SuperfluidQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -1054,3 +1583,59 @@ impl<'a, Q: cosmwasm_std::CustomQuery> SuperfluidQuerier<'a, Q> {
         QueryTotalDelegationByDelegatorRequest { delegator_address }.query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct SuperfluidMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl SuperfluidMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn superfluid_delegate(
+        &self,
+        lock_id: u64,
+        val_addr: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSuperfluidDelegate {
+            sender: self.sender.to_string(),
+            lock_id,
+            val_addr,
+        }
+        .into()
+    }
+    pub fn superfluid_undelegate(&self, lock_id: u64) -> cosmwasm_std::CosmosMsg {
+        MsgSuperfluidUndelegate {
+            sender: self.sender.to_string(),
+            lock_id,
+        }
+        .into()
+    }
+    pub fn superfluid_unbond_lock(&self, lock_id: u64) -> cosmwasm_std::CosmosMsg {
+        MsgSuperfluidUnbondLock {
+            sender: self.sender.to_string(),
+            lock_id,
+        }
+        .into()
+    }
+    pub fn lock_and_superfluid_delegate(
+        &self,
+        coins: ::prost::alloc::vec::Vec<super::super::cosmos::base::v1beta1::Coin>,
+        val_addr: ::prost::alloc::string::String,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgLockAndSuperfluidDelegate {
+            sender: self.sender.to_string(),
+            coins,
+            val_addr,
+        }
+        .into()
+    }
+    pub fn un_pool_whitelisted_pool(&self, pool_id: u64) -> cosmwasm_std::CosmosMsg {
+        MsgUnPoolWhitelistedPool {
+            sender: self.sender.to_string(),
+            pool_id,
+        }
+        .into()
+    }
+}
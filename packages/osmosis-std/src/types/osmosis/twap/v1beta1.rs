@@ -13,9 +13,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.TwapRecord")]
 pub struct TwapRecord {
     #[prost(uint64, tag = "1")]
@@ -43,17 +43,17 @@ pub struct TwapRecord {
     pub time: ::core::option::Option<crate::shim::Timestamp>,
     /// We store the last spot prices in the struct, so that we can interpolate
     /// accumulator values for times between when accumulator records are stored.
-    #[prost(string, tag = "6")]
-    pub p0_last_spot_price: ::prost::alloc::string::String,
-    #[prost(string, tag = "7")]
-    pub p1_last_spot_price: ::prost::alloc::string::String,
-    #[prost(string, tag = "8")]
-    pub p0_arithmetic_twap_accumulator: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "6", default = "0")]
+    pub p0_last_spot_price: crate::shim::Dec,
+    #[prost(string, required, tag = "7", default = "0")]
+    pub p1_last_spot_price: crate::shim::Dec,
+    #[prost(string, required, tag = "8", default = "0")]
+    pub p0_arithmetic_twap_accumulator: crate::shim::Dec,
     /// string geometric_twap_accumulator = 7 [(gogoproto.customtype) =
     /// "github.com/cosmos/cosmos-sdk/types.Dec",
     /// (gogoproto.nullable) = false];
-    #[prost(string, tag = "9")]
-    pub p1_arithmetic_twap_accumulator: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "9", default = "0")]
+    pub p1_arithmetic_twap_accumulator: crate::shim::Dec,
     /// This field contains the time in which the last spot price error occured.
     /// It is used to alert the caller if they are getting a potentially erroneous
     /// TWAP, due to an unforeseen underlying error.
@@ -68,9 +68,9 @@ pub struct TwapRecord {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.Params")]
 pub struct Params {
     #[prost(string, tag = "1")]
@@ -86,9 +86,9 @@ pub struct Params {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.GenesisState")]
 pub struct GenesisState {
     /// twaps is the collection of all twap records.
@@ -105,9 +105,9 @@ pub struct GenesisState {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ArithmeticTwapRequest")]
 #[proto_query(
     path = "/osmosis.twap.v1beta1.Query/ArithmeticTwap",
@@ -136,13 +136,13 @@ pub struct ArithmeticTwapRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ArithmeticTwapResponse")]
 pub struct ArithmeticTwapResponse {
-    #[prost(string, tag = "1")]
-    pub arithmetic_twap: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub arithmetic_twap: crate::shim::Dec,
 }
 #[derive(
     Clone,
@@ -151,9 +151,9 @@ pub struct ArithmeticTwapResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ArithmeticTwapToNowRequest")]
 #[proto_query(
     path = "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow",
@@ -180,13 +180,13 @@ pub struct ArithmeticTwapToNowRequest {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ArithmeticTwapToNowResponse")]
 pub struct ArithmeticTwapToNowResponse {
-    #[prost(string, tag = "1")]
-    pub arithmetic_twap: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "1", default = "0")]
+    pub arithmetic_twap: crate::shim::Dec,
 }
 #[derive(
     Clone,
@@ -195,9 +195,9 @@ pub struct ArithmeticTwapToNowResponse {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ParamsRequest")]
 #[proto_query(
     path = "/osmosis.twap.v1beta1.Query/Params",
@@ -211,17 +211,139 @@ pub struct ParamsRequest {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.twap.v1beta1.ParamsResponse")]
 pub struct ParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn params(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ParamsRequest>,
+        ) -> Result<tonic::Response<super::ParamsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/osmosis.twap.v1beta1.Query/Params");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn arithmetic_twap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ArithmeticTwapRequest>,
+        ) -> Result<tonic::Response<super::ArithmeticTwapResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/osmosis.twap.v1beta1.Query/ArithmeticTwap");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn arithmetic_twap_to_now(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ArithmeticTwapToNowRequest>,
+        ) -> Result<tonic::Response<super::ArithmeticTwapToNowResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct TwapQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> TwapQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -11,9 +11,9 @@ use osmosis_std_derive::CosmwasmExt;
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.ValidatorPreference")]
 pub struct ValidatorPreference {
     /// val_oper_address holds the validator address the user wants to delegate
@@ -21,8 +21,9 @@ pub struct ValidatorPreference {
     #[prost(string, tag = "1")]
     pub val_oper_address: ::prost::alloc::string::String,
     /// weight is decimal between 0 and 1, and they all sum to 1.
-    #[prost(string, tag = "2")]
-    pub weight: ::prost::alloc::string::String,
+    #[prost(string, required, tag = "2", default = "0")]
+    #[amino(not_omitted)]
+    pub weight: crate::shim::Dec,
 }
 /// ValidatorSetPreferences defines a delegator's validator set preference.
 /// It contains a list of (validator, percent_allocation) pairs.
@@ -35,9 +36,9 @@ pub struct ValidatorPreference {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.ValidatorSetPreferences")]
 pub struct ValidatorSetPreferences {
     /// preference holds {valAddr, weight} for the user who created it.
@@ -52,16 +53,21 @@ pub struct ValidatorSetPreferences {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgSetValidatorSetPreference")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.valsetpref.v1beta1.MsgSetValidatorSetPreference",
+    amino_type = "osmosis/valset-pref/MsgSetValidatorSetPreference",
+    response_type = MsgSetValidatorSetPreferenceResponse
+)]
 pub struct MsgSetValidatorSetPreference {
     /// delegator is the user who is trying to create a validator-set.
     #[prost(string, tag = "1")]
     pub delegator: ::prost::alloc::string::String,
     /// list of {valAddr, weight} to delegate to
     #[prost(message, repeated, tag = "2")]
+    #[amino(not_omitted)]
     pub preferences: ::prost::alloc::vec::Vec<ValidatorPreference>,
 }
 #[derive(
@@ -71,9 +77,9 @@ pub struct MsgSetValidatorSetPreference {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgSetValidatorSetPreferenceResponse")]
 pub struct MsgSetValidatorSetPreferenceResponse {}
 /// MsgDelegateToValidatorSet allows users to delegate to an existing
@@ -85,10 +91,14 @@ pub struct MsgSetValidatorSetPreferenceResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgDelegateToValidatorSet")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.valsetpref.v1beta1.MsgDelegateToValidatorSet",
+    amino_type = "osmosis/valset-pref/MsgDelegateToValidatorSet",
+    response_type = MsgDelegateToValidatorSetResponse
+)]
 pub struct MsgDelegateToValidatorSet {
     /// delegator is the user who is trying to delegate.
     #[prost(string, tag = "1")]
@@ -98,6 +108,7 @@ pub struct MsgDelegateToValidatorSet {
     /// -> 0.2} our staking logic would attempt to delegate 5osmo to A , 3osmo to
     /// B, 2osmo to C.
     #[prost(message, optional, tag = "2")]
+    #[amino(not_omitted)]
     pub coin: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -107,9 +118,9 @@ pub struct MsgDelegateToValidatorSet {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgDelegateToValidatorSetResponse")]
 pub struct MsgDelegateToValidatorSetResponse {}
 #[derive(
@@ -119,10 +130,14 @@ pub struct MsgDelegateToValidatorSetResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgUndelegateFromValidatorSet")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.valsetpref.v1beta1.MsgUndelegateFromValidatorSet",
+    amino_type = "osmosis/valset-pref/MsgUndelegateFromValidatorSet",
+    response_type = MsgUndelegateFromValidatorSetResponse
+)]
 pub struct MsgUndelegateFromValidatorSet {
     /// delegator is the user who is trying to undelegate.
     #[prost(string, tag = "1")]
@@ -133,6 +148,7 @@ pub struct MsgUndelegateFromValidatorSet {
     /// -> 0.2} our undelegate logic would attempt to undelegate 5osmo from A ,
     /// 3osmo from B, 2osmo from C
     #[prost(message, optional, tag = "3")]
+    #[amino(not_omitted)]
     pub coin: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
 }
 #[derive(
@@ -142,9 +158,9 @@ pub struct MsgUndelegateFromValidatorSet {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgUndelegateFromValidatorSetResponse")]
 pub struct MsgUndelegateFromValidatorSetResponse {}
 /// MsgWithdrawDelegationRewards allows user to claim staking rewards from the
@@ -156,10 +172,14 @@ pub struct MsgUndelegateFromValidatorSetResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
-#[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgWithdrawDelegationRewards")]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(
+    type_url = "/osmosis.valsetpref.v1beta1.MsgWithdrawDelegationRewards",
+    amino_type = "osmosis/valset-pref/MsgWithdrawDelegationRewards",
+    response_type = MsgWithdrawDelegationRewardsResponse
+)]
 pub struct MsgWithdrawDelegationRewards {
     /// delegator is the user who is trying to claim staking rewards.
     #[prost(string, tag = "1")]
@@ -172,9 +192,9 @@ pub struct MsgWithdrawDelegationRewards {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.MsgWithdrawDelegationRewardsResponse")]
 pub struct MsgWithdrawDelegationRewardsResponse {}
 /// Request type for UserValidatorPreferences.
@@ -185,9 +205,9 @@ pub struct MsgWithdrawDelegationRewardsResponse {}
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.QueryUserValidatorPreferences")]
 #[proto_query(
     path = "/osmosis.valsetpref.v1beta1.Query/UserValidatorPreferences",
@@ -206,17 +226,250 @@ pub struct QueryUserValidatorPreferences {
     ::prost::Message,
     serde::Serialize,
     serde::Deserialize,
-    schemars::JsonSchema,
     CosmwasmExt,
 )]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
 #[proto_message(type_url = "/osmosis.valsetpref.v1beta1.QueryUserValidatorPreferenceResponse")]
 pub struct QueryUserValidatorPreferenceResponse {
     #[prost(message, repeated, tag = "1")]
     pub preferences: ::prost::alloc::vec::Vec<ValidatorPreference>,
 }
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn user_validator_preferences(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryUserValidatorPreferences>,
+        ) -> Result<tonic::Response<super::QueryUserValidatorPreferenceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.valsetpref.v1beta1.Query/UserValidatorPreferences",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub mod msg_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct MsgClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    #[cfg(feature = "grpc-transport")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-transport")))]
+    impl MsgClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MsgClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> MsgClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            MsgClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn set_validator_set_preference(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgSetValidatorSetPreference>,
+        ) -> Result<tonic::Response<super::MsgSetValidatorSetPreferenceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.valsetpref.v1beta1.Msg/SetValidatorSetPreference",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delegate_to_validator_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgDelegateToValidatorSet>,
+        ) -> Result<tonic::Response<super::MsgDelegateToValidatorSetResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.valsetpref.v1beta1.Msg/DelegateToValidatorSet",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn undelegate_from_validator_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgUndelegateFromValidatorSet>,
+        ) -> Result<tonic::Response<super::MsgUndelegateFromValidatorSetResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.valsetpref.v1beta1.Msg/UndelegateFromValidatorSet",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn withdraw_delegation_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MsgWithdrawDelegationRewards>,
+        ) -> Result<tonic::Response<super::MsgWithdrawDelegationRewardsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.valsetpref.v1beta1.Msg/WithdrawDelegationRewards",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
 pub struct ValsetprefQuerier<'a, Q: cosmwasm_std::CustomQuery> {
     querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
 }
+#[cfg(feature = "cosmwasm")]
 impl<'a, Q: cosmwasm_std::CustomQuery> ValsetprefQuerier<'a, Q> {
     pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
         Self { querier }
@@ -228,3 +481,50 @@ impl<'a, Q: cosmwasm_std::CustomQuery> ValsetprefQuerier<'a, Q> {
         QueryUserValidatorPreferences { address }.query(self.querier)
     }
 }
+#[cfg(feature = "cosmwasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+pub struct ValsetprefMsgBuilder {
+    sender: cosmwasm_std::Addr,
+}
+#[cfg(feature = "cosmwasm")]
+impl ValsetprefMsgBuilder {
+    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+        Self { sender }
+    }
+    pub fn set_validator_set_preference(
+        &self,
+        preferences: ::prost::alloc::vec::Vec<ValidatorPreference>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgSetValidatorSetPreference {
+            delegator: self.sender.to_string(),
+            preferences,
+        }
+        .into()
+    }
+    pub fn delegate_to_validator_set(
+        &self,
+        coin: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgDelegateToValidatorSet {
+            delegator: self.sender.to_string(),
+            coin,
+        }
+        .into()
+    }
+    pub fn undelegate_from_validator_set(
+        &self,
+        coin: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+    ) -> cosmwasm_std::CosmosMsg {
+        MsgUndelegateFromValidatorSet {
+            delegator: self.sender.to_string(),
+            coin,
+        }
+        .into()
+    }
+    pub fn withdraw_delegation_rewards(&self) -> cosmwasm_std::CosmosMsg {
+        MsgWithdrawDelegationRewards {
+            delegator: self.sender.to_string(),
+        }
+        .into()
+    }
+}
@@ -0,0 +1,806 @@
+crate::registry::register_types! {
+    types: {
+        crate::types::cosmos::auth::v1beta1::BaseAccount,
+        crate::types::cosmos::auth::v1beta1::ModuleAccount,
+        crate::types::cosmos::auth::v1beta1::Params,
+        crate::types::cosmos::authz::v1beta1::EventGrant,
+        crate::types::cosmos::authz::v1beta1::EventRevoke,
+        crate::types::cosmos::authz::v1beta1::GenericAuthorization,
+        crate::types::cosmos::authz::v1beta1::GenesisState,
+        crate::types::cosmos::authz::v1beta1::Grant,
+        crate::types::cosmos::authz::v1beta1::GrantAuthorization,
+        crate::types::cosmos::authz::v1beta1::MsgExec,
+        crate::types::cosmos::authz::v1beta1::MsgExecResponse,
+        crate::types::cosmos::authz::v1beta1::MsgGrant,
+        crate::types::cosmos::authz::v1beta1::MsgGrantResponse,
+        crate::types::cosmos::authz::v1beta1::MsgRevoke,
+        crate::types::cosmos::authz::v1beta1::MsgRevokeResponse,
+        crate::types::cosmos::authz::v1beta1::QueryGranteeGrantsRequest,
+        crate::types::cosmos::authz::v1beta1::QueryGranteeGrantsResponse,
+        crate::types::cosmos::authz::v1beta1::QueryGranterGrantsRequest,
+        crate::types::cosmos::authz::v1beta1::QueryGranterGrantsResponse,
+        crate::types::cosmos::authz::v1beta1::QueryGrantsRequest,
+        crate::types::cosmos::authz::v1beta1::QueryGrantsResponse,
+        crate::types::cosmos::bank::v1beta1::Balance,
+        crate::types::cosmos::bank::v1beta1::DenomUnit,
+        crate::types::cosmos::bank::v1beta1::GenesisState,
+        crate::types::cosmos::bank::v1beta1::GenesisSupplyOffset,
+        crate::types::cosmos::bank::v1beta1::Input,
+        crate::types::cosmos::bank::v1beta1::Metadata,
+        crate::types::cosmos::bank::v1beta1::MsgMultiSend,
+        crate::types::cosmos::bank::v1beta1::MsgMultiSendResponse,
+        crate::types::cosmos::bank::v1beta1::MsgSend,
+        crate::types::cosmos::bank::v1beta1::MsgSendResponse,
+        crate::types::cosmos::bank::v1beta1::Output,
+        crate::types::cosmos::bank::v1beta1::Params,
+        crate::types::cosmos::bank::v1beta1::QueryAllBalancesRequest,
+        crate::types::cosmos::bank::v1beta1::QueryAllBalancesResponse,
+        crate::types::cosmos::bank::v1beta1::QueryBalanceRequest,
+        crate::types::cosmos::bank::v1beta1::QueryBalanceResponse,
+        crate::types::cosmos::bank::v1beta1::QueryBaseDenomRequest,
+        crate::types::cosmos::bank::v1beta1::QueryBaseDenomResponse,
+        crate::types::cosmos::bank::v1beta1::QueryDenomMetadataRequest,
+        crate::types::cosmos::bank::v1beta1::QueryDenomMetadataResponse,
+        crate::types::cosmos::bank::v1beta1::QueryDenomsMetadataRequest,
+        crate::types::cosmos::bank::v1beta1::QueryDenomsMetadataResponse,
+        crate::types::cosmos::bank::v1beta1::QueryParamsRequest,
+        crate::types::cosmos::bank::v1beta1::QueryParamsResponse,
+        crate::types::cosmos::bank::v1beta1::QuerySupplyOfRequest,
+        crate::types::cosmos::bank::v1beta1::QuerySupplyOfResponse,
+        crate::types::cosmos::bank::v1beta1::QuerySupplyOfWithoutOffsetRequest,
+        crate::types::cosmos::bank::v1beta1::QuerySupplyOfWithoutOffsetResponse,
+        crate::types::cosmos::bank::v1beta1::QueryTotalSupplyRequest,
+        crate::types::cosmos::bank::v1beta1::QueryTotalSupplyResponse,
+        crate::types::cosmos::bank::v1beta1::QueryTotalSupplyWithoutOffsetRequest,
+        crate::types::cosmos::bank::v1beta1::QueryTotalSupplyWithoutOffsetResponse,
+        crate::types::cosmos::bank::v1beta1::SendAuthorization,
+        crate::types::cosmos::bank::v1beta1::SendEnabled,
+        crate::types::cosmos::bank::v1beta1::Supply,
+        crate::types::cosmos::base::query::v1beta1::PageRequest,
+        crate::types::cosmos::base::query::v1beta1::PageResponse,
+        crate::types::cosmos::base::v1beta1::Coin,
+        crate::types::cosmos::base::v1beta1::DecCoin,
+        crate::types::cosmos::base::v1beta1::DecProto,
+        crate::types::cosmos::base::v1beta1::IntProto,
+        crate::types::cosmos::distribution::v1beta1::CommunityPoolSpendProposal,
+        crate::types::cosmos::distribution::v1beta1::CommunityPoolSpendProposalWithDeposit,
+        crate::types::cosmos::distribution::v1beta1::DelegationDelegatorReward,
+        crate::types::cosmos::distribution::v1beta1::DelegatorStartingInfo,
+        crate::types::cosmos::distribution::v1beta1::DelegatorStartingInfoRecord,
+        crate::types::cosmos::distribution::v1beta1::DelegatorWithdrawInfo,
+        crate::types::cosmos::distribution::v1beta1::FeePool,
+        crate::types::cosmos::distribution::v1beta1::GenesisState,
+        crate::types::cosmos::distribution::v1beta1::MsgFundCommunityPool,
+        crate::types::cosmos::distribution::v1beta1::MsgFundCommunityPoolResponse,
+        crate::types::cosmos::distribution::v1beta1::MsgSetWithdrawAddress,
+        crate::types::cosmos::distribution::v1beta1::MsgSetWithdrawAddressResponse,
+        crate::types::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward,
+        crate::types::cosmos::distribution::v1beta1::MsgWithdrawDelegatorRewardResponse,
+        crate::types::cosmos::distribution::v1beta1::MsgWithdrawValidatorCommission,
+        crate::types::cosmos::distribution::v1beta1::MsgWithdrawValidatorCommissionResponse,
+        crate::types::cosmos::distribution::v1beta1::Params,
+        crate::types::cosmos::distribution::v1beta1::QueryCommunityPoolRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryCommunityPoolResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegationRewardsRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegationRewardsResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegationTotalRewardsRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegationTotalRewardsResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegatorValidatorsRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegatorValidatorsResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegatorWithdrawAddressRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryDelegatorWithdrawAddressResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryParamsRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryParamsResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorCommissionRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorCommissionResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorOutstandingRewardsRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorOutstandingRewardsResponse,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorSlashesRequest,
+        crate::types::cosmos::distribution::v1beta1::QueryValidatorSlashesResponse,
+        crate::types::cosmos::distribution::v1beta1::ValidatorAccumulatedCommission,
+        crate::types::cosmos::distribution::v1beta1::ValidatorAccumulatedCommissionRecord,
+        crate::types::cosmos::distribution::v1beta1::ValidatorCurrentRewards,
+        crate::types::cosmos::distribution::v1beta1::ValidatorCurrentRewardsRecord,
+        crate::types::cosmos::distribution::v1beta1::ValidatorHistoricalRewards,
+        crate::types::cosmos::distribution::v1beta1::ValidatorHistoricalRewardsRecord,
+        crate::types::cosmos::distribution::v1beta1::ValidatorOutstandingRewards,
+        crate::types::cosmos::distribution::v1beta1::ValidatorOutstandingRewardsRecord,
+        crate::types::cosmos::distribution::v1beta1::ValidatorSlashEvent,
+        crate::types::cosmos::distribution::v1beta1::ValidatorSlashEventRecord,
+        crate::types::cosmos::distribution::v1beta1::ValidatorSlashEvents,
+        crate::types::cosmos::feegrant::v1beta1::AllowedMsgAllowance,
+        crate::types::cosmos::feegrant::v1beta1::BasicAllowance,
+        crate::types::cosmos::feegrant::v1beta1::GenesisState,
+        crate::types::cosmos::feegrant::v1beta1::Grant,
+        crate::types::cosmos::feegrant::v1beta1::MsgGrantAllowance,
+        crate::types::cosmos::feegrant::v1beta1::MsgGrantAllowanceResponse,
+        crate::types::cosmos::feegrant::v1beta1::MsgRevokeAllowance,
+        crate::types::cosmos::feegrant::v1beta1::MsgRevokeAllowanceResponse,
+        crate::types::cosmos::feegrant::v1beta1::PeriodicAllowance,
+        crate::types::cosmos::feegrant::v1beta1::QueryAllowanceRequest,
+        crate::types::cosmos::feegrant::v1beta1::QueryAllowanceResponse,
+        crate::types::cosmos::feegrant::v1beta1::QueryAllowancesRequest,
+        crate::types::cosmos::feegrant::v1beta1::QueryAllowancesResponse,
+        crate::types::cosmos::gov::v1::Deposit,
+        crate::types::cosmos::gov::v1::DepositParams,
+        crate::types::cosmos::gov::v1::GenesisState,
+        crate::types::cosmos::gov::v1::MsgDeposit,
+        crate::types::cosmos::gov::v1::MsgDepositResponse,
+        crate::types::cosmos::gov::v1::MsgExecLegacyContent,
+        crate::types::cosmos::gov::v1::MsgExecLegacyContentResponse,
+        crate::types::cosmos::gov::v1::MsgSubmitProposal,
+        crate::types::cosmos::gov::v1::MsgSubmitProposalResponse,
+        crate::types::cosmos::gov::v1::MsgVote,
+        crate::types::cosmos::gov::v1::MsgVoteResponse,
+        crate::types::cosmos::gov::v1::MsgVoteWeighted,
+        crate::types::cosmos::gov::v1::MsgVoteWeightedResponse,
+        crate::types::cosmos::gov::v1::Proposal,
+        crate::types::cosmos::gov::v1::QueryDepositRequest,
+        crate::types::cosmos::gov::v1::QueryDepositResponse,
+        crate::types::cosmos::gov::v1::QueryDepositsRequest,
+        crate::types::cosmos::gov::v1::QueryDepositsResponse,
+        crate::types::cosmos::gov::v1::QueryParamsRequest,
+        crate::types::cosmos::gov::v1::QueryParamsResponse,
+        crate::types::cosmos::gov::v1::QueryProposalRequest,
+        crate::types::cosmos::gov::v1::QueryProposalResponse,
+        crate::types::cosmos::gov::v1::QueryProposalsRequest,
+        crate::types::cosmos::gov::v1::QueryProposalsResponse,
+        crate::types::cosmos::gov::v1::QueryTallyResultRequest,
+        crate::types::cosmos::gov::v1::QueryTallyResultResponse,
+        crate::types::cosmos::gov::v1::QueryVoteRequest,
+        crate::types::cosmos::gov::v1::QueryVoteResponse,
+        crate::types::cosmos::gov::v1::QueryVotesRequest,
+        crate::types::cosmos::gov::v1::QueryVotesResponse,
+        crate::types::cosmos::gov::v1::TallyParams,
+        crate::types::cosmos::gov::v1::TallyResult,
+        crate::types::cosmos::gov::v1::Vote,
+        crate::types::cosmos::gov::v1::VotingParams,
+        crate::types::cosmos::gov::v1::WeightedVoteOption,
+        crate::types::cosmos::gov::v1beta1::Deposit,
+        crate::types::cosmos::gov::v1beta1::DepositParams,
+        crate::types::cosmos::gov::v1beta1::GenesisState,
+        crate::types::cosmos::gov::v1beta1::MsgDeposit,
+        crate::types::cosmos::gov::v1beta1::MsgDepositResponse,
+        crate::types::cosmos::gov::v1beta1::MsgSubmitProposal,
+        crate::types::cosmos::gov::v1beta1::MsgSubmitProposalResponse,
+        crate::types::cosmos::gov::v1beta1::MsgVote,
+        crate::types::cosmos::gov::v1beta1::MsgVoteResponse,
+        crate::types::cosmos::gov::v1beta1::MsgVoteWeighted,
+        crate::types::cosmos::gov::v1beta1::MsgVoteWeightedResponse,
+        crate::types::cosmos::gov::v1beta1::Proposal,
+        crate::types::cosmos::gov::v1beta1::QueryDepositRequest,
+        crate::types::cosmos::gov::v1beta1::QueryDepositResponse,
+        crate::types::cosmos::gov::v1beta1::QueryDepositsRequest,
+        crate::types::cosmos::gov::v1beta1::QueryDepositsResponse,
+        crate::types::cosmos::gov::v1beta1::QueryParamsRequest,
+        crate::types::cosmos::gov::v1beta1::QueryParamsResponse,
+        crate::types::cosmos::gov::v1beta1::QueryProposalRequest,
+        crate::types::cosmos::gov::v1beta1::QueryProposalResponse,
+        crate::types::cosmos::gov::v1beta1::QueryProposalsRequest,
+        crate::types::cosmos::gov::v1beta1::QueryProposalsResponse,
+        crate::types::cosmos::gov::v1beta1::QueryTallyResultRequest,
+        crate::types::cosmos::gov::v1beta1::QueryTallyResultResponse,
+        crate::types::cosmos::gov::v1beta1::QueryVoteRequest,
+        crate::types::cosmos::gov::v1beta1::QueryVoteResponse,
+        crate::types::cosmos::gov::v1beta1::QueryVotesRequest,
+        crate::types::cosmos::gov::v1beta1::QueryVotesResponse,
+        crate::types::cosmos::gov::v1beta1::TallyParams,
+        crate::types::cosmos::gov::v1beta1::TallyResult,
+        crate::types::cosmos::gov::v1beta1::TextProposal,
+        crate::types::cosmos::gov::v1beta1::Vote,
+        crate::types::cosmos::gov::v1beta1::VotingParams,
+        crate::types::cosmos::gov::v1beta1::WeightedVoteOption,
+        crate::types::cosmos::slashing::v1beta1::GenesisState,
+        crate::types::cosmos::slashing::v1beta1::MissedBlock,
+        crate::types::cosmos::slashing::v1beta1::MsgUnjail,
+        crate::types::cosmos::slashing::v1beta1::MsgUnjailResponse,
+        crate::types::cosmos::slashing::v1beta1::Params,
+        crate::types::cosmos::slashing::v1beta1::QueryParamsRequest,
+        crate::types::cosmos::slashing::v1beta1::QueryParamsResponse,
+        crate::types::cosmos::slashing::v1beta1::QuerySigningInfoRequest,
+        crate::types::cosmos::slashing::v1beta1::QuerySigningInfoResponse,
+        crate::types::cosmos::slashing::v1beta1::QuerySigningInfosRequest,
+        crate::types::cosmos::slashing::v1beta1::QuerySigningInfosResponse,
+        crate::types::cosmos::slashing::v1beta1::SigningInfo,
+        crate::types::cosmos::slashing::v1beta1::ValidatorMissedBlocks,
+        crate::types::cosmos::slashing::v1beta1::ValidatorSigningInfo,
+        crate::types::cosmos::staking::v1beta1::Commission,
+        crate::types::cosmos::staking::v1beta1::CommissionRates,
+        crate::types::cosmos::staking::v1beta1::Delegation,
+        crate::types::cosmos::staking::v1beta1::DelegationResponse,
+        crate::types::cosmos::staking::v1beta1::Description,
+        crate::types::cosmos::staking::v1beta1::DvPair,
+        crate::types::cosmos::staking::v1beta1::DvPairs,
+        crate::types::cosmos::staking::v1beta1::DvvTriplet,
+        crate::types::cosmos::staking::v1beta1::DvvTriplets,
+        crate::types::cosmos::staking::v1beta1::HistoricalInfo,
+        crate::types::cosmos::staking::v1beta1::Params,
+        crate::types::cosmos::staking::v1beta1::Pool,
+        crate::types::cosmos::staking::v1beta1::Redelegation,
+        crate::types::cosmos::staking::v1beta1::RedelegationEntry,
+        crate::types::cosmos::staking::v1beta1::RedelegationEntryResponse,
+        crate::types::cosmos::staking::v1beta1::RedelegationResponse,
+        crate::types::cosmos::staking::v1beta1::UnbondingDelegation,
+        crate::types::cosmos::staking::v1beta1::UnbondingDelegationEntry,
+        crate::types::cosmos::staking::v1beta1::ValAddresses,
+        crate::types::cosmos::staking::v1beta1::Validator,
+        crate::types::cosmos::upgrade::v1beta1::CancelSoftwareUpgradeProposal,
+        crate::types::cosmos::upgrade::v1beta1::ModuleVersion,
+        crate::types::cosmos::upgrade::v1beta1::Plan,
+        crate::types::cosmos::upgrade::v1beta1::QueryAppliedPlanRequest,
+        crate::types::cosmos::upgrade::v1beta1::QueryAppliedPlanResponse,
+        crate::types::cosmos::upgrade::v1beta1::QueryCurrentPlanRequest,
+        crate::types::cosmos::upgrade::v1beta1::QueryCurrentPlanResponse,
+        crate::types::cosmos::upgrade::v1beta1::QueryModuleVersionsRequest,
+        crate::types::cosmos::upgrade::v1beta1::QueryModuleVersionsResponse,
+        crate::types::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateRequest,
+        crate::types::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse,
+        crate::types::cosmos::upgrade::v1beta1::SoftwareUpgradeProposal,
+        crate::types::ibc::applications::transfer::v1::DenomTrace,
+        crate::types::ibc::applications::transfer::v1::GenesisState,
+        crate::types::ibc::applications::transfer::v1::MsgTransfer,
+        crate::types::ibc::applications::transfer::v1::MsgTransferResponse,
+        crate::types::ibc::applications::transfer::v1::Params,
+        crate::types::ibc::applications::transfer::v1::QueryDenomHashRequest,
+        crate::types::ibc::applications::transfer::v1::QueryDenomHashResponse,
+        crate::types::ibc::applications::transfer::v1::QueryDenomTraceRequest,
+        crate::types::ibc::applications::transfer::v1::QueryDenomTraceResponse,
+        crate::types::ibc::applications::transfer::v1::QueryDenomTracesRequest,
+        crate::types::ibc::applications::transfer::v1::QueryDenomTracesResponse,
+        crate::types::ibc::applications::transfer::v1::QueryParamsRequest,
+        crate::types::ibc::applications::transfer::v1::QueryParamsResponse,
+        crate::types::ibc::applications::transfer::v2::FungibleTokenPacketData,
+        crate::types::ibc::core::channel::v1::Acknowledgement,
+        crate::types::ibc::core::channel::v1::Channel,
+        crate::types::ibc::core::channel::v1::Counterparty,
+        crate::types::ibc::core::channel::v1::GenesisState,
+        crate::types::ibc::core::channel::v1::IdentifiedChannel,
+        crate::types::ibc::core::channel::v1::MsgAcknowledgement,
+        crate::types::ibc::core::channel::v1::MsgAcknowledgementResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelCloseConfirm,
+        crate::types::ibc::core::channel::v1::MsgChannelCloseConfirmResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelCloseInit,
+        crate::types::ibc::core::channel::v1::MsgChannelCloseInitResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenAck,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenAckResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenConfirm,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenConfirmResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenInit,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenInitResponse,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenTry,
+        crate::types::ibc::core::channel::v1::MsgChannelOpenTryResponse,
+        crate::types::ibc::core::channel::v1::MsgRecvPacket,
+        crate::types::ibc::core::channel::v1::MsgRecvPacketResponse,
+        crate::types::ibc::core::channel::v1::MsgTimeout,
+        crate::types::ibc::core::channel::v1::MsgTimeoutOnClose,
+        crate::types::ibc::core::channel::v1::MsgTimeoutOnCloseResponse,
+        crate::types::ibc::core::channel::v1::MsgTimeoutResponse,
+        crate::types::ibc::core::channel::v1::Packet,
+        crate::types::ibc::core::channel::v1::PacketSequence,
+        crate::types::ibc::core::channel::v1::PacketState,
+        crate::types::ibc::core::channel::v1::QueryChannelClientStateRequest,
+        crate::types::ibc::core::channel::v1::QueryChannelClientStateResponse,
+        crate::types::ibc::core::channel::v1::QueryChannelConsensusStateRequest,
+        crate::types::ibc::core::channel::v1::QueryChannelConsensusStateResponse,
+        crate::types::ibc::core::channel::v1::QueryChannelRequest,
+        crate::types::ibc::core::channel::v1::QueryChannelResponse,
+        crate::types::ibc::core::channel::v1::QueryChannelsRequest,
+        crate::types::ibc::core::channel::v1::QueryChannelsResponse,
+        crate::types::ibc::core::channel::v1::QueryConnectionChannelsRequest,
+        crate::types::ibc::core::channel::v1::QueryConnectionChannelsResponse,
+        crate::types::ibc::core::channel::v1::QueryNextSequenceReceiveRequest,
+        crate::types::ibc::core::channel::v1::QueryNextSequenceReceiveResponse,
+        crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementRequest,
+        crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementResponse,
+        crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementsRequest,
+        crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementsResponse,
+        crate::types::ibc::core::channel::v1::QueryPacketCommitmentRequest,
+        crate::types::ibc::core::channel::v1::QueryPacketCommitmentResponse,
+        crate::types::ibc::core::channel::v1::QueryPacketCommitmentsRequest,
+        crate::types::ibc::core::channel::v1::QueryPacketCommitmentsResponse,
+        crate::types::ibc::core::channel::v1::QueryPacketReceiptRequest,
+        crate::types::ibc::core::channel::v1::QueryPacketReceiptResponse,
+        crate::types::ibc::core::channel::v1::QueryUnreceivedAcksRequest,
+        crate::types::ibc::core::channel::v1::QueryUnreceivedAcksResponse,
+        crate::types::ibc::core::channel::v1::QueryUnreceivedPacketsRequest,
+        crate::types::ibc::core::channel::v1::QueryUnreceivedPacketsResponse,
+        crate::types::ibc::core::client::v1::ClientConsensusStates,
+        crate::types::ibc::core::client::v1::ClientUpdateProposal,
+        crate::types::ibc::core::client::v1::ConsensusStateWithHeight,
+        crate::types::ibc::core::client::v1::GenesisMetadata,
+        crate::types::ibc::core::client::v1::GenesisState,
+        crate::types::ibc::core::client::v1::Height,
+        crate::types::ibc::core::client::v1::IdentifiedClientState,
+        crate::types::ibc::core::client::v1::IdentifiedGenesisMetadata,
+        crate::types::ibc::core::client::v1::MsgCreateClient,
+        crate::types::ibc::core::client::v1::MsgCreateClientResponse,
+        crate::types::ibc::core::client::v1::MsgSubmitMisbehaviour,
+        crate::types::ibc::core::client::v1::MsgSubmitMisbehaviourResponse,
+        crate::types::ibc::core::client::v1::MsgUpdateClient,
+        crate::types::ibc::core::client::v1::MsgUpdateClientResponse,
+        crate::types::ibc::core::client::v1::MsgUpgradeClient,
+        crate::types::ibc::core::client::v1::MsgUpgradeClientResponse,
+        crate::types::ibc::core::client::v1::Params,
+        crate::types::ibc::core::client::v1::QueryClientParamsRequest,
+        crate::types::ibc::core::client::v1::QueryClientParamsResponse,
+        crate::types::ibc::core::client::v1::QueryClientStateRequest,
+        crate::types::ibc::core::client::v1::QueryClientStateResponse,
+        crate::types::ibc::core::client::v1::QueryClientStatesRequest,
+        crate::types::ibc::core::client::v1::QueryClientStatesResponse,
+        crate::types::ibc::core::client::v1::QueryClientStatusRequest,
+        crate::types::ibc::core::client::v1::QueryClientStatusResponse,
+        crate::types::ibc::core::client::v1::QueryConsensusStateRequest,
+        crate::types::ibc::core::client::v1::QueryConsensusStateResponse,
+        crate::types::ibc::core::client::v1::QueryConsensusStatesRequest,
+        crate::types::ibc::core::client::v1::QueryConsensusStatesResponse,
+        crate::types::ibc::core::client::v1::QueryUpgradedClientStateRequest,
+        crate::types::ibc::core::client::v1::QueryUpgradedClientStateResponse,
+        crate::types::ibc::core::client::v1::QueryUpgradedConsensusStateRequest,
+        crate::types::ibc::core::client::v1::QueryUpgradedConsensusStateResponse,
+        crate::types::ibc::core::client::v1::UpgradeProposal,
+        crate::types::osmosis::epochs::v1beta1::EpochInfo,
+        crate::types::osmosis::epochs::v1beta1::GenesisState,
+        crate::types::osmosis::epochs::v1beta1::QueryCurrentEpochRequest,
+        crate::types::osmosis::epochs::v1beta1::QueryCurrentEpochResponse,
+        crate::types::osmosis::epochs::v1beta1::QueryEpochsInfoRequest,
+        crate::types::osmosis::epochs::v1beta1::QueryEpochsInfoResponse,
+        crate::types::osmosis::gamm::poolmodels::balancer::v1beta1::MsgCreateBalancerPool,
+        crate::types::osmosis::gamm::poolmodels::balancer::v1beta1::MsgCreateBalancerPoolResponse,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::MsgCreateStableswapPool,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::MsgCreateStableswapPoolResponse,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::MsgStableSwapAdjustScalingFactors,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::MsgStableSwapAdjustScalingFactorsResponse,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::Pool,
+        crate::types::osmosis::gamm::poolmodels::stableswap::v1beta1::PoolParams,
+        crate::types::osmosis::gamm::v1beta1::GenesisState,
+        crate::types::osmosis::gamm::v1beta1::MsgExitPool,
+        crate::types::osmosis::gamm::v1beta1::MsgExitPoolResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgExitSwapExternAmountOut,
+        crate::types::osmosis::gamm::v1beta1::MsgExitSwapExternAmountOutResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgExitSwapShareAmountIn,
+        crate::types::osmosis::gamm::v1beta1::MsgExitSwapShareAmountInResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinPool,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinPoolResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinSwapExternAmountIn,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinSwapExternAmountInResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinSwapShareAmountOut,
+        crate::types::osmosis::gamm::v1beta1::MsgJoinSwapShareAmountOutResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgSwapExactAmountIn,
+        crate::types::osmosis::gamm::v1beta1::MsgSwapExactAmountInResponse,
+        crate::types::osmosis::gamm::v1beta1::MsgSwapExactAmountOut,
+        crate::types::osmosis::gamm::v1beta1::MsgSwapExactAmountOutResponse,
+        crate::types::osmosis::gamm::v1beta1::Params,
+        crate::types::osmosis::gamm::v1beta1::Pool,
+        crate::types::osmosis::gamm::v1beta1::PoolAsset,
+        crate::types::osmosis::gamm::v1beta1::PoolParams,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcExitPoolCoinsFromSharesRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcExitPoolCoinsFromSharesResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolNoSwapSharesRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolNoSwapSharesResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolSharesRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolSharesResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryNumPoolsRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryNumPoolsResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolParamsRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolParamsResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolTypeRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolTypeResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolsRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolsResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolsWithFilterRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryPoolsWithFilterResponse,
+        crate::types::osmosis::gamm::v1beta1::QuerySpotPriceRequest,
+        crate::types::osmosis::gamm::v1beta1::QuerySpotPriceResponse,
+        crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountInRequest,
+        crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountInResponse,
+        crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountOutRequest,
+        crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountOutResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalLiquidityRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalLiquidityResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalPoolLiquidityRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalPoolLiquidityResponse,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalSharesRequest,
+        crate::types::osmosis::gamm::v1beta1::QueryTotalSharesResponse,
+        crate::types::osmosis::gamm::v1beta1::SmoothWeightChangeParams,
+        crate::types::osmosis::gamm::v1beta1::SwapAmountInRoute,
+        crate::types::osmosis::gamm::v1beta1::SwapAmountOutRoute,
+        crate::types::osmosis::gamm::v2::QuerySpotPriceRequest,
+        crate::types::osmosis::gamm::v2::QuerySpotPriceResponse,
+        crate::types::osmosis::ibcratelimit::v1beta1::Params,
+        crate::types::osmosis::incentives::ActiveGaugesPerDenomRequest,
+        crate::types::osmosis::incentives::ActiveGaugesPerDenomResponse,
+        crate::types::osmosis::incentives::ActiveGaugesRequest,
+        crate::types::osmosis::incentives::ActiveGaugesResponse,
+        crate::types::osmosis::incentives::Gauge,
+        crate::types::osmosis::incentives::GaugeByIdRequest,
+        crate::types::osmosis::incentives::GaugeByIdResponse,
+        crate::types::osmosis::incentives::GaugesRequest,
+        crate::types::osmosis::incentives::GaugesResponse,
+        crate::types::osmosis::incentives::GenesisState,
+        crate::types::osmosis::incentives::LockableDurationsInfo,
+        crate::types::osmosis::incentives::ModuleToDistributeCoinsRequest,
+        crate::types::osmosis::incentives::ModuleToDistributeCoinsResponse,
+        crate::types::osmosis::incentives::MsgAddToGauge,
+        crate::types::osmosis::incentives::MsgAddToGaugeResponse,
+        crate::types::osmosis::incentives::MsgCreateGauge,
+        crate::types::osmosis::incentives::MsgCreateGaugeResponse,
+        crate::types::osmosis::incentives::Params,
+        crate::types::osmosis::incentives::QueryLockableDurationsRequest,
+        crate::types::osmosis::incentives::QueryLockableDurationsResponse,
+        crate::types::osmosis::incentives::RewardsEstRequest,
+        crate::types::osmosis::incentives::RewardsEstResponse,
+        crate::types::osmosis::incentives::UpcomingGaugesPerDenomRequest,
+        crate::types::osmosis::incentives::UpcomingGaugesPerDenomResponse,
+        crate::types::osmosis::incentives::UpcomingGaugesRequest,
+        crate::types::osmosis::incentives::UpcomingGaugesResponse,
+        crate::types::osmosis::lockup::AccountLockedCoinsRequest,
+        crate::types::osmosis::lockup::AccountLockedCoinsResponse,
+        crate::types::osmosis::lockup::AccountLockedDurationRequest,
+        crate::types::osmosis::lockup::AccountLockedDurationResponse,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationDenomRequest,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationDenomResponse,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationNotUnlockingOnlyRequest,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationNotUnlockingOnlyResponse,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationRequest,
+        crate::types::osmosis::lockup::AccountLockedLongerDurationResponse,
+        crate::types::osmosis::lockup::AccountLockedPastTimeDenomRequest,
+        crate::types::osmosis::lockup::AccountLockedPastTimeDenomResponse,
+        crate::types::osmosis::lockup::AccountLockedPastTimeNotUnlockingOnlyRequest,
+        crate::types::osmosis::lockup::AccountLockedPastTimeNotUnlockingOnlyResponse,
+        crate::types::osmosis::lockup::AccountLockedPastTimeRequest,
+        crate::types::osmosis::lockup::AccountLockedPastTimeResponse,
+        crate::types::osmosis::lockup::AccountUnlockableCoinsRequest,
+        crate::types::osmosis::lockup::AccountUnlockableCoinsResponse,
+        crate::types::osmosis::lockup::AccountUnlockedBeforeTimeRequest,
+        crate::types::osmosis::lockup::AccountUnlockedBeforeTimeResponse,
+        crate::types::osmosis::lockup::AccountUnlockingCoinsRequest,
+        crate::types::osmosis::lockup::AccountUnlockingCoinsResponse,
+        crate::types::osmosis::lockup::GenesisState,
+        crate::types::osmosis::lockup::LockedDenomRequest,
+        crate::types::osmosis::lockup::LockedDenomResponse,
+        crate::types::osmosis::lockup::LockedRequest,
+        crate::types::osmosis::lockup::LockedResponse,
+        crate::types::osmosis::lockup::ModuleBalanceRequest,
+        crate::types::osmosis::lockup::ModuleBalanceResponse,
+        crate::types::osmosis::lockup::ModuleLockedAmountRequest,
+        crate::types::osmosis::lockup::ModuleLockedAmountResponse,
+        crate::types::osmosis::lockup::MsgBeginUnlocking,
+        crate::types::osmosis::lockup::MsgBeginUnlockingAll,
+        crate::types::osmosis::lockup::MsgBeginUnlockingAllResponse,
+        crate::types::osmosis::lockup::MsgBeginUnlockingResponse,
+        crate::types::osmosis::lockup::MsgExtendLockup,
+        crate::types::osmosis::lockup::MsgExtendLockupResponse,
+        crate::types::osmosis::lockup::MsgForceUnlock,
+        crate::types::osmosis::lockup::MsgForceUnlockResponse,
+        crate::types::osmosis::lockup::MsgLockTokens,
+        crate::types::osmosis::lockup::MsgLockTokensResponse,
+        crate::types::osmosis::lockup::Params,
+        crate::types::osmosis::lockup::PeriodLock,
+        crate::types::osmosis::lockup::QueryCondition,
+        crate::types::osmosis::lockup::QueryParamsRequest,
+        crate::types::osmosis::lockup::QueryParamsResponse,
+        crate::types::osmosis::lockup::SyntheticLock,
+        crate::types::osmosis::lockup::SyntheticLockupsByLockupIdRequest,
+        crate::types::osmosis::lockup::SyntheticLockupsByLockupIdResponse,
+        crate::types::osmosis::mint::v1beta1::DistributionProportions,
+        crate::types::osmosis::mint::v1beta1::GenesisState,
+        crate::types::osmosis::mint::v1beta1::Minter,
+        crate::types::osmosis::mint::v1beta1::Params,
+        crate::types::osmosis::mint::v1beta1::QueryEpochProvisionsRequest,
+        crate::types::osmosis::mint::v1beta1::QueryEpochProvisionsResponse,
+        crate::types::osmosis::mint::v1beta1::QueryParamsRequest,
+        crate::types::osmosis::mint::v1beta1::QueryParamsResponse,
+        crate::types::osmosis::mint::v1beta1::WeightedAddress,
+        crate::types::osmosis::poolincentives::v1beta1::DistrInfo,
+        crate::types::osmosis::poolincentives::v1beta1::DistrRecord,
+        crate::types::osmosis::poolincentives::v1beta1::GenesisState,
+        crate::types::osmosis::poolincentives::v1beta1::IncentivizedPool,
+        crate::types::osmosis::poolincentives::v1beta1::LockableDurationsInfo,
+        crate::types::osmosis::poolincentives::v1beta1::Params,
+        crate::types::osmosis::poolincentives::v1beta1::PoolToGauge,
+        crate::types::osmosis::poolincentives::v1beta1::PoolToGauges,
+        crate::types::osmosis::poolincentives::v1beta1::QueryDistrInfoRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryDistrInfoResponse,
+        crate::types::osmosis::poolincentives::v1beta1::QueryExternalIncentiveGaugesRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryExternalIncentiveGaugesResponse,
+        crate::types::osmosis::poolincentives::v1beta1::QueryGaugeIdsRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryGaugeIdsResponse,
+        crate::types::osmosis::poolincentives::v1beta1::QueryIncentivizedPoolsRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryIncentivizedPoolsResponse,
+        crate::types::osmosis::poolincentives::v1beta1::QueryLockableDurationsRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryLockableDurationsResponse,
+        crate::types::osmosis::poolincentives::v1beta1::QueryParamsRequest,
+        crate::types::osmosis::poolincentives::v1beta1::QueryParamsResponse,
+        crate::types::osmosis::poolincentives::v1beta1::ReplacePoolIncentivesProposal,
+        crate::types::osmosis::poolincentives::v1beta1::UpdatePoolIncentivesProposal,
+        crate::types::osmosis::poolincentives::v1beta1::query_gauge_ids_response::GaugeIdWithDuration,
+        crate::types::osmosis::store::v1beta1::Child,
+        crate::types::osmosis::store::v1beta1::Leaf,
+        crate::types::osmosis::store::v1beta1::Node,
+        crate::types::osmosis::superfluid::AllAssetsRequest,
+        crate::types::osmosis::superfluid::AllAssetsResponse,
+        crate::types::osmosis::superfluid::AllIntermediaryAccountsRequest,
+        crate::types::osmosis::superfluid::AllIntermediaryAccountsResponse,
+        crate::types::osmosis::superfluid::AssetMultiplierRequest,
+        crate::types::osmosis::superfluid::AssetMultiplierResponse,
+        crate::types::osmosis::superfluid::AssetTypeRequest,
+        crate::types::osmosis::superfluid::AssetTypeResponse,
+        crate::types::osmosis::superfluid::ConnectedIntermediaryAccountRequest,
+        crate::types::osmosis::superfluid::ConnectedIntermediaryAccountResponse,
+        crate::types::osmosis::superfluid::Delegations,
+        crate::types::osmosis::superfluid::EstimateSuperfluidDelegatedAmountByValidatorDenomRequest,
+        crate::types::osmosis::superfluid::EstimateSuperfluidDelegatedAmountByValidatorDenomResponse,
+        crate::types::osmosis::superfluid::GenesisState,
+        crate::types::osmosis::superfluid::LockIdIntermediaryAccountConnection,
+        crate::types::osmosis::superfluid::MsgLockAndSuperfluidDelegate,
+        crate::types::osmosis::superfluid::MsgLockAndSuperfluidDelegateResponse,
+        crate::types::osmosis::superfluid::MsgSuperfluidDelegate,
+        crate::types::osmosis::superfluid::MsgSuperfluidDelegateResponse,
+        crate::types::osmosis::superfluid::MsgSuperfluidUnbondLock,
+        crate::types::osmosis::superfluid::MsgSuperfluidUnbondLockResponse,
+        crate::types::osmosis::superfluid::MsgSuperfluidUndelegate,
+        crate::types::osmosis::superfluid::MsgSuperfluidUndelegateResponse,
+        crate::types::osmosis::superfluid::MsgUnPoolWhitelistedPool,
+        crate::types::osmosis::superfluid::MsgUnPoolWhitelistedPoolResponse,
+        crate::types::osmosis::superfluid::OsmoEquivalentMultiplierRecord,
+        crate::types::osmosis::superfluid::Params,
+        crate::types::osmosis::superfluid::QueryParamsRequest,
+        crate::types::osmosis::superfluid::QueryParamsResponse,
+        crate::types::osmosis::superfluid::QueryTotalDelegationByDelegatorRequest,
+        crate::types::osmosis::superfluid::QueryTotalDelegationByDelegatorResponse,
+        crate::types::osmosis::superfluid::QueryTotalDelegationByValidatorForDenomRequest,
+        crate::types::osmosis::superfluid::QueryTotalDelegationByValidatorForDenomResponse,
+        crate::types::osmosis::superfluid::SuperfluidAsset,
+        crate::types::osmosis::superfluid::SuperfluidDelegationAmountRequest,
+        crate::types::osmosis::superfluid::SuperfluidDelegationAmountResponse,
+        crate::types::osmosis::superfluid::SuperfluidDelegationRecord,
+        crate::types::osmosis::superfluid::SuperfluidDelegationsByDelegatorRequest,
+        crate::types::osmosis::superfluid::SuperfluidDelegationsByDelegatorResponse,
+        crate::types::osmosis::superfluid::SuperfluidDelegationsByValidatorDenomRequest,
+        crate::types::osmosis::superfluid::SuperfluidDelegationsByValidatorDenomResponse,
+        crate::types::osmosis::superfluid::SuperfluidIntermediaryAccount,
+        crate::types::osmosis::superfluid::SuperfluidIntermediaryAccountInfo,
+        crate::types::osmosis::superfluid::SuperfluidUndelegationsByDelegatorRequest,
+        crate::types::osmosis::superfluid::SuperfluidUndelegationsByDelegatorResponse,
+        crate::types::osmosis::superfluid::TotalSuperfluidDelegationsRequest,
+        crate::types::osmosis::superfluid::TotalSuperfluidDelegationsResponse,
+        crate::types::osmosis::superfluid::UnpoolWhitelistedPools,
+        crate::types::osmosis::superfluid::v1beta1::RemoveSuperfluidAssetsProposal,
+        crate::types::osmosis::superfluid::v1beta1::SetSuperfluidAssetsProposal,
+        crate::types::osmosis::tokenfactory::v1beta1::DenomAuthorityMetadata,
+        crate::types::osmosis::tokenfactory::v1beta1::GenesisDenom,
+        crate::types::osmosis::tokenfactory::v1beta1::GenesisState,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgBurn,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgBurnResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgChangeAdmin,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgChangeAdminResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgCreateDenom,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgCreateDenomResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgMint,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgMintResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgSetDenomMetadata,
+        crate::types::osmosis::tokenfactory::v1beta1::MsgSetDenomMetadataResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::Params,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryDenomAuthorityMetadataRequest,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryDenomAuthorityMetadataResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryDenomsFromCreatorRequest,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryDenomsFromCreatorResponse,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryParamsRequest,
+        crate::types::osmosis::tokenfactory::v1beta1::QueryParamsResponse,
+        crate::types::osmosis::twap::v1beta1::ArithmeticTwapRequest,
+        crate::types::osmosis::twap::v1beta1::ArithmeticTwapResponse,
+        crate::types::osmosis::twap::v1beta1::ArithmeticTwapToNowRequest,
+        crate::types::osmosis::twap::v1beta1::ArithmeticTwapToNowResponse,
+        crate::types::osmosis::twap::v1beta1::GenesisState,
+        crate::types::osmosis::twap::v1beta1::Params,
+        crate::types::osmosis::twap::v1beta1::ParamsRequest,
+        crate::types::osmosis::twap::v1beta1::ParamsResponse,
+        crate::types::osmosis::twap::v1beta1::TwapRecord,
+        crate::types::osmosis::twap::v2::ArithmeticTwapRequest,
+        crate::types::osmosis::twap::v2::ArithmeticTwapResponse,
+        crate::types::osmosis::twap::v2::ArithmeticTwapToNowRequest,
+        crate::types::osmosis::twap::v2::ArithmeticTwapToNowResponse,
+        crate::types::osmosis::txfees::v1beta1::FeeToken,
+        crate::types::osmosis::txfees::v1beta1::GenesisState,
+        crate::types::osmosis::txfees::v1beta1::QueryBaseDenomRequest,
+        crate::types::osmosis::txfees::v1beta1::QueryBaseDenomResponse,
+        crate::types::osmosis::txfees::v1beta1::QueryDenomPoolIdRequest,
+        crate::types::osmosis::txfees::v1beta1::QueryDenomPoolIdResponse,
+        crate::types::osmosis::txfees::v1beta1::QueryDenomSpotPriceRequest,
+        crate::types::osmosis::txfees::v1beta1::QueryDenomSpotPriceResponse,
+        crate::types::osmosis::txfees::v1beta1::QueryFeeTokensRequest,
+        crate::types::osmosis::txfees::v1beta1::QueryFeeTokensResponse,
+        crate::types::osmosis::txfees::v1beta1::UpdateFeeTokenProposal,
+        crate::types::osmosis::valsetpref::v1beta1::MsgDelegateToValidatorSet,
+        crate::types::osmosis::valsetpref::v1beta1::MsgDelegateToValidatorSetResponse,
+        crate::types::osmosis::valsetpref::v1beta1::MsgSetValidatorSetPreference,
+        crate::types::osmosis::valsetpref::v1beta1::MsgSetValidatorSetPreferenceResponse,
+        crate::types::osmosis::valsetpref::v1beta1::MsgUndelegateFromValidatorSet,
+        crate::types::osmosis::valsetpref::v1beta1::MsgUndelegateFromValidatorSetResponse,
+        crate::types::osmosis::valsetpref::v1beta1::MsgWithdrawDelegationRewards,
+        crate::types::osmosis::valsetpref::v1beta1::MsgWithdrawDelegationRewardsResponse,
+        crate::types::osmosis::valsetpref::v1beta1::QueryUserValidatorPreferenceResponse,
+        crate::types::osmosis::valsetpref::v1beta1::QueryUserValidatorPreferences,
+        crate::types::osmosis::valsetpref::v1beta1::ValidatorPreference,
+        crate::types::osmosis::valsetpref::v1beta1::ValidatorSetPreferences,
+        crate::types::tendermint::crypto::DominoOp,
+        crate::types::tendermint::crypto::Proof,
+        crate::types::tendermint::crypto::ProofOp,
+        crate::types::tendermint::crypto::ProofOps,
+        crate::types::tendermint::crypto::PublicKey,
+        crate::types::tendermint::crypto::ValueOp,
+        crate::types::tendermint::types::BlockId,
+        crate::types::tendermint::types::BlockMeta,
+        crate::types::tendermint::types::Commit,
+        crate::types::tendermint::types::CommitSig,
+        crate::types::tendermint::types::Data,
+        crate::types::tendermint::types::Header,
+        crate::types::tendermint::types::LightBlock,
+        crate::types::tendermint::types::Part,
+        crate::types::tendermint::types::PartSetHeader,
+        crate::types::tendermint::types::Proposal,
+        crate::types::tendermint::types::SignedHeader,
+        crate::types::tendermint::types::SimpleValidator,
+        crate::types::tendermint::types::TxProof,
+        crate::types::tendermint::types::Validator,
+        crate::types::tendermint::types::ValidatorSet,
+        crate::types::tendermint::types::Vote,
+        crate::types::tendermint::version::App,
+        crate::types::tendermint::version::Consensus,
+    }
+    queries: {
+        "/cosmos.authz.v1beta1.Query/GranteeGrants" => crate::types::cosmos::authz::v1beta1::QueryGranteeGrantsResponse,
+        "/cosmos.authz.v1beta1.Query/GranterGrants" => crate::types::cosmos::authz::v1beta1::QueryGranterGrantsResponse,
+        "/cosmos.authz.v1beta1.Query/Grants" => crate::types::cosmos::authz::v1beta1::QueryGrantsResponse,
+        "/cosmos.bank.v1beta1.Query/AllBalances" => crate::types::cosmos::bank::v1beta1::QueryAllBalancesResponse,
+        "/cosmos.bank.v1beta1.Query/Balance" => crate::types::cosmos::bank::v1beta1::QueryBalanceResponse,
+        "/cosmos.bank.v1beta1.Query/BaseDenom" => crate::types::cosmos::bank::v1beta1::QueryBaseDenomResponse,
+        "/cosmos.bank.v1beta1.Query/DenomMetadata" => crate::types::cosmos::bank::v1beta1::QueryDenomMetadataResponse,
+        "/cosmos.bank.v1beta1.Query/DenomsMetadata" => crate::types::cosmos::bank::v1beta1::QueryDenomsMetadataResponse,
+        "/cosmos.bank.v1beta1.Query/Params" => crate::types::cosmos::bank::v1beta1::QueryParamsResponse,
+        "/cosmos.bank.v1beta1.Query/SupplyOf" => crate::types::cosmos::bank::v1beta1::QuerySupplyOfResponse,
+        "/cosmos.bank.v1beta1.Query/SupplyOfWithoutOffset" => crate::types::cosmos::bank::v1beta1::QuerySupplyOfWithoutOffsetResponse,
+        "/cosmos.bank.v1beta1.Query/TotalSupply" => crate::types::cosmos::bank::v1beta1::QueryTotalSupplyResponse,
+        "/cosmos.bank.v1beta1.Query/TotalSupplyWithoutOffset" => crate::types::cosmos::bank::v1beta1::QueryTotalSupplyWithoutOffsetResponse,
+        "/cosmos.distribution.v1beta1.Query/CommunityPool" => crate::types::cosmos::distribution::v1beta1::QueryCommunityPoolResponse,
+        "/cosmos.distribution.v1beta1.Query/DelegationRewards" => crate::types::cosmos::distribution::v1beta1::QueryDelegationRewardsResponse,
+        "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards" => crate::types::cosmos::distribution::v1beta1::QueryDelegationTotalRewardsResponse,
+        "/cosmos.distribution.v1beta1.Query/DelegatorValidators" => crate::types::cosmos::distribution::v1beta1::QueryDelegatorValidatorsResponse,
+        "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => crate::types::cosmos::distribution::v1beta1::QueryDelegatorWithdrawAddressResponse,
+        "/cosmos.distribution.v1beta1.Query/Params" => crate::types::cosmos::distribution::v1beta1::QueryParamsResponse,
+        "/cosmos.distribution.v1beta1.Query/ValidatorCommission" => crate::types::cosmos::distribution::v1beta1::QueryValidatorCommissionResponse,
+        "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards" => crate::types::cosmos::distribution::v1beta1::QueryValidatorOutstandingRewardsResponse,
+        "/cosmos.distribution.v1beta1.Query/ValidatorSlashes" => crate::types::cosmos::distribution::v1beta1::QueryValidatorSlashesResponse,
+        "/cosmos.feegrant.v1beta1.Query/Allowance" => crate::types::cosmos::feegrant::v1beta1::QueryAllowanceResponse,
+        "/cosmos.feegrant.v1beta1.Query/Allowances" => crate::types::cosmos::feegrant::v1beta1::QueryAllowancesResponse,
+        "/cosmos.gov.v1.Query/Deposit" => crate::types::cosmos::gov::v1::QueryDepositResponse,
+        "/cosmos.gov.v1.Query/Deposits" => crate::types::cosmos::gov::v1::QueryDepositsResponse,
+        "/cosmos.gov.v1.Query/Params" => crate::types::cosmos::gov::v1::QueryParamsResponse,
+        "/cosmos.gov.v1.Query/Proposal" => crate::types::cosmos::gov::v1::QueryProposalResponse,
+        "/cosmos.gov.v1.Query/Proposals" => crate::types::cosmos::gov::v1::QueryProposalsResponse,
+        "/cosmos.gov.v1.Query/TallyResult" => crate::types::cosmos::gov::v1::QueryTallyResultResponse,
+        "/cosmos.gov.v1.Query/Vote" => crate::types::cosmos::gov::v1::QueryVoteResponse,
+        "/cosmos.gov.v1.Query/Votes" => crate::types::cosmos::gov::v1::QueryVotesResponse,
+        "/cosmos.gov.v1beta1.Query/Deposit" => crate::types::cosmos::gov::v1beta1::QueryDepositResponse,
+        "/cosmos.gov.v1beta1.Query/Deposits" => crate::types::cosmos::gov::v1beta1::QueryDepositsResponse,
+        "/cosmos.gov.v1beta1.Query/Params" => crate::types::cosmos::gov::v1beta1::QueryParamsResponse,
+        "/cosmos.gov.v1beta1.Query/Proposal" => crate::types::cosmos::gov::v1beta1::QueryProposalResponse,
+        "/cosmos.gov.v1beta1.Query/Proposals" => crate::types::cosmos::gov::v1beta1::QueryProposalsResponse,
+        "/cosmos.gov.v1beta1.Query/TallyResult" => crate::types::cosmos::gov::v1beta1::QueryTallyResultResponse,
+        "/cosmos.gov.v1beta1.Query/Vote" => crate::types::cosmos::gov::v1beta1::QueryVoteResponse,
+        "/cosmos.gov.v1beta1.Query/Votes" => crate::types::cosmos::gov::v1beta1::QueryVotesResponse,
+        "/cosmos.slashing.v1beta1.Query/Params" => crate::types::cosmos::slashing::v1beta1::QueryParamsResponse,
+        "/cosmos.slashing.v1beta1.Query/SigningInfo" => crate::types::cosmos::slashing::v1beta1::QuerySigningInfoResponse,
+        "/cosmos.slashing.v1beta1.Query/SigningInfos" => crate::types::cosmos::slashing::v1beta1::QuerySigningInfosResponse,
+        "/cosmos.upgrade.v1beta1.Query/AppliedPlan" => crate::types::cosmos::upgrade::v1beta1::QueryAppliedPlanResponse,
+        "/cosmos.upgrade.v1beta1.Query/CurrentPlan" => crate::types::cosmos::upgrade::v1beta1::QueryCurrentPlanResponse,
+        "/cosmos.upgrade.v1beta1.Query/ModuleVersions" => crate::types::cosmos::upgrade::v1beta1::QueryModuleVersionsResponse,
+        "/cosmos.upgrade.v1beta1.Query/UpgradedConsensusState" => crate::types::cosmos::upgrade::v1beta1::QueryUpgradedConsensusStateResponse,
+        "/ibc.applications.transfer.v1.Query/DenomHash" => crate::types::ibc::applications::transfer::v1::QueryDenomHashResponse,
+        "/ibc.applications.transfer.v1.Query/DenomTrace" => crate::types::ibc::applications::transfer::v1::QueryDenomTraceResponse,
+        "/ibc.applications.transfer.v1.Query/DenomTraces" => crate::types::ibc::applications::transfer::v1::QueryDenomTracesResponse,
+        "/ibc.applications.transfer.v1.Query/Params" => crate::types::ibc::applications::transfer::v1::QueryParamsResponse,
+        "/ibc.core.channel.v1.Query/Channel" => crate::types::ibc::core::channel::v1::QueryChannelResponse,
+        "/ibc.core.channel.v1.Query/ChannelClientState" => crate::types::ibc::core::channel::v1::QueryChannelClientStateResponse,
+        "/ibc.core.channel.v1.Query/ChannelConsensusState" => crate::types::ibc::core::channel::v1::QueryChannelConsensusStateResponse,
+        "/ibc.core.channel.v1.Query/Channels" => crate::types::ibc::core::channel::v1::QueryChannelsResponse,
+        "/ibc.core.channel.v1.Query/ConnectionChannels" => crate::types::ibc::core::channel::v1::QueryConnectionChannelsResponse,
+        "/ibc.core.channel.v1.Query/NextSequenceReceive" => crate::types::ibc::core::channel::v1::QueryNextSequenceReceiveResponse,
+        "/ibc.core.channel.v1.Query/PacketAcknowledgement" => crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementResponse,
+        "/ibc.core.channel.v1.Query/PacketAcknowledgements" => crate::types::ibc::core::channel::v1::QueryPacketAcknowledgementsResponse,
+        "/ibc.core.channel.v1.Query/PacketCommitment" => crate::types::ibc::core::channel::v1::QueryPacketCommitmentResponse,
+        "/ibc.core.channel.v1.Query/PacketCommitments" => crate::types::ibc::core::channel::v1::QueryPacketCommitmentsResponse,
+        "/ibc.core.channel.v1.Query/PacketReceipt" => crate::types::ibc::core::channel::v1::QueryPacketReceiptResponse,
+        "/ibc.core.channel.v1.Query/UnreceivedAcks" => crate::types::ibc::core::channel::v1::QueryUnreceivedAcksResponse,
+        "/ibc.core.channel.v1.Query/UnreceivedPackets" => crate::types::ibc::core::channel::v1::QueryUnreceivedPacketsResponse,
+        "/ibc.core.client.v1.Query/ClientParams" => crate::types::ibc::core::client::v1::QueryClientParamsResponse,
+        "/ibc.core.client.v1.Query/ClientState" => crate::types::ibc::core::client::v1::QueryClientStateResponse,
+        "/ibc.core.client.v1.Query/ClientStates" => crate::types::ibc::core::client::v1::QueryClientStatesResponse,
+        "/ibc.core.client.v1.Query/ClientStatus" => crate::types::ibc::core::client::v1::QueryClientStatusResponse,
+        "/ibc.core.client.v1.Query/ConsensusState" => crate::types::ibc::core::client::v1::QueryConsensusStateResponse,
+        "/ibc.core.client.v1.Query/ConsensusStates" => crate::types::ibc::core::client::v1::QueryConsensusStatesResponse,
+        "/ibc.core.client.v1.Query/UpgradedClientState" => crate::types::ibc::core::client::v1::QueryUpgradedClientStateResponse,
+        "/ibc.core.client.v1.Query/UpgradedConsensusState" => crate::types::ibc::core::client::v1::QueryUpgradedConsensusStateResponse,
+        "/osmosis.epochs.v1beta1.Query/CurrentEpoch" => crate::types::osmosis::epochs::v1beta1::QueryCurrentEpochResponse,
+        "/osmosis.epochs.v1beta1.Query/EpochInfos" => crate::types::osmosis::epochs::v1beta1::QueryEpochsInfoResponse,
+        "/osmosis.gamm.v1beta1.Query/CalcExitPoolCoinsFromShares" => crate::types::osmosis::gamm::v1beta1::QueryCalcExitPoolCoinsFromSharesResponse,
+        "/osmosis.gamm.v1beta1.Query/CalcJoinPoolNoSwapShares" => crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolNoSwapSharesResponse,
+        "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares" => crate::types::osmosis::gamm::v1beta1::QueryCalcJoinPoolSharesResponse,
+        "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountIn" => crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountInResponse,
+        "/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountOut" => crate::types::osmosis::gamm::v1beta1::QuerySwapExactAmountOutResponse,
+        "/osmosis.gamm.v1beta1.Query/NumPools" => crate::types::osmosis::gamm::v1beta1::QueryNumPoolsResponse,
+        "/osmosis.gamm.v1beta1.Query/Pool" => crate::types::osmosis::gamm::v1beta1::QueryPoolResponse,
+        "/osmosis.gamm.v1beta1.Query/PoolParams" => crate::types::osmosis::gamm::v1beta1::QueryPoolParamsResponse,
+        "/osmosis.gamm.v1beta1.Query/PoolType" => crate::types::osmosis::gamm::v1beta1::QueryPoolTypeResponse,
+        "/osmosis.gamm.v1beta1.Query/Pools" => crate::types::osmosis::gamm::v1beta1::QueryPoolsResponse,
+        "/osmosis.gamm.v1beta1.Query/PoolsWithFilter" => crate::types::osmosis::gamm::v1beta1::QueryPoolsWithFilterResponse,
+        "/osmosis.gamm.v1beta1.Query/SpotPrice" => crate::types::osmosis::gamm::v1beta1::QuerySpotPriceResponse,
+        "/osmosis.gamm.v1beta1.Query/TotalLiquidity" => crate::types::osmosis::gamm::v1beta1::QueryTotalLiquidityResponse,
+        "/osmosis.gamm.v1beta1.Query/TotalPoolLiquidity" => crate::types::osmosis::gamm::v1beta1::QueryTotalPoolLiquidityResponse,
+        "/osmosis.gamm.v1beta1.Query/TotalShares" => crate::types::osmosis::gamm::v1beta1::QueryTotalSharesResponse,
+        "/osmosis.gamm.v2.Query/SpotPrice" => crate::types::osmosis::gamm::v2::QuerySpotPriceResponse,
+        "/osmosis.incentives.Query/ActiveGauges" => crate::types::osmosis::incentives::ActiveGaugesResponse,
+        "/osmosis.incentives.Query/ActiveGaugesPerDenom" => crate::types::osmosis::incentives::ActiveGaugesPerDenomResponse,
+        "/osmosis.incentives.Query/GaugeByID" => crate::types::osmosis::incentives::GaugeByIdResponse,
+        "/osmosis.incentives.Query/Gauges" => crate::types::osmosis::incentives::GaugesResponse,
+        "/osmosis.incentives.Query/LockableDurations" => crate::types::osmosis::incentives::QueryLockableDurationsResponse,
+        "/osmosis.incentives.Query/ModuleToDistributeCoins" => crate::types::osmosis::incentives::ModuleToDistributeCoinsResponse,
+        "/osmosis.incentives.Query/RewardsEst" => crate::types::osmosis::incentives::RewardsEstResponse,
+        "/osmosis.incentives.Query/UpcomingGauges" => crate::types::osmosis::incentives::UpcomingGaugesResponse,
+        "/osmosis.incentives.Query/UpcomingGaugesPerDenom" => crate::types::osmosis::incentives::UpcomingGaugesPerDenomResponse,
+        "/osmosis.lockup.Query/AccountLockedCoins" => crate::types::osmosis::lockup::AccountLockedCoinsResponse,
+        "/osmosis.lockup.Query/AccountLockedDuration" => crate::types::osmosis::lockup::AccountLockedDurationResponse,
+        "/osmosis.lockup.Query/AccountLockedLongerDuration" => crate::types::osmosis::lockup::AccountLockedLongerDurationResponse,
+        "/osmosis.lockup.Query/AccountLockedLongerDurationDenom" => crate::types::osmosis::lockup::AccountLockedLongerDurationDenomResponse,
+        "/osmosis.lockup.Query/AccountLockedLongerDurationNotUnlockingOnly" => crate::types::osmosis::lockup::AccountLockedLongerDurationNotUnlockingOnlyResponse,
+        "/osmosis.lockup.Query/AccountLockedPastTime" => crate::types::osmosis::lockup::AccountLockedPastTimeResponse,
+        "/osmosis.lockup.Query/AccountLockedPastTimeDenom" => crate::types::osmosis::lockup::AccountLockedPastTimeDenomResponse,
+        "/osmosis.lockup.Query/AccountLockedPastTimeNotUnlockingOnly" => crate::types::osmosis::lockup::AccountLockedPastTimeNotUnlockingOnlyResponse,
+        "/osmosis.lockup.Query/AccountUnlockableCoins" => crate::types::osmosis::lockup::AccountUnlockableCoinsResponse,
+        "/osmosis.lockup.Query/AccountUnlockedBeforeTime" => crate::types::osmosis::lockup::AccountUnlockedBeforeTimeResponse,
+        "/osmosis.lockup.Query/AccountUnlockingCoins" => crate::types::osmosis::lockup::AccountUnlockingCoinsResponse,
+        "/osmosis.lockup.Query/LockedByID" => crate::types::osmosis::lockup::LockedResponse,
+        "/osmosis.lockup.Query/LockedDenom" => crate::types::osmosis::lockup::LockedDenomResponse,
+        "/osmosis.lockup.Query/ModuleBalance" => crate::types::osmosis::lockup::ModuleBalanceResponse,
+        "/osmosis.lockup.Query/ModuleLockedAmount" => crate::types::osmosis::lockup::ModuleLockedAmountResponse,
+        "/osmosis.lockup.Query/Params" => crate::types::osmosis::lockup::QueryParamsResponse,
+        "/osmosis.lockup.Query/SyntheticLockupsByLockupID" => crate::types::osmosis::lockup::SyntheticLockupsByLockupIdResponse,
+        "/osmosis.mint.v1beta1.Query/EpochProvisions" => crate::types::osmosis::mint::v1beta1::QueryEpochProvisionsResponse,
+        "/osmosis.mint.v1beta1.Query/Params" => crate::types::osmosis::mint::v1beta1::QueryParamsResponse,
+        "/osmosis.poolincentives.v1beta1.Query/DistrInfo" => crate::types::osmosis::poolincentives::v1beta1::QueryDistrInfoResponse,
+        "/osmosis.poolincentives.v1beta1.Query/ExternalIncentiveGauges" => crate::types::osmosis::poolincentives::v1beta1::QueryExternalIncentiveGaugesResponse,
+        "/osmosis.poolincentives.v1beta1.Query/GaugeIds" => crate::types::osmosis::poolincentives::v1beta1::QueryGaugeIdsResponse,
+        "/osmosis.poolincentives.v1beta1.Query/IncentivizedPools" => crate::types::osmosis::poolincentives::v1beta1::QueryIncentivizedPoolsResponse,
+        "/osmosis.poolincentives.v1beta1.Query/LockableDurations" => crate::types::osmosis::poolincentives::v1beta1::QueryLockableDurationsResponse,
+        "/osmosis.poolincentives.v1beta1.Query/Params" => crate::types::osmosis::poolincentives::v1beta1::QueryParamsResponse,
+        "/osmosis.superfluid.Query/AllAssets" => crate::types::osmosis::superfluid::AllAssetsResponse,
+        "/osmosis.superfluid.Query/AllIntermediaryAccounts" => crate::types::osmosis::superfluid::AllIntermediaryAccountsResponse,
+        "/osmosis.superfluid.Query/AssetMultiplier" => crate::types::osmosis::superfluid::AssetMultiplierResponse,
+        "/osmosis.superfluid.Query/AssetType" => crate::types::osmosis::superfluid::AssetTypeResponse,
+        "/osmosis.superfluid.Query/ConnectedIntermediaryAccount" => crate::types::osmosis::superfluid::ConnectedIntermediaryAccountResponse,
+        "/osmosis.superfluid.Query/EstimateSuperfluidDelegatedAmountByValidatorDenom" => crate::types::osmosis::superfluid::EstimateSuperfluidDelegatedAmountByValidatorDenomResponse,
+        "/osmosis.superfluid.Query/Params" => crate::types::osmosis::superfluid::QueryParamsResponse,
+        "/osmosis.superfluid.Query/SuperfluidDelegationAmount" => crate::types::osmosis::superfluid::SuperfluidDelegationAmountResponse,
+        "/osmosis.superfluid.Query/SuperfluidDelegationsByDelegator" => crate::types::osmosis::superfluid::SuperfluidDelegationsByDelegatorResponse,
+        "/osmosis.superfluid.Query/SuperfluidDelegationsByValidatorDenom" => crate::types::osmosis::superfluid::SuperfluidDelegationsByValidatorDenomResponse,
+        "/osmosis.superfluid.Query/SuperfluidUndelegationsByDelegator" => crate::types::osmosis::superfluid::SuperfluidUndelegationsByDelegatorResponse,
+        "/osmosis.superfluid.Query/TotalDelegationByDelegator" => crate::types::osmosis::superfluid::QueryTotalDelegationByDelegatorResponse,
+        "/osmosis.superfluid.Query/TotalDelegationByValidatorForDenom" => crate::types::osmosis::superfluid::QueryTotalDelegationByValidatorForDenomResponse,
+        "/osmosis.superfluid.Query/TotalSuperfluidDelegations" => crate::types::osmosis::superfluid::TotalSuperfluidDelegationsResponse,
+        "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata" => crate::types::osmosis::tokenfactory::v1beta1::QueryDenomAuthorityMetadataResponse,
+        "/osmosis.tokenfactory.v1beta1.Query/DenomsFromCreator" => crate::types::osmosis::tokenfactory::v1beta1::QueryDenomsFromCreatorResponse,
+        "/osmosis.tokenfactory.v1beta1.Query/Params" => crate::types::osmosis::tokenfactory::v1beta1::QueryParamsResponse,
+        "/osmosis.twap.v1beta1.Query/ArithmeticTwap" => crate::types::osmosis::twap::v1beta1::ArithmeticTwapResponse,
+        "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => crate::types::osmosis::twap::v1beta1::ArithmeticTwapToNowResponse,
+        "/osmosis.twap.v1beta1.Query/Params" => crate::types::osmosis::twap::v1beta1::ParamsResponse,
+        "/osmosis.twap.v2.Query/ArithmeticTwap" => crate::types::osmosis::twap::v2::ArithmeticTwapResponse,
+        "/osmosis.twap.v2.Query/ArithmeticTwapToNow" => crate::types::osmosis::twap::v2::ArithmeticTwapToNowResponse,
+        "/osmosis.txfees.v1beta1.Query/BaseDenom" => crate::types::osmosis::txfees::v1beta1::QueryBaseDenomResponse,
+        "/osmosis.txfees.v1beta1.Query/DenomPoolId" => crate::types::osmosis::txfees::v1beta1::QueryDenomPoolIdResponse,
+        "/osmosis.txfees.v1beta1.Query/DenomSpotPrice" => crate::types::osmosis::txfees::v1beta1::QueryDenomSpotPriceResponse,
+        "/osmosis.txfees.v1beta1.Query/FeeTokens" => crate::types::osmosis::txfees::v1beta1::QueryFeeTokensResponse,
+        "/osmosis.valsetpref.v1beta1.Query/UserValidatorPreferences" => crate::types::osmosis::valsetpref::v1beta1::QueryUserValidatorPreferenceResponse,
+    }
+}
@@ -0,0 +1,564 @@
+use osmosis_std_derive::CosmwasmExt;
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.ValidatorSet")]
+pub struct ValidatorSet {
+    #[prost(message, repeated, tag = "1")]
+    pub validators: ::prost::alloc::vec::Vec<Validator>,
+    #[prost(message, optional, tag = "2")]
+    pub proposer: ::core::option::Option<Validator>,
+    #[prost(int64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub total_voting_power: i64,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Validator")]
+pub struct Validator {
+    #[prost(bytes = "vec", tag = "1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub pub_key: ::core::option::Option<super::crypto::PublicKey>,
+    #[prost(int64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub voting_power: i64,
+    #[prost(int64, tag = "4")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub proposer_priority: i64,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.SimpleValidator")]
+pub struct SimpleValidator {
+    #[prost(message, optional, tag = "1")]
+    pub pub_key: ::core::option::Option<super::crypto::PublicKey>,
+    #[prost(int64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub voting_power: i64,
+}
+/// PartsetHeader
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.PartSetHeader")]
+pub struct PartSetHeader {
+    #[prost(uint32, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub total: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Part")]
+pub struct Part {
+    #[prost(uint32, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<super::crypto::Proof>,
+}
+/// BlockID
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.BlockID")]
+pub struct BlockId {
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub part_set_header: ::core::option::Option<PartSetHeader>,
+}
+/// Header defines the structure of a Tendermint block header.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Header")]
+pub struct Header {
+    /// basic block info
+    #[prost(message, optional, tag = "1")]
+    pub version: ::core::option::Option<super::version::Consensus>,
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: i64,
+    #[prost(message, optional, tag = "4")]
+    pub time: ::core::option::Option<crate::shim::Timestamp>,
+    /// prev block info
+    #[prost(message, optional, tag = "5")]
+    pub last_block_id: ::core::option::Option<BlockId>,
+    /// hashes of block data
+    ///
+    /// commit from validators from the last block
+    #[prost(bytes = "vec", tag = "6")]
+    pub last_commit_hash: ::prost::alloc::vec::Vec<u8>,
+    /// transactions
+    #[prost(bytes = "vec", tag = "7")]
+    pub data_hash: ::prost::alloc::vec::Vec<u8>,
+    /// hashes from the app output from the prev block
+    ///
+    /// validators for the current block
+    #[prost(bytes = "vec", tag = "8")]
+    pub validators_hash: ::prost::alloc::vec::Vec<u8>,
+    /// validators for the next block
+    #[prost(bytes = "vec", tag = "9")]
+    pub next_validators_hash: ::prost::alloc::vec::Vec<u8>,
+    /// consensus params for current block
+    #[prost(bytes = "vec", tag = "10")]
+    pub consensus_hash: ::prost::alloc::vec::Vec<u8>,
+    /// state after txs from the previous block
+    #[prost(bytes = "vec", tag = "11")]
+    pub app_hash: ::prost::alloc::vec::Vec<u8>,
+    /// root hash of all results from the txs from the previous block
+    #[prost(bytes = "vec", tag = "12")]
+    pub last_results_hash: ::prost::alloc::vec::Vec<u8>,
+    /// consensus info
+    ///
+    /// evidence included in the block
+    #[prost(bytes = "vec", tag = "13")]
+    pub evidence_hash: ::prost::alloc::vec::Vec<u8>,
+    /// original proposer of the block
+    #[prost(bytes = "vec", tag = "14")]
+    pub proposer_address: ::prost::alloc::vec::Vec<u8>,
+}
+/// Data contains the set of transactions included in the block
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Data")]
+pub struct Data {
+    /// Txs that will be applied by state @ block.Height+1.
+    /// NOTE: not all txs here are valid.  We're just agreeing on the order first.
+    /// This means that block.AppHash does not include these txs.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub txs: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// Vote represents a prevote, precommit, or commit vote from validators for
+/// consensus.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Vote")]
+pub struct Vote {
+    #[prost(enumeration = "SignedMsgType", tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<SignedMsgType, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<SignedMsgType, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "SignedMsgType"))]
+    pub r#type: i32,
+    #[prost(int64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: i64,
+    #[prost(int32, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub round: i32,
+    /// zero if vote is nil.
+    #[prost(message, optional, tag = "4")]
+    pub block_id: ::core::option::Option<BlockId>,
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub validator_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int32, tag = "7")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub validator_index: i32,
+    #[prost(bytes = "vec", tag = "8")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// Commit contains the evidence that a block was committed by a set of validators.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Commit")]
+pub struct Commit {
+    #[prost(int64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: i64,
+    #[prost(int32, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub round: i32,
+    #[prost(message, optional, tag = "3")]
+    pub block_id: ::core::option::Option<BlockId>,
+    #[prost(message, repeated, tag = "4")]
+    pub signatures: ::prost::alloc::vec::Vec<CommitSig>,
+}
+/// CommitSig is a part of the Vote included in a Commit.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.CommitSig")]
+pub struct CommitSig {
+    #[prost(enumeration = "BlockIdFlag", tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<BlockIdFlag, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<BlockIdFlag, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "BlockIdFlag"))]
+    pub block_id_flag: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub validator_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.Proposal")]
+pub struct Proposal {
+    #[prost(enumeration = "SignedMsgType", tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::enumeration::serialize::<SignedMsgType, _>",
+        deserialize_with = "crate::serde::enumeration::deserialize::<SignedMsgType, _>"
+    )]
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "SignedMsgType"))]
+    pub r#type: i32,
+    #[prost(int64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub height: i64,
+    #[prost(int32, tag = "3")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub round: i32,
+    #[prost(int32, tag = "4")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub pol_round: i32,
+    #[prost(message, optional, tag = "5")]
+    pub block_id: ::core::option::Option<BlockId>,
+    #[prost(message, optional, tag = "6")]
+    pub timestamp: ::core::option::Option<crate::shim::Timestamp>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.SignedHeader")]
+pub struct SignedHeader {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub commit: ::core::option::Option<Commit>,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.LightBlock")]
+pub struct LightBlock {
+    #[prost(message, optional, tag = "1")]
+    pub signed_header: ::core::option::Option<SignedHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub validator_set: ::core::option::Option<ValidatorSet>,
+}
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.BlockMeta")]
+pub struct BlockMeta {
+    #[prost(message, optional, tag = "1")]
+    pub block_id: ::core::option::Option<BlockId>,
+    #[prost(int64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub block_size: i64,
+    #[prost(message, optional, tag = "3")]
+    pub header: ::core::option::Option<Header>,
+    #[prost(int64, tag = "4")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub num_txs: i64,
+}
+/// TxProof represents a Merkle proof of the presence of a transaction in the Merkle tree.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.types.TxProof")]
+pub struct TxProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<super::crypto::Proof>,
+}
+/// BlockIdFlag indicates which BlcokID the signature is for
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[repr(i32)]
+pub enum BlockIdFlag {
+    #[serde(rename = "BLOCK_ID_FLAG_UNKNOWN")]
+    Unknown = 0,
+    #[serde(rename = "BLOCK_ID_FLAG_ABSENT")]
+    Absent = 1,
+    #[serde(rename = "BLOCK_ID_FLAG_COMMIT")]
+    Commit = 2,
+    #[serde(rename = "BLOCK_ID_FLAG_NIL")]
+    Nil = 3,
+}
+impl BlockIdFlag {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BlockIdFlag::Unknown => "BLOCK_ID_FLAG_UNKNOWN",
+            BlockIdFlag::Absent => "BLOCK_ID_FLAG_ABSENT",
+            BlockIdFlag::Commit => "BLOCK_ID_FLAG_COMMIT",
+            BlockIdFlag::Nil => "BLOCK_ID_FLAG_NIL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BLOCK_ID_FLAG_UNKNOWN" => Some(Self::Unknown),
+            "BLOCK_ID_FLAG_ABSENT" => Some(Self::Absent),
+            "BLOCK_ID_FLAG_COMMIT" => Some(Self::Commit),
+            "BLOCK_ID_FLAG_NIL" => Some(Self::Nil),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for BlockIdFlag {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        BlockIdFlag::from_i32(value)
+    }
+}
+/// SignedMsgType is a type of signed message in the consensus.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[repr(i32)]
+pub enum SignedMsgType {
+    #[serde(rename = "SIGNED_MSG_TYPE_UNKNOWN")]
+    Unknown = 0,
+    /// Votes
+    #[serde(rename = "SIGNED_MSG_TYPE_PREVOTE")]
+    Prevote = 1,
+    #[serde(rename = "SIGNED_MSG_TYPE_PRECOMMIT")]
+    Precommit = 2,
+    /// Proposals
+    #[serde(rename = "SIGNED_MSG_TYPE_PROPOSAL")]
+    Proposal = 32,
+}
+impl SignedMsgType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SignedMsgType::Unknown => "SIGNED_MSG_TYPE_UNKNOWN",
+            SignedMsgType::Prevote => "SIGNED_MSG_TYPE_PREVOTE",
+            SignedMsgType::Precommit => "SIGNED_MSG_TYPE_PRECOMMIT",
+            SignedMsgType::Proposal => "SIGNED_MSG_TYPE_PROPOSAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIGNED_MSG_TYPE_UNKNOWN" => Some(Self::Unknown),
+            "SIGNED_MSG_TYPE_PREVOTE" => Some(Self::Prevote),
+            "SIGNED_MSG_TYPE_PRECOMMIT" => Some(Self::Precommit),
+            "SIGNED_MSG_TYPE_PROPOSAL" => Some(Self::Proposal),
+            _ => None,
+        }
+    }
+}
+impl crate::serde::enumeration::Enumeration for SignedMsgType {
+    fn from_i32(value: i32) -> ::core::option::Option<Self> {
+        SignedMsgType::from_i32(value)
+    }
+}
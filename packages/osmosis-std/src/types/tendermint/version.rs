@@ -0,0 +1,53 @@
+use osmosis_std_derive::CosmwasmExt;
+/// App includes the protocol and software version for the application.
+/// This information is included in ResponseInfo. The App.Protocol can be
+/// updated in ResponseEndBlock.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.version.App")]
+pub struct App {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub protocol: u64,
+    #[prost(string, tag = "2")]
+    pub software: ::prost::alloc::string::String,
+}
+/// Consensus captures the consensus rules for processing a block in the blockchain,
+/// including all blockchain data structures and the rules of the application's
+/// state transition machine.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    ::prost::Message,
+    serde::Serialize,
+    serde::Deserialize,
+    CosmwasmExt,
+)]
+#[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+#[proto_message(type_url = "/tendermint.version.Consensus")]
+pub struct Consensus {
+    #[prost(uint64, tag = "1")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub block: u64,
+    #[prost(uint64, tag = "2")]
+    #[serde(
+        serialize_with = "crate::serde::as_str::serialize",
+        deserialize_with = "crate::serde::as_str::deserialize"
+    )]
+    pub app: u64,
+}
@@ -0,0 +1,135 @@
+use osmosis_std::amino::AminoMsg;
+use osmosis_std::shim::Duration;
+use osmosis_std::types::cosmos::bank::v1beta1::{Input, MsgMultiSend, MsgSend, Output};
+use osmosis_std::types::cosmos::base::v1beta1::Coin;
+use osmosis_std::types::cosmos::gov::v1;
+use osmosis_std::types::cosmos::gov::v1beta1::MsgDeposit;
+use osmosis_std::types::cosmos::slashing::v1beta1::MsgUnjail;
+use osmosis_std::types::osmosis::gamm::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute};
+use osmosis_std::types::osmosis::lockup::MsgLockTokens;
+
+fn coin(amount: u128, denom: &str) -> Coin {
+    Coin {
+        denom: denom.to_string(),
+        amount: amount.into(),
+    }
+}
+
+fn amino_json(msg: &impl AminoMsg) -> String {
+    msg.to_amino_msg().unwrap().to_string()
+}
+
+// The messages of the SDK below are the ones of its `GetSignBytes` tests, and are checked
+// against what those tests expect from the Go implementation.
+
+#[test]
+fn bank_msgs() {
+    let msg = MsgSend {
+        from_address: "cosmos1d9h8qat57ljhcm".to_string(),
+        to_address: "cosmos1da6hgur4wsmpnjyg".to_string(),
+        amount: vec![coin(10, "atom")],
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"10","denom":"atom"}],"from_address":"cosmos1d9h8qat57ljhcm","to_address":"cosmos1da6hgur4wsmpnjyg"}}"#
+    );
+
+    let msg = MsgMultiSend {
+        inputs: vec![Input {
+            address: "cosmos1d9h8qat57ljhcm".to_string(),
+            coins: vec![coin(10, "atom")],
+        }],
+        outputs: vec![Output {
+            address: "cosmos1da6hgur4wsmpnjyg".to_string(),
+            coins: vec![coin(10, "atom")],
+        }],
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"cosmos-sdk/MsgMultiSend","value":{"inputs":[{"address":"cosmos1d9h8qat57ljhcm","coins":[{"amount":"10","denom":"atom"}]}],"outputs":[{"address":"cosmos1da6hgur4wsmpnjyg","coins":[{"amount":"10","denom":"atom"}]}]}}"#
+    );
+}
+
+#[test]
+fn gov_deposit_keeps_a_zero_proposal_id() {
+    let msg = MsgDeposit {
+        proposal_id: 0,
+        depositor: "cosmos1v9jxgu33kfsgr5".to_string(),
+        amount: vec![coin(1000, "stake")],
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"cosmos-sdk/MsgDeposit","value":{"amount":[{"amount":"1000","denom":"stake"}],"depositor":"cosmos1v9jxgu33kfsgr5","proposal_id":"0"}}"#
+    );
+}
+
+// Cosmos SDK v0.46 registers the gov v1 messages under `cosmos-sdk/v1/`
+#[test]
+fn gov_v1_deposit_is_named_after_its_version() {
+    let msg = v1::MsgDeposit {
+        proposal_id: 1,
+        depositor: "cosmos1v9jxgu33kfsgr5".to_string(),
+        amount: vec![coin(1000, "stake")],
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"cosmos-sdk/v1/MsgDeposit","value":{"amount":[{"amount":"1000","denom":"stake"}],"depositor":"cosmos1v9jxgu33kfsgr5","proposal_id":"1"}}"#
+    );
+}
+
+#[test]
+fn slashing_unjail_is_named_after_its_json_tag() {
+    let msg = MsgUnjail {
+        validator_addr: "cosmosvaloper1v93xxeqhg9nn6".to_string(),
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"cosmos-sdk/MsgUnjail","value":{"address":"cosmosvaloper1v93xxeqhg9nn6"}}"#
+    );
+}
+
+// Osmosis has no such tests, so these follow the JSON tags of its v13 protos.
+
+#[test]
+fn gamm_swap_uses_the_camel_case_names() {
+    let msg = MsgSwapExactAmountIn {
+        sender: "osmo1sender".to_string(),
+        routes: vec![SwapAmountInRoute {
+            pool_id: 1,
+            token_out_denom: "uosmo".to_string(),
+        }],
+        token_in: Some(coin(100, "uatom")),
+        token_out_min_amount: 90u128.into(),
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"osmosis/gamm/swap-exact-amount-in","value":{"routes":[{"poolId":"1","tokenOutDenom":"uosmo"}],"sender":"osmo1sender","tokenIn":{"amount":"100","denom":"uatom"},"tokenOutMinAmount":"90"}}"#
+    );
+}
+
+#[test]
+fn non_nullable_fields_are_never_left_out() {
+    let msg = MsgSwapExactAmountIn {
+        sender: "osmo1sender".to_string(),
+        routes: vec![],
+        token_in: None,
+        token_out_min_amount: 0u128.into(),
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"osmosis/gamm/swap-exact-amount-in","value":{"routes":null,"sender":"osmo1sender","tokenIn":{"amount":"0"},"tokenOutMinAmount":"0"}}"#
+    );
+
+    let msg = MsgLockTokens {
+        owner: "osmo1owner".to_string(),
+        duration: Some(Duration {
+            seconds: 86400,
+            nanos: 0,
+        }),
+        coins: vec![],
+    };
+    assert_eq!(
+        amino_json(&msg),
+        r#"{"type":"osmosis/lockup/lock-tokens","value":{"coins":null,"duration":"86400000000000","owner":"osmo1owner"}}"#
+    );
+}
@@ -0,0 +1,73 @@
+use osmosis_std::types::osmosis::lockup::{LockQueryType, QueryCondition};
+use osmosis_std::types::osmosis::superfluid::{SuperfluidAsset, SuperfluidAssetType};
+use serde_json::{from_str, to_string};
+
+#[test]
+fn enum_fields_serialize_as_value_names() {
+    let asset = SuperfluidAsset {
+        denom: "gamm/pool/1".to_string(),
+        asset_type: SuperfluidAssetType::LpShare.into(),
+    };
+
+    let json = to_string(&asset).unwrap();
+    assert_eq!(
+        json,
+        r#"{"denom":"gamm/pool/1","asset_type":"SuperfluidAssetTypeLPShare"}"#
+    );
+    assert_eq!(from_str::<SuperfluidAsset>(&json).unwrap(), asset);
+    assert_eq!(asset.asset_type(), SuperfluidAssetType::LpShare);
+}
+
+#[test]
+fn enum_fields_deserialize_from_numbers() {
+    let condition: QueryCondition =
+        from_str(r#"{"lock_query_type":"1","denom":"uosmo","duration":null,"timestamp":null}"#)
+            .unwrap();
+    assert_eq!(condition.lock_query_type(), LockQueryType::ByTime);
+
+    let condition: QueryCondition =
+        from_str(r#"{"lock_query_type":0,"denom":"uosmo","duration":null,"timestamp":null}"#)
+            .unwrap();
+    assert_eq!(condition.lock_query_type(), LockQueryType::ByDuration);
+}
+
+#[test]
+fn invalid_enum_values_are_rejected() {
+    for lock_query_type in [r#""ByHeight""#, r#""2""#, "2", "-1"] {
+        let json = format!(
+            r#"{{"lock_query_type":{},"denom":"uosmo","duration":null,"timestamp":null}}"#,
+            lock_query_type
+        );
+        assert!(
+            from_str::<QueryCondition>(&json).is_err(),
+            "{} is not a LockQueryType",
+            lock_query_type
+        );
+    }
+
+    let asset = SuperfluidAsset {
+        denom: "uosmo".to_string(),
+        asset_type: 7,
+    };
+    assert!(to_string(&asset).is_err());
+}
+
+#[test]
+fn enum_fields_have_enum_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(SuperfluidAsset)).unwrap();
+
+    assert_eq!(
+        schema["properties"]["asset_type"]["allOf"][0]["$ref"],
+        "#/definitions/SuperfluidAssetType"
+    );
+    let values: Vec<_> = schema["definitions"]["SuperfluidAssetType"]["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|value| value["enum"][0].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        values,
+        ["SuperfluidAssetTypeNative", "SuperfluidAssetTypeLPShare"]
+    );
+}
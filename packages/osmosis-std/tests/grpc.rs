@@ -0,0 +1,106 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+
+use prost::Message;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::server::{Grpc, NamedService, UnaryService};
+use tonic::transport::{Body, Server};
+use tonic::{Code, Request, Response, Status};
+
+use osmosis_std::shim::Any;
+use osmosis_std::types::osmosis::gamm::v1beta1::{
+    query_client::QueryClient, Pool, QueryNumPoolsRequest, QueryPoolRequest, QueryPoolResponse,
+};
+
+/// Stand-in for a node's gamm `Query` service, which only knows about `Pool`.
+#[derive(Clone)]
+struct GammQuery;
+
+impl NamedService for GammQuery {
+    const NAME: &'static str = "osmosis.gamm.v1beta1.Query";
+}
+
+impl Service<http::Request<Body>> for GammQuery {
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        match req.uri().path() {
+            "/osmosis.gamm.v1beta1.Query/Pool" => Box::pin(async move {
+                let mut grpc = Grpc::new(tonic::codec::ProstCodec::default());
+                Ok(grpc.unary(PoolQuery, req).await)
+            }),
+            _ => Box::pin(async { Ok(Status::unimplemented("").to_http()) }),
+        }
+    }
+}
+
+struct PoolQuery;
+
+impl UnaryService<QueryPoolRequest> for PoolQuery {
+    type Response = QueryPoolResponse;
+    type Future = BoxFuture<Response<Self::Response>, Status>;
+
+    fn call(&mut self, request: Request<QueryPoolRequest>) -> Self::Future {
+        let pool = Pool {
+            id: request.into_inner().pool_id,
+            ..Default::default()
+        };
+
+        Box::pin(async move {
+            Ok(Response::new(QueryPoolResponse {
+                pool: Some(Any {
+                    type_url: Pool::TYPE_URL.to_string(),
+                    value: pool.encode_to_vec(),
+                }),
+            }))
+        })
+    }
+}
+
+async fn serve() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(GammQuery)
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    addr
+}
+
+#[tokio::test]
+async fn query_client_queries_node() {
+    let addr = serve().await;
+    let mut client = QueryClient::connect(format!("http://{}", addr))
+        .await
+        .unwrap();
+
+    let res = client
+        .pool(QueryPoolRequest { pool_id: 7 })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let pool: Pool = res.pool.unwrap().try_into().unwrap();
+    assert_eq!(pool.id, 7);
+}
+
+#[tokio::test]
+async fn query_client_returns_status_from_node() {
+    let addr = serve().await;
+    let mut client = QueryClient::connect(format!("http://{}", addr))
+        .await
+        .unwrap();
+
+    let err = client.num_pools(QueryNumPoolsRequest {}).await.unwrap_err();
+    assert_eq!(err.code(), Code::Unimplemented);
+}
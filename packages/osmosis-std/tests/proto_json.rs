@@ -0,0 +1,132 @@
+use osmosis_std::proto_json::{decode_proto_json, encode_proto_json};
+use osmosis_std::shim::Any;
+use osmosis_std::types::cosmos::base::query::v1beta1::PageResponse;
+use osmosis_std::types::cosmos::staking::v1beta1::{BondStatus, Validator};
+use osmosis_std::types::ibc::core::channel::v1::{acknowledgement, Acknowledgement};
+use osmosis_std::types::osmosis::gamm::v1beta1::{Pool, QueryPoolResponse};
+use osmosis_std::types::osmosis::lockup::{LockQueryType, QueryCondition};
+use osmosis_std::types::tendermint::crypto::{public_key, Proof, PublicKey};
+
+/// `/osmosis/gamm/v1beta1/pools/1`, as returned by the REST endpoints of a node.
+const POOL_RESPONSE: &str = r#"{
+  "pool": {
+    "@type": "/osmosis.gamm.v1beta1.Pool",
+    "address": "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t",
+    "id": "1",
+    "pool_params": {
+      "swap_fee": "0.002000000000000000",
+      "exit_fee": "0.000000000000000000",
+      "smooth_weight_change_params": null
+    },
+    "future_pool_governor": "24h",
+    "total_shares": {
+      "denom": "gamm/pool/1",
+      "amount": "100000000000000000000"
+    },
+    "pool_assets": [
+      {
+        "token": {
+          "denom": "uatom",
+          "amount": "1000000"
+        },
+        "weight": "536870912000000"
+      },
+      {
+        "token": {
+          "denom": "uosmo",
+          "amount": "8000000"
+        },
+        "weight": "536870912000000"
+      }
+    ],
+    "total_weight": "1073741824000000"
+  }
+}"#;
+
+#[test]
+fn decodes_rest_response_with_any() {
+    let res: QueryPoolResponse = decode_proto_json(POOL_RESPONSE).unwrap();
+    let any = res.pool.unwrap();
+    assert_eq!(any.type_url, Pool::TYPE_URL);
+
+    let pool: Pool = any.clone().try_into().unwrap();
+    assert_eq!(pool.id, 1);
+    assert_eq!(pool.future_pool_governor, "24h");
+    assert_eq!(pool.pool_assets.len(), 2);
+    assert_eq!(pool.pool_assets[1].token.as_ref().unwrap().denom, "uosmo");
+    assert_eq!(pool.total_weight.to_string(), "1073741824000000");
+
+    let json = encode_proto_json(&any).unwrap();
+    assert!(json.contains(r#""@type":"/osmosis.gamm.v1beta1.Pool""#));
+    assert!(json.contains(r#""futurePoolGovernor":"24h""#));
+    assert!(json.contains(r#""id":"1""#));
+    assert_eq!(decode_proto_json::<Any>(&json).unwrap(), any);
+}
+
+#[test]
+fn encodes_enums_as_names_and_bytes_as_base64() {
+    let validator = Validator {
+        operator_address: "osmovaloper1".to_string(),
+        jailed: true,
+        status: BondStatus::Bonded.into(),
+        ..Default::default()
+    };
+
+    let json = encode_proto_json(&validator).unwrap();
+    assert!(json.contains(r#""status":"BOND_STATUS_BONDED""#));
+    assert!(json.contains(r#""operatorAddress":"osmovaloper1""#));
+    assert!(
+        !json.contains("unbondingHeight"),
+        "default values are left out"
+    );
+    assert_eq!(decode_proto_json::<Validator>(&json).unwrap(), validator);
+
+    let condition: QueryCondition = decode_proto_json(r#"{"lockQueryType":1}"#).unwrap();
+    assert_eq!(condition.lock_query_type, LockQueryType::ByTime as i32);
+    assert!(decode_proto_json::<QueryCondition>(r#"{"lockQueryType":"ByHeight"}"#).is_err());
+
+    let page = PageResponse {
+        next_key: vec![0xfb, 0xff],
+        total: 2,
+    };
+    let json = encode_proto_json(&page).unwrap();
+    assert_eq!(json, r#"{"nextKey":"+/8=","total":"2"}"#);
+    assert_eq!(decode_proto_json::<PageResponse>(&json).unwrap(), page);
+    assert_eq!(
+        decode_proto_json::<PageResponse>(r#"{"next_key":"-_8=","total":2}"#).unwrap(),
+        page
+    );
+}
+
+#[test]
+fn encodes_oneofs_as_the_field_that_is_set() {
+    let ack = Acknowledgement {
+        response: Some(acknowledgement::Response::Error("timeout".to_string())),
+    };
+    let json = encode_proto_json(&ack).unwrap();
+    assert_eq!(json, r#"{"error":"timeout"}"#);
+    assert_eq!(decode_proto_json::<Acknowledgement>(&json).unwrap(), ack);
+
+    let pub_key: PublicKey = decode_proto_json(r#"{"ed25519":"+/8="}"#).unwrap();
+    assert_eq!(
+        pub_key.sum,
+        Some(public_key::Sum::Ed25519(vec![0xfb, 0xff]))
+    );
+    assert_eq!(
+        decode_proto_json::<PublicKey>("{}").unwrap(),
+        PublicKey { sum: None }
+    );
+
+    let proof = Proof {
+        total: 2,
+        index: 1,
+        leaf_hash: vec![0xfb],
+        aunts: vec![vec![0xfb, 0xff], vec![]],
+    };
+    let json = encode_proto_json(&proof).unwrap();
+    assert_eq!(
+        json,
+        r#"{"aunts":["+/8=",""],"index":"1","leafHash":"+w==","total":"2"}"#
+    );
+    assert_eq!(decode_proto_json::<Proof>(&json).unwrap(), proof);
+}
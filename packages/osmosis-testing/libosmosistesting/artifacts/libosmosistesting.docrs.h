@@ -77,10 +77,21 @@ extern "C" {
 extern GoUint64 InitTestEnv();
 extern char* InitAccount(GoUint64 envId, GoString coinsJson);
 extern void BeginBlock(GoUint64 envId);
+extern char* BeginBlockAt(GoUint64 envId, GoInt64 unixNanos);
 extern void EndBlock(GoUint64 envId);
+extern GoUint8 IsBlockInProgress(GoUint64 envId);
+extern void CommitEmptyBlocks(GoUint64 envId, GoUint64 count, GoUint64 secondsPerBlock);
+extern char* SetBlockTime(GoUint64 envId, GoInt64 unixNanos);
+extern GoInt64 GetBlockHeight(GoUint64 envId);
+extern GoInt64 GetBlockTime(GoUint64 envId);
 extern void IncreaseTime(GoUint64 envId, GoInt64 seconds);
 extern char* Execute(GoUint64 envId, GoString base64ReqDeliverTx);
 extern char* Query(GoUint64 envId, GoString path, GoString base64QueryMsgBytes);
+extern char* AddSuperfluidLPShare(GoUint64 envId, GoString denom);
+extern char* GetValidatorAddresses(GoUint64 envId);
+extern char* AddValidator(GoUint64 envId);
+extern GoUint64 Snapshot(GoUint64 envId);
+extern char* Revert(GoUint64 envId, GoUint64 snapshotId);
 extern GoUint64 AccountSequence(GoUint64 envId, GoString bech32Address);
 extern GoUint64 AccountNumber(GoUint64 envId, GoString bech32Address);
 extern char* Simulate(GoUint64 envId, GoString base64TxBytes);
@@ -17,15 +17,20 @@ import (
 	// tendermint
 	abci "github.com/tendermint/tendermint/abci/types"
 	tmproto "github.com/tendermint/tendermint/proto/tendermint/types"
+	dbm "github.com/tendermint/tm-db"
 
 	// cosmos sdk
 	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
 	"github.com/cosmos/cosmos-sdk/simapp"
 	sdk "github.com/cosmos/cosmos-sdk/types"
+	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
 
 	// wasmd
 	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
 
+	// osmosis
+	superfluidtypes "github.com/osmosis-labs/osmosis/v13/x/superfluid/types"
+
 	// cosmwasm-testing
 	"github.com/osmosis-labs/osmosis-rust/osmosis-testing/result"
 	"github.com/osmosis-labs/osmosis-rust/osmosis-testing/testenv"
@@ -35,8 +40,20 @@ var (
 	envCounter  uint64 = 0
 	envRegister        = sync.Map{}
 	mu          sync.Mutex
+
+	snapshotCounter  uint64 = 0
+	snapshotRegister        = sync.Map{}
 )
 
+// snapshot holds a copy of committed state along with the header
+// of the last committed block, which is all that's needed to rebuild an env,
+// and the id of the env it was taken of, which is the only one it can be reverted to.
+type snapshot struct {
+	envId  uint64
+	db     *dbm.MemDB
+	header tmproto.Header
+}
+
 //export InitTestEnv
 func InitTestEnv() uint64 {
 	// Allow testing unoptimized contract
@@ -46,15 +63,13 @@ func InitTestEnv() uint64 {
 	defer mu.Unlock()
 
 	env := new(testenv.TestEnv)
-	env.App = testenv.SetupOsmosisApp()
+	env.DB = dbm.NewMemDB()
+	env.App = testenv.SetupOsmosisApp(env.DB)
 
 	env.Ctx = env.App.BaseApp.NewContext(false, tmproto.Header{Height: 0, ChainID: "osmosis-1", Time: time.Now().UTC()})
 
 	env.BeginNewBlock(false, 5)
-
-	reqEndBlock := abci.RequestEndBlock{Height: env.Ctx.BlockHeight()}
-	env.App.EndBlock(reqEndBlock)
-	env.App.Commit()
+	env.EndBlock()
 
 	envCounter += 1
 	id := envCounter
@@ -92,8 +107,8 @@ func InitAccount(envId uint64, coinsJson string) *C.char {
 func IncreaseTime(envId uint64, seconds uint64) {
 	env := loadEnv(envId)
 	env.BeginNewBlock(false, seconds)
+	env.EndBlock()
 	envRegister.Store(envId, env)
-	EndBlock(envId)
 }
 
 //export BeginBlock
@@ -106,12 +121,69 @@ func BeginBlock(envId uint64) {
 //export EndBlock
 func EndBlock(envId uint64) {
 	env := loadEnv(envId)
-	reqEndBlock := abci.RequestEndBlock{Height: env.Ctx.BlockHeight()}
-	env.App.EndBlock(reqEndBlock)
-	env.App.Commit()
+	env.EndBlock()
+	envRegister.Store(envId, env)
+}
+
+//export IsBlockInProgress
+func IsBlockInProgress(envId uint64) bool {
+	env := loadEnv(envId)
+	return env.BlockInProgress
+}
+
+//export CommitEmptyBlocks
+func CommitEmptyBlocks(envId uint64, count uint64, secondsPerBlock uint64) {
+	env := loadEnv(envId)
+	for i := uint64(0); i < count; i++ {
+		env.BeginNewBlock(false, secondsPerBlock)
+		env.EndBlock()
+	}
 	envRegister.Store(envId, env)
 }
 
+//export SetBlockTime
+func SetBlockTime(envId uint64, unixNanos int64) *C.char {
+	env := loadEnv(envId)
+
+	newBlockTime := time.Unix(0, unixNanos).UTC()
+	if err := checkBlockTime(env, newBlockTime); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	env.BeginNewBlockAt(newBlockTime)
+	env.EndBlock()
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export BeginBlockAt
+func BeginBlockAt(envId uint64, unixNanos int64) *C.char {
+	env := loadEnv(envId)
+
+	newBlockTime := time.Unix(0, unixNanos).UTC()
+	if err := checkBlockTime(env, newBlockTime); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	env.BeginNewBlockAt(newBlockTime)
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export GetBlockHeight
+func GetBlockHeight(envId uint64) int64 {
+	env := loadEnv(envId)
+	return env.Ctx.BlockHeight()
+}
+
+//export GetBlockTime
+func GetBlockTime(envId uint64) int64 {
+	env := loadEnv(envId)
+	return env.Ctx.BlockTime().UnixNano()
+}
+
 //export Execute
 func Execute(envId uint64, base64ReqDeliverTx string) *C.char {
 	env := loadEnv(envId)
@@ -130,6 +202,12 @@ func Execute(envId uint64, base64ReqDeliverTx string) *C.char {
 		return encodeErrToResultBytes(result.ExecuteError, err)
 	}
 
+	// Run the tx through `CheckTx` first, as a node's mempool would, so that check state
+	// (used by `Simulate`) keeps track of account sequences when the same account sends
+	// multiple txs within one block. `CheckTx` only runs the ante handler, which `DeliverTx`
+	// runs again, so any error it returns is reported by `DeliverTx` below.
+	env.App.CheckTx(abci.RequestCheckTx{Tx: reqDeliverTx.Tx, Type: abci.CheckTxType_New})
+
 	resDeliverTx := env.App.DeliverTx(reqDeliverTx)
 	bz, err := proto.Marshal(&resDeliverTx)
 
@@ -169,13 +247,124 @@ func Query(envId uint64, path, base64QueryMsgBytes string) *C.char {
 
 //export WhitelistAddressForForceUnlock
 func WhitelistAddressForForceUnlock(envId uint64, address string) {
-	BeginBlock(envId)
 	env := loadEnv(envId)
 	params := env.App.LockupKeeper.GetParams(env.Ctx)
 	params.ForceUnlockAllowedAddresses = append(params.ForceUnlockAllowedAddresses, address)
 	env.App.LockupKeeper.SetParams(env.Ctx, params)
 	envRegister.Store(envId, env)
-	EndBlock(envId)
+}
+
+//export AddSuperfluidLPShare
+func AddSuperfluidLPShare(envId uint64, denom string) *C.char {
+	env := loadEnv(envId)
+	err := env.App.SuperfluidKeeper.AddNewSuperfluidAsset(env.Ctx, superfluidtypes.SuperfluidAsset{
+		Denom:     denom,
+		AssetType: superfluidtypes.SuperfluidAssetTypeLPShare,
+	})
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export GetValidatorAddresses
+func GetValidatorAddresses(envId uint64) *C.char {
+	env := loadEnv(envId)
+
+	// genesis validator always comes first
+	genesisValidator := env.GenesisValidator.String()
+	validators := env.App.StakingKeeper.GetAllValidators(env.Ctx)
+	addresses := []string{genesisValidator}
+	for _, validator := range validators {
+		if validator.OperatorAddress != genesisValidator {
+			addresses = append(addresses, validator.OperatorAddress)
+		}
+	}
+
+	bz, err := json.Marshal(addresses)
+	if err != nil {
+		panic(err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+//export AddValidator
+func AddValidator(envId uint64) *C.char {
+	env := loadEnv(envId)
+
+	valAddr := env.SetupValidator(stakingtypes.Bonded)
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte(valAddr.String()))
+}
+
+//export Snapshot
+func Snapshot(envId uint64) uint64 {
+	env := loadEnv(envId)
+	mu.Lock()
+	defer mu.Unlock()
+
+	snapshotCounter += 1
+	id := snapshotCounter
+
+	snapshotRegister.Store(id, snapshot{
+		envId:  envId,
+		db:     testenv.CopyDB(env.DB),
+		header: env.Ctx.BlockHeader(),
+	})
+
+	return id
+}
+
+//export Revert
+func Revert(envId uint64, snapshotId uint64) *C.char {
+	env := loadEnv(envId)
+	mu.Lock()
+	defer mu.Unlock()
+
+	snap, err := loadSnapshot(envId, snapshotId)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// copy again so that the same snapshot can be reverted to more than once
+	env.DB = testenv.CopyDB(snap.db)
+	env.App = testenv.NewOsmosisApp(env.DB)
+	env.Ctx = env.App.BaseApp.NewUncachedContext(false, snap.header)
+	env.BlockInProgress = false
+
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export ReleaseSnapshot
+func ReleaseSnapshot(envId uint64, snapshotId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	if _, err := loadSnapshot(envId, snapshotId); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+	snapshotRegister.Delete(snapshotId)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export ReleaseSnapshots
+func ReleaseSnapshots(envId uint64) {
+	mu.Lock()
+	defer mu.Unlock()
+
+	snapshotRegister.Range(func(id, item any) bool {
+		if item.(snapshot).envId == envId {
+			snapshotRegister.Delete(id)
+		}
+		return true
+	})
 }
 
 //export AccountSequence
@@ -248,6 +437,18 @@ func loadEnv(envId uint64) testenv.TestEnv {
 	return env
 }
 
+func loadSnapshot(envId uint64, snapshotId uint64) (snapshot, error) {
+	item, ok := snapshotRegister.Load(snapshotId)
+	if !ok {
+		return snapshot{}, errors.Errorf("snapshot not found: %d", snapshotId)
+	}
+	snap := item.(snapshot)
+	if snap.envId != envId {
+		return snapshot{}, errors.Errorf("snapshot %d was not taken of this app", snapshotId)
+	}
+	return snap, nil
+}
+
 func encodeErrToResultBytes(code byte, err error) *C.char {
 	return C.CString(result.EncodeResultFromError(code, err))
 }
@@ -258,3 +459,10 @@ func encodeBytesResultBytes(bytes []byte) *C.char {
 
 // must define main for ffi build
 func main() {}
+
+func checkBlockTime(env testenv.TestEnv, newBlockTime time.Time) error {
+	if newBlockTime.Before(env.Ctx.BlockTime()) {
+		return errors.Errorf("block time must not go backward: current block time is %s, got %s", env.Ctx.BlockTime(), newBlockTime)
+	}
+	return nil
+}
@@ -35,6 +35,13 @@ import (
 type TestEnv struct {
 	App *app.OsmosisApp
 	Ctx sdk.Context
+	DB  *dbm.MemDB
+
+	// BlockInProgress is true between the beginning and the end of a block
+	BlockInProgress bool
+
+	// GenesisValidator is the validator set up along with the chain, it proposes every block
+	GenesisValidator sdk.ValAddress
 }
 
 // DebugAppOptions is a stub implementing AppOptions
@@ -48,9 +55,8 @@ func (ao DebugAppOptions) Get(o string) interface{} {
 	return nil
 }
 
-func SetupOsmosisApp() *app.OsmosisApp {
-	db := dbm.NewMemDB()
-	appInstance := app.NewOsmosisApp(
+func NewOsmosisApp(db dbm.DB) *app.OsmosisApp {
+	return app.NewOsmosisApp(
 		log.NewNopLogger(),
 		db,
 		nil,
@@ -62,6 +68,10 @@ func SetupOsmosisApp() *app.OsmosisApp {
 		app.GetWasmEnabledProposals(),
 		app.EmptyWasmOpts,
 	)
+}
+
+func SetupOsmosisApp(db dbm.DB) *app.OsmosisApp {
+	appInstance := NewOsmosisApp(db)
 	genesisState := app.NewDefaultGenesisState()
 
 	encCfg := app.MakeEncodingConfig()
@@ -99,26 +109,53 @@ func SetupOsmosisApp() *app.OsmosisApp {
 	return appInstance
 }
 
+// CopyDB copies every key of the committed state in `src` into a new MemDB,
+// so that the copy can be loaded by another app instance without sharing state.
+func CopyDB(src dbm.DB) *dbm.MemDB {
+	dst := dbm.NewMemDB()
+
+	itr, err := src.Iterator(nil, nil)
+	requireNoErr(err)
+	defer itr.Close()
+
+	for ; itr.Valid(); itr.Next() {
+		requireNoErr(dst.Set(itr.Key(), itr.Value()))
+	}
+	requireNoErr(itr.Error())
+
+	return dst
+}
+
 func (env *TestEnv) BeginNewBlock(executeNextEpoch bool, timeIncreaseSeconds uint64) {
-	var valAddr []byte
+	newBlockTime := env.Ctx.BlockTime().Add(time.Duration(timeIncreaseSeconds) * time.Second)
+	if executeNextEpoch {
+		epochIdentifier := env.App.SuperfluidKeeper.GetEpochIdentifier(env.Ctx)
+		epoch := env.App.EpochsKeeper.GetEpochInfo(env.Ctx, epochIdentifier)
+		newBlockTime = env.Ctx.BlockTime().Add(epoch.Duration).Add(time.Second)
+	}
 
-	validators := env.App.StakingKeeper.GetAllValidators(env.Ctx)
-	if len(validators) >= 1 {
-		valAddrFancy, err := validators[0].GetConsAddr()
-		requireNoErr(err)
-		valAddr = valAddrFancy.Bytes()
-	} else {
-		valAddrFancy := env.setupValidator(stakingtypes.Bonded)
-		validator, _ := env.App.StakingKeeper.GetValidator(env.Ctx, valAddrFancy)
-		valAddr2, _ := validator.GetConsAddr()
-		valAddr = valAddr2.Bytes()
+	env.BeginNewBlockAt(newBlockTime)
+}
+
+// BeginNewBlockAt begins a new block with the given block time.
+func (env *TestEnv) BeginNewBlockAt(newBlockTime time.Time) {
+	if env.GenesisValidator.Empty() {
+		env.GenesisValidator = env.SetupValidator(stakingtypes.Bonded)
 	}
 
-	env.beginNewBlockWithProposer(executeNextEpoch, valAddr, timeIncreaseSeconds)
+	env.beginNewBlockWithProposer(env.GenesisValidator, newBlockTime)
+}
+
+// EndBlock ends the current block and commits its state.
+func (env *TestEnv) EndBlock() {
+	reqEndBlock := abci.RequestEndBlock{Height: env.Ctx.BlockHeight()}
+	env.App.EndBlock(reqEndBlock)
+	env.App.Commit()
+	env.BlockInProgress = false
 }
 
 // beginNewBlockWithProposer begins a new block with a proposer.
-func (env *TestEnv) beginNewBlockWithProposer(executeNextEpoch bool, proposer sdk.ValAddress, timeIncreaseSeconds uint64) {
+func (env *TestEnv) beginNewBlockWithProposer(proposer sdk.ValAddress, newBlockTime time.Time) {
 	validator, found := env.App.StakingKeeper.GetValidator(env.Ctx, proposer)
 
 	if !found {
@@ -130,29 +167,35 @@ func (env *TestEnv) beginNewBlockWithProposer(executeNextEpoch bool, proposer sd
 
 	valAddr := valConsAddr.Bytes()
 
-	epochIdentifier := env.App.SuperfluidKeeper.GetEpochIdentifier(env.Ctx)
-	epoch := env.App.EpochsKeeper.GetEpochInfo(env.Ctx, epochIdentifier)
-	newBlockTime := env.Ctx.BlockTime().Add(time.Duration(timeIncreaseSeconds) * time.Second)
-	if executeNextEpoch {
-		newBlockTime = env.Ctx.BlockTime().Add(epoch.Duration).Add(time.Second)
-	}
-
 	header := tmtypes.Header{ChainID: "osmosis-1", Height: env.Ctx.BlockHeight() + 1, Time: newBlockTime}
 	newCtx := env.Ctx.WithBlockTime(newBlockTime).WithBlockHeight(env.Ctx.BlockHeight() + 1)
 	env.Ctx = newCtx
-	lastCommitInfo := abci.LastCommitInfo{
-		Votes: []abci.VoteInfo{{
-			Validator:       abci.Validator{Address: valAddr, Power: 1000},
+	// every validator signs the previous block, so that all of them get their share of rewards
+	votes := []abci.VoteInfo{{
+		Validator:       abci.Validator{Address: valAddr, Power: 1000},
+		SignedLastBlock: true,
+	}}
+	for _, val := range env.App.StakingKeeper.GetAllValidators(env.Ctx) {
+		consAddr, err := val.GetConsAddr()
+		requireNoErr(err)
+		if consAddr.Equals(valConsAddr) {
+			continue
+		}
+		votes = append(votes, abci.VoteInfo{
+			Validator:       abci.Validator{Address: consAddr.Bytes(), Power: 1000},
 			SignedLastBlock: true,
-		}},
+		})
 	}
+	lastCommitInfo := abci.LastCommitInfo{Votes: votes}
 	reqBeginBlock := abci.RequestBeginBlock{Header: header, LastCommitInfo: lastCommitInfo}
 
 	env.App.BeginBlock(reqBeginBlock)
 	env.Ctx = env.App.NewContext(false, reqBeginBlock.Header)
+	env.BlockInProgress = true
 }
 
-func (env *TestEnv) setupValidator(bondStatus stakingtypes.BondStatus) sdk.ValAddress {
+// SetupValidator creates a validator with the given bond status and a minimal self bond.
+func (env *TestEnv) SetupValidator(bondStatus stakingtypes.BondStatus) sdk.ValAddress {
 	valPub := secp256k1.GenPrivKey().PubKey()
 	valAddr := sdk.ValAddress(valPub.Address())
 	bondDenom := env.App.StakingKeeper.GetParams(env.Ctx).BondDenom
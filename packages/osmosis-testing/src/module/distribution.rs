@@ -0,0 +1,161 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    MsgFundCommunityPool, MsgFundCommunityPoolResponse, MsgSetWithdrawAddress,
+    MsgSetWithdrawAddressResponse, MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse,
+    MsgWithdrawValidatorCommission, MsgWithdrawValidatorCommissionResponse,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+};
+
+use crate::module::Module;
+use crate::runner::result::RunnerExecuteResult;
+use crate::{
+    account::{Account, SigningAccount},
+    runner::Runner,
+};
+use crate::{fn_execute, fn_query};
+
+pub struct Distribution<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Distribution<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Distribution<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub set_withdraw_address: MsgSetWithdrawAddress["/cosmos.distribution.v1beta1.MsgSetWithdrawAddress"] => MsgSetWithdrawAddressResponse
+    }
+
+    fn_execute! {
+        pub withdraw_delegator_reward: MsgWithdrawDelegatorReward["/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"] => MsgWithdrawDelegatorRewardResponse
+    }
+
+    fn_execute! {
+        pub withdraw_validator_commission: MsgWithdrawValidatorCommission["/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission"] => MsgWithdrawValidatorCommissionResponse
+    }
+
+    fn_execute! {
+        pub fund_community_pool: MsgFundCommunityPool["/cosmos.distribution.v1beta1.MsgFundCommunityPool"] => MsgFundCommunityPoolResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.distribution.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_validator_outstanding_rewards ["/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards"]: QueryValidatorOutstandingRewardsRequest => QueryValidatorOutstandingRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegation_rewards ["/cosmos.distribution.v1beta1.Query/DelegationRewards"]: QueryDelegationRewardsRequest => QueryDelegationRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegation_total_rewards ["/cosmos.distribution.v1beta1.Query/DelegationTotalRewards"]: QueryDelegationTotalRewardsRequest => QueryDelegationTotalRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_withdraw_address ["/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress"]: QueryDelegatorWithdrawAddressRequest => QueryDelegatorWithdrawAddressResponse
+    }
+
+    fn_query! {
+        pub query_community_pool ["/cosmos.distribution.v1beta1.Query/CommunityPool"]: QueryCommunityPoolRequest => QueryCommunityPoolResponse
+    }
+
+    /// Withdraw the signer's rewards from delegating to the validator
+    /// with the given operator address.
+    pub fn withdraw_rewards(
+        &self,
+        validator_address: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgWithdrawDelegatorRewardResponse> {
+        self.withdraw_delegator_reward(
+            MsgWithdrawDelegatorReward {
+                delegator_address: signer.address(),
+                validator_address: validator_address.to_string(),
+            },
+            signer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest;
+    use cosmrs::proto::cosmos::distribution::v1beta1::QueryDelegationRewardsRequest;
+    use cosmwasm_std::Coin;
+    use osmosis_std::types::osmosis::mint::v1beta1::{
+        QueryParamsRequest as QueryMintParamsRequest,
+        QueryParamsResponse as QueryMintParamsResponse,
+    };
+
+    use crate::{Account, Bank, Distribution, Module, OsmosisTestApp, Runner, Staking};
+
+    #[test]
+    fn distribution_integration() {
+        let app = OsmosisTestApp::new();
+        let delegator = app
+            .init_account(&[Coin::new(1_000_000_000_000, "uosmo")])
+            .unwrap();
+        let bank = Bank::new(&app);
+        let staking = Staking::new(&app);
+        let distribution = Distribution::new(&app);
+
+        let validator = app.add_validator().unwrap();
+        staking
+            .delegate_to(&validator, Coin::new(1_000_000_000, "uosmo"), &delegator)
+            .unwrap();
+
+        // gas is free by default, so rewards only come from mint, which mints at the end of
+        // its epoch. distribution allocates them to validators at the beginning of a block.
+        let mint_epoch_identifier = app
+            .query::<QueryMintParamsRequest, QueryMintParamsResponse>(
+                "/osmosis.mint.v1beta1.Query/Params",
+                &QueryMintParamsRequest {},
+            )
+            .unwrap()
+            .params
+            .unwrap()
+            .epoch_identifier;
+        app.skip_to_next_epoch(&mint_epoch_identifier).unwrap();
+        app.commit_empty_blocks(1).unwrap();
+
+        let rewards = distribution
+            .query_delegation_rewards(&QueryDelegationRewardsRequest {
+                delegator_address: delegator.address(),
+                validator_address: validator.clone(),
+            })
+            .unwrap()
+            .rewards;
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].denom, "uosmo");
+
+        let query_balance = || {
+            bank.query_balance(&QueryBalanceRequest {
+                address: delegator.address(),
+                denom: "uosmo".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse::<u128>()
+            .unwrap()
+        };
+
+        let balance_before = query_balance();
+        distribution
+            .withdraw_rewards(&validator, &delegator)
+            .unwrap();
+        assert!(query_balance() > balance_before);
+    }
+}
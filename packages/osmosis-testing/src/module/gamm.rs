@@ -1,8 +1,15 @@
-use cosmwasm_std::{Coin, StdResult};
+use cosmwasm_std::{Coin, Decimal, StdResult, Uint128};
 use osmosis_std::types::osmosis::gamm;
 use osmosis_std::types::osmosis::gamm::{
     poolmodels::balancer::v1beta1::{MsgCreateBalancerPool, MsgCreateBalancerPoolResponse},
-    v1beta1::{PoolAsset, PoolParams, QueryPoolRequest, QueryPoolResponse},
+    poolmodels::stableswap::v1beta1::MsgCreateStableswapPool,
+    v1beta1::{
+        MsgSwapExactAmountIn, PoolAsset, PoolParams, QueryCalcExitPoolCoinsFromSharesRequest,
+        QueryCalcExitPoolCoinsFromSharesResponse, QueryCalcJoinPoolSharesRequest,
+        QueryCalcJoinPoolSharesResponse, QueryPoolRequest, QueryPoolResponse, QueryPoolsRequest,
+        QueryPoolsResponse, QuerySwapExactAmountInRequest, QuerySwapExactAmountInResponse,
+        QuerySwapExactAmountOutRequest, QuerySwapExactAmountOutResponse,
+    },
 };
 use prost::Message;
 
@@ -30,13 +37,41 @@ where
     R: Runner<'a>,
 {
     fn_execute! {
-        pub create_balancer_pool: MsgCreateBalancerPool => MsgCreateBalancerPoolResponse
+        pub create_balancer_pool: MsgCreateBalancerPool
+    }
+
+    fn_execute! {
+        pub create_stableswap_pool: MsgCreateStableswapPool
+    }
+
+    fn_execute! {
+        pub swap_exact_amount_in: MsgSwapExactAmountIn
     }
 
     fn_query! {
         _query_pool ["/osmosis.gamm.v1beta1.Query/Pool"]: QueryPoolRequest => QueryPoolResponse
     }
 
+    fn_query! {
+        pub query_pools ["/osmosis.gamm.v1beta1.Query/Pools"]: QueryPoolsRequest => QueryPoolsResponse
+    }
+
+    fn_query! {
+        pub query_estimate_swap_exact_amount_in ["/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountIn"]: QuerySwapExactAmountInRequest => QuerySwapExactAmountInResponse
+    }
+
+    fn_query! {
+        pub query_estimate_swap_exact_amount_out ["/osmosis.gamm.v1beta1.Query/EstimateSwapExactAmountOut"]: QuerySwapExactAmountOutRequest => QuerySwapExactAmountOutResponse
+    }
+
+    fn_query! {
+        pub query_calc_join_pool_shares ["/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares"]: QueryCalcJoinPoolSharesRequest => QueryCalcJoinPoolSharesResponse
+    }
+
+    fn_query! {
+        pub query_calc_exit_pool_coins_from_shares ["/osmosis.gamm.v1beta1.Query/CalcExitPoolCoinsFromShares"]: QueryCalcExitPoolCoinsFromSharesRequest => QueryCalcExitPoolCoinsFromSharesResponse
+    }
+
     pub fn query_pool_reserves(&self, pool_id: u64) -> RunnerResult<Vec<Coin>> {
         let pool = self.query_pool(pool_id)?;
 
@@ -57,18 +92,15 @@ where
             MsgCreateBalancerPool {
                 sender: signer.address(),
                 pool_params: Some(PoolParams {
-                    swap_fee: "10000000000000000".to_string(),
-                    exit_fee: "10000000000000000".to_string(),
+                    swap_fee: Decimal::percent(1).into(),
+                    exit_fee: Decimal::percent(1).into(),
                     smooth_weight_change_params: None,
                 }),
                 pool_assets: initial_liquidity
                     .iter()
                     .map(|c| PoolAsset {
-                        token: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
-                            denom: c.denom.to_owned(),
-                            amount: format!("{}", c.amount),
-                        }),
-                        weight: "1000000".to_string(),
+                        token: Some(c.clone().into()),
+                        weight: Uint128::new(1_000_000).into(),
                     })
                     .collect(),
                 future_pool_governor: "".to_string(),
@@ -84,3 +116,490 @@ where
             .map_err(RunnerError::DecodeError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Coin, Decimal, Uint128};
+    use osmosis_std::gamm::{Amm, Pool, PoolI, Router};
+    use osmosis_std::types::osmosis::gamm::poolmodels::balancer::v1beta1::MsgCreateBalancerPool;
+    use osmosis_std::types::osmosis::gamm::poolmodels::stableswap::v1beta1::{
+        MsgCreateStableswapPool, PoolParams as StableswapPoolParams,
+    };
+    use osmosis_std::types::osmosis::gamm::v1beta1::{
+        MsgSwapExactAmountIn, PoolAsset, PoolParams, QueryCalcExitPoolCoinsFromSharesRequest,
+        QueryCalcJoinPoolSharesRequest, QueryPoolRequest, QueryPoolsRequest,
+        QuerySwapExactAmountInRequest, QuerySwapExactAmountOutRequest, SwapAmountInRoute,
+        SwapAmountOutRoute,
+    };
+
+    use crate::account::{Account, SigningAccount};
+    use crate::{Gamm, Module, OsmosisTestApp};
+
+    #[test]
+    fn decode_pool_from_query_pool_response() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+
+        let pool_liquidity = vec![
+            Coin::new(1_000_000_000, "uatom"),
+            Coin::new(2_000_000_000, "uosmo"),
+        ];
+        let pool_id = gamm
+            .create_basic_pool(&pool_liquidity, &signer)
+            .unwrap()
+            .data
+            .pool_id;
+
+        let pool: Pool = gamm
+            ._query_pool(&QueryPoolRequest { pool_id })
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(matches!(pool, Pool::Balancer(_)));
+        assert_eq!(pool.id(), pool_id);
+        assert_eq!(pool.reserves().unwrap(), pool_liquidity);
+        assert_eq!(pool.swap_fee().unwrap(), Decimal::percent(1));
+        assert_eq!(pool.exit_fee().unwrap(), Decimal::percent(1));
+        assert_eq!(
+            pool.total_shares().unwrap(),
+            Coin {
+                denom: format!("gamm/pool/{}", pool_id),
+                amount: Uint128::from(100_000_000_000_000_000_000u128),
+            }
+        );
+    }
+
+    fn query_pool(gamm: &Gamm<'_, OsmosisTestApp>, pool_id: u64) -> Pool {
+        gamm._query_pool(&QueryPoolRequest { pool_id })
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn estimate_out_given_in(
+        gamm: &Gamm<'_, OsmosisTestApp>,
+        signer: &SigningAccount,
+        pool_id: u64,
+        token_in: &Coin,
+        token_out_denom: &str,
+    ) -> Uint128 {
+        gamm.query_estimate_swap_exact_amount_in(&QuerySwapExactAmountInRequest {
+            sender: signer.address(),
+            pool_id,
+            token_in: token_in.to_string(),
+            routes: vec![SwapAmountInRoute {
+                pool_id,
+                token_out_denom: token_out_denom.to_string(),
+            }],
+        })
+        .unwrap()
+        .token_out_amount
+        .try_into()
+        .unwrap()
+    }
+
+    fn estimate_in_given_out(
+        gamm: &Gamm<'_, OsmosisTestApp>,
+        signer: &SigningAccount,
+        pool_id: u64,
+        token_out: &Coin,
+        token_in_denom: &str,
+    ) -> Uint128 {
+        gamm.query_estimate_swap_exact_amount_out(&QuerySwapExactAmountOutRequest {
+            sender: signer.address(),
+            pool_id,
+            routes: vec![SwapAmountOutRoute {
+                pool_id,
+                token_in_denom: token_in_denom.to_string(),
+            }],
+            token_out: token_out.to_string(),
+        })
+        .unwrap()
+        .token_in_amount
+        .try_into()
+        .unwrap()
+    }
+
+    fn calc_join_pool_shares(
+        gamm: &Gamm<'_, OsmosisTestApp>,
+        pool_id: u64,
+        tokens_in: &[Coin],
+    ) -> Uint128 {
+        gamm.query_calc_join_pool_shares(&QueryCalcJoinPoolSharesRequest {
+            pool_id,
+            tokens_in: tokens_in.iter().cloned().map(Into::into).collect(),
+        })
+        .unwrap()
+        .share_out_amount
+        .try_into()
+        .unwrap()
+    }
+
+    fn calc_exit_pool_coins(
+        gamm: &Gamm<'_, OsmosisTestApp>,
+        pool_id: u64,
+        share_in_amount: Uint128,
+    ) -> Vec<Coin> {
+        gamm.query_calc_exit_pool_coins_from_shares(&QueryCalcExitPoolCoinsFromSharesRequest {
+            pool_id,
+            share_in_amount: share_in_amount.into(),
+        })
+        .unwrap()
+        .tokens_out
+        .into_iter()
+        .map(|coin| coin.try_into().unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn balancer_math_matches_chain() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+                Coin::new(1_000_000_000_000, "uion"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+
+        let pool_id = gamm
+            .create_balancer_pool(
+                MsgCreateBalancerPool {
+                    sender: signer.address(),
+                    pool_params: Some(PoolParams {
+                        swap_fee: Decimal::permille(3).into(),
+                        exit_fee: Decimal::permille(1).into(),
+                        smooth_weight_change_params: None,
+                    }),
+                    pool_assets: vec![
+                        PoolAsset {
+                            token: Some(Coin::new(1_000_000_000, "uatom").into()),
+                            weight: Uint128::new(3_000_000).into(),
+                        },
+                        PoolAsset {
+                            token: Some(Coin::new(4_567_000_000, "uosmo").into()),
+                            weight: Uint128::new(5_000_000).into(),
+                        },
+                        PoolAsset {
+                            token: Some(Coin::new(250_000_000, "uion").into()),
+                            weight: Uint128::new(2_000_000).into(),
+                        },
+                    ],
+                    future_pool_governor: "".to_string(),
+                },
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+        let pool = query_pool(&gamm, pool_id);
+
+        for amount in [997u128, 1_000_000, 123_456_789, 900_000_000] {
+            for (denom_in, denom_out) in [("uatom", "uosmo"), ("uosmo", "uion"), ("uion", "uatom")]
+            {
+                let token_in = Coin::new(amount, denom_in);
+                let token_out = Coin::new(amount, denom_out);
+
+                assert_eq!(
+                    pool.calc_out_amt_given_in(&token_in, denom_out)
+                        .map(|coin| coin.amount)
+                        .ok(),
+                    gamm.query_estimate_swap_exact_amount_in(&QuerySwapExactAmountInRequest {
+                        sender: signer.address(),
+                        pool_id,
+                        token_in: token_in.to_string(),
+                        routes: vec![SwapAmountInRoute {
+                            pool_id,
+                            token_out_denom: denom_out.to_string(),
+                        }],
+                    })
+                    .ok()
+                    .map(|res| res.token_out_amount.try_into().unwrap()),
+                    "out given in: {} -> {}",
+                    token_in,
+                    denom_out
+                );
+
+                if amount < 200_000_000 {
+                    assert_eq!(
+                        pool.calc_in_amt_given_out(&token_out, denom_in)
+                            .unwrap()
+                            .amount,
+                        estimate_in_given_out(&gamm, &signer, pool_id, &token_out, denom_in),
+                        "in given out: {} <- {}",
+                        denom_in,
+                        token_out
+                    );
+                }
+            }
+
+            let single = [Coin::new(amount, "uosmo")];
+            assert_eq!(
+                pool.calc_join_pool_shares(&single).unwrap().0,
+                calc_join_pool_shares(&gamm, pool_id, &single),
+                "single asset join: {}",
+                amount
+            );
+
+            let multi = [
+                Coin::new(amount, "uatom"),
+                Coin::new(amount * 3, "uosmo"),
+                Coin::new(amount / 2 + 1, "uion"),
+            ];
+            assert_eq!(
+                pool.calc_join_pool_shares(&multi).unwrap().0,
+                calc_join_pool_shares(&gamm, pool_id, &multi),
+                "multi asset join: {}",
+                amount
+            );
+        }
+
+        for shares in [1_000_000u128, 10u128.pow(18), 3 * 10u128.pow(19)] {
+            assert_eq!(
+                pool.calc_exit_pool_coins_from_shares(Uint128::new(shares))
+                    .unwrap(),
+                calc_exit_pool_coins(&gamm, pool_id, Uint128::new(shares)),
+                "exit: {}",
+                shares
+            );
+        }
+
+        // the actual swap gives exactly the quoted amount
+        let token_in = Coin::new(12_345_678, "uatom");
+        let quote = pool.calc_out_amt_given_in(&token_in, "uion").unwrap();
+        let token_out_amount: Uint128 = gamm
+            .swap_exact_amount_in(
+                MsgSwapExactAmountIn {
+                    sender: signer.address(),
+                    routes: vec![SwapAmountInRoute {
+                        pool_id,
+                        token_out_denom: "uion".to_string(),
+                    }],
+                    token_in: Some(token_in.into()),
+                    token_out_min_amount: quote.amount.into(),
+                },
+                &signer,
+            )
+            .unwrap()
+            .data
+            .token_out_amount
+            .try_into()
+            .unwrap();
+        assert_eq!(token_out_amount, quote.amount);
+    }
+
+    #[test]
+    fn stableswap_math_matches_chain() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000_000, "uusdc"),
+                Coin::new(1_000_000_000_000_000, "uusdt"),
+                Coin::new(1_000_000_000_000_000_000_000, "adai"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+
+        let pool_id = gamm
+            .create_stableswap_pool(
+                MsgCreateStableswapPool {
+                    sender: signer.address(),
+                    pool_params: Some(StableswapPoolParams {
+                        swap_fee: Decimal::permille(1).into(),
+                        exit_fee: Decimal::zero().into(),
+                    }),
+                    initial_pool_liquidity: vec![
+                        Coin::new(1_000_000_000_000_000_000, "adai").into(),
+                        Coin::new(1_020_000_000_000, "uusdc").into(),
+                        Coin::new(987_000_000_000, "uusdt").into(),
+                    ],
+                    scaling_factors: vec![1_000_000_000_000, 1, 1],
+                    future_pool_governor: "".to_string(),
+                    scaling_factor_controller: "".to_string(),
+                },
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+        let pool = query_pool(&gamm, pool_id);
+        assert!(matches!(pool, Pool::StableSwap(_)));
+
+        for amount in [1_000u128, 1_000_000, 123_456_789, 50_000_000_000] {
+            for (denom_in, denom_out) in [("uusdc", "uusdt"), ("uusdt", "uusdc")] {
+                let token_in = Coin::new(amount, denom_in);
+                let token_out = Coin::new(amount, denom_out);
+
+                assert_eq!(
+                    pool.calc_out_amt_given_in(&token_in, denom_out)
+                        .unwrap()
+                        .amount,
+                    estimate_out_given_in(&gamm, &signer, pool_id, &token_in, denom_out),
+                    "out given in: {} -> {}",
+                    token_in,
+                    denom_out
+                );
+
+                // the binary search for the amount in starts from different bounds than the
+                // chain's, so it can settle on a neighbouring value within the search tolerance
+                let expected = estimate_in_given_out(&gamm, &signer, pool_id, &token_out, denom_in);
+                let actual = pool
+                    .calc_in_amt_given_out(&token_out, denom_in)
+                    .unwrap()
+                    .amount;
+                assert!(
+                    actual.max(expected) - actual.min(expected) <= Uint128::one(),
+                    "in given out: {} <- {}, expected {}, got {}",
+                    denom_in,
+                    token_out,
+                    expected,
+                    actual
+                );
+            }
+        }
+
+        let exact_ratio = [
+            Coin::new(1_000_000_000_000, "adai"),
+            Coin::new(1_020_000, "uusdc"),
+            Coin::new(987_000, "uusdt"),
+        ];
+        assert_eq!(
+            pool.calc_join_pool_shares(&exact_ratio).unwrap().0,
+            calc_join_pool_shares(&gamm, pool_id, &exact_ratio),
+        );
+
+        for shares in [1_000_000u128, 10u128.pow(18), 3 * 10u128.pow(19)] {
+            assert_eq!(
+                pool.calc_exit_pool_coins_from_shares(Uint128::new(shares))
+                    .unwrap(),
+                calc_exit_pool_coins(&gamm, pool_id, Uint128::new(shares)),
+                "exit: {}",
+                shares
+            );
+        }
+    }
+
+    #[test]
+    fn router_swaps_through_best_route() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+                Coin::new(1_000_000_000_000, "uion"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+
+        // the direct pool is too shallow, so the route through uosmo gives more out
+        let shallow_pool_id = gamm
+            .create_basic_pool(
+                &[Coin::new(1_000_000, "uatom"), Coin::new(1_000_000, "uion")],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+        let atom_osmo_pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uatom"),
+                    Coin::new(1_000_000_000, "uosmo"),
+                ],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+        let osmo_ion_pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uosmo"),
+                    Coin::new(1_000_000_000, "uion"),
+                ],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+
+        let router: Router = gamm
+            .query_pools(&QueryPoolsRequest { pagination: None })
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let token_in = Coin::new(100_000, "uatom");
+
+        let direct = router
+            .best_swap_exact_amount_in(&token_in, "uion", 1)
+            .unwrap();
+        assert_eq!(
+            direct.routes,
+            vec![SwapAmountInRoute {
+                pool_id: shallow_pool_id,
+                token_out_denom: "uion".to_string(),
+            }]
+        );
+
+        let best = router
+            .best_swap_exact_amount_in(&token_in, "uion", 3)
+            .unwrap();
+        assert_eq!(
+            best.routes,
+            vec![
+                SwapAmountInRoute {
+                    pool_id: atom_osmo_pool_id,
+                    token_out_denom: "uosmo".to_string(),
+                },
+                SwapAmountInRoute {
+                    pool_id: osmo_ion_pool_id,
+                    token_out_denom: "uion".to_string(),
+                },
+            ]
+        );
+        assert!(best.token_out.amount > direct.token_out.amount);
+
+        let best_out = router
+            .best_swap_exact_amount_out("uatom", &best.token_out, 3)
+            .unwrap();
+        assert_eq!(
+            best_out.routes,
+            vec![
+                SwapAmountOutRoute {
+                    pool_id: atom_osmo_pool_id,
+                    token_in_denom: "uatom".to_string(),
+                },
+                SwapAmountOutRoute {
+                    pool_id: osmo_ion_pool_id,
+                    token_in_denom: "uosmo".to_string(),
+                },
+            ]
+        );
+
+        let msg = router
+            .swap_exact_amount_in_msg(signer.address(), &token_in, "uion", 3, Decimal::percent(1))
+            .unwrap();
+        assert_eq!(
+            msg.token_out_min_amount,
+            (best.token_out.amount * Decimal::percent(99)).into()
+        );
+
+        let token_out_amount: Uint128 = gamm
+            .swap_exact_amount_in(msg, &signer)
+            .unwrap()
+            .data
+            .token_out_amount
+            .try_into()
+            .unwrap();
+        assert_eq!(token_out_amount, best.token_out.amount);
+    }
+}
@@ -0,0 +1,275 @@
+use cosmrs::proto::cosmos::bank::v1beta1::QueryAllBalancesRequest;
+use cosmwasm_std::Coin;
+use osmosis_std::shim::Duration;
+use osmosis_std::types::osmosis::incentives::{
+    ActiveGaugesPerDenomRequest, ActiveGaugesPerDenomResponse, ActiveGaugesRequest,
+    ActiveGaugesResponse, Gauge, GaugeByIdRequest, GaugeByIdResponse, GaugesRequest,
+    GaugesResponse, ModuleToDistributeCoinsRequest, ModuleToDistributeCoinsResponse, MsgAddToGauge,
+    MsgAddToGaugeResponse, MsgCreateGauge, MsgCreateGaugeResponse, QueryLockableDurationsRequest,
+    QueryLockableDurationsResponse, RewardsEstRequest, RewardsEstResponse,
+    UpcomingGaugesPerDenomRequest, UpcomingGaugesPerDenomResponse, UpcomingGaugesRequest,
+    UpcomingGaugesResponse,
+};
+use osmosis_std::types::osmosis::lockup::{LockQueryType, QueryCondition};
+
+use crate::module::utils::{proto_coins_to_coins, timestamp_to_proto};
+use crate::module::Module;
+use crate::runner::error::RunnerError;
+use crate::runner::result::{RunnerExecuteResult, RunnerResult};
+use crate::{
+    account::{Account, SigningAccount},
+    runner::Runner,
+};
+use crate::{fn_execute, fn_query, Bank, OsmosisTestApp};
+
+/// Epoch identifier that incentives distribution is triggered on, as set in the genesis.
+const DISTR_EPOCH_IDENTIFIER: &str = "week";
+
+pub struct Incentives<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Incentives<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Incentives<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub create_gauge: MsgCreateGauge
+    }
+
+    fn_execute! {
+        pub add_to_gauge: MsgAddToGauge
+    }
+
+    fn_query! {
+        pub query_module_to_distribute_coins ["/osmosis.incentives.Query/ModuleToDistributeCoins"]: ModuleToDistributeCoinsRequest => ModuleToDistributeCoinsResponse
+    }
+
+    fn_query! {
+        pub query_gauge_by_id ["/osmosis.incentives.Query/GaugeByID"]: GaugeByIdRequest => GaugeByIdResponse
+    }
+
+    fn_query! {
+        pub query_gauges ["/osmosis.incentives.Query/Gauges"]: GaugesRequest => GaugesResponse
+    }
+
+    fn_query! {
+        pub query_active_gauges ["/osmosis.incentives.Query/ActiveGauges"]: ActiveGaugesRequest => ActiveGaugesResponse
+    }
+
+    fn_query! {
+        pub query_active_gauges_per_denom ["/osmosis.incentives.Query/ActiveGaugesPerDenom"]: ActiveGaugesPerDenomRequest => ActiveGaugesPerDenomResponse
+    }
+
+    fn_query! {
+        pub query_upcoming_gauges ["/osmosis.incentives.Query/UpcomingGauges"]: UpcomingGaugesRequest => UpcomingGaugesResponse
+    }
+
+    fn_query! {
+        pub query_upcoming_gauges_per_denom ["/osmosis.incentives.Query/UpcomingGaugesPerDenom"]: UpcomingGaugesPerDenomRequest => UpcomingGaugesPerDenomResponse
+    }
+
+    fn_query! {
+        pub query_rewards_est ["/osmosis.incentives.Query/RewardsEst"]: RewardsEstRequest => RewardsEstResponse
+    }
+
+    fn_query! {
+        pub query_lockable_durations ["/osmosis.incentives.Query/LockableDurations"]: QueryLockableDurationsRequest => QueryLockableDurationsResponse
+    }
+
+    /// Add `rewards` to the gauge with the given id.
+    pub fn add_rewards_to_gauge(
+        &self,
+        gauge_id: u64,
+        rewards: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgAddToGaugeResponse> {
+        self.add_to_gauge(
+            MsgAddToGauge {
+                owner: signer.address(),
+                gauge_id,
+                rewards: rewards.iter().cloned().map(Into::into).collect(),
+            },
+            signer,
+        )
+    }
+
+    pub fn query_gauge(&self, gauge_id: u64) -> RunnerResult<Gauge> {
+        self.query_gauge_by_id(&GaugeByIdRequest { id: gauge_id })?
+            .gauge
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("gauge with ID {} does not exist", gauge_id),
+            })
+    }
+}
+
+impl<'a> Incentives<'a, OsmosisTestApp> {
+    /// Create a non-perpetual gauge that starts at the current block time and pays `rewards`
+    /// over `num_epochs_paid_over` epochs to locks of `denom` with duration of at least
+    /// `min_lock_duration`. `min_lock_duration` must be one of the lockable durations.
+    /// Returns the id of the created gauge.
+    pub fn create_lock_duration_gauge(
+        &self,
+        denom: &str,
+        min_lock_duration: std::time::Duration,
+        rewards: &[Coin],
+        num_epochs_paid_over: u64,
+        signer: &SigningAccount,
+    ) -> RunnerResult<u64> {
+        let now = self.runner.get_block_timestamp();
+        let res = self.create_gauge(
+            MsgCreateGauge {
+                is_perpetual: false,
+                owner: signer.address(),
+                distribute_to: Some(QueryCondition {
+                    lock_query_type: LockQueryType::ByDuration.into(),
+                    denom: denom.to_string(),
+                    duration: Some(Duration {
+                        seconds: min_lock_duration.as_secs() as i64,
+                        nanos: min_lock_duration.subsec_nanos() as i32,
+                    }),
+                    timestamp: None,
+                }),
+                coins: rewards.iter().cloned().map(Into::into).collect(),
+                start_time: Some(timestamp_to_proto(&now)),
+                num_epochs_paid_over,
+            },
+            signer,
+        )?;
+
+        // `MsgCreateGaugeResponse` is empty, gauge id is only available in the emitted event
+        let gauge_id = res
+            .events
+            .iter()
+            .filter(|e| e.ty == "create_gauge")
+            .flat_map(|e| e.attributes.iter())
+            .find(|attr| attr.key == "gauge_id")
+            .ok_or_else(|| RunnerError::ExecuteError {
+                msg: "gauge id not found in `create_gauge` event".to_string(),
+            })?
+            .value
+            .parse()?;
+
+        Ok(gauge_id)
+    }
+
+    /// Skip to the next incentives distribution epoch and return the rewards
+    /// that `owner` received from the distribution.
+    ///
+    /// Rewards are paid straight to the lock owner's balance, so only distributions that
+    /// happen while this is running are counted; rewards distributed in blocks committed
+    /// earlier are already part of the owner's balance.
+    pub fn distribute_rewards(&self, owner: &str) -> RunnerResult<Vec<Coin>> {
+        let balances_before = self.query_balances(owner)?;
+        self.runner.skip_to_next_epoch(DISTR_EPOCH_IDENTIFIER)?;
+        let balances_after = self.query_balances(owner)?;
+
+        Ok(balances_after
+            .into_iter()
+            .filter_map(|after| {
+                let before = balances_before
+                    .iter()
+                    .find(|c| c.denom == after.denom)
+                    .map(|c| c.amount)
+                    .unwrap_or_default();
+
+                (after.amount > before).then(|| Coin {
+                    denom: after.denom,
+                    amount: after.amount - before,
+                })
+            })
+            .collect())
+    }
+
+    fn query_balances(&self, address: &str) -> RunnerResult<Vec<Coin>> {
+        let balances = Bank::new(self.runner)
+            .query_all_balances(&QueryAllBalancesRequest {
+                address: address.to_string(),
+                pagination: None,
+            })?
+            .balances;
+
+        Ok(proto_coins_to_coins(&balances))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmwasm_std::Coin;
+
+    use crate::module::utils::osmosis_coins_to_coins;
+    use crate::{Incentives, Lockup, Module, OsmosisTestApp};
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    #[test]
+    fn incentives_integration() {
+        let app = OsmosisTestApp::new();
+        let accs = app
+            .init_accounts(
+                &[
+                    Coin::new(1_000_000_000_000, "uatom"),
+                    Coin::new(1_000_000_000_000, "uosmo"),
+                ],
+                2,
+            )
+            .unwrap();
+        let gauge_creator = &accs[0];
+        let lock_owner = &accs[1];
+
+        let incentives = Incentives::new(&app);
+        let lockup = Lockup::new(&app);
+
+        lockup
+            .lock(&[Coin::new(1_000_000, "uosmo")], HOUR, lock_owner)
+            .unwrap();
+
+        // create gauge and top it up
+        let gauge_id = incentives
+            .create_lock_duration_gauge(
+                "uosmo",
+                HOUR,
+                &[Coin::new(1_000_000, "uatom")],
+                1,
+                gauge_creator,
+            )
+            .unwrap();
+        incentives
+            .add_rewards_to_gauge(gauge_id, &[Coin::new(1_000_000, "uatom")], gauge_creator)
+            .unwrap();
+
+        let gauge = incentives.query_gauge(gauge_id).unwrap();
+        assert_eq!(
+            osmosis_coins_to_coins(&gauge.coins),
+            vec![Coin::new(2_000_000, "uatom")]
+        );
+        assert_eq!(gauge.filled_epochs, 0);
+
+        // the only qualifying lock gets all of the rewards
+        let rewards = incentives
+            .distribute_rewards(&lock_owner.address())
+            .unwrap();
+        assert_eq!(rewards, vec![Coin::new(2_000_000, "uatom")]);
+
+        let gauge = incentives.query_gauge(gauge_id).unwrap();
+        assert_eq!(gauge.filled_epochs, 1);
+        assert_eq!(
+            osmosis_coins_to_coins(&gauge.distributed_coins),
+            vec![Coin::new(2_000_000, "uatom")]
+        );
+
+        // nothing is left to distribute
+        let rewards = incentives
+            .distribute_rewards(&lock_owner.address())
+            .unwrap();
+        assert!(rewards.is_empty());
+    }
+}
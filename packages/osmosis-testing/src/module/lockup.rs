@@ -0,0 +1,281 @@
+use cosmwasm_std::Coin;
+use osmosis_std::shim::Duration;
+use osmosis_std::types::osmosis::lockup::{
+    AccountLockedCoinsRequest, AccountLockedCoinsResponse, AccountLockedLongerDurationRequest,
+    AccountLockedLongerDurationResponse, AccountUnlockableCoinsRequest,
+    AccountUnlockableCoinsResponse, AccountUnlockingCoinsRequest, AccountUnlockingCoinsResponse,
+    LockedRequest, LockedResponse, ModuleBalanceRequest, ModuleBalanceResponse, MsgBeginUnlocking,
+    MsgBeginUnlockingAll, MsgBeginUnlockingResponse, MsgExtendLockup, MsgForceUnlock,
+    MsgLockTokens, MsgLockTokensResponse, PeriodLock, QueryParamsRequest, QueryParamsResponse,
+};
+
+use crate::module::Module;
+use crate::runner::error::RunnerError;
+use crate::runner::result::{RunnerExecuteResult, RunnerResult};
+use crate::{
+    account::{Account, SigningAccount},
+    runner::Runner,
+};
+use crate::{fn_execute, fn_query, OsmosisTestApp};
+
+pub struct Lockup<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Lockup<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Lockup<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub lock_tokens: MsgLockTokens
+    }
+
+    fn_execute! {
+        pub begin_unlocking_all: MsgBeginUnlockingAll
+    }
+
+    fn_execute! {
+        pub begin_unlocking: MsgBeginUnlocking
+    }
+
+    fn_execute! {
+        pub extend_lockup: MsgExtendLockup
+    }
+
+    fn_execute! {
+        pub force_unlock: MsgForceUnlock
+    }
+
+    fn_query! {
+        pub query_module_balance ["/osmosis.lockup.Query/ModuleBalance"]: ModuleBalanceRequest => ModuleBalanceResponse
+    }
+
+    fn_query! {
+        pub query_account_unlockable_coins ["/osmosis.lockup.Query/AccountUnlockableCoins"]: AccountUnlockableCoinsRequest => AccountUnlockableCoinsResponse
+    }
+
+    fn_query! {
+        pub query_account_unlocking_coins ["/osmosis.lockup.Query/AccountUnlockingCoins"]: AccountUnlockingCoinsRequest => AccountUnlockingCoinsResponse
+    }
+
+    fn_query! {
+        pub query_account_locked_coins ["/osmosis.lockup.Query/AccountLockedCoins"]: AccountLockedCoinsRequest => AccountLockedCoinsResponse
+    }
+
+    fn_query! {
+        pub query_account_locked_longer_duration ["/osmosis.lockup.Query/AccountLockedLongerDuration"]: AccountLockedLongerDurationRequest => AccountLockedLongerDurationResponse
+    }
+
+    fn_query! {
+        pub query_locked_by_id ["/osmosis.lockup.Query/LockedByID"]: LockedRequest => LockedResponse
+    }
+
+    fn_query! {
+        pub query_params ["/osmosis.lockup.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    /// Lock `coins` for `duration`.
+    pub fn lock(
+        &self,
+        coins: &[Coin],
+        duration: std::time::Duration,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgLockTokensResponse> {
+        self.lock_tokens(
+            MsgLockTokens {
+                owner: signer.address(),
+                duration: Some(Duration {
+                    seconds: duration.as_secs() as i64,
+                    nanos: duration.subsec_nanos() as i32,
+                }),
+                coins: coins.iter().cloned().map(Into::into).collect(),
+            },
+            signer,
+        )
+    }
+
+    /// Begin unlocking `coins` from the lock with the given id.
+    /// Empty `coins` means unlocking every coin in the lock.
+    pub fn begin_unlocking_by_id(
+        &self,
+        lock_id: u64,
+        coins: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgBeginUnlockingResponse> {
+        self.begin_unlocking(
+            MsgBeginUnlocking {
+                owner: signer.address(),
+                id: lock_id,
+                coins: coins.iter().cloned().map(Into::into).collect(),
+            },
+            signer,
+        )
+    }
+
+    /// Query every lock owned by `owner`, including the ones that are unlocking.
+    pub fn query_locks_by_owner(&self, owner: &str) -> RunnerResult<Vec<PeriodLock>> {
+        Ok(self
+            .query_account_locked_longer_duration(&AccountLockedLongerDurationRequest {
+                owner: owner.to_string(),
+                duration: Some(Duration {
+                    seconds: 0,
+                    nanos: 0,
+                }),
+            })?
+            .locks)
+    }
+
+    pub fn query_lock(&self, lock_id: u64) -> RunnerResult<PeriodLock> {
+        self.query_locked_by_id(&LockedRequest { lock_id })?
+            .lock
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("lock with ID {} does not exist", lock_id),
+            })
+    }
+}
+
+impl<'a> Lockup<'a, OsmosisTestApp> {
+    /// Time left until the lock with the given id matures.
+    /// Lock that has not started unlocking yet will take its full duration to mature.
+    pub fn time_to_maturity(&self, lock_id: u64) -> RunnerResult<std::time::Duration> {
+        let lock = self.query_lock(lock_id)?;
+
+        let end_time = lock.end_time.unwrap_or_default();
+        let end_time_nanos = end_time.seconds as i128 * 1_000_000_000 + end_time.nanos as i128;
+
+        // lock that is not unlocking has zero time (`0001-01-01T00:00:00Z`) as its end time
+        if end_time_nanos <= 0 {
+            let duration = lock.duration.unwrap_or_default();
+            return Ok(std::time::Duration::new(
+                duration.seconds as u64,
+                duration.nanos as u32,
+            ));
+        }
+
+        let now_nanos = self.runner.get_block_time_nanos() as i128;
+        let remaining_nanos = (end_time_nanos - now_nanos).max(0);
+
+        Ok(std::time::Duration::from_nanos(remaining_nanos as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest;
+    use cosmwasm_std::Coin;
+    use osmosis_std::types::osmosis::lockup::{AccountLockedCoinsRequest, MsgForceUnlock};
+
+    use crate::{Account, Bank, Lockup, Module, OsmosisTestApp};
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    #[test]
+    fn lockup_integration() {
+        let app = OsmosisTestApp::new();
+        let owner = app
+            .init_account(&[Coin::new(1_000_000_000_000, "uosmo")])
+            .unwrap();
+        let lockup = Lockup::new(&app);
+        let bank = Bank::new(&app);
+
+        // lock
+        let coins = [Coin::new(1_000_000, "uosmo")];
+        let lock_id = lockup.lock(&coins, DAY, &owner).unwrap().data.id;
+
+        let locks = lockup.query_locks_by_owner(&owner.address()).unwrap();
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].id, lock_id);
+        assert_eq!(locks[0].owner, owner.address());
+        assert_eq!(locks[0].coins, vec![coins[0].clone().into()]);
+
+        let locked_coins = lockup
+            .query_account_locked_coins(&AccountLockedCoinsRequest {
+                owner: owner.address(),
+            })
+            .unwrap()
+            .coins;
+        assert_eq!(locked_coins, vec![coins[0].clone().into()]);
+
+        // lock does not mature until it starts unlocking
+        assert_eq!(lockup.time_to_maturity(lock_id).unwrap(), DAY);
+        app.increase_time(DAY.as_secs() * 2).unwrap();
+        assert_eq!(lockup.time_to_maturity(lock_id).unwrap(), DAY);
+
+        // begin unlocking
+        lockup.begin_unlocking_by_id(lock_id, &[], &owner).unwrap();
+        assert_eq!(lockup.time_to_maturity(lock_id).unwrap(), DAY);
+
+        app.increase_time(3600).unwrap();
+        assert_eq!(
+            lockup.time_to_maturity(lock_id).unwrap(),
+            DAY - Duration::from_secs(3600)
+        );
+
+        // matured lock is removed and its coins are returned to the owner
+        let balance_before = bank
+            .query_balance(&QueryBalanceRequest {
+                address: owner.address(),
+                denom: "uosmo".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse::<u128>()
+            .unwrap();
+
+        app.increase_time(DAY.as_secs()).unwrap();
+        assert!(lockup.query_lock(lock_id).is_err());
+        assert!(lockup
+            .query_locks_by_owner(&owner.address())
+            .unwrap()
+            .is_empty());
+
+        let balance_after = bank
+            .query_balance(&QueryBalanceRequest {
+                address: owner.address(),
+                denom: "uosmo".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse::<u128>()
+            .unwrap();
+        assert_eq!(balance_after - balance_before, coins[0].amount.u128());
+    }
+
+    #[test]
+    fn lockup_force_unlock() {
+        let app = OsmosisTestApp::new();
+        let owner = app
+            .init_account(&[Coin::new(1_000_000_000_000, "uosmo")])
+            .unwrap();
+        let lockup = Lockup::new(&app);
+
+        let coins = [Coin::new(1_000_000, "uosmo")];
+        let lock_id = lockup.lock(&coins, DAY, &owner).unwrap().data.id;
+
+        let force_unlock = MsgForceUnlock {
+            owner: owner.address(),
+            id: lock_id,
+            coins: vec![],
+        };
+
+        // only whitelisted address can force unlock
+        assert!(lockup.force_unlock(force_unlock.clone(), &owner).is_err());
+
+        app.whitelist_address_for_force_unlock(&owner.address());
+        lockup.force_unlock(force_unlock, &owner).unwrap();
+
+        assert!(lockup.query_lock(lock_id).is_err());
+    }
+}
@@ -36,6 +36,28 @@ macro_rules! fn_execute {
             self.runner.execute(msg, <$req>::TYPE_URL, signer)
         }
     };
+    (pub $name:ident: $req:ty) => {
+        pub fn $name(
+            &self,
+            msg: $req,
+            signer: &$crate::SigningAccount,
+        ) -> $crate::RunnerExecuteResult<
+            <$req as $crate::osmosis_std::msg::MsgWithResponse>::Response,
+        > {
+            self.runner.execute(msg, <$req>::TYPE_URL, signer)
+        }
+    };
+    ($name:ident: $req:ty) => {
+        pub fn $name(
+            &self,
+            msg: $req,
+            signer: &$crate::SigningAccount,
+        ) -> $crate::RunnerExecuteResult<
+            <$req as $crate::osmosis_std::msg::MsgWithResponse>::Response,
+        > {
+            self.runner.execute(msg, <$req>::TYPE_URL, signer)
+        }
+    };
 }
 
 #[macro_export]
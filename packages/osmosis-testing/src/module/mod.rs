@@ -1,8 +1,15 @@
 use crate::runner::Runner;
 
 mod bank;
+mod distribution;
 mod gamm;
+mod incentives;
+mod lockup;
+mod pool_incentives;
+mod staking;
+mod superfluid;
 mod tokenfactory;
+mod twap;
 pub mod utils;
 mod wasm;
 
@@ -10,8 +17,15 @@ mod wasm;
 pub mod macros;
 
 pub use bank::Bank;
+pub use distribution::Distribution;
 pub use gamm::Gamm;
+pub use incentives::Incentives;
+pub use lockup::Lockup;
+pub use pool_incentives::PoolIncentives;
+pub use staking::Staking;
+pub use superfluid::Superfluid;
 pub use tokenfactory::TokenFactory;
+pub use twap::{ScheduledSwap, Twap};
 pub use wasm::Wasm;
 
 pub trait Module<'a, R: Runner<'a>> {
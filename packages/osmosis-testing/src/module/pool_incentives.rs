@@ -0,0 +1,146 @@
+use osmosis_std::types::osmosis::poolincentives::v1beta1::{
+    QueryDistrInfoRequest, QueryDistrInfoResponse, QueryExternalIncentiveGaugesRequest,
+    QueryExternalIncentiveGaugesResponse, QueryGaugeIdsRequest, QueryGaugeIdsResponse,
+    QueryIncentivizedPoolsRequest, QueryIncentivizedPoolsResponse, QueryLockableDurationsRequest,
+    QueryLockableDurationsResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+use crate::fn_query;
+use crate::module::Module;
+use crate::runner::error::RunnerError;
+use crate::runner::result::RunnerResult;
+use crate::runner::Runner;
+
+pub struct PoolIncentives<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for PoolIncentives<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> PoolIncentives<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_query! {
+        pub query_gauge_ids ["/osmosis.poolincentives.v1beta1.Query/GaugeIds"]: QueryGaugeIdsRequest => QueryGaugeIdsResponse
+    }
+
+    fn_query! {
+        pub query_distr_info ["/osmosis.poolincentives.v1beta1.Query/DistrInfo"]: QueryDistrInfoRequest => QueryDistrInfoResponse
+    }
+
+    fn_query! {
+        pub query_params ["/osmosis.poolincentives.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_lockable_durations ["/osmosis.poolincentives.v1beta1.Query/LockableDurations"]: QueryLockableDurationsRequest => QueryLockableDurationsResponse
+    }
+
+    fn_query! {
+        pub query_incentivized_pools ["/osmosis.poolincentives.v1beta1.Query/IncentivizedPools"]: QueryIncentivizedPoolsRequest => QueryIncentivizedPoolsResponse
+    }
+
+    fn_query! {
+        pub query_external_incentive_gauges ["/osmosis.poolincentives.v1beta1.Query/ExternalIncentiveGauges"]: QueryExternalIncentiveGaugesRequest => QueryExternalIncentiveGaugesResponse
+    }
+
+    /// Query id of the gauge that pool incentives for `pool_id` are paid to
+    /// locks with duration of at least `lockable_duration`.
+    pub fn query_gauge_id(
+        &self,
+        pool_id: u64,
+        lockable_duration: std::time::Duration,
+    ) -> RunnerResult<u64> {
+        self.query_gauge_ids(&QueryGaugeIdsRequest { pool_id })?
+            .gauge_ids_with_duration
+            .into_iter()
+            .find(|g| {
+                g.duration.as_ref().map_or(false, |d| {
+                    d.seconds == lockable_duration.as_secs() as i64
+                        && d.nanos == lockable_duration.subsec_nanos() as i32
+                })
+            })
+            .map(|g| g.gauge_id)
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!(
+                    "gauge for pool {} with lockable duration {:?} does not exist",
+                    pool_id, lockable_duration
+                ),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmwasm_std::Coin;
+    use osmosis_std::types::osmosis::poolincentives::v1beta1::{
+        QueryGaugeIdsRequest, QueryLockableDurationsRequest,
+    };
+
+    use crate::{Gamm, Incentives, Module, OsmosisTestApp, PoolIncentives};
+
+    #[test]
+    fn pool_incentives_integration() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+        let incentives = Incentives::new(&app);
+        let pool_incentives = PoolIncentives::new(&app);
+
+        let pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uatom"),
+                    Coin::new(1_000_000_000, "uosmo"),
+                ],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+
+        // a gauge is created for every lockable duration along with the pool
+        let lockable_durations = pool_incentives
+            .query_lockable_durations(&QueryLockableDurationsRequest {})
+            .unwrap()
+            .lockable_durations;
+        let gauge_ids = pool_incentives
+            .query_gauge_ids(&QueryGaugeIdsRequest { pool_id })
+            .unwrap()
+            .gauge_ids_with_duration;
+        assert_eq!(gauge_ids.len(), lockable_durations.len());
+
+        for lockable_duration in lockable_durations {
+            let lockable_duration = Duration::new(
+                lockable_duration.seconds as u64,
+                lockable_duration.nanos as u32,
+            );
+            let gauge_id = pool_incentives
+                .query_gauge_id(pool_id, lockable_duration)
+                .unwrap();
+
+            let distribute_to = incentives
+                .query_gauge(gauge_id)
+                .unwrap()
+                .distribute_to
+                .unwrap();
+            assert_eq!(distribute_to.denom, format!("gamm/pool/{}", pool_id));
+        }
+
+        assert!(pool_incentives
+            .query_gauge_id(pool_id, Duration::from_secs(1))
+            .is_err());
+    }
+}
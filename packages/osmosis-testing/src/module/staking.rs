@@ -0,0 +1,236 @@
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    MsgBeginRedelegate, MsgBeginRedelegateResponse, MsgDelegate, MsgDelegateResponse,
+    MsgUndelegate, MsgUndelegateResponse, QueryDelegationRequest, QueryDelegationResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryPoolRequest, QueryPoolResponse,
+    QueryRedelegationsRequest, QueryRedelegationsResponse, QueryUnbondingDelegationRequest,
+    QueryUnbondingDelegationResponse, QueryValidatorDelegationsRequest,
+    QueryValidatorDelegationsResponse, QueryValidatorRequest, QueryValidatorResponse,
+    QueryValidatorsRequest, QueryValidatorsResponse,
+};
+use cosmwasm_std::Coin;
+
+use crate::module::utils::coin_to_proto;
+use crate::module::Module;
+use crate::runner::result::RunnerExecuteResult;
+use crate::{
+    account::{Account, SigningAccount},
+    runner::Runner,
+};
+use crate::{fn_execute, fn_query};
+
+pub struct Staking<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Staking<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Staking<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub delegate: MsgDelegate["/cosmos.staking.v1beta1.MsgDelegate"] => MsgDelegateResponse
+    }
+
+    fn_execute! {
+        pub undelegate: MsgUndelegate["/cosmos.staking.v1beta1.MsgUndelegate"] => MsgUndelegateResponse
+    }
+
+    fn_execute! {
+        pub begin_redelegate: MsgBeginRedelegate["/cosmos.staking.v1beta1.MsgBeginRedelegate"] => MsgBeginRedelegateResponse
+    }
+
+    fn_query! {
+        pub query_validators ["/cosmos.staking.v1beta1.Query/Validators"]: QueryValidatorsRequest => QueryValidatorsResponse
+    }
+
+    fn_query! {
+        pub query_validator ["/cosmos.staking.v1beta1.Query/Validator"]: QueryValidatorRequest => QueryValidatorResponse
+    }
+
+    fn_query! {
+        pub query_validator_delegations ["/cosmos.staking.v1beta1.Query/ValidatorDelegations"]: QueryValidatorDelegationsRequest => QueryValidatorDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegation ["/cosmos.staking.v1beta1.Query/Delegation"]: QueryDelegationRequest => QueryDelegationResponse
+    }
+
+    fn_query! {
+        pub query_unbonding_delegation ["/cosmos.staking.v1beta1.Query/UnbondingDelegation"]: QueryUnbondingDelegationRequest => QueryUnbondingDelegationResponse
+    }
+
+    fn_query! {
+        pub query_delegator_delegations ["/cosmos.staking.v1beta1.Query/DelegatorDelegations"]: QueryDelegatorDelegationsRequest => QueryDelegatorDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_unbonding_delegations ["/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations"]: QueryDelegatorUnbondingDelegationsRequest => QueryDelegatorUnbondingDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_redelegations ["/cosmos.staking.v1beta1.Query/Redelegations"]: QueryRedelegationsRequest => QueryRedelegationsResponse
+    }
+
+    fn_query! {
+        pub query_pool ["/cosmos.staking.v1beta1.Query/Pool"]: QueryPoolRequest => QueryPoolResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.staking.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    /// Delegate `amount` to the validator with the given operator address.
+    pub fn delegate_to(
+        &self,
+        validator_address: &str,
+        amount: Coin,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgDelegateResponse> {
+        self.delegate(
+            MsgDelegate {
+                delegator_address: signer.address(),
+                validator_address: validator_address.to_string(),
+                amount: Some(coin_to_proto(&amount)),
+            },
+            signer,
+        )
+    }
+
+    /// Undelegate `amount` from the validator with the given operator address.
+    pub fn undelegate_from(
+        &self,
+        validator_address: &str,
+        amount: Coin,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgUndelegateResponse> {
+        self.undelegate(
+            MsgUndelegate {
+                delegator_address: signer.address(),
+                validator_address: validator_address.to_string(),
+                amount: Some(coin_to_proto(&amount)),
+            },
+            signer,
+        )
+    }
+
+    /// Move `amount` of delegation from `src_validator_address` to `dst_validator_address`.
+    pub fn redelegate(
+        &self,
+        src_validator_address: &str,
+        dst_validator_address: &str,
+        amount: Coin,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgBeginRedelegateResponse> {
+        self.begin_redelegate(
+            MsgBeginRedelegate {
+                delegator_address: signer.address(),
+                validator_src_address: src_validator_address.to_string(),
+                validator_dst_address: dst_validator_address.to_string(),
+                amount: Some(coin_to_proto(&amount)),
+            },
+            signer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::staking::v1beta1::{
+        QueryDelegationRequest, QueryDelegatorDelegationsRequest, QueryUnbondingDelegationRequest,
+        QueryValidatorsRequest,
+    };
+    use cosmwasm_std::Coin;
+
+    use crate::{Account, Module, OsmosisTestApp, Staking};
+
+    #[test]
+    fn staking_integration() {
+        let app = OsmosisTestApp::new();
+        let delegator = app
+            .init_account(&[Coin::new(1_000_000_000_000, "uosmo")])
+            .unwrap();
+        let staking = Staking::new(&app);
+
+        let genesis_validator = app.get_first_validator_address().unwrap();
+        let new_validator = app.add_validator().unwrap();
+        assert_ne!(genesis_validator, new_validator);
+        assert_eq!(
+            app.get_validator_addresses().unwrap(),
+            vec![genesis_validator.clone(), new_validator.clone()]
+        );
+
+        let validators = staking
+            .query_validators(&QueryValidatorsRequest {
+                status: "".to_string(),
+                pagination: None,
+            })
+            .unwrap()
+            .validators;
+        assert_eq!(validators.len(), 2);
+
+        // delegate
+        staking
+            .delegate_to(
+                &genesis_validator,
+                Coin::new(1_000_000, "uosmo"),
+                &delegator,
+            )
+            .unwrap();
+
+        let delegation = staking
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: delegator.address(),
+                validator_addr: genesis_validator.clone(),
+            })
+            .unwrap()
+            .delegation_response
+            .unwrap();
+        assert_eq!(delegation.balance.unwrap().amount, "1000000");
+
+        // redelegate
+        staking
+            .redelegate(
+                &genesis_validator,
+                &new_validator,
+                Coin::new(400_000, "uosmo"),
+                &delegator,
+            )
+            .unwrap();
+
+        let delegations = staking
+            .query_delegator_delegations(&QueryDelegatorDelegationsRequest {
+                delegator_addr: delegator.address(),
+                pagination: None,
+            })
+            .unwrap()
+            .delegation_responses;
+        assert_eq!(delegations.len(), 2);
+
+        // undelegate
+        let completion_time = staking
+            .undelegate_from(&new_validator, Coin::new(100_000, "uosmo"), &delegator)
+            .unwrap()
+            .data
+            .completion_time;
+        assert!(completion_time.is_some());
+
+        let entries = staking
+            .query_unbonding_delegation(&QueryUnbondingDelegationRequest {
+                delegator_addr: delegator.address(),
+                validator_addr: new_validator.clone(),
+            })
+            .unwrap()
+            .unbond
+            .unwrap()
+            .entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].balance, "100000");
+    }
+}
@@ -0,0 +1,177 @@
+use osmosis_std::types::osmosis::superfluid::{
+    AllAssetsRequest, AllAssetsResponse, AllIntermediaryAccountsRequest,
+    AllIntermediaryAccountsResponse, AssetMultiplierRequest, AssetMultiplierResponse,
+    AssetTypeRequest, AssetTypeResponse, ConnectedIntermediaryAccountRequest,
+    ConnectedIntermediaryAccountResponse, MsgLockAndSuperfluidDelegate, MsgSuperfluidDelegate,
+    MsgSuperfluidUnbondLock, MsgSuperfluidUndelegate, MsgUnPoolWhitelistedPool, QueryParamsRequest,
+    QueryParamsResponse, SuperfluidDelegationAmountRequest, SuperfluidDelegationAmountResponse,
+    SuperfluidDelegationsByDelegatorRequest, SuperfluidDelegationsByDelegatorResponse,
+    SuperfluidDelegationsByValidatorDenomRequest, SuperfluidDelegationsByValidatorDenomResponse,
+    SuperfluidUndelegationsByDelegatorRequest, SuperfluidUndelegationsByDelegatorResponse,
+    TotalSuperfluidDelegationsRequest, TotalSuperfluidDelegationsResponse,
+};
+
+use crate::module::Module;
+use crate::runner::Runner;
+use crate::{fn_execute, fn_query};
+
+pub struct Superfluid<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Superfluid<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Superfluid<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub superfluid_delegate: MsgSuperfluidDelegate
+    }
+
+    fn_execute! {
+        pub superfluid_undelegate: MsgSuperfluidUndelegate
+    }
+
+    fn_execute! {
+        pub superfluid_unbond_lock: MsgSuperfluidUnbondLock
+    }
+
+    fn_execute! {
+        pub lock_and_superfluid_delegate: MsgLockAndSuperfluidDelegate
+    }
+
+    fn_execute! {
+        pub unpool_whitelisted_pool: MsgUnPoolWhitelistedPool
+    }
+
+    fn_query! {
+        pub query_params ["/osmosis.superfluid.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_asset_type ["/osmosis.superfluid.Query/AssetType"]: AssetTypeRequest => AssetTypeResponse
+    }
+
+    fn_query! {
+        pub query_all_assets ["/osmosis.superfluid.Query/AllAssets"]: AllAssetsRequest => AllAssetsResponse
+    }
+
+    fn_query! {
+        pub query_asset_multiplier ["/osmosis.superfluid.Query/AssetMultiplier"]: AssetMultiplierRequest => AssetMultiplierResponse
+    }
+
+    fn_query! {
+        pub query_all_intermediary_accounts ["/osmosis.superfluid.Query/AllIntermediaryAccounts"]: AllIntermediaryAccountsRequest => AllIntermediaryAccountsResponse
+    }
+
+    fn_query! {
+        pub query_connected_intermediary_account ["/osmosis.superfluid.Query/ConnectedIntermediaryAccount"]: ConnectedIntermediaryAccountRequest => ConnectedIntermediaryAccountResponse
+    }
+
+    fn_query! {
+        pub query_total_superfluid_delegations ["/osmosis.superfluid.Query/TotalSuperfluidDelegations"]: TotalSuperfluidDelegationsRequest => TotalSuperfluidDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_superfluid_delegation_amount ["/osmosis.superfluid.Query/SuperfluidDelegationAmount"]: SuperfluidDelegationAmountRequest => SuperfluidDelegationAmountResponse
+    }
+
+    fn_query! {
+        pub query_superfluid_delegations_by_delegator ["/osmosis.superfluid.Query/SuperfluidDelegationsByDelegator"]: SuperfluidDelegationsByDelegatorRequest => SuperfluidDelegationsByDelegatorResponse
+    }
+
+    fn_query! {
+        pub query_superfluid_undelegations_by_delegator ["/osmosis.superfluid.Query/SuperfluidUndelegationsByDelegator"]: SuperfluidUndelegationsByDelegatorRequest => SuperfluidUndelegationsByDelegatorResponse
+    }
+
+    fn_query! {
+        pub query_superfluid_delegations_by_validator_denom ["/osmosis.superfluid.Query/SuperfluidDelegationsByValidatorDenom"]: SuperfluidDelegationsByValidatorDenomRequest => SuperfluidDelegationsByValidatorDenomResponse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Coin;
+    use osmosis_std::types::osmosis::superfluid::{
+        AllAssetsRequest, ConnectedIntermediaryAccountRequest, MsgLockAndSuperfluidDelegate,
+        SuperfluidAssetType, SuperfluidDelegationsByDelegatorRequest,
+    };
+
+    use crate::{Account, Gamm, Module, OsmosisTestApp, Superfluid};
+
+    #[test]
+    fn superfluid_integration() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+        let superfluid = Superfluid::new(&app);
+
+        let pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uatom"),
+                    Coin::new(1_000_000_000, "uosmo"),
+                ],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+        let share_denom = format!("gamm/pool/{}", pool_id);
+
+        // register pool share as superfluid asset
+        app.add_superfluid_lp_share(&share_denom).unwrap();
+
+        let assets = superfluid
+            .query_all_assets(&AllAssetsRequest {})
+            .unwrap()
+            .assets;
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].denom, share_denom);
+        assert_eq!(assets[0].asset_type, SuperfluidAssetType::LpShare as i32);
+
+        // lock and superfluid delegate to the validator set up along with the chain
+        let val_addr = app.get_first_validator_address().unwrap();
+        assert!(val_addr.starts_with("osmovaloper"));
+
+        let lock_id = superfluid
+            .lock_and_superfluid_delegate(
+                MsgLockAndSuperfluidDelegate {
+                    sender: signer.address(),
+                    coins: vec![Coin::new(1_000_000_000_000_000_000, &share_denom).into()],
+                    val_addr: val_addr.clone(),
+                },
+                &signer,
+            )
+            .unwrap()
+            .data
+            .id;
+
+        let records = superfluid
+            .query_superfluid_delegations_by_delegator(&SuperfluidDelegationsByDelegatorRequest {
+                delegator_address: signer.address(),
+            })
+            .unwrap()
+            .superfluid_delegation_records;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].validator_address, val_addr);
+
+        let intermediary_account = superfluid
+            .query_connected_intermediary_account(&ConnectedIntermediaryAccountRequest { lock_id })
+            .unwrap()
+            .account
+            .unwrap();
+        assert_eq!(intermediary_account.denom, share_denom);
+        assert_eq!(intermediary_account.val_addr, val_addr);
+    }
+}
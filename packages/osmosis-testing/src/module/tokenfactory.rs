@@ -1,9 +1,8 @@
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-    MsgBurn, MsgBurnResponse, MsgChangeAdmin, MsgChangeAdminResponse, MsgCreateDenom,
-    MsgCreateDenomResponse, MsgMint, MsgMintResponse, MsgSetDenomMetadata,
-    MsgSetDenomMetadataResponse, QueryDenomAuthorityMetadataRequest,
-    QueryDenomAuthorityMetadataResponse, QueryDenomsFromCreatorRequest,
-    QueryDenomsFromCreatorResponse, QueryParamsRequest, QueryParamsResponse,
+    MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+    QueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse,
+    QueryDenomsFromCreatorRequest, QueryDenomsFromCreatorResponse, QueryParamsRequest,
+    QueryParamsResponse,
 };
 
 use crate::module::Module;
@@ -25,23 +24,23 @@ where
     R: Runner<'a>,
 {
     fn_execute! {
-        pub create_denom: MsgCreateDenom => MsgCreateDenomResponse
+        pub create_denom: MsgCreateDenom
     }
 
     fn_execute! {
-        pub mint: MsgMint => MsgMintResponse
+        pub mint: MsgMint
     }
 
     fn_execute! {
-        pub burn: MsgBurn => MsgBurnResponse
+        pub burn: MsgBurn
     }
 
     fn_execute! {
-        pub change_admin: MsgChangeAdmin => MsgChangeAdminResponse
+        pub change_admin: MsgChangeAdmin
     }
 
     fn_execute! {
-        pub set_denom_metadata: MsgSetDenomMetadata => MsgSetDenomMetadataResponse
+        pub set_denom_metadata: MsgSetDenomMetadata
     }
 
     fn_query! {
@@ -0,0 +1,222 @@
+use cosmwasm_std::{Coin, Decimal, Timestamp, Uint128};
+use osmosis_std::types::osmosis::gamm::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute};
+use osmosis_std::types::osmosis::twap::{v1beta1, v2};
+
+use crate::module::utils::timestamp_to_proto;
+use crate::module::Module;
+use crate::runner::error::RunnerError;
+use crate::runner::result::RunnerResult;
+use crate::{
+    account::{Account, SigningAccount},
+    runner::Runner,
+};
+use crate::{fn_query, Gamm, OsmosisTestApp};
+
+pub struct Twap<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Twap<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+/// Swap to be executed by [`Twap::replay_swaps`] at `offset` after the replay starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledSwap {
+    pub offset: std::time::Duration,
+    pub token_in: Coin,
+    pub token_out_denom: String,
+}
+
+impl<'a, R> Twap<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_query! {
+        pub query_arithmetic_twap ["/osmosis.twap.v1beta1.Query/ArithmeticTwap"]: v1beta1::ArithmeticTwapRequest => v1beta1::ArithmeticTwapResponse
+    }
+
+    fn_query! {
+        pub query_arithmetic_twap_to_now ["/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow"]: v1beta1::ArithmeticTwapToNowRequest => v1beta1::ArithmeticTwapToNowResponse
+    }
+
+    fn_query! {
+        pub query_params ["/osmosis.twap.v1beta1.Query/Params"]: v1beta1::ParamsRequest => v1beta1::ParamsResponse
+    }
+
+    fn_query! {
+        pub query_arithmetic_twap_v2 ["/osmosis.twap.v2.Query/ArithmeticTwap"]: v2::ArithmeticTwapRequest => v2::ArithmeticTwapResponse
+    }
+
+    fn_query! {
+        pub query_arithmetic_twap_to_now_v2 ["/osmosis.twap.v2.Query/ArithmeticTwapToNow"]: v2::ArithmeticTwapToNowRequest => v2::ArithmeticTwapToNowResponse
+    }
+
+    /// Arithmetic TWAP of `base_asset` priced in `quote_asset` over `[start_time, end_time]`.
+    pub fn arithmetic_twap(
+        &self,
+        pool_id: u64,
+        base_asset: &str,
+        quote_asset: &str,
+        start_time: Timestamp,
+        end_time: Timestamp,
+    ) -> RunnerResult<Decimal> {
+        let twap = self
+            .query_arithmetic_twap(&v1beta1::ArithmeticTwapRequest {
+                pool_id,
+                base_asset: base_asset.to_string(),
+                quote_asset: quote_asset.to_string(),
+                start_time: Some(timestamp_to_proto(&start_time)),
+                end_time: Some(timestamp_to_proto(&end_time)),
+            })?
+            .arithmetic_twap;
+
+        Ok(Decimal::try_from(twap)?)
+    }
+}
+
+impl<'a> Twap<'a, OsmosisTestApp> {
+    /// Replay `schedule` against the pool with the given id, starting at the current block time.
+    /// Each swap is executed by `signer` in its own block, with the block time set to
+    /// the start time plus the swap's `offset`, so the resulting price history does not depend
+    /// on how many blocks have been committed before. Offsets must be non-decreasing.
+    ///
+    /// Returns the time the replay started at, which offsets are relative to.
+    pub fn replay_swaps(
+        &self,
+        pool_id: u64,
+        schedule: &[ScheduledSwap],
+        signer: &SigningAccount,
+    ) -> RunnerResult<Timestamp> {
+        let gamm = Gamm::new(self.runner);
+        let start_time = self.runner.get_block_timestamp();
+
+        for swap in schedule {
+            let offset_nanos =
+                u64::try_from(swap.offset.as_nanos()).map_err(|_| RunnerError::ExecuteError {
+                    msg: format!("swap offset is too large: {:?}", swap.offset),
+                })?;
+            self.runner
+                .begin_block_at(start_time.plus_nanos(offset_nanos))?;
+
+            let res = gamm.swap_exact_amount_in(
+                MsgSwapExactAmountIn {
+                    sender: signer.address(),
+                    routes: vec![SwapAmountInRoute {
+                        pool_id,
+                        token_out_denom: swap.token_out_denom.clone(),
+                    }],
+                    token_in: Some(swap.token_in.clone().into()),
+                    token_out_min_amount: Uint128::one().into(),
+                },
+                signer,
+            );
+
+            // end the block before bailing out, so that the app is left usable
+            self.runner.end_block()?;
+            res?;
+        }
+
+        Ok(start_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmwasm_std::{Coin, Decimal};
+
+    use crate::{Gamm, Module, OsmosisTestApp, ScheduledSwap, Twap};
+
+    #[test]
+    fn twap_integration() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ])
+            .unwrap();
+        let gamm = Gamm::new(&app);
+        let twap = Twap::new(&app);
+
+        let pool_id = gamm
+            .create_basic_pool(
+                &[
+                    Coin::new(1_000_000_000, "uatom"),
+                    Coin::new(1_000_000_000, "uosmo"),
+                ],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .pool_id;
+
+        let schedule = [
+            ScheduledSwap {
+                offset: Duration::from_secs(10),
+                token_in: Coin::new(100_000_000, "uatom"),
+                token_out_denom: "uosmo".to_string(),
+            },
+            ScheduledSwap {
+                offset: Duration::from_secs(20),
+                token_in: Coin::new(100_000_000, "uosmo"),
+                token_out_denom: "uatom".to_string(),
+            },
+        ];
+        let start = twap.replay_swaps(pool_id, &schedule, &signer).unwrap();
+
+        assert_eq!(app.get_block_timestamp(), start.plus_seconds(20));
+
+        // price does not move until the first swap
+        let before_first_swap = twap
+            .arithmetic_twap(pool_id, "uatom", "uosmo", start, start.plus_seconds(10))
+            .unwrap();
+        assert_eq!(before_first_swap, Decimal::one());
+
+        // selling uatom makes it cheaper until the next swap
+        let after_first_swap = twap
+            .arithmetic_twap(
+                pool_id,
+                "uatom",
+                "uosmo",
+                start.plus_seconds(10),
+                start.plus_seconds(20),
+            )
+            .unwrap();
+        assert!(after_first_swap < Decimal::one());
+
+        // twap over both segments is weighted by how long each price lasted
+        let whole = twap
+            .arithmetic_twap(pool_id, "uatom", "uosmo", start, start.plus_seconds(20))
+            .unwrap();
+        let expected = (before_first_swap + after_first_swap) * Decimal::percent(50);
+        let diff = if whole > expected {
+            whole - expected
+        } else {
+            expected - whole
+        };
+        assert!(diff <= Decimal::raw(1));
+
+        // replaying again continues from the current block time
+        let next_start = twap.replay_swaps(pool_id, &schedule, &signer).unwrap();
+        assert_eq!(next_start, start.plus_seconds(20));
+
+        // swaps cannot be scheduled in the past
+        let backward = [
+            ScheduledSwap {
+                offset: Duration::from_secs(10),
+                ..schedule[0].clone()
+            },
+            ScheduledSwap {
+                offset: Duration::from_secs(5),
+                ..schedule[1].clone()
+            },
+        ];
+        assert!(twap.replay_swaps(pool_id, &backward, &signer).is_err());
+        assert!(!app.is_block_in_progress());
+    }
+}
@@ -1,24 +1,37 @@
 use cosmrs::proto::{
-    cosmos::bank::v1beta1::MsgSend,
+    cosmos::{
+        bank::v1beta1::MsgSend,
+        distribution::v1beta1::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward},
+        gov::v1beta1::{MsgVote, VoteOption as ProtoVoteOption},
+        staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
+    },
     cosmwasm::wasm::v1::{
         MsgClearAdmin, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
         MsgUpdateAdmin,
     },
+    ibc::{applications::transfer::v1::MsgTransfer, core::client::v1::Height},
+};
+use cosmwasm_std::{
+    BankMsg, Coin, Decimal, DistributionMsg, GovMsg, IbcMsg, StakingMsg, StdResult, VoteOption,
+    WasmMsg,
 };
-use cosmwasm_std::{BankMsg, Coin, StdResult, WasmMsg};
 use itertools::Itertools;
 use prost::Message;
 
 use crate::{Account, EncodeError, RunnerError, SigningAccount};
 
+pub fn coin_to_proto(coin: &Coin) -> cosmrs::proto::cosmos::base::v1beta1::Coin {
+    cosmrs::proto::cosmos::base::v1beta1::Coin {
+        denom: coin.denom.parse().unwrap(),
+        amount: format!("{}", coin.amount.u128()),
+    }
+}
+
 pub fn coins_to_proto(coins: &[Coin]) -> Vec<cosmrs::proto::cosmos::base::v1beta1::Coin> {
     coins
         .iter()
         .sorted_by(|a, b| a.denom.cmp(&b.denom))
-        .map(|c| cosmrs::proto::cosmos::base::v1beta1::Coin {
-            denom: c.denom.parse().unwrap(),
-            amount: format!("{}", c.amount.u128()),
-        })
+        .map(coin_to_proto)
         .collect()
 }
 
@@ -52,6 +65,19 @@ pub fn osmosis_coins_to_coins(
         .unwrap()
 }
 
+pub fn timestamp_to_proto(timestamp: &cosmwasm_std::Timestamp) -> osmosis_std::shim::Timestamp {
+    osmosis_std::shim::Timestamp {
+        seconds: timestamp.seconds() as i64,
+        nanos: timestamp.subsec_nanos() as i32,
+    }
+}
+
+/// Parse an `sdk.Dec` as it is encoded in protobuf, which is the decimal value
+/// scaled by 10^18 and written out as an integer, e.g. "10000000000000000" for 0.01.
+pub fn proto_dec_to_decimal(dec: &str) -> Result<Decimal, RunnerError> {
+    Ok(Decimal::raw(dec.parse()?))
+}
+
 pub fn msg_to_any<T: Message>(type_url: &str, msg: &T) -> Result<cosmrs::Any, RunnerError> {
     let mut buf = Vec::new();
     msg.encode(&mut buf)
@@ -74,9 +100,140 @@ pub fn bank_msg_to_any(msg: &BankMsg, signer: &SigningAccount) -> Result<cosmrs:
             };
             msg_to_any(type_url, &msg)
         }
-        _ => {
-            todo!() // TODO: Can't find BurnMsg...?
+        // burning is done by wasmd's own message handler, there is no sdk msg for it
+        BankMsg::Burn { .. } => Err(RunnerError::UnsupportedMsg {
+            msg: "BankMsg::Burn can only be dispatched by a contract".to_string(),
+        }),
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
+        }),
+    }
+}
+
+pub fn staking_msg_to_any(
+    msg: &StakingMsg,
+    signer: &SigningAccount,
+) -> Result<cosmrs::Any, RunnerError> {
+    match msg {
+        StakingMsg::Delegate { validator, amount } => msg_to_any(
+            "/cosmos.staking.v1beta1.MsgDelegate",
+            &MsgDelegate {
+                delegator_address: signer.address(),
+                validator_address: validator.clone(),
+                amount: Some(coin_to_proto(amount)),
+            },
+        ),
+        StakingMsg::Undelegate { validator, amount } => msg_to_any(
+            "/cosmos.staking.v1beta1.MsgUndelegate",
+            &MsgUndelegate {
+                delegator_address: signer.address(),
+                validator_address: validator.clone(),
+                amount: Some(coin_to_proto(amount)),
+            },
+        ),
+        StakingMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount,
+        } => msg_to_any(
+            "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+            &MsgBeginRedelegate {
+                delegator_address: signer.address(),
+                validator_src_address: src_validator.clone(),
+                validator_dst_address: dst_validator.clone(),
+                amount: Some(coin_to_proto(amount)),
+            },
+        ),
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
+        }),
+    }
+}
+
+pub fn distribution_msg_to_any(
+    msg: &DistributionMsg,
+    signer: &SigningAccount,
+) -> Result<cosmrs::Any, RunnerError> {
+    match msg {
+        DistributionMsg::SetWithdrawAddress { address } => msg_to_any(
+            "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+            &MsgSetWithdrawAddress {
+                delegator_address: signer.address(),
+                withdraw_address: address.clone(),
+            },
+        ),
+        DistributionMsg::WithdrawDelegatorReward { validator } => msg_to_any(
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+            &MsgWithdrawDelegatorReward {
+                delegator_address: signer.address(),
+                validator_address: validator.clone(),
+            },
+        ),
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
+        }),
+    }
+}
+
+pub fn ibc_msg_to_any(msg: &IbcMsg, signer: &SigningAccount) -> Result<cosmrs::Any, RunnerError> {
+    match msg {
+        IbcMsg::Transfer {
+            channel_id,
+            to_address,
+            amount,
+            timeout,
+            ..
+        } => msg_to_any(
+            "/ibc.applications.transfer.v1.MsgTransfer",
+            &MsgTransfer {
+                source_port: "transfer".to_string(),
+                source_channel: channel_id.clone(),
+                token: Some(coin_to_proto(amount)),
+                sender: signer.address(),
+                receiver: to_address.clone(),
+                timeout_height: timeout.block().map(|block| Height {
+                    revision_number: block.revision,
+                    revision_height: block.height,
+                }),
+                timeout_timestamp: timeout.timestamp().map(|t| t.nanos()).unwrap_or_default(),
+            },
+        ),
+        // packets and channels are owned by the contract's port, not by an account
+        IbcMsg::SendPacket { .. } | IbcMsg::CloseChannel { .. } => {
+            Err(RunnerError::UnsupportedMsg {
+                msg: format!("{:?} can only be dispatched by a contract", msg),
+            })
         }
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
+        }),
+    }
+}
+
+pub fn gov_msg_to_any(msg: &GovMsg, signer: &SigningAccount) -> Result<cosmrs::Any, RunnerError> {
+    match msg {
+        GovMsg::Vote { proposal_id, vote } => msg_to_any(
+            "/cosmos.gov.v1beta1.MsgVote",
+            &MsgVote {
+                proposal_id: *proposal_id,
+                voter: signer.address(),
+                option: vote_option_to_proto(vote).into(),
+            },
+        ),
+        // `GovMsg::VoteWeighted` only exists when `cosmwasm_1_2` is enabled by another crate
+        #[allow(unreachable_patterns)]
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
+        }),
+    }
+}
+
+fn vote_option_to_proto(vote: &VoteOption) -> ProtoVoteOption {
+    match vote {
+        VoteOption::Yes => ProtoVoteOption::Yes,
+        VoteOption::No => ProtoVoteOption::No,
+        VoteOption::Abstain => ProtoVoteOption::Abstain,
+        VoteOption::NoWithVeto => ProtoVoteOption::NoWithVeto,
     }
 }
 
@@ -143,8 +300,8 @@ pub fn wasm_msg_to_any(msg: &WasmMsg, signer: &SigningAccount) -> Result<cosmrs:
                 sender: signer.address(),
             },
         ),
-        _ => Err(RunnerError::ExecuteError {
-            msg: "Unsupported WasmMsg".to_string(),
+        _ => Err(RunnerError::UnsupportedMsg {
+            msg: format!("{:?}", msg),
         }),
     }
 }
@@ -153,15 +310,22 @@ pub fn wasm_msg_to_any(msg: &WasmMsg, signer: &SigningAccount) -> Result<cosmrs:
 mod tests {
     use cosmrs::proto::{
         cosmos::bank::v1beta1::{MsgSendResponse, QueryBalanceRequest},
+        cosmos::distribution::v1beta1::MsgWithdrawDelegatorRewardResponse,
+        cosmos::gov::v1beta1::MsgVoteResponse,
+        cosmos::staking::v1beta1::{MsgDelegateResponse, QueryDelegationRequest},
         cosmwasm::wasm::v1::{MsgExecuteContractResponse, MsgInstantiateContractResponse},
+        ibc::applications::transfer::v1::MsgTransferResponse,
+    };
+    use cosmwasm_std::{
+        to_binary, BankMsg, Binary, Coin, CosmosMsg, DistributionMsg, Empty, Event, GovMsg, IbcMsg,
+        IbcTimeout, StakingMsg, Timestamp, VoteOption, WasmMsg,
     };
-    use cosmwasm_std::{to_binary, BankMsg, Coin, CosmosMsg, Empty, Event, WasmMsg};
     use cw1_whitelist::msg::{ExecuteMsg, InstantiateMsg};
     use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
         MsgCreateDenom, MsgCreateDenomResponse,
     };
 
-    use crate::{Account, Bank, Module, OsmosisTestApp, Runner, Wasm};
+    use crate::{Account, Bank, Module, OsmosisTestApp, Runner, RunnerError, Staking, Wasm};
 
     #[test]
     fn test_cosmos_msg() {
@@ -252,5 +416,80 @@ mod tests {
             create_denom_res.data.new_token_denom,
             format!("factory/{}/{}", signer.address(), denom.clone())
         );
+
+        // StakingMsg::Delegate
+        let validator = app.get_first_validator_address().unwrap();
+        let delegate_msg = CosmosMsg::Staking(StakingMsg::Delegate {
+            validator: validator.clone(),
+            amount: Coin::new(1_000_000, "uosmo"),
+        });
+        app.execute_cosmos_msgs::<MsgDelegateResponse>(&[delegate_msg], &signer)
+            .unwrap();
+        let delegation = Staking::new(&app)
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: signer.address(),
+                validator_addr: validator.clone(),
+            })
+            .unwrap()
+            .delegation_response
+            .unwrap();
+        assert_eq!(delegation.balance.unwrap().amount, "1000000".to_string());
+
+        // DistributionMsg::WithdrawDelegatorReward
+        let withdraw_msg =
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward { validator });
+        app.execute_cosmos_msgs::<MsgWithdrawDelegatorRewardResponse>(&[withdraw_msg], &signer)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_unsupported_cosmos_msg() {
+        let app = OsmosisTestApp::new();
+        let signer = app
+            .init_account(&[Coin::new(10000000000, "uosmo")])
+            .unwrap();
+
+        let unsupported_msgs: Vec<CosmosMsg> = vec![
+            CosmosMsg::Bank(BankMsg::Burn {
+                amount: vec![Coin::new(100, "uosmo")],
+            }),
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id: "channel-0".to_string(),
+                data: Binary::default(),
+                timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(0)),
+            }),
+            CosmosMsg::Ibc(IbcMsg::CloseChannel {
+                channel_id: "channel-0".to_string(),
+            }),
+            CosmosMsg::Custom(Empty {}),
+        ];
+
+        for msg in unsupported_msgs {
+            let err = app
+                .execute_cosmos_msgs::<MsgSendResponse>(&[msg], &signer)
+                .unwrap_err();
+            assert!(matches!(err, RunnerError::UnsupportedMsg { .. }));
+        }
+
+        // supported msgs are translated and then rejected by the chain
+        let vote_msg = CosmosMsg::Gov(GovMsg::Vote {
+            proposal_id: 1,
+            vote: VoteOption::Yes,
+        });
+        let err = app
+            .execute_cosmos_msgs::<MsgVoteResponse>(&[vote_msg], &signer)
+            .unwrap_err();
+        assert!(matches!(err, RunnerError::ExecuteError { .. }));
+
+        let transfer_msg = CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id: "channel-0".to_string(),
+            to_address: "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu".to_string(),
+            amount: Coin::new(100, "uosmo"),
+            timeout: IbcTimeout::with_timestamp(app.get_block_timestamp().plus_seconds(60)),
+        });
+        let err = app
+            .execute_cosmos_msgs::<MsgTransferResponse>(&[transfer_msg], &signer)
+            .unwrap_err();
+        assert!(matches!(err, RunnerError::ExecuteError { .. }));
     }
 }
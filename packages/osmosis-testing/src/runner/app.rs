@@ -2,40 +2,63 @@ use std::ffi::CString;
 
 use cosmrs::crypto::secp256k1::SigningKey;
 use cosmrs::proto::cosmos::bank::v1beta1::{
-    QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest,
+    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse,
+    QuerySupplyOfRequest,
+};
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+};
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryParamsRequest as QueryStakingParamsRequest,
+    QueryParamsResponse as QueryStakingParamsResponse, QueryRedelegationsRequest,
+    QueryRedelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse,
 };
 use cosmrs::proto::cosmwasm::wasm::v1::{
+    QueryContractInfoRequest, QueryContractInfoResponse, QueryPinnedCodesRequest,
+    QueryPinnedCodesResponse, QueryRawContractStateRequest, QueryRawContractStateResponse,
     QuerySmartContractStateRequest, QuerySmartContractStateResponse,
 };
 use cosmrs::proto::tendermint::abci::{RequestDeliverTx, ResponseDeliverTx};
 use cosmrs::tx;
 use cosmrs::tx::{Fee, SignerInfo};
 use cosmwasm_std::{
-    from_binary, to_binary, BalanceResponse, BankQuery, Binary, Coin, ContractResult, Empty,
-    QuerierResult, QueryRequest, SystemResult, WasmQuery,
+    from_slice, to_binary, Addr, AllBalanceResponse, AllDelegationsResponse, AllValidatorsResponse,
+    BalanceResponse, BankQuery, Binary, BondedDenomResponse, Coin, ContractInfoResponse,
+    ContractResult, Delegation, DelegationResponse, Empty, FullDelegation, QuerierResult,
+    QueryRequest, StakingQuery, StdError, SystemError, SystemResult, Timestamp, Validator,
+    ValidatorResponse, WasmQuery,
 };
+use osmosis_std::registry;
+use osmosis_std::shim::{Duration, Timestamp as ProtoTimestamp};
 use osmosis_std::types::cosmos::bank::v1beta1::QuerySupplyOfResponse;
-use osmosis_std::types::osmosis::gamm::v1beta1::{
-    QueryCalcJoinPoolNoSwapSharesResponse, QueryCalcJoinPoolSharesResponse,
+use osmosis_std::types::osmosis::epochs::v1beta1::{
+    EpochInfo, QueryEpochsInfoRequest, QueryEpochsInfoResponse,
 };
 use prost::Message;
 use serde::{Deserialize, Serialize};
 
 use crate::account::{Account, FeeSetting, SigningAccount};
 use crate::bindings::{
-    AccountNumber, AccountSequence, BeginBlock, EndBlock, Execute, IncreaseTime, InitAccount,
-    InitTestEnv, Query, Simulate, WhitelistAddressForForceUnlock,
+    AccountNumber, AccountSequence, AddSuperfluidLPShare, AddValidator, BeginBlock, BeginBlockAt,
+    CommitEmptyBlocks, EndBlock, Execute, GetBlockHeight, GetBlockTime, GetValidatorAddresses,
+    IncreaseTime, InitAccount, InitTestEnv, IsBlockInProgress, Query, ReleaseSnapshot,
+    ReleaseSnapshots, Revert, SetBlockTime, Simulate, Snapshot, WhitelistAddressForForceUnlock,
 };
 use crate::redefine_as_go_string;
 use crate::runner::error::{DecodeError, EncodeError, RunnerError};
 use crate::runner::result::RawResult;
 use crate::runner::result::{RunnerExecuteResult, RunnerResult};
 use crate::runner::Runner;
-use crate::utils::{osmosis_proto_coin_to_coin, proto_coin_to_coin};
+use crate::utils::{
+    osmosis_proto_coin_to_coin, proto_coin_to_coin, proto_coins_to_coins, proto_dec_to_decimal,
+};
 
 const FEE_DENOM: &str = "uosmo";
 const CHAIN_ID: &str = "osmosis-1";
 const DEFAULT_GAS_ADJUSTMENT: f64 = 1.2;
+/// Number of seconds each block advances the block time by, unless specified otherwise.
+const DEFAULT_BLOCK_TIME_SECONDS: u64 = 5;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct OsmosisTestApp {
@@ -48,6 +71,13 @@ impl Default for OsmosisTestApp {
     }
 }
 
+/// Frees the snapshots taken of the app, each of which holds a copy of its state.
+impl Drop for OsmosisTestApp {
+    fn drop(&mut self) {
+        unsafe { ReleaseSnapshots(self.id) };
+    }
+}
+
 impl OsmosisTestApp {
     pub fn new() -> Self {
         Self {
@@ -56,17 +86,254 @@ impl OsmosisTestApp {
     }
 
     /// Increase the time of the blockchain by the given number of seconds.
-    pub fn increase_time(&self, seconds: u64) {
+    ///
+    /// This commits a new block, so it must not be called while a block is in progress.
+    pub fn increase_time(&self, seconds: u64) -> RunnerResult<()> {
+        self.ensure_no_block_in_progress("increase_time")?;
         unsafe {
             IncreaseTime(self.id, seconds);
         }
+
+        Ok(())
     }
 
     pub fn whitelist_address_for_force_unlock(&self, address: &str) {
         redefine_as_go_string!(address);
-        unsafe {
+        self.with_block(|| unsafe {
             WhitelistAddressForForceUnlock(self.id, address);
+        })
+    }
+
+    /// Register `denom` (eg. `gamm/pool/1`) as a superfluid LP share asset, so that
+    /// locks of the pool share can be superfluid delegated. The pool must contain `uosmo`.
+    pub fn add_superfluid_lp_share(&self, denom: &str) -> RunnerResult<()> {
+        redefine_as_go_string!(denom);
+        self.with_block(|| unsafe {
+            let res = AddSuperfluidLPShare(self.id, denom);
+            RawResult::from_non_null_ptr(res).into_result()
+        })?;
+
+        Ok(())
+    }
+
+    /// Get operator addresses (`osmovaloper...`) of every validator,
+    /// starting with the one that is set up along with the chain.
+    pub fn get_validator_addresses(&self) -> RunnerResult<Vec<String>> {
+        let res = unsafe {
+            let res = GetValidatorAddresses(self.id);
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        serde_json::from_slice(&res)
+            .map_err(DecodeError::JsonDecodeError)
+            .map_err(RunnerError::DecodeError)
+    }
+
+    /// Get operator address of the bonded validator that is set up along with the chain.
+    pub fn get_first_validator_address(&self) -> RunnerResult<String> {
+        self.get_validator_addresses()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: "no validator found".to_string(),
+            })
+    }
+
+    /// Create a new bonded validator with a minimal self bond and return its operator address.
+    /// Like the validator set up along with the chain, it signs every block from then on,
+    /// so its delegators earn distribution rewards.
+    pub fn add_validator(&self) -> RunnerResult<String> {
+        let res = self.with_block(|| unsafe {
+            let res = AddValidator(self.id);
+            RawResult::from_non_null_ptr(res).into_result()
+        })?;
+
+        String::from_utf8(res)
+            .map_err(|e| DecodeError::Utf8Error(e.utf8_error()))
+            .map_err(RunnerError::DecodeError)
+    }
+
+    /// Begin a new block. Every tx executed until [`OsmosisTestApp::end_block`] is called
+    /// will be included in this block instead of getting a block of its own.
+    pub fn begin_block(&self) -> RunnerResult<()> {
+        if self.is_block_in_progress() {
+            return Err(RunnerError::ExecuteError {
+                msg: "block is already in progress".to_string(),
+            });
+        }
+
+        unsafe { BeginBlock(self.id) };
+        Ok(())
+    }
+
+    /// Begin a new block with the given block time, see [`OsmosisTestApp::begin_block`].
+    /// Block time must not go backward.
+    pub fn begin_block_at(&self, time: Timestamp) -> RunnerResult<()> {
+        if self.is_block_in_progress() {
+            return Err(RunnerError::ExecuteError {
+                msg: "block is already in progress".to_string(),
+            });
+        }
+
+        unsafe {
+            let res = BeginBlockAt(self.id, time.nanos() as i64);
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// End the block started by [`OsmosisTestApp::begin_block`] and commit its state.
+    pub fn end_block(&self) -> RunnerResult<()> {
+        if !self.is_block_in_progress() {
+            return Err(RunnerError::ExecuteError {
+                msg: "no block is in progress".to_string(),
+            });
+        }
+
+        unsafe { EndBlock(self.id) };
+        Ok(())
+    }
+
+    /// Check whether a block has begun and has not yet ended.
+    pub fn is_block_in_progress(&self) -> bool {
+        unsafe { IsBlockInProgress(self.id) != 0 }
+    }
+
+    /// Commit `count` empty blocks, each advancing the block time by 5 seconds.
+    pub fn commit_empty_blocks(&self, count: u64) -> RunnerResult<()> {
+        self.ensure_no_block_in_progress("commit_empty_blocks")?;
+        unsafe { CommitEmptyBlocks(self.id, count, DEFAULT_BLOCK_TIME_SECONDS) };
+        Ok(())
+    }
+
+    /// Increase the block height by `count` without advancing the block time.
+    pub fn increase_height(&self, count: u64) -> RunnerResult<()> {
+        self.ensure_no_block_in_progress("increase_height")?;
+        unsafe { CommitEmptyBlocks(self.id, count, 0) };
+        Ok(())
+    }
+
+    /// Commit an empty block with the given block time.
+    /// Block time must not go backward.
+    pub fn set_block_time(&self, time: Timestamp) -> RunnerResult<()> {
+        self.ensure_no_block_in_progress("set_block_time")?;
+        unsafe {
+            let res = SetBlockTime(self.id, time.nanos() as i64);
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the height of the current block.
+    pub fn get_block_height(&self) -> i64 {
+        unsafe { GetBlockHeight(self.id) }
+    }
+
+    /// Get the time of the current block in nanoseconds since unix epoch.
+    pub fn get_block_time_nanos(&self) -> i64 {
+        unsafe { GetBlockTime(self.id) }
+    }
+
+    /// Get the time of the current block as a `cosmwasm_std::Timestamp`.
+    pub fn get_block_timestamp(&self) -> Timestamp {
+        Timestamp::from_nanos(self.get_block_time_nanos() as u64)
+    }
+
+    /// Commit blocks until the epoch with the given identifier (eg. `"day"`, `"week"`) ends
+    /// and the next one starts, so that every hook that runs on epoch end (incentives distribution,
+    /// mint, twap pruning, superfluid refresh, ..) is triggered.
+    /// Returns the info of the newly started epoch.
+    pub fn skip_to_next_epoch(&self, identifier: &str) -> RunnerResult<EpochInfo> {
+        self.ensure_no_block_in_progress("skip_to_next_epoch")?;
+        let next_epoch = self.query_epoch_info(identifier)?.current_epoch + 1;
+
+        loop {
+            let epoch = self.query_epoch_info(identifier)?;
+            if epoch.current_epoch >= next_epoch {
+                return Ok(epoch);
+            }
+
+            // epoch module only starts a new epoch at the first block that has block time
+            // at or after `start_time` for the very first epoch, and strictly after
+            // `current_epoch_start_time + duration` for the following ones.
+            let epoch_start_nanos = if epoch.epoch_counting_started {
+                timestamp_to_nanos(&epoch.current_epoch_start_time)
+                    + duration_to_nanos(&epoch.duration)
+                    + 1
+            } else {
+                timestamp_to_nanos(&epoch.start_time)
+            };
+
+            let now_nanos = self.get_block_time_nanos() as i128;
+            let block_time_nanos = epoch_start_nanos.max(now_nanos) as u64;
+
+            self.set_block_time(Timestamp::from_nanos(block_time_nanos))?;
+        }
+    }
+
+    /// Skip `count` epochs with the given identifier. See [`OsmosisTestApp::skip_to_next_epoch`].
+    /// Returns the info of the last started epoch. `count` must not be zero,
+    /// use [`OsmosisTestApp::query_epoch_info`] to get the current epoch instead.
+    pub fn advance_epochs(&self, identifier: &str, count: u64) -> RunnerResult<EpochInfo> {
+        if count == 0 {
+            return Err(RunnerError::ExecuteError {
+                msg: "epoch count to advance must be greater than zero".to_string(),
+            });
+        }
+
+        let mut epoch = self.skip_to_next_epoch(identifier)?;
+        for _ in 1..count {
+            epoch = self.skip_to_next_epoch(identifier)?;
+        }
+
+        Ok(epoch)
+    }
+
+    /// Get the info of the current epoch with the given identifier (eg. `"day"`, `"week"`).
+    pub fn query_epoch_info(&self, identifier: &str) -> RunnerResult<EpochInfo> {
+        self.query::<_, QueryEpochsInfoResponse>(
+            "/osmosis.epochs.v1beta1.Query/EpochInfos",
+            &QueryEpochsInfoRequest {},
+        )?
+        .epochs
+        .into_iter()
+        .find(|epoch| epoch.identifier == identifier)
+        .ok_or_else(|| RunnerError::QueryError {
+            msg: format!("epoch not found: {}", identifier),
+        })
+    }
+
+    /// Take a snapshot of the current chain state and return its id.
+    /// The same snapshot can be reverted to any number of times, so an expensive
+    /// fixture only needs to be built once. It holds a copy of the state until it is
+    /// released with [`Self::release_snapshot`] or the app is dropped.
+    pub fn snapshot(&self) -> RunnerResult<u64> {
+        self.ensure_no_block_in_progress("snapshot")?;
+        Ok(unsafe { Snapshot(self.id) })
+    }
+
+    /// Revert the chain state to the snapshot with the given id, which must have been taken
+    /// of this app, discarding everything that happened after the snapshot was taken.
+    pub fn revert(&self, snapshot_id: u64) -> RunnerResult<()> {
+        self.ensure_no_block_in_progress("revert")?;
+        unsafe {
+            let res = Revert(self.id, snapshot_id);
+            RawResult::from_non_null_ptr(res).into_result()?;
         }
+
+        Ok(())
+    }
+
+    /// Free the snapshot with the given id, which can't be reverted to afterwards.
+    pub fn release_snapshot(&self, snapshot_id: u64) -> RunnerResult<()> {
+        unsafe {
+            let res = ReleaseSnapshot(self.id, snapshot_id);
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
     }
 
     /// Initialize account with initial balance of any coins.
@@ -80,15 +347,11 @@ impl OsmosisTestApp {
         let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
         redefine_as_go_string!(coins_json);
 
-        let base64_priv = unsafe {
-            BeginBlock(self.id);
-            let addr = InitAccount(self.id, coins_json);
-            EndBlock(self.id);
-            CString::from_raw(addr)
-        }
-        .to_str()
-        .map_err(DecodeError::Utf8Error)?
-        .to_string();
+        let base64_priv = self
+            .with_block(|| unsafe { CString::from_raw(InitAccount(self.id, coins_json)) })
+            .to_str()
+            .map_err(DecodeError::Utf8Error)?
+            .to_string();
 
         let secp256k1_priv = base64::decode(base64_priv).map_err(DecodeError::Base64DecodeError)?;
         let signging_key = SigningKey::from_bytes(&secp256k1_priv).map_err(|e| {
@@ -114,6 +377,33 @@ impl OsmosisTestApp {
             .collect()
     }
 
+    /// Run `f` inside a block. If no block is in progress, a new block is
+    /// started before and ended after `f`, otherwise `f` joins the current block.
+    fn with_block<T>(&self, f: impl FnOnce() -> T) -> T {
+        if self.is_block_in_progress() {
+            return f();
+        }
+
+        unsafe { BeginBlock(self.id) };
+        let res = f();
+        unsafe { EndBlock(self.id) };
+
+        res
+    }
+
+    fn ensure_no_block_in_progress(&self, fn_name: &str) -> RunnerResult<()> {
+        if self.is_block_in_progress() {
+            return Err(RunnerError::ExecuteError {
+                msg: format!(
+                    "`{}` must not be called while a block is in progress, call `end_block` first",
+                    fn_name
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
     fn create_signed_tx<I>(
         &self,
         msgs: I,
@@ -212,90 +502,337 @@ impl OsmosisTestApp {
     }
 }
 
+fn timestamp_to_nanos(timestamp: &Option<ProtoTimestamp>) -> i128 {
+    timestamp
+        .as_ref()
+        .map(|t| t.seconds as i128 * 1_000_000_000 + t.nanos as i128)
+        .unwrap_or_default()
+}
+
+fn duration_to_nanos(duration: &Option<Duration>) -> i128 {
+    duration
+        .as_ref()
+        .map(|d| d.seconds as i128 * 1_000_000_000 + d.nanos as i128)
+        .unwrap_or_default()
+}
+
+/// Implements the querier that contracts see, so that `QuerierWrapper` can be used against the app.
+///
+/// Errors returned by the chain, e.g. querying a non-existent contract, come back as
+/// `ContractResult::Err` like they do on chain. Requests that can't be parsed or aren't supported,
+/// and responses that can't be decoded, come back as `SystemResult::Err`.
 impl cosmwasm_std::Querier for OsmosisTestApp {
     fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
-        let x = match from_binary::<QueryRequest<Empty>>(&bin_request.into()).unwrap() {
-            QueryRequest::Wasm(wasm_query) => match wasm_query {
-                WasmQuery::Smart { contract_addr, msg } => self
-                    .query::<_, QuerySmartContractStateResponse>(
-                        "/cosmwasm.wasm.v1.Query/SmartContractState",
-                        &QuerySmartContractStateRequest {
-                            address: contract_addr.to_owned(),
-                            query_data: msg.into(),
+        let request = match from_slice::<QueryRequest<Empty>>(bin_request) {
+            Ok(request) => request,
+            Err(e) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: e.to_string(),
+                    request: bin_request.into(),
+                })
+            }
+        };
+
+        let res = match request {
+            QueryRequest::Wasm(wasm_query) => self.query_wasm(wasm_query),
+            QueryRequest::Bank(bank_query) => self.query_bank(bank_query),
+            QueryRequest::Staking(staking_query) => self.query_staking(staking_query),
+            QueryRequest::Stargate { path, data } => self.query_stargate(&path, data),
+            _ => Err(RunnerError::UnsupportedMsg {
+                msg: format!("{:?}", request),
+            }),
+        };
+
+        match res {
+            Ok(bin) => SystemResult::Ok(ContractResult::Ok(bin)),
+            Err(RunnerError::QueryError { msg }) => SystemResult::Ok(ContractResult::Err(msg)),
+            Err(RunnerError::UnsupportedMsg { msg }) => {
+                SystemResult::Err(SystemError::UnsupportedRequest { kind: msg })
+            }
+            Err(e) => SystemResult::Err(SystemError::InvalidResponse {
+                error: e.to_string(),
+                response: Binary::default(),
+            }),
+        }
+    }
+}
+
+impl OsmosisTestApp {
+    fn query_wasm(&self, wasm_query: WasmQuery) -> RunnerResult<Binary> {
+        match wasm_query {
+            WasmQuery::Smart { contract_addr, msg } => Ok(self
+                .query::<_, QuerySmartContractStateResponse>(
+                    "/cosmwasm.wasm.v1.Query/SmartContractState",
+                    &QuerySmartContractStateRequest {
+                        address: contract_addr,
+                        query_data: msg.into(),
+                    },
+                )?
+                .data
+                .into()),
+            WasmQuery::Raw { contract_addr, key } => Ok(self
+                .query::<_, QueryRawContractStateResponse>(
+                    "/cosmwasm.wasm.v1.Query/RawContractState",
+                    &QueryRawContractStateRequest {
+                        address: contract_addr,
+                        query_data: key.into(),
+                    },
+                )?
+                .data
+                .into()),
+            WasmQuery::ContractInfo { contract_addr } => {
+                let contract_info = self
+                    .query::<_, QueryContractInfoResponse>(
+                        "/cosmwasm.wasm.v1.Query/ContractInfo",
+                        &QueryContractInfoRequest {
+                            address: contract_addr.clone(),
                         },
-                    )
-                    .unwrap()
-                    .data
-                    .into(),
-                _ => todo!("unsupported WasmQuery variant"),
-            },
-            QueryRequest::Bank(bank_query) => match bank_query {
-                BankQuery::Balance { address, denom } => {
-                    let balance = self
-                        .query::<_, QueryBalanceResponse>(
-                            "/cosmos.bank.v1beta1.Query/Balance",
-                            &QueryBalanceRequest {
-                                address: address.to_owned(),
-                                denom: denom.to_owned(),
-                            },
-                        )
-                        .unwrap()
-                        .balance
-                        .unwrap();
-                    to_binary(&BalanceResponse {
-                        amount: proto_coin_to_coin(&balance),
-                    })
-                    .unwrap()
+                    )?
+                    .contract_info
+                    .ok_or_else(|| RunnerError::QueryError {
+                        msg: format!("no such contract: {}", contract_addr),
+                    })?;
+                let pinned_code_ids = self
+                    .query::<_, QueryPinnedCodesResponse>(
+                        "/cosmwasm.wasm.v1.Query/PinnedCodes",
+                        &QueryPinnedCodesRequest { pagination: None },
+                    )?
+                    .code_ids;
+
+                // `ContractInfoResponse` is non-exhaustive, so it can only be built from default
+                let mut res = ContractInfoResponse::default();
+                res.code_id = contract_info.code_id;
+                res.creator = contract_info.creator;
+                res.admin = Some(contract_info.admin).filter(|admin| !admin.is_empty());
+                res.pinned = pinned_code_ids.contains(&contract_info.code_id);
+                res.ibc_port = Some(contract_info.ibc_port_id).filter(|port| !port.is_empty());
+                Ok(to_binary(&res)?)
+            }
+            _ => Err(RunnerError::UnsupportedMsg {
+                msg: format!("{:?}", wasm_query),
+            }),
+        }
+    }
+
+    fn query_bank(&self, bank_query: BankQuery) -> RunnerResult<Binary> {
+        match bank_query {
+            BankQuery::Balance { address, denom } => {
+                let balance = self
+                    .query::<_, QueryBalanceResponse>(
+                        "/cosmos.bank.v1beta1.Query/Balance",
+                        &QueryBalanceRequest {
+                            address,
+                            denom: denom.clone(),
+                        },
+                    )?
+                    .balance
+                    .map(|balance| proto_coin_to_coin(&balance))
+                    .unwrap_or_else(|| Coin::new(0, denom));
+                Ok(to_binary(&BalanceResponse { amount: balance })?)
+            }
+            BankQuery::AllBalances { address } => {
+                let balances = self
+                    .query::<_, QueryAllBalancesResponse>(
+                        "/cosmos.bank.v1beta1.Query/AllBalances",
+                        &QueryAllBalancesRequest {
+                            address,
+                            pagination: None,
+                        },
+                    )?
+                    .balances;
+                Ok(to_binary(&AllBalanceResponse {
+                    amount: proto_coins_to_coins(&balances),
+                })?)
+            }
+            BankQuery::Supply { denom } => {
+                let supply = self
+                    .query::<_, QuerySupplyOfResponse>(
+                        "/cosmos.bank.v1beta1.Query/SupplyOf",
+                        &QuerySupplyOfRequest {
+                            denom: denom.clone(),
+                        },
+                    )?
+                    .amount
+                    .map(|supply| osmosis_proto_coin_to_coin(&supply))
+                    .unwrap_or_else(|| Coin::new(0, denom));
+
+                // We must copy this struct because the original is non-exhaustive
+                #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+                #[serde(rename_all = "snake_case")]
+                pub struct SupplyResponse {
+                    pub amount: Coin,
                 }
-                BankQuery::Supply { denom } => {
-                    let supply = self
-                        .query::<_, QuerySupplyOfResponse>(
-                            "/cosmos.bank.v1beta1.Query/SupplyOf",
-                            &QuerySupplyOfRequest {
-                                denom: denom.to_owned(),
-                            },
-                        )
-                        .unwrap()
-                        .amount
-                        .unwrap();
-
-                    // We must copy this struct because the original is non-exhaustive
-                    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-                    #[serde(rename_all = "snake_case")]
-                    pub struct SupplyResponse {
-                        pub amount: Coin,
-                    }
-                    to_binary(&SupplyResponse {
-                        amount: osmosis_proto_coin_to_coin(&supply),
+                Ok(to_binary(&SupplyResponse { amount: supply })?)
+            }
+            _ => Err(RunnerError::UnsupportedMsg {
+                msg: format!("{:?}", bank_query),
+            }),
+        }
+    }
+
+    fn query_staking(&self, staking_query: StakingQuery) -> RunnerResult<Binary> {
+        match staking_query {
+            StakingQuery::BondedDenom {} => {
+                let denom = self
+                    .query::<_, QueryStakingParamsResponse>(
+                        "/cosmos.staking.v1beta1.Query/Params",
+                        &QueryStakingParamsRequest {},
+                    )?
+                    .params
+                    .map(|params| params.bond_denom)
+                    .unwrap_or_default();
+                Ok(to_binary(&BondedDenomResponse { denom })?)
+            }
+            StakingQuery::AllDelegations { delegator } => {
+                let delegations = self
+                    .query_delegations(&delegator)?
+                    .into_iter()
+                    .map(|(validator, amount)| Delegation {
+                        delegator: Addr::unchecked(&delegator),
+                        validator,
+                        amount,
                     })
-                    .unwrap()
-                }
-                _ => todo!("unsupported BankQuery variant"),
-            },
-            QueryRequest::Stargate { path, data } => {
-                let proto_binary: Binary = self.query_raw(&path, data.into()).unwrap().into();
-                match path.as_str() {
-                    "/cosmos.bank.v1beta1.Query/SupplyOf" => {
-                        let proto: QuerySupplyOfResponse = proto_binary.try_into().unwrap();
-                        to_binary(&proto).unwrap()
-                    }
-                    "/osmosis.gamm.v1beta1.Query/CalcJoinPoolNoSwapShares" => {
-                        let proto: QueryCalcJoinPoolNoSwapSharesResponse =
-                            proto_binary.try_into().unwrap();
-                        to_binary(&proto).unwrap()
-                    }
-                    "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares" => {
-                        let proto: QueryCalcJoinPoolSharesResponse =
-                            proto_binary.try_into().unwrap();
-                        to_binary(&proto).unwrap()
-                    }
-                    _ => todo!("Unsupported Stargate query. path = {}", path),
-                }
+                    .collect();
+                Ok(to_binary(&AllDelegationsResponse { delegations })?)
+            }
+            StakingQuery::Delegation {
+                delegator,
+                validator,
+            } => {
+                let delegation = self
+                    .query_delegations(&delegator)?
+                    .into_iter()
+                    .find(|(v, _)| *v == validator)
+                    .map(|(validator, amount)| {
+                        self.query_full_delegation(&delegator, validator, amount)
+                    })
+                    .transpose()?;
+                Ok(to_binary(&DelegationResponse { delegation })?)
+            }
+            StakingQuery::AllValidators {} => {
+                let validators = self.query_validators("BOND_STATUS_BONDED")?;
+                Ok(to_binary(&AllValidatorsResponse { validators })?)
             }
-            _ => todo!("unsupported QueryRequest variant"),
+            StakingQuery::Validator { address } => {
+                let validator = self
+                    .query_validators("")?
+                    .into_iter()
+                    .find(|v| v.address == address);
+                Ok(to_binary(&ValidatorResponse { validator })?)
+            }
+            _ => Err(RunnerError::UnsupportedMsg {
+                msg: format!("{:?}", staking_query),
+            }),
+        }
+    }
+
+    /// Forwards the query as is and converts the response to JSON, which is how Osmosis serves
+    /// Stargate queries to contracts. The response type is looked up by path in the registry.
+    fn query_stargate(&self, path: &str, data: Binary) -> RunnerResult<Binary> {
+        let res = self.query_raw(path, data.into())?;
+        let res = registry::decode_query_response(path, &res).map_err(StdError::from)?;
+        Ok(to_binary(&res)?)
+    }
+
+    /// Returns `(validator, amount)` of every delegation of `delegator`.
+    fn query_delegations(&self, delegator: &str) -> RunnerResult<Vec<(String, Coin)>> {
+        Ok(self
+            .query::<_, QueryDelegatorDelegationsResponse>(
+                "/cosmos.staking.v1beta1.Query/DelegatorDelegations",
+                &QueryDelegatorDelegationsRequest {
+                    delegator_addr: delegator.to_string(),
+                    pagination: None,
+                },
+            )?
+            .delegation_responses
+            .into_iter()
+            .filter_map(|res| {
+                Some((
+                    res.delegation?.validator_address,
+                    proto_coin_to_coin(&res.balance?),
+                ))
+            })
+            .collect())
+    }
+
+    fn query_full_delegation(
+        &self,
+        delegator: &str,
+        validator: String,
+        amount: Coin,
+    ) -> RunnerResult<FullDelegation> {
+        let accumulated_rewards = self
+            .query::<_, QueryDelegationRewardsResponse>(
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+                &QueryDelegationRewardsRequest {
+                    delegator_address: delegator.to_string(),
+                    validator_address: validator.clone(),
+                },
+            )?
+            .rewards
+            .into_iter()
+            .map(|reward| {
+                Ok(Coin {
+                    denom: reward.denom,
+                    amount: proto_dec_to_decimal(&reward.amount)?.to_uint_floor(),
+                })
+            })
+            .collect::<RunnerResult<Vec<_>>>()?;
+
+        // same as wasmd, delegation that was redelegated to the validator
+        // can't be redelegated again until the redelegation matures
+        let has_receiving_redelegation = self
+            .query::<_, QueryRedelegationsResponse>(
+                "/cosmos.staking.v1beta1.Query/Redelegations",
+                &QueryRedelegationsRequest {
+                    delegator_addr: delegator.to_string(),
+                    src_validator_addr: "".to_string(),
+                    dst_validator_addr: "".to_string(),
+                    pagination: None,
+                },
+            )?
+            .redelegation_responses
+            .into_iter()
+            .filter_map(|res| res.redelegation)
+            .any(|r| r.validator_dst_address == validator && !r.entries.is_empty());
+        let can_redelegate = if has_receiving_redelegation {
+            Coin::new(0, &amount.denom)
+        } else {
+            amount.clone()
         };
 
-        SystemResult::Ok(ContractResult::Ok(x))
+        Ok(FullDelegation {
+            delegator: Addr::unchecked(delegator),
+            validator,
+            amount,
+            can_redelegate,
+            accumulated_rewards,
+        })
+    }
+
+    fn query_validators(&self, status: &str) -> RunnerResult<Vec<Validator>> {
+        self.query::<_, QueryValidatorsResponse>(
+            "/cosmos.staking.v1beta1.Query/Validators",
+            &QueryValidatorsRequest {
+                status: status.to_string(),
+                pagination: None,
+            },
+        )?
+        .validators
+        .into_iter()
+        .map(|v| {
+            let rates = v
+                .commission
+                .and_then(|c| c.commission_rates)
+                .unwrap_or_default();
+            Ok(Validator {
+                address: v.operator_address,
+                commission: proto_dec_to_decimal(&rates.rate)?,
+                max_commission: proto_dec_to_decimal(&rates.max_rate)?,
+                max_change_rate: proto_dec_to_decimal(&rates.max_change_rate)?,
+            })
+        })
+        .collect()
     }
 }
 
@@ -333,8 +870,6 @@ impl<'a> Runner<'a> for OsmosisTestApp {
     where
         R: ::prost::Message + Default,
     {
-        unsafe { BeginBlock(self.id) };
-
         let execute_multiple_raw_inner = || -> RunnerExecuteResult<R> {
             let fee = match &signer.fee_setting() {
                 FeeSetting::Auto { .. } => self.estimate_fee(msgs.clone(), signer)?,
@@ -365,15 +900,8 @@ impl<'a> Runner<'a> for OsmosisTestApp {
             res
         };
 
-        // Even if the tx fails we must still call EndBlock
-        let res = execute_multiple_raw_inner().map_err(|e| {
-            unsafe { EndBlock(self.id) };
-            e
-        })?;
-
-        unsafe { EndBlock(self.id) };
-
-        Ok(res)
+        // Even if the tx fails the block must still be ended
+        self.with_block(execute_multiple_raw_inner)
     }
 
     fn query_raw(&self, path: &str, protobuf: Vec<u8>) -> RunnerResult<Vec<u8>> {
@@ -409,10 +937,16 @@ mod tests {
     use std::option::Option::None;
 
     use cosmrs::proto::cosmos::bank::v1beta1::QueryAllBalancesRequest;
-    use cosmwasm_std::{attr, coins, Coin, Empty, QuerierWrapper, Uint128};
+    use cosmwasm_std::{
+        attr, coins, to_vec, Coin, Empty, QuerierWrapper, QueryRequest, SystemError, SystemResult,
+        Uint128,
+    };
+    use osmosis_std::types::osmosis::gamm::v1beta1::{Pool, QueryPoolRequest, QueryPoolResponse};
+    use prost::Message;
 
     use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
-        MsgCreateDenom, MsgCreateDenomResponse, QueryParamsRequest, QueryParamsResponse,
+        MsgCreateDenom, MsgCreateDenomResponse, QueryDenomsFromCreatorRequest,
+        QueryDenomsFromCreatorResponse, QueryParamsRequest, QueryParamsResponse,
     };
 
     use crate::account::{Account, FeeSetting};
@@ -421,7 +955,7 @@ mod tests {
     use crate::module::Wasm;
     use crate::runner::app::OsmosisTestApp;
     use crate::runner::*;
-    use crate::{Bank, ExecuteResponse};
+    use crate::{Bank, ExecuteResponse, RunnerError, Staking};
 
     #[test]
     fn test_init_accounts() {
@@ -498,6 +1032,221 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_block_control() {
+        let app = OsmosisTestApp::default();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000, "uosmo"), 2)
+            .unwrap();
+
+        // batch multiple txs into one block
+        let height = app.get_block_height();
+        app.begin_block().unwrap();
+        assert!(app.is_block_in_progress());
+        assert_eq!(
+            app.begin_block().unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: "block is already in progress".to_string()
+            }
+        );
+        assert_eq!(
+            app.commit_empty_blocks(1).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: "`commit_empty_blocks` must not be called while a block is in progress, call `end_block` first".to_string()
+            }
+        );
+
+        for (acc, subdenom) in accs.iter().zip(["newdenom", "otherdenom"]) {
+            let _: ExecuteResponse<MsgCreateDenomResponse> = app
+                .execute(
+                    MsgCreateDenom {
+                        sender: acc.address(),
+                        subdenom: subdenom.to_string(),
+                    },
+                    MsgCreateDenom::TYPE_URL,
+                    acc,
+                )
+                .unwrap();
+            assert_eq!(app.get_block_height(), height + 1);
+        }
+
+        app.end_block().unwrap();
+        assert!(!app.is_block_in_progress());
+        assert_eq!(
+            app.end_block().unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: "no block is in progress".to_string()
+            }
+        );
+
+        // advance height without advancing time
+        let time = app.get_block_timestamp();
+        app.increase_height(3).unwrap();
+        assert_eq!(app.get_block_height(), height + 4);
+        assert_eq!(app.get_block_timestamp(), time);
+
+        // empty blocks advance time by 5 seconds each
+        app.commit_empty_blocks(2).unwrap();
+        assert_eq!(app.get_block_height(), height + 6);
+        assert_eq!(app.get_block_timestamp(), time.plus_seconds(10));
+
+        // set block time
+        let new_time = time.plus_seconds(3600);
+        app.set_block_time(new_time).unwrap();
+        assert_eq!(app.get_block_height(), height + 7);
+        assert_eq!(app.get_block_timestamp(), new_time);
+
+        assert!(app.set_block_time(time).is_err());
+    }
+
+    #[test]
+    fn test_same_signer_multiple_txs_in_block() {
+        let app = OsmosisTestApp::default();
+        let acc = app.init_account(&coins(100_000_000_000, "uosmo")).unwrap();
+
+        // fee estimation simulates against check state, which must see the sequence
+        // of every tx the signer already sent in the current block
+        let height = app.get_block_height();
+        app.begin_block().unwrap();
+        for subdenom in ["newdenom", "otherdenom", "thirddenom"] {
+            let _: ExecuteResponse<MsgCreateDenomResponse> = app
+                .execute(
+                    MsgCreateDenom {
+                        sender: acc.address(),
+                        subdenom: subdenom.to_string(),
+                    },
+                    MsgCreateDenom::TYPE_URL,
+                    &acc,
+                )
+                .unwrap();
+        }
+        app.end_block().unwrap();
+        assert_eq!(app.get_block_height(), height + 1);
+
+        let denoms = app
+            .query::<QueryDenomsFromCreatorRequest, QueryDenomsFromCreatorResponse>(
+                "/osmosis.tokenfactory.v1beta1.Query/DenomsFromCreator",
+                &QueryDenomsFromCreatorRequest {
+                    creator: acc.address(),
+                },
+            )
+            .unwrap()
+            .denoms;
+        assert_eq!(denoms.len(), 3);
+    }
+
+    #[test]
+    fn test_skip_to_next_epoch() {
+        let app = OsmosisTestApp::default();
+
+        let day = app.query_epoch_info("day").unwrap();
+        let next_day = app.skip_to_next_epoch("day").unwrap();
+        assert_eq!(next_day.current_epoch, day.current_epoch + 1);
+        assert_eq!(next_day.current_epoch_start_height, app.get_block_height());
+
+        let week = app.query_epoch_info("week").unwrap();
+        let next_week = app.advance_epochs("week", 2).unwrap();
+        assert_eq!(next_week.current_epoch, week.current_epoch + 2);
+        assert_eq!(next_week.current_epoch_start_height, app.get_block_height());
+        assert_eq!(app.query_epoch_info("week").unwrap(), next_week);
+
+        assert_eq!(
+            app.advance_epochs("week", 0).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: "epoch count to advance must be greater than zero".to_string()
+            }
+        );
+
+        assert_eq!(
+            app.skip_to_next_epoch("fortnight").unwrap_err(),
+            RunnerError::QueryError {
+                msg: "epoch not found: fortnight".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_snapshot_and_revert() {
+        let app = OsmosisTestApp::default();
+        let acc = app.init_account(&coins(100_000_000_000, "uosmo")).unwrap();
+
+        let query_denoms = || {
+            app.query::<QueryDenomsFromCreatorRequest, QueryDenomsFromCreatorResponse>(
+                "/osmosis.tokenfactory.v1beta1.Query/DenomsFromCreator",
+                &QueryDenomsFromCreatorRequest {
+                    creator: acc.address(),
+                },
+            )
+            .unwrap()
+            .denoms
+        };
+
+        let snapshot_id = app.snapshot().unwrap();
+
+        // state changes after the snapshot should be discarded on every revert
+        for subdenom in ["newdenom", "otherdenom"] {
+            let _: ExecuteResponse<MsgCreateDenomResponse> = app
+                .execute(
+                    MsgCreateDenom {
+                        sender: acc.address(),
+                        subdenom: subdenom.to_string(),
+                    },
+                    MsgCreateDenom::TYPE_URL,
+                    &acc,
+                )
+                .unwrap();
+
+            assert_eq!(
+                query_denoms(),
+                vec![format!("factory/{}/{}", acc.address(), subdenom)]
+            );
+
+            app.revert(snapshot_id).unwrap();
+
+            assert!(query_denoms().is_empty());
+        }
+
+        let err = app.revert(snapshot_id + 1).unwrap_err();
+        assert_eq!(
+            err,
+            RunnerError::ExecuteError {
+                msg: format!("snapshot not found: {}", snapshot_id + 1)
+            }
+        );
+
+        app.begin_block().unwrap();
+        assert_eq!(
+            app.revert(snapshot_id).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: "`revert` must not be called while a block is in progress, call `end_block` first".to_string()
+            }
+        );
+        app.end_block().unwrap();
+
+        // a snapshot can only be reverted to by the app it was taken of
+        let other_app = OsmosisTestApp::default();
+        assert_eq!(
+            other_app.revert(snapshot_id).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: format!("snapshot {} was not taken of this app", snapshot_id)
+            }
+        );
+        assert_eq!(
+            other_app.release_snapshot(snapshot_id).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: format!("snapshot {} was not taken of this app", snapshot_id)
+            }
+        );
+
+        app.release_snapshot(snapshot_id).unwrap();
+        assert_eq!(
+            app.revert(snapshot_id).unwrap_err(),
+            RunnerError::ExecuteError {
+                msg: format!("snapshot not found: {}", snapshot_id)
+            }
+        );
+    }
+
     #[test]
     fn test_query() {
         let app = OsmosisTestApp::default();
@@ -687,8 +1436,8 @@ mod tests {
 
         // Create pool
         let pool_liquidity = vec![Coin::new(1_000, "uatom"), Coin::new(1_000, "uosmo")];
-        let _pool_id = gamm
-            .create_basic_pool(&pool_liquidity, &acc1)
+        let pool_id = gamm
+            .create_basic_pool(&pool_liquidity, acc1)
             .unwrap()
             .data
             .pool_id;
@@ -703,19 +1452,109 @@ mod tests {
         let supply = querier.query_supply("uosmo").unwrap();
         assert!(supply.amount > Uint128::zero());
 
-        // TODO: fix this
-        // Stargate Query
-        // let msg = QueryPoolRequest { pool_id };
-        // let mut buf = Vec::new();
-        // QueryPoolRequest::encode(&msg, &mut buf).unwrap();
-        // let res: Vec<u8> = querier
-        //     .query(&QueryRequest::Stargate {
-        //         path: "/osmosis.gamm.v1beta1.Query/Pool".into(),
-        //         data: buf.into(),
-        //     })
-        //     .unwrap();
-        // let res = QueryPoolResponse::decode(&mut res.as_slice()).unwrap();
-        // let pool = gamm::v1beta1::Pool::decode(res.pool.unwrap().value.as_slice()).unwrap();
-        // assert_eq!(pool.id, pool_id);
+        // Bank::AllBalances
+        let balances = querier.query_all_balances(acc2.address()).unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                Coin::new(1_000_000_000_000, "uatom"),
+                Coin::new(1_000_000_000_000, "uosmo"),
+            ]
+        );
+
+        // Wasm::ContractInfo and Wasm::Raw
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, acc1)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &cw1_whitelist::msg::InstantiateMsg {
+                    admins: vec![acc1.address()],
+                    mutable: true,
+                },
+                Some(&acc1.address()),
+                None,
+                &[],
+                acc1,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let contract_info = querier.query_wasm_contract_info(&contract_addr).unwrap();
+        assert_eq!(contract_info.code_id, code_id);
+        assert_eq!(contract_info.creator, acc1.address());
+        assert_eq!(contract_info.admin, Some(acc1.address()));
+
+        let contract_version = querier
+            .query_wasm_raw(&contract_addr, b"contract_info".as_slice())
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(contract_version)
+            .unwrap()
+            .contains("cw1-whitelist"));
+        assert_eq!(
+            querier
+                .query_wasm_raw(&contract_addr, b"nonexistent".as_slice())
+                .unwrap(),
+            None
+        );
+
+        // errors from the chain are returned instead of panicking
+        assert!(querier.query_wasm_contract_info(acc2.address()).is_err());
+
+        // Staking
+        let validator = app.get_first_validator_address().unwrap();
+        assert_eq!(querier.query_bonded_denom().unwrap(), "uosmo");
+        assert_eq!(
+            querier
+                .query_all_validators()
+                .unwrap()
+                .into_iter()
+                .map(|v| v.address)
+                .collect::<Vec<_>>(),
+            vec![validator.clone()]
+        );
+        assert!(querier.query_validator(&validator).unwrap().is_some());
+
+        Staking::new(&app)
+            .delegate_to(&validator, Coin::new(1_000_000, "uosmo"), acc2)
+            .unwrap();
+        let delegations = querier.query_all_delegations(acc2.address()).unwrap();
+        assert_eq!(delegations.len(), 1);
+        assert_eq!(delegations[0].amount, Coin::new(1_000_000, "uosmo"));
+
+        let delegation = querier
+            .query_delegation(acc2.address(), &validator)
+            .unwrap()
+            .unwrap();
+        assert_eq!(delegation.amount, Coin::new(1_000_000, "uosmo"));
+        assert_eq!(delegation.can_redelegate, Coin::new(1_000_000, "uosmo"));
+        assert!(querier
+            .query_delegation(acc1.address(), &validator)
+            .unwrap()
+            .is_none());
+
+        // Stargate query responses are converted to json, whatever the path
+        let res: QueryPoolResponse = querier
+            .query(&QueryRequest::Stargate {
+                path: "/osmosis.gamm.v1beta1.Query/Pool".to_string(),
+                data: QueryPoolRequest { pool_id }.into(),
+            })
+            .unwrap();
+        let pool = Pool::decode(res.pool.unwrap().value.as_slice()).unwrap();
+        assert_eq!(pool.id, pool_id);
+
+        // unsupported requests are system errors
+        let request: QueryRequest<Empty> = QueryRequest::Custom(Empty {});
+        assert!(matches!(
+            querier.raw_query(&to_vec(&request).unwrap()),
+            SystemResult::Err(SystemError::UnsupportedRequest { .. })
+        ));
     }
 }
@@ -27,6 +27,9 @@ pub enum RunnerError {
     #[error("execute error: {}", .msg)]
     ExecuteError { msg: String },
 
+    #[error("unsupported message: {}", .msg)]
+    UnsupportedMsg { msg: String },
+
     #[error("{0}")]
     StdError(#[from] StdError),
 
@@ -60,10 +63,14 @@ impl PartialEq for RunnerError {
             (RunnerError::DecodeError(a), RunnerError::DecodeError(b)) => a == b,
             (RunnerError::QueryError { msg: a }, RunnerError::QueryError { msg: b }) => a == b,
             (RunnerError::ExecuteError { msg: a }, RunnerError::ExecuteError { msg: b }) => a == b,
+            (RunnerError::UnsupportedMsg { msg: a }, RunnerError::UnsupportedMsg { msg: b }) => {
+                a == b
+            }
             (RunnerError::StdError(a), RunnerError::StdError(b)) => a.to_string() == b.to_string(),
             (RunnerError::TendermintRpc(a), RunnerError::TendermintRpc(b)) => a.0 == b.0,
             (RunnerError::Io(a), RunnerError::Io(b)) => a.to_string() == b.to_string(),
             (RunnerError::Tonic(a), RunnerError::Tonic(b)) => a.to_string() == b.to_string(),
+            (RunnerError::ParseInt(a), RunnerError::ParseInt(b)) => a == b,
             _ => false,
         }
     }
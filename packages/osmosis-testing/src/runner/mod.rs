@@ -2,7 +2,10 @@ use cosmwasm_std::CosmosMsg;
 
 use crate::account::SigningAccount;
 use crate::runner::result::{RunnerExecuteResult, RunnerResult};
-use crate::utils::{bank_msg_to_any, wasm_msg_to_any};
+use crate::utils::{
+    bank_msg_to_any, distribution_msg_to_any, gov_msg_to_any, ibc_msg_to_any, staking_msg_to_any,
+    wasm_msg_to_any,
+};
 use crate::RunnerError;
 
 pub mod app;
@@ -27,7 +30,13 @@ pub trait Runner<'a> {
                     value: value.0.clone(),
                 }),
                 CosmosMsg::Wasm(msg) => wasm_msg_to_any(msg, signer),
-                _ => todo!("unsupported cosmos msg variant"),
+                CosmosMsg::Staking(msg) => staking_msg_to_any(msg, signer),
+                CosmosMsg::Distribution(msg) => distribution_msg_to_any(msg, signer),
+                CosmosMsg::Ibc(msg) => ibc_msg_to_any(msg, signer),
+                CosmosMsg::Gov(msg) => gov_msg_to_any(msg, signer),
+                _ => Err(RunnerError::UnsupportedMsg {
+                    msg: format!("{:?}", msg),
+                }),
             })
             .collect::<Result<Vec<_>, RunnerError>>()?;
 
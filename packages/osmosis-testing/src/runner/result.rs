@@ -204,6 +204,7 @@ mod tests {
     use crate::runner::app::OsmosisTestApp;
     use crate::runner::error::RunnerError::{ExecuteError, QueryError};
     use crate::runner::Runner;
+    use cosmwasm_std::Decimal;
     use osmosis_std::types::osmosis::gamm::poolmodels::balancer::v1beta1::{
         MsgCreateBalancerPool, MsgCreateBalancerPoolResponse,
     };
@@ -265,8 +266,8 @@ mod tests {
             MsgCreateBalancerPool {
                 sender: signer.address(),
                 pool_params: Some(PoolParams {
-                    swap_fee: "10000000000000000".to_string(),
-                    exit_fee: "10000000000000000".to_string(),
+                    swap_fee: Decimal::percent(1).into(),
+                    exit_fee: Decimal::percent(1).into(),
                     smooth_weight_change_params: None,
                 }),
                 pool_assets: vec![],
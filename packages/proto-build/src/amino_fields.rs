@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+
+use crate::descriptor;
+
+/// How a field appears in amino JSON, which follows the field's `json` tag in Go. gogoproto names
+/// it after the proto field, leaves `omitempty` out of it for non-nullable fields, and replaces it
+/// with `(gogoproto.jsontag)` when that is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AminoField {
+    /// Name of the field, if it is not the one prost gives it, e.g. `poolId` for `pool_id`.
+    pub name: Option<String>,
+    /// Whether the field is encoded even when it holds an empty value.
+    pub not_omitted: bool,
+}
+
+/// Fields that differ in amino JSON from their prost name, left out when empty, keyed like
+/// [`crate::custom_types::CustomTypes`].
+pub type AminoFields = HashMap<(String, String), AminoField>;
+
+pub fn extract_amino_fields(descriptor_bytes: &[u8]) -> AminoFields {
+    let descriptor = descriptor::FileDescriptorSet::decode(descriptor_bytes).unwrap();
+
+    let mut amino_fields = AminoFields::new();
+    for file in descriptor.file {
+        let package = file.package.unwrap_or_default();
+        for message in &file.message_type {
+            collect_amino_fields(&package, message, &mut amino_fields);
+        }
+    }
+    amino_fields
+}
+
+fn collect_amino_fields(
+    path: &str,
+    message: &descriptor::DescriptorProto,
+    amino_fields: &mut AminoFields,
+) {
+    let message_name = message.name.clone().unwrap_or_default();
+    let path = format!("{}.{}", path, message_name.to_upper_camel_case());
+
+    for field in &message.field {
+        if let Some(name) = &field.name {
+            let amino_field = amino_field(name, field);
+            if amino_field != AminoField::default() {
+                amino_fields.insert((path.clone(), name.to_snake_case()), amino_field);
+            }
+        }
+    }
+
+    for nested in &message.nested_type {
+        collect_amino_fields(&path, nested, amino_fields);
+    }
+}
+
+fn amino_field(name: &str, field: &descriptor::FieldDescriptorProto) -> AminoField {
+    let options = field.options.clone().unwrap_or_default();
+
+    let (json_name, omit_empty) = match &options.jsontag {
+        Some(jsontag) => {
+            let (json_name, tag_options) = jsontag.split_once(',').unwrap_or((jsontag, ""));
+            let json_name = if json_name.is_empty() {
+                name
+            } else {
+                json_name
+            };
+            (json_name, tag_options.split(',').any(|o| o == "omitempty"))
+        }
+        None => {
+            // gogoproto keeps `omitempty` for lists of scalars, which can't be nullable
+            let repeated_scalar = field.label == Some(Label::Repeated as i32)
+                && field.r#type != Some(Type::Message as i32)
+                && options.customtype.is_none();
+            (name, options.nullable != Some(false) || repeated_scalar)
+        }
+    };
+
+    AminoField {
+        name: (json_name != name.to_snake_case()).then(|| json_name.to_string()),
+        not_omitted: !omit_empty,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(
+        name: &str,
+        label: Label,
+        r#type: Type,
+        options: descriptor::FieldOptions,
+    ) -> descriptor::FieldDescriptorProto {
+        descriptor::FieldDescriptorProto {
+            name: Some(name.to_string()),
+            label: Some(label as i32),
+            r#type: Some(r#type as i32),
+            options: Some(options),
+        }
+    }
+
+    #[test]
+    fn test_extract_amino_fields() {
+        let non_nullable = || descriptor::FieldOptions {
+            nullable: Some(false),
+            ..Default::default()
+        };
+        let descriptor = descriptor::FileDescriptorSet {
+            file: vec![descriptor::FileDescriptorProto {
+                package: Some("osmosis.gamm.v1beta1".to_string()),
+                message_type: vec![descriptor::DescriptorProto {
+                    name: Some("MsgSwapExactAmountIn".to_string()),
+                    field: vec![
+                        field("sender", Label::Optional, Type::String, Default::default()),
+                        field("routes", Label::Repeated, Type::Message, non_nullable()),
+                        field("tokenIn", Label::Optional, Type::Message, non_nullable()),
+                        field(
+                            "tokenOutMinAmount",
+                            Label::Optional,
+                            Type::String,
+                            descriptor::FieldOptions {
+                                customtype: Some("github.com/cosmos/cosmos-sdk/types.Int".into()),
+                                ..non_nullable()
+                            },
+                        ),
+                        field("pool_ids", Label::Repeated, Type::Uint64, non_nullable()),
+                        field(
+                            "proposal_id",
+                            Label::Optional,
+                            Type::Uint64,
+                            descriptor::FieldOptions {
+                                jsontag: Some("proposal_id".to_string()),
+                                ..Default::default()
+                            },
+                        ),
+                        field(
+                            "validator_addr",
+                            Label::Optional,
+                            Type::String,
+                            descriptor::FieldOptions {
+                                jsontag: Some("address,omitempty".to_string()),
+                                ..Default::default()
+                            },
+                        ),
+                    ],
+                    nested_type: vec![],
+                }],
+            }],
+        };
+
+        let key = |field: &str| {
+            (
+                "osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string(),
+                field.to_string(),
+            )
+        };
+        assert_eq!(
+            extract_amino_fields(&descriptor.encode_to_vec()),
+            AminoFields::from([
+                (
+                    key("routes"),
+                    AminoField {
+                        name: None,
+                        not_omitted: true,
+                    },
+                ),
+                (
+                    key("token_in"),
+                    AminoField {
+                        name: Some("tokenIn".to_string()),
+                        not_omitted: true,
+                    },
+                ),
+                (
+                    key("token_out_min_amount"),
+                    AminoField {
+                        name: Some("tokenOutMinAmount".to_string()),
+                        not_omitted: true,
+                    },
+                ),
+                (
+                    key("proposal_id"),
+                    AminoField {
+                        name: None,
+                        not_omitted: true,
+                    },
+                ),
+                (
+                    key("validator_addr"),
+                    AminoField {
+                        name: Some("address".to_string()),
+                        not_omitted: false,
+                    },
+                ),
+            ])
+        );
+    }
+}
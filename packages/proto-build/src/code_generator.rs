@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{create_dir_all, remove_dir_all};
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -6,21 +7,60 @@ use std::{env, fs};
 use log::info;
 use prost::Message;
 use prost_types::FileDescriptorSet;
+use serde::Deserialize;
 use walkdir::WalkDir;
 
-use crate::{mod_gen, transform};
+use crate::amino_fields::{extract_amino_fields, AminoFields};
+use crate::custom_types::{extract_custom_types, CustomTypes};
+use crate::descriptor::{EncodedFileDescriptorSet, FileImports};
+use crate::manifest::{default_extern_paths, Manifest};
+use crate::{mod_gen, registry_gen, transform};
 
-const DESCRIPTOR_FILE: &str = "descriptor.bin";
+pub(crate) const DESCRIPTOR_FILE: &str = "descriptor.bin";
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct CosmosProject {
     pub name: String,
+    /// the commit or tag that `--update-deps` checks out
     pub version: String,
+    /// not needed when generating from a descriptor set
+    #[serde(default)]
     pub project_dir: String,
 
-    /// determines which modules to include from the project
+    /// directory in `project_dir` with the project's protos, under `<proto_dir>/<name>/<module>`
+    #[serde(default = "default_proto_dir")]
+    pub proto_dir: String,
+    /// directories in `project_dir` with protos that the project's protos import
+    #[serde(default = "default_include_dirs")]
+    pub include_dirs: Vec<String>,
+
+    /// determines which modules to include from the project, by their path under
+    /// `<proto_dir>/<name>`, e.g. `bank` or `core/channel`, along with the modules nested in them
     /// empty vector means include all modules
+    #[serde(default)]
     pub include_mods: Vec<String>,
+    /// modules to leave out, even if they are in `include_mods`
+    #[serde(default)]
+    pub exclude_mods: Vec<String>,
+}
+
+impl CosmosProject {
+    /// `module` is the path of its directory under `<proto_dir>/<name>`, e.g. `core/channel/v1`
+    pub fn includes_mod(&self, module: &str) -> bool {
+        let listed_in = |mods: &[String]| mods.iter().any(|m| Path::new(module).starts_with(m));
+
+        (self.include_mods.is_empty() || listed_in(&self.include_mods))
+            && !listed_in(&self.exclude_mods)
+    }
+}
+
+fn default_proto_dir() -> String {
+    "proto".to_string()
+}
+
+fn default_include_dirs() -> Vec<String> {
+    vec!["third_party/proto".to_string()]
 }
 
 pub struct CodeGenerator {
@@ -30,6 +70,7 @@ pub struct CodeGenerator {
     out_dir: PathBuf,
     tmp_build_dir: PathBuf,
     deps: Vec<CosmosProject>,
+    descriptor_set: Option<PathBuf>,
 }
 
 impl CodeGenerator {
@@ -40,12 +81,53 @@ impl CodeGenerator {
         project: CosmosProject,
         deps: Vec<CosmosProject>,
     ) -> Self {
-        let tonic_build_config = tonic_build::configure()
-            .build_client(false)
-            .build_server(false)
-            .extern_path(".google.protobuf.Timestamp", "crate::shim::Timestamp")
-            .extern_path(".google.protobuf.Duration", "crate::shim::Duration")
-            .extern_path(".google.protobuf.Any", "crate::shim::Any");
+        Self::with_extern_paths(
+            out_dir,
+            tmp_build_dir,
+            project,
+            deps,
+            &default_extern_paths(),
+        )
+    }
+
+    pub fn from_manifest(manifest: &Manifest) -> Self {
+        let code_generator = Self::with_extern_paths(
+            manifest.out_dir.clone(),
+            manifest.tmp_build_dir.clone(),
+            manifest.project.clone(),
+            manifest.deps.clone(),
+            &manifest.extern_paths,
+        );
+
+        match &manifest.descriptor_set {
+            Some(descriptor_set) => code_generator.descriptor_set(descriptor_set.clone()),
+            None => code_generator,
+        }
+    }
+
+    /// Generate from a serialized `FileDescriptorSet` instead of running `protoc`
+    /// on the projects' protos. The files in it of the modules to generate are generated,
+    /// along with the files they import, as `protoc` would do.
+    pub fn descriptor_set(self, descriptor_set: PathBuf) -> Self {
+        Self {
+            descriptor_set: Some(descriptor_set),
+            ..self
+        }
+    }
+
+    fn with_extern_paths(
+        out_dir: PathBuf,
+        tmp_build_dir: PathBuf,
+        project: CosmosProject,
+        deps: Vec<CosmosProject>,
+        extern_paths: &BTreeMap<String, String>,
+    ) -> Self {
+        let tonic_build_config = extern_paths.iter().fold(
+            tonic_build::configure()
+                .build_client(true)
+                .build_server(false),
+            |config, (proto_path, rust_path)| config.extern_path(proto_path, rust_path),
+        );
 
         Self {
             project,
@@ -54,6 +136,7 @@ impl CodeGenerator {
             out_dir,
             tmp_build_dir,
             deps,
+            descriptor_set: None,
         }
     }
 
@@ -68,6 +151,7 @@ impl CodeGenerator {
 
         self.transform();
         self.generate_mod_file();
+        self.generate_registry_file();
         self.fmt();
 
         info!(
@@ -92,11 +176,17 @@ impl CodeGenerator {
         mod_gen::generate_mod_file(&self.absolute_out_dir());
     }
 
+    fn generate_registry_file(&self) {
+        registry_gen::generate_registry_file(&self.absolute_out_dir());
+    }
+
     fn transform(&self) {
         transform::copy_and_transform_all(
             &self.tmp_namespaced_dir(),
             &self.absolute_out_dir(),
             &self.file_descriptor_set(),
+            &self.custom_types(),
+            &self.amino_fields(),
         );
     }
 
@@ -124,77 +214,157 @@ impl CodeGenerator {
     }
 
     fn compile_proto(&self) {
-        let include_paths = ["proto", "third_party/proto"];
+        let descriptor_file = self.tmp_namespaced_dir().join(DESCRIPTOR_FILE);
+        let mut config = prost_build::Config::new();
+
+        let (protos, proto_includes_paths) = match &self.descriptor_set {
+            Some(descriptor_set) => {
+                info!(
+                    "🧪 [{}] Compiling types from {}...",
+                    self.project.name,
+                    descriptor_set.display()
+                );
+
+                let descriptor_bytes = fs::read(self.root.join(descriptor_set)).unwrap();
+                fs::write(&descriptor_file, self.included_files(&descriptor_bytes)).unwrap();
+                config.skip_protoc_run();
+                (vec![], vec![])
+            }
+            None => {
+                info!(
+                    "🧪 [{}] Compiling types from protobuf definitions...",
+                    self.project.name
+                );
+
+                self.protos()
+            }
+        };
+
+        self.tonic_build_config
+            .clone()
+            .out_dir(self.tmp_namespaced_dir())
+            .file_descriptor_set_path(&descriptor_file)
+            .compile_with_config(config, &protos, &proto_includes_paths)
+            .unwrap();
 
+        info!(
+            "✨  [{}] Types from protobuf definitions is compiled successfully!",
+            self.project.name
+        );
+    }
+
+    /// The proto files of the modules to generate, sorted so that `protoc` sees them
+    /// in the same order on every machine, along with the paths to include.
+    fn protos(&self) -> (Vec<PathBuf>, Vec<PathBuf>) {
         let all_related_projects = vec![self.deps.clone(), vec![self.project.clone()]].concat();
 
         // construct absolute paths to be included from all related projects
         let proto_includes_paths: Vec<PathBuf> = all_related_projects
             .iter()
             .flat_map(|project| {
-                {
-                    include_paths
-                        .iter()
-                        .map(|path| self.root.join(&project.project_dir).join(path))
-                }
+                let project_dir = self.root.join(&project.project_dir);
+                [&project.proto_dir]
+                    .into_iter()
+                    .chain(&project.include_dirs)
+                    .map(move |path| project_dir.join(path))
             })
-            .map(PathBuf::from)
             .collect();
 
-        let proto_paths = all_related_projects
+        // List available proto files of the modules in include_mods, or every module if it is
+        // empty, minus exclude_mods
+        let mut protos: Vec<PathBuf> = all_related_projects
             .iter()
-            .map(|p| {
-                let paths = fs::read_dir(
-                    self.root
-                        .join(&p.project_dir)
-                        .join(format!("proto/{}", p.name)),
-                )
-                .unwrap()
-                .map(|d| d.unwrap().path().to_string_lossy().to_string())
-                .collect::<Vec<String>>();
-
-                // if include_mods is not empty, include only those, else include everything
-                if !p.include_mods.is_empty() {
-                    let include_mods = p.include_mods.to_vec();
-                    paths
-                        .into_iter()
-                        .filter(|p| include_mods.iter().any(|m| p.contains(m)))
-                        .collect()
-                } else {
-                    paths
-                }
+            .flat_map(|p| {
+                let proto_root = self
+                    .root
+                    .join(&p.project_dir)
+                    .join(&p.proto_dir)
+                    .join(&p.name);
+
+                let mut protos = vec![];
+                collect_protos(&[proto_root.to_string_lossy().to_string()], &mut protos);
+                protos.retain(|proto| {
+                    proto
+                        .parent()
+                        .and_then(|dir| dir.strip_prefix(&proto_root).ok())
+                        .and_then(Path::to_str)
+                        .is_some_and(|module| p.includes_mod(module))
+                });
+                protos
             })
-            .collect::<Vec<Vec<String>>>()
-            .concat();
+            .collect();
+        protos.sort();
 
-        // List available proto files
-        let mut protos: Vec<PathBuf> = vec![];
-        collect_protos(&proto_paths, &mut protos);
+        (protos, proto_includes_paths)
+    }
 
-        info!(
-            "🧪 [{}] Compiling types from protobuf definitions...",
-            self.project.name
-        );
+    /// The files of a serialized `FileDescriptorSet` that are in the modules to generate, along
+    /// with the files they import, which is what `protoc --include_imports` gives for `protos`.
+    fn included_files(&self, descriptor_bytes: &[u8]) -> Vec<u8> {
+        let all_related_projects = [self.deps.clone(), vec![self.project.clone()]].concat();
+
+        // files are named by their path under the `proto_dir` of their project,
+        // e.g. `osmosis/gamm/v1beta1/tx.proto`
+        let is_included = |name: &str| {
+            all_related_projects.iter().any(|p| {
+                Path::new(name)
+                    .parent()
+                    .and_then(|dir| dir.strip_prefix(&p.name).ok())
+                    .and_then(Path::to_str)
+                    .is_some_and(|module| p.includes_mod(module))
+            })
+        };
 
-        let descriptor_file = self.tmp_namespaced_dir().join(DESCRIPTOR_FILE);
-        self.tonic_build_config
-            .clone()
-            .out_dir(self.tmp_namespaced_dir())
-            .file_descriptor_set_path(&descriptor_file)
-            .compile(&protos, &proto_includes_paths)
-            .unwrap();
+        let files: Vec<(FileImports, Vec<u8>)> = EncodedFileDescriptorSet::decode(descriptor_bytes)
+            .unwrap()
+            .file
+            .into_iter()
+            .map(|file| (FileImports::decode(&file[..]).unwrap(), file))
+            .collect();
 
-        info!(
-            "✨  [{}] Types from protobuf definitions is compiled successfully!",
-            self.project.name
-        );
+        let mut included: HashSet<&str> = files
+            .iter()
+            .map(|(imports, _)| imports.name())
+            .filter(|name| is_included(name))
+            .collect();
+        let mut to_visit: Vec<&str> = included.iter().copied().collect();
+        while let Some(name) = to_visit.pop() {
+            let dependencies = files
+                .iter()
+                .filter(|(imports, _)| imports.name() == name)
+                .flat_map(|(imports, _)| &imports.dependency);
+            for dependency in dependencies {
+                if included.insert(dependency) {
+                    to_visit.push(dependency);
+                }
+            }
+        }
+
+        EncodedFileDescriptorSet {
+            file: files
+                .iter()
+                .filter(|(imports, _)| included.contains(imports.name()))
+                .map(|(_, file)| file.clone())
+                .collect(),
+        }
+        .encode_to_vec()
     }
 
     pub fn file_descriptor_set(&self) -> FileDescriptorSet {
-        let descriptor_file = self.tmp_namespaced_dir().join(DESCRIPTOR_FILE);
-        let descriptor_bytes = &fs::read(descriptor_file).unwrap()[..];
+        FileDescriptorSet::decode(&self.descriptor_bytes()[..]).unwrap()
+    }
 
-        FileDescriptorSet::decode(descriptor_bytes).unwrap()
+    pub fn custom_types(&self) -> CustomTypes {
+        extract_custom_types(&self.descriptor_bytes())
+    }
+
+    pub fn amino_fields(&self) -> AminoFields {
+        extract_amino_fields(&self.descriptor_bytes())
+    }
+
+    fn descriptor_bytes(&self) -> Vec<u8> {
+        let descriptor_file = self.tmp_namespaced_dir().join(DESCRIPTOR_FILE);
+        fs::read(descriptor_file).unwrap()
     }
 
     // TODO: create config tonic
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use prost::Message;
+use syn::parse_quote;
+
+use crate::descriptor;
+
+/// Cosmos SDK custom types that are mapped to the wrappers in `osmosis_std::shim`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CustomType {
+    Dec,
+    Int,
+}
+
+impl CustomType {
+    fn from_gogoproto(customtype: &str) -> Option<Self> {
+        match customtype.rsplit('.').next() {
+            Some("Dec") => Some(CustomType::Dec),
+            Some("Int") => Some(CustomType::Int),
+            _ => None,
+        }
+    }
+
+    pub fn rust_type(&self) -> syn::Type {
+        match self {
+            CustomType::Dec => parse_quote!(crate::shim::Dec),
+            CustomType::Int => parse_quote!(crate::shim::Int),
+        }
+    }
+}
+
+/// Custom typed fields keyed by `(message path, field name)`, where message path is the package
+/// followed by the (nested) message name in upper camel case, e.g. `osmosis.gamm.v1beta1.PoolParams`,
+/// and field name is in snake case, the same way prost names them.
+pub type CustomTypes = HashMap<(String, String), CustomType>;
+
+/// Collect non-nullable `sdk.Dec` and `sdk.Int` fields from serialized `FileDescriptorSet`.
+/// Nullable ones are left as `String` since an empty value must not be encoded for them.
+pub fn extract_custom_types(descriptor_bytes: &[u8]) -> CustomTypes {
+    let descriptor = descriptor::FileDescriptorSet::decode(descriptor_bytes).unwrap();
+
+    let mut custom_types = CustomTypes::new();
+    for file in descriptor.file {
+        let package = file.package.unwrap_or_default();
+        for message in &file.message_type {
+            collect_custom_types(&package, message, &mut custom_types);
+        }
+    }
+    custom_types
+}
+
+fn collect_custom_types(
+    path: &str,
+    message: &descriptor::DescriptorProto,
+    custom_types: &mut CustomTypes,
+) {
+    let message_name = message.name.clone().unwrap_or_default();
+    let path = format!("{}.{}", path, message_name.to_upper_camel_case());
+
+    for field in &message.field {
+        let custom_type = field
+            .options
+            .as_ref()
+            .filter(|options| options.nullable == Some(false))
+            .and_then(|options| options.customtype.as_deref())
+            .and_then(CustomType::from_gogoproto);
+
+        if let (Some(custom_type), Some(name)) = (custom_type, &field.name) {
+            custom_types.insert((path.clone(), name.to_snake_case()), custom_type);
+        }
+    }
+
+    for nested in &message.nested_type {
+        collect_custom_types(&path, nested, custom_types);
+    }
+}
@@ -0,0 +1,76 @@
+//! gogoproto field options are extensions, which `prost_types::FieldOptions` drops
+//! when decoding. These mirror just enough of `descriptor.proto` to keep them.
+
+/// A `FileDescriptorSet` with its files left encoded, so that it can be filtered without losing
+/// anything in them.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EncodedFileDescriptorSet {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub file: Vec<Vec<u8>>,
+}
+
+/// The name of a file and the names of the files it imports.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileImports {
+    #[prost(string, optional, tag = "1")]
+    pub name: Option<String>,
+    #[prost(string, repeated, tag = "3")]
+    pub dependency: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileDescriptorSet {
+    #[prost(message, repeated, tag = "1")]
+    pub file: Vec<FileDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileDescriptorProto {
+    #[prost(string, optional, tag = "2")]
+    pub package: Option<String>,
+    #[prost(message, repeated, tag = "4")]
+    pub message_type: Vec<DescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    pub name: Option<String>,
+    #[prost(message, repeated, tag = "2")]
+    pub field: Vec<FieldDescriptorProto>,
+    #[prost(message, repeated, tag = "3")]
+    pub nested_type: Vec<DescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldDescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    pub name: Option<String>,
+    #[prost(
+        enumeration = "prost_types::field_descriptor_proto::Label",
+        optional,
+        tag = "4"
+    )]
+    pub label: Option<i32>,
+    #[prost(
+        enumeration = "prost_types::field_descriptor_proto::Type",
+        optional,
+        tag = "5"
+    )]
+    pub r#type: Option<i32>,
+    #[prost(message, optional, tag = "8")]
+    pub options: Option<FieldOptions>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldOptions {
+    /// `(gogoproto.nullable)`
+    #[prost(bool, optional, tag = "65001")]
+    pub nullable: Option<bool>,
+    /// `(gogoproto.customtype)`
+    #[prost(string, optional, tag = "65003")]
+    pub customtype: Option<String>,
+    /// `(gogoproto.jsontag)`
+    #[prost(string, optional, tag = "65005")]
+    pub jsontag: Option<String>,
+}
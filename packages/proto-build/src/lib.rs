@@ -1,5 +1,10 @@
+pub mod amino_fields;
 pub mod code_generator;
+pub mod custom_types;
+mod descriptor;
 pub mod git;
+pub mod manifest;
 pub mod mod_gen;
+pub mod registry_gen;
 pub mod transform;
 pub mod transformers;
@@ -1,65 +1,34 @@
-//! Build Osmosis proto files. This build script clones the CosmosSDK and Osmosis version
-//! specified in the COSMOS_SDK_REV and OSMOSIS_REV constant respectively and then
-//! uses that to build the required proto files for further compilation.
+//! Build Osmosis proto files. This build script reads the projects to build from a manifest,
+//! `proto-build.toml` unless another one is given with `--manifest <path>`, and builds the
+//! required proto files for further compilation. With `--update-deps`, the submodule of each
+//! project is checked out at the version given in the manifest first, which is skipped when
+//! the manifest gives a prebuilt `descriptor_set` to generate from, since that needs no sources.
 //! This is based on the proto-compiler code in github.com/informalsystems/ibc-rs
 
 use std::{env, path::PathBuf};
 
 use proto_build::{
-    code_generator::{CodeGenerator, CosmosProject},
+    code_generator::CodeGenerator,
     git,
+    manifest::{Manifest, DEFAULT_MANIFEST},
 };
 
-/// The Cosmos SDK commit or tag to be cloned and used to build the proto files
-const COSMOS_SDK_REV: &str = "sdk-v13.0.0-rc1";
-
-/// The osmosis commit or tag to be cloned and used to build the proto files
-const OSMOSIS_REV: &str = "v13.0.0-rc2";
-
-// All paths must end with a / and either be absolute or include a ./ to reference the current
-// working directory.
-
-/// The directory generated cosmos-sdk proto files go into in this repo
-const OUT_DIR: &str = "../osmosis-std/src/types/";
-/// Directory where the cosmos-sdk submodule is located
-const COSMOS_SDK_DIR: &str = "../../dependencies/cosmos-sdk/";
-/// Directory where the osmosis submodule is located
-const OSMOSIS_DIR: &str = "../../dependencies/osmosis/";
-
-/// A temporary directory for proto building
-const TMP_BUILD_DIR: &str = "/tmp/tmp-protobuf/";
-
 pub fn generate() {
     let args: Vec<String> = env::args().collect();
-    if args.iter().any(|arg| arg == "--update-deps") {
-        git::update_submodule(COSMOS_SDK_DIR, COSMOS_SDK_REV);
-        git::update_submodule(OSMOSIS_DIR, OSMOSIS_REV);
-    }
-
-    let tmp_build_dir: PathBuf = TMP_BUILD_DIR.parse().unwrap();
-    let out_dir: PathBuf = OUT_DIR.parse().unwrap();
 
-    let osmosis_project = CosmosProject {
-        name: "osmosis".to_string(),
-        version: OSMOSIS_REV.to_string(),
-        project_dir: OSMOSIS_DIR.to_string(),
-        include_mods: vec![],
-    };
-    let cosmos_project = CosmosProject {
-        name: "cosmos".to_string(),
-        version: COSMOS_SDK_REV.to_string(),
-        project_dir: COSMOS_SDK_DIR.to_string(),
-        include_mods: vec!["bank".to_string()],
+    let manifest_path = match args.iter().position(|arg| arg == "--manifest") {
+        Some(i) => PathBuf::from(args.get(i + 1).expect("--manifest takes a path")),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_MANIFEST),
     };
+    let manifest = Manifest::from_file(&manifest_path);
 
-    let osmosis_code_generator = CodeGenerator::new(
-        out_dir,
-        tmp_build_dir,
-        osmosis_project,
-        vec![cosmos_project],
-    );
+    if args.iter().any(|arg| arg == "--update-deps") && manifest.descriptor_set.is_none() {
+        for project in manifest.deps.iter().chain([&manifest.project]) {
+            git::update_submodule(&project.project_dir, &project.version);
+        }
+    }
 
-    osmosis_code_generator.generate();
+    CodeGenerator::from_manifest(&manifest).generate();
 }
 
 fn main() {
@@ -0,0 +1,258 @@
+//! The TOML manifest that describes what to generate, e.g.
+//!
+//! ```toml
+//! out_dir = "../osmosis-std/src/types/"
+//!
+//! [project]
+//! name = "osmosis"
+//! version = "v13.0.0-rc2"
+//! project_dir = "../../dependencies/osmosis/"
+//!
+//! [[deps]]
+//! name = "cosmos"
+//! version = "sdk-v13.0.0-rc1"
+//! project_dir = "../../dependencies/cosmos-sdk/"
+//! include_mods = ["auth", "bank", "staking"]
+//!
+//! [extern_paths]
+//! ".google.protobuf.Any" = "crate::shim::Any"
+//! ```
+//!
+//! Relative paths are relative to the directory of the manifest. To generate without network
+//! access or `protoc`, point `descriptor_set` at a prebuilt `FileDescriptorSet` instead:
+//!
+//! ```toml
+//! out_dir = "../osmosis-std/src/types/"
+//! descriptor_set = "descriptor.bin"
+//!
+//! [project]
+//! name = "osmosis"
+//! version = "v13.0.0-rc2"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::code_generator::CosmosProject;
+
+/// The manifest that `proto-build` reads when not given one.
+pub const DEFAULT_MANIFEST: &str = "proto-build.toml";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// The directory generated files go into
+    pub out_dir: PathBuf,
+
+    /// A temporary directory for proto building
+    #[serde(default = "default_tmp_build_dir")]
+    pub tmp_build_dir: PathBuf,
+
+    /// The project whose modules are generated
+    pub project: CosmosProject,
+
+    /// Projects whose modules are generated along with `project`, which it imports from
+    #[serde(default)]
+    pub deps: Vec<CosmosProject>,
+
+    /// A serialized `FileDescriptorSet` to generate from, instead of running `protoc` on the
+    /// protos of `project` and `deps`, which then only name the projects and the modules to take
+    /// from it with `include_mods` and `exclude_mods`. Generating from it needs neither `protoc`
+    /// nor the projects' sources, and gives the same output on every machine. The one `protoc`
+    /// writes is kept in `tmp_build_dir` as `<project>/descriptor.bin`.
+    #[serde(default)]
+    pub descriptor_set: Option<PathBuf>,
+
+    /// Proto paths, e.g. `.google.protobuf.Any`, mapped to the Rust path of an existing type
+    /// instead of having one generated
+    #[serde(default = "default_extern_paths")]
+    pub extern_paths: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Read the manifest at `path`, with relative paths in it resolved against its directory.
+    pub fn from_file(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("unable to read manifest {}: {}", path.display(), e));
+        let manifest: Manifest = toml::from_str(&contents)
+            .unwrap_or_else(|e| panic!("invalid manifest {}: {}", path.display(), e));
+
+        let dir = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+            .canonicalize()
+            .unwrap_or_else(|e| panic!("unable to resolve {}: {}", path.display(), e));
+
+        manifest.resolve_paths(&dir)
+    }
+
+    fn resolve_paths(self, dir: &Path) -> Self {
+        let resolve_project = |project: CosmosProject| CosmosProject {
+            project_dir: dir.join(&project.project_dir).to_string_lossy().to_string(),
+            ..project
+        };
+
+        Self {
+            out_dir: dir.join(self.out_dir),
+            tmp_build_dir: dir.join(self.tmp_build_dir),
+            project: resolve_project(self.project),
+            deps: self.deps.into_iter().map(resolve_project).collect(),
+            descriptor_set: self.descriptor_set.map(|path| dir.join(path)),
+            extern_paths: self.extern_paths,
+        }
+    }
+}
+
+fn default_tmp_build_dir() -> PathBuf {
+    PathBuf::from("/tmp/tmp-protobuf/")
+}
+
+/// Well known types that have a counterpart in `osmosis_std::shim`
+pub fn default_extern_paths() -> BTreeMap<String, String> {
+    [
+        (".google.protobuf.Timestamp", "crate::shim::Timestamp"),
+        (".google.protobuf.Duration", "crate::shim::Duration"),
+        (".google.protobuf.Any", "crate::shim::Any"),
+    ]
+    .into_iter()
+    .map(|(proto_path, rust_path)| (proto_path.to_string(), rust_path.to_string()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            out_dir = "../mychain-std/src/types/"
+
+            [project]
+            name = "mychain"
+            version = "v1.0.0"
+            project_dir = "../../dependencies/mychain/"
+
+            [[deps]]
+            name = "cosmos"
+            version = "v0.46.6"
+            project_dir = "../../dependencies/cosmos-sdk/"
+            include_mods = ["auth", "bank", "gov"]
+            exclude_mods = ["gov"]
+            "#,
+        )
+        .unwrap();
+        let manifest = manifest.resolve_paths(Path::new("/build"));
+
+        assert_eq!(
+            manifest.out_dir,
+            Path::new("/build/../mychain-std/src/types/")
+        );
+        assert_eq!(manifest.tmp_build_dir, Path::new("/tmp/tmp-protobuf/"));
+        assert_eq!(
+            manifest.project.project_dir,
+            "/build/../../dependencies/mychain/"
+        );
+        assert!(manifest.project.include_mods.is_empty());
+        assert_eq!(manifest.project.proto_dir, "proto");
+        assert_eq!(manifest.project.include_dirs, ["third_party/proto"]);
+        assert_eq!(manifest.deps[0].include_mods, ["auth", "bank", "gov"]);
+        assert_eq!(manifest.deps[0].exclude_mods, ["gov"]);
+        assert_eq!(manifest.descriptor_set, None);
+        assert_eq!(manifest.extern_paths, default_extern_paths());
+    }
+
+    #[test]
+    fn test_parse_manifest_with_descriptor_set() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            out_dir = "../osmosis-std/src/types/"
+            descriptor_set = "descriptor.bin"
+
+            [project]
+            name = "osmosis"
+            version = "v13.0.0-rc2"
+            "#,
+        )
+        .unwrap();
+        let manifest = manifest.resolve_paths(Path::new("/build"));
+
+        assert_eq!(
+            manifest.descriptor_set.as_deref(),
+            Some(Path::new("/build/descriptor.bin"))
+        );
+        assert!(manifest.deps.is_empty());
+    }
+
+    #[test]
+    fn test_default_manifest() {
+        let manifest =
+            Manifest::from_file(Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_MANIFEST));
+
+        assert_eq!(manifest.project.name, "osmosis");
+        assert_eq!(manifest.deps[0].name, "cosmos");
+        assert_eq!(
+            manifest.deps[0].include_mods,
+            [
+                "authz",
+                "bank",
+                "distribution",
+                "feegrant",
+                "gov",
+                "slashing",
+                "upgrade"
+            ]
+        );
+        assert_eq!(manifest.deps[1].name, "tendermint");
+        assert_eq!(manifest.deps[1].proto_dir, "third_party/proto");
+        assert_eq!(manifest.deps[2].name, "ibc");
+        assert_eq!(manifest.deps[3].name, "cosmos");
+        assert_eq!(manifest.deps[3].include_mods, ["gov/v1"]);
+        assert!(manifest.out_dir.ends_with("../osmosis-std/src/types/"));
+        assert_eq!(manifest.extern_paths, default_extern_paths());
+    }
+
+    #[test]
+    fn test_module_filter() {
+        let project = CosmosProject {
+            name: "cosmos".to_string(),
+            version: "v0.46.6".to_string(),
+            project_dir: ".".to_string(),
+            proto_dir: "proto".to_string(),
+            include_dirs: vec![],
+            include_mods: vec!["auth".to_string(), "bank".to_string()],
+            exclude_mods: vec!["bank".to_string()],
+        };
+
+        assert!(project.includes_mod("auth"));
+        assert!(project.includes_mod("auth/v1beta1"));
+        assert!(!project.includes_mod("authz"));
+        assert!(!project.includes_mod("bank"));
+        assert!(!project.includes_mod("bank/v1beta1"));
+    }
+
+    #[test]
+    fn test_nested_module_filter() {
+        let project = CosmosProject {
+            name: "ibc".to_string(),
+            version: "v3.0.0".to_string(),
+            project_dir: ".".to_string(),
+            proto_dir: "proto".to_string(),
+            include_dirs: vec![],
+            include_mods: vec!["applications/transfer".to_string(), "core".to_string()],
+            exclude_mods: vec!["core/connection".to_string()],
+        };
+
+        assert!(project.includes_mod("applications/transfer/v1"));
+        assert!(!project.includes_mod("applications/interchain_accounts/v1"));
+        assert!(!project.includes_mod("applications"));
+        assert!(project.includes_mod("core/channel/v1"));
+        assert!(!project.includes_mod("core/connection/v1"));
+    }
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::Path;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprAssign, Item, ItemStruct, Lit, Token};
+use walkdir::WalkDir;
+
+const REGISTRY_MOD: &str = "registry";
+
+/// Generate `registry.rs` in `types_dir` which registers every message that has a type url,
+/// so that `Any` can be decoded without knowing its type in advance, along with the response
+/// type of each `Query` method, so that a response can be decoded knowing only its path.
+/// Must run after `mod_gen`, since module paths are derived from the final file layout.
+pub fn generate_registry_file(types_dir: &Path) {
+    let mut type_paths = vec![];
+    let mut queries = vec![];
+
+    for e in WalkDir::new(types_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().and_then(|ext| ext.to_str()) == Some("rs"))
+    {
+        let module_path = module_path(types_dir, e.path());
+        let contents = fs::read_to_string(e.path()).unwrap();
+        let file = syn::parse_file(&contents).unwrap_or_else(|err| {
+            panic!(
+                "[error] Unable to parse '{}' while generating registry: {}",
+                e.path().display(),
+                err
+            )
+        });
+
+        collect_type_paths(&module_path, &file.items, &mut type_paths, &mut queries);
+    }
+
+    type_paths.sort();
+    queries.sort();
+
+    // rustfmt leaves the content of brace delimited macro invocations as is,
+    // so the entries are written out one per line here
+    let types = type_paths
+        .iter()
+        .map(|p| format!("        crate::types::{},\n", p.join("::")))
+        .collect::<String>();
+    let queries = queries
+        .iter()
+        .map(|(path, response_type)| {
+            format!(
+                "        {:?} => crate::types::{},\n",
+                path,
+                response_type.join("::")
+            )
+        })
+        .collect::<String>();
+
+    fs::write(
+        types_dir.join(format!("{}.rs", REGISTRY_MOD)),
+        format!(
+            "crate::registry::register_types! {{\n    types: {{\n{}    }}\n    queries: {{\n{}    }}\n}}\n",
+            types, queries
+        ),
+    )
+    .unwrap_or_else(|e| panic!("[error] Error while generating registry.rs: {}", e));
+
+    // the registry is built on `serde-cw-value`, which needs `std`
+    let mod_rs = types_dir.join("mod.rs");
+    let mod_rs_contents = fs::read_to_string(&mod_rs).unwrap();
+    fs::write(
+        mod_rs,
+        format!(
+            "{}#[cfg(feature = \"std\")]\npub(crate) mod {};\n",
+            mod_rs_contents, REGISTRY_MOD
+        ),
+    )
+    .unwrap_or_else(|e| panic!("[error] Error while registering registry module: {}", e));
+}
+
+/// Module path of `file` relative to `types_dir`, e.g. `osmosis/gamm/v1beta1.rs` and
+/// `osmosis/gamm/v1beta1/mod.rs` are both `["osmosis", "gamm", "v1beta1"]`.
+fn module_path(types_dir: &Path, file: &Path) -> Vec<String> {
+    let relative = file.strip_prefix(types_dir).unwrap().with_extension("");
+
+    relative
+        .iter()
+        .map(|s| s.to_string_lossy().to_string())
+        .filter(|s| s != "mod")
+        .collect()
+}
+
+fn collect_type_paths(
+    module_path: &[String],
+    items: &[Item],
+    type_paths: &mut Vec<Vec<String>>,
+    queries: &mut Vec<(String, Vec<String>)>,
+) {
+    for item in items {
+        match item {
+            Item::Struct(s) if has_type_url(s) => {
+                type_paths.push([module_path, &[s.ident.to_string()]].concat());
+
+                if let Some((path, response_type)) = get_query(s) {
+                    queries.push((path, [module_path, &[response_type]].concat()));
+                }
+            }
+            Item::Mod(m) => {
+                if let Some((_, items)) = &m.content {
+                    let module_path = [module_path, &[m.ident.to_string()]].concat();
+                    collect_type_paths(&module_path, items, type_paths, queries);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn has_type_url(s: &ItemStruct) -> bool {
+    s.attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("proto_message"))
+}
+
+/// Path and response type of the `Query` method that takes `s`, from its `proto_query`
+/// attribute. The response type is in the same module as the request.
+fn get_query(s: &ItemStruct) -> Option<(String, String)> {
+    let attr = s
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("proto_query"))?;
+    let args = attr
+        .parse_args_with(Punctuated::<ExprAssign, Token![,]>::parse_terminated)
+        .unwrap_or_else(|e| panic!("[error] Invalid `proto_query` on `{}`: {}", s.ident, e));
+
+    let mut path = None;
+    let mut response_type = None;
+    for arg in args {
+        match (*arg.left, *arg.right) {
+            (Expr::Path(key), Expr::Lit(value)) if key.path.is_ident("path") => {
+                if let Lit::Str(value) = value.lit {
+                    path = Some(value.value());
+                }
+            }
+            (Expr::Path(key), Expr::Path(value)) if key.path.is_ident("response_type") => {
+                response_type = value.path.get_ident().map(|ident| ident.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    path.zip(response_type)
+}
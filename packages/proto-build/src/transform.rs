@@ -7,16 +7,25 @@ use std::fs::{create_dir_all, remove_dir_all};
 use std::path::{Path, PathBuf};
 use std::{fs, io};
 use syn::__private::ToTokens;
-use syn::{parse_quote, File, Item, ItemMod};
+use syn::{File, Item, ItemMod, Type};
 use walkdir::WalkDir;
 
+use crate::amino_fields::AminoFields;
+use crate::code_generator::DESCRIPTOR_FILE;
+use crate::custom_types::CustomTypes;
 use crate::transformers;
 
 /// Protos belonging to these Protobuf packages will be excluded
-/// (i.e. because they are sourced from `tendermint-proto`)
-const EXCLUDED_PROTO_PACKAGES: &[&str] = &["cosmos_proto", "gogoproto", "google", "tendermint"];
+/// (i.e. because they only hold proto options, or are well known types mapped to `crate::shim`)
+const EXCLUDED_PROTO_PACKAGES: &[&str] = &["cosmos_proto", "gogoproto", "google"];
 
-pub fn copy_and_transform_all(from_dir: &Path, to_dir: &Path, descriptor: &FileDescriptorSet) {
+pub fn copy_and_transform_all(
+    from_dir: &Path,
+    to_dir: &Path,
+    descriptor: &FileDescriptorSet,
+    custom_types: &CustomTypes,
+    amino_fields: &AminoFields,
+) {
     let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let to_dir = root.join(to_dir);
     debug!("Copying generated files into '{}'...", to_dir.display());
@@ -31,7 +40,7 @@ pub fn copy_and_transform_all(from_dir: &Path, to_dir: &Path, descriptor: &FileD
     let errors = WalkDir::new(from_dir)
         .into_iter()
         .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.file_type().is_file() && e.file_name() != DESCRIPTOR_FILE)
         .map(|e| {
             let filename = e.file_name().to_os_string().to_str().unwrap().to_string();
             filenames.push(filename.clone());
@@ -39,6 +48,8 @@ pub fn copy_and_transform_all(from_dir: &Path, to_dir: &Path, descriptor: &FileD
                 e.path(),
                 format!("{}/{}", to_dir.display(), &filename),
                 descriptor,
+                custom_types,
+                amino_fields,
             )
         })
         .filter_map(|e| e.err())
@@ -57,6 +68,8 @@ fn copy_and_transform(
     src: &Path,
     dest: impl AsRef<Path>,
     descriptor: &FileDescriptorSet,
+    custom_types: &CustomTypes,
+    amino_fields: &AminoFields,
 ) -> io::Result<()> {
     // Skip proto files belonging to `EXCLUDED_PROTO_PACKAGES`
     for package in EXCLUDED_PROTO_PACKAGES {
@@ -85,8 +98,17 @@ fn copy_and_transform(
     let file = syn::parse_file(&contents);
     if let Ok(file) = file {
         // only transform rust file (skipping `*_COMMIT` file)
-        let items = transform_module(file.items, src, &[], descriptor, false);
+        let items = transform_module(
+            file.items,
+            src,
+            &[],
+            descriptor,
+            custom_types,
+            amino_fields,
+            false,
+        );
         contents = prettyplease::unparse(&File { items, ..file });
+        contents = transformers::sanitize_doc_comments(&contents);
     }
 
     fs::write(dest, &*contents)
@@ -97,9 +119,18 @@ fn transform_module(
     src: &Path,
     ancestors: &[String],
     descriptor: &FileDescriptorSet,
+    custom_types: &CustomTypes,
+    amino_fields: &AminoFields,
     nested_mod: bool,
 ) -> Vec<Item> {
-    let items = transform_items(items, src, ancestors, descriptor);
+    let items = transform_items(
+        items,
+        src,
+        ancestors,
+        descriptor,
+        custom_types,
+        amino_fields,
+    );
     let items = prepend(items);
 
     append(items, src, descriptor, nested_mod)
@@ -122,7 +153,8 @@ fn append(
     descriptor: &FileDescriptorSet,
     nested_mod: bool,
 ) -> Vec<Item> {
-    transformers::append_querier(items, src, nested_mod, descriptor)
+    let items = transformers::append_querier(items, src, nested_mod, descriptor);
+    transformers::append_msg_builder(items, src, nested_mod, descriptor)
 }
 
 fn transform_items(
@@ -130,44 +162,45 @@ fn transform_items(
     src: &Path,
     ancestors: &[String],
     descriptor: &FileDescriptorSet,
+    custom_types: &CustomTypes,
+    amino_fields: &AminoFields,
 ) -> Vec<Item> {
-    // TODO: Remove this temporary hack when cosmos & tendermint code gen is supported
-    let remove_struct_fields_that_depends_on_tendermint_proto = |i: Item| match i.clone() {
-        Item::Struct(s) => {
-            let is_depending_on_tendermint = s.fields.iter().any(|field| {
-                let tt = field.ty.to_token_stream();
-                tt.to_string().contains("tendermint_proto")
-            });
+    let enum_value_names = transformers::extract_enum_value_names(&items);
 
-            if is_depending_on_tendermint {
-                let ident = s.ident;
-                Item::Struct(parse_quote! {
-                    /// NOTE: The following type is not implemented due to current limitations of code generator
-                    /// which currently has issue with tendermint_proto.
-                    /// This will be fixed in the upcoming release.
-                    #[allow(dead_code)]
-                    struct #ident {}
-                })
-            } else {
-                Item::Struct(s)
-            }
-        }
-        _ => i,
-    };
     items
         .into_iter()
-        .map(|i| match i.clone() {
-            Item::Struct(s) => Item::Struct({
+        .flat_map(|i| match i.clone() {
+            Item::Struct(s) => vec![Item::Struct({
                 let s = transformers::add_derive_eq(&s);
                 let s = transformers::append_attrs(src, &s, descriptor);
+                let s = transformers::map_custom_types(src, ancestors, s, custom_types);
+                let s = transformers::add_amino_attrs(src, ancestors, s, amino_fields);
+                let s = transformers::allow_serde_enumeration(s);
                 transformers::allow_serde_int_as_str(s)
-            }),
-
-            _ => i,
+            })],
+            Item::Enum(e) if transformers::is_oneof(&e) => {
+                vec![Item::Enum(transformers::add_serde_to_oneof(e))]
+            }
+            Item::Enum(e) => {
+                let value_names = enum_value_names.get(&e.ident).cloned().unwrap_or_default();
+                vec![Item::Enum(transformers::add_serde_to_enum(e, &value_names))]
+            }
+            // `as_str_name` and `from_str_name` of an enum, followed by its `Enumeration` impl
+            Item::Impl(item_impl) => match &*item_impl.self_ty {
+                Type::Path(ty) => match ty.path.get_ident() {
+                    Some(ident) if enum_value_names.contains_key(ident) => {
+                        vec![i.clone(), transformers::enumeration_impl(ident)]
+                    }
+                    _ => vec![i],
+                },
+                _ => vec![i],
+            },
+
+            _ => vec![i],
+        })
+        .map(|i: Item| {
+            transform_nested_mod(i, src, ancestors, descriptor, custom_types, amino_fields)
         })
-        // TODO: Remove this temporary hack when cosmos & tendermint code gen is supported
-        .map(remove_struct_fields_that_depends_on_tendermint_proto)
-        .map(|i: Item| transform_nested_mod(i, src, ancestors, descriptor))
         .collect::<Vec<Item>>()
 }
 
@@ -176,8 +209,12 @@ fn transform_nested_mod(
     src: &Path,
     ancestors: &[String],
     descriptor: &FileDescriptorSet,
+    custom_types: &CustomTypes,
+    amino_fields: &AminoFields,
 ) -> Item {
     match i.clone() {
+        // gRPC clients generated by tonic are not protobuf messages
+        Item::Mod(m) if is_grpc_client_mod(&m) => i,
         Item::Mod(m) => {
             let parent = &m.ident.to_string().to_upper_camel_case();
             let content = m.content.map(|(brace, items)| {
@@ -188,6 +225,8 @@ fn transform_nested_mod(
                         src,
                         &[ancestors, &[parent.to_string()]].concat(),
                         descriptor,
+                        custom_types,
+                        amino_fields,
                         true,
                     ),
                 )
@@ -198,3 +237,15 @@ fn transform_nested_mod(
         _ => i,
     }
 }
+
+fn is_grpc_client_mod(m: &ItemMod) -> bool {
+    let uses_tonic_codegen = |items: &[Item]| {
+        items.iter().any(|item| match item {
+            Item::Use(u) => u.to_token_stream().to_string() == "use tonic :: codegen :: * ;",
+            _ => false,
+        })
+    };
+
+    m.ident.to_string().ends_with("_client")
+        && matches!(&m.content, Some((_, items)) if uses_tonic_codegen(items))
+}
@@ -3,21 +3,25 @@ use std::path::Path;
 
 use heck::ToSnakeCase;
 use heck::ToUpperCamelCase;
+use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree};
 use prost_types::{
-    DescriptorProto, EnumDescriptorProto, FileDescriptorSet, ServiceDescriptorProto,
+    DescriptorProto, EnumDescriptorProto, FileDescriptorSet, MethodDescriptorProto,
+    ServiceDescriptorProto,
 };
 use regex::Regex;
-use syn::__private::quote::__private::Group;
-use syn::__private::quote::__private::TokenStream as TokenStream2;
-use syn::__private::quote::__private::TokenTree;
 use syn::__private::quote::format_ident;
 use syn::__private::quote::quote;
-use syn::{parse_quote, Attribute, Fields, Ident, Item, ItemStruct, Type};
+use syn::ext::IdentExt;
+use syn::{
+    parse_quote, Attribute, Expr, ExprLit, Fields, Ident, ImplItem, Item, ItemEnum, ItemStruct,
+    Lit, Meta, NestedMeta, Pat, Stmt, Type,
+};
+
+use crate::amino_fields::AminoFields;
+use crate::custom_types::CustomTypes;
 
 /// Regex substitutions to apply to the prost-generated output
 pub const REPLACEMENTS: &[(&str, &str)] = &[
-    // Use `tendermint-proto` proto definitions
-    ("(super::)+tendermint", "tendermint_proto"),
     // Feature-gate gRPC client modules
     (
         "/// Generated client implementations.",
@@ -34,10 +38,237 @@ pub const REPLACEMENTS: &[(&str, &str)] = &[
     ),
 ];
 
+/// Names that messages are registered under with the legacy amino codec, by type url.
+/// The protos don't annotate them, so these mirror the `RegisterLegacyAminoCodec` of each module.
+pub const AMINO_TYPES: &[(&str, &str)] = &[
+    ("/cosmos.bank.v1beta1.MsgSend", "cosmos-sdk/MsgSend"),
+    (
+        "/cosmos.bank.v1beta1.MsgMultiSend",
+        "cosmos-sdk/MsgMultiSend",
+    ),
+    (
+        "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+        "cosmos-sdk/MsgModifyWithdrawAddress",
+    ),
+    (
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+        "cosmos-sdk/MsgWithdrawDelegationReward",
+    ),
+    (
+        "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+        "cosmos-sdk/MsgWithdrawValidatorCommission",
+    ),
+    (
+        "/cosmos.distribution.v1beta1.MsgFundCommunityPool",
+        "cosmos-sdk/MsgFundCommunityPool",
+    ),
+    (
+        "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal",
+        "cosmos-sdk/CommunityPoolSpendProposal",
+    ),
+    (
+        "/cosmos.gov.v1.MsgSubmitProposal",
+        "cosmos-sdk/v1/MsgSubmitProposal",
+    ),
+    (
+        "/cosmos.gov.v1.MsgExecLegacyContent",
+        "cosmos-sdk/v1/MsgExecLegacyContent",
+    ),
+    ("/cosmos.gov.v1.MsgDeposit", "cosmos-sdk/v1/MsgDeposit"),
+    ("/cosmos.gov.v1.MsgVote", "cosmos-sdk/v1/MsgVote"),
+    (
+        "/cosmos.gov.v1.MsgVoteWeighted",
+        "cosmos-sdk/v1/MsgVoteWeighted",
+    ),
+    (
+        "/cosmos.gov.v1beta1.MsgSubmitProposal",
+        "cosmos-sdk/MsgSubmitProposal",
+    ),
+    ("/cosmos.gov.v1beta1.MsgDeposit", "cosmos-sdk/MsgDeposit"),
+    ("/cosmos.gov.v1beta1.MsgVote", "cosmos-sdk/MsgVote"),
+    (
+        "/cosmos.gov.v1beta1.MsgVoteWeighted",
+        "cosmos-sdk/MsgVoteWeighted",
+    ),
+    (
+        "/cosmos.gov.v1beta1.TextProposal",
+        "cosmos-sdk/TextProposal",
+    ),
+    ("/cosmos.slashing.v1beta1.MsgUnjail", "cosmos-sdk/MsgUnjail"),
+    ("/cosmos.upgrade.v1beta1.Plan", "cosmos-sdk/Plan"),
+    (
+        "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal",
+        "cosmos-sdk/SoftwareUpgradeProposal",
+    ),
+    (
+        "/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal",
+        "cosmos-sdk/CancelSoftwareUpgradeProposal",
+    ),
+    (
+        "/ibc.applications.transfer.v1.MsgTransfer",
+        "cosmos-sdk/MsgTransfer",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgJoinPool",
+        "osmosis/gamm/join-pool",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgExitPool",
+        "osmosis/gamm/exit-pool",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn",
+        "osmosis/gamm/swap-exact-amount-in",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgSwapExactAmountOut",
+        "osmosis/gamm/swap-exact-amount-out",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgJoinSwapExternAmountIn",
+        "osmosis/gamm/join-swap-extern-amount-in",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgJoinSwapShareAmountOut",
+        "osmosis/gamm/join-swap-share-amount-out",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgExitSwapExternAmountOut",
+        "osmosis/gamm/exit-swap-extern-amount-out",
+    ),
+    (
+        "/osmosis.gamm.v1beta1.MsgExitSwapShareAmountIn",
+        "osmosis/gamm/exit-swap-share-amount-in",
+    ),
+    (
+        "/osmosis.gamm.poolmodels.balancer.v1beta1.MsgCreateBalancerPool",
+        "osmosis/gamm/create-balancer-pool",
+    ),
+    (
+        "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgCreateStableswapPool",
+        "osmosis/gamm/create-stableswap-pool",
+    ),
+    (
+        "/osmosis.gamm.poolmodels.stableswap.v1beta1.MsgStableSwapAdjustScalingFactors",
+        "osmosis/gamm/stableswap-adjust-scaling-factors",
+    ),
+    (
+        "/osmosis.incentives.MsgCreateGauge",
+        "osmosis/incentives/create-gauge",
+    ),
+    (
+        "/osmosis.incentives.MsgAddToGauge",
+        "osmosis/incentives/add-to-gauge",
+    ),
+    (
+        "/osmosis.lockup.MsgLockTokens",
+        "osmosis/lockup/lock-tokens",
+    ),
+    (
+        "/osmosis.lockup.MsgBeginUnlockingAll",
+        "osmosis/lockup/begin-unlock-tokens",
+    ),
+    (
+        "/osmosis.lockup.MsgBeginUnlocking",
+        "osmosis/lockup/begin-unlock-period-lock",
+    ),
+    (
+        "/osmosis.lockup.MsgExtendLockup",
+        "osmosis/lockup/extend-lockup",
+    ),
+    (
+        "/osmosis.lockup.MsgForceUnlock",
+        "osmosis/lockup/force-unlock-tokens",
+    ),
+    (
+        "/osmosis.superfluid.MsgSuperfluidDelegate",
+        "osmosis/superfluid-delegate",
+    ),
+    (
+        "/osmosis.superfluid.MsgSuperfluidUndelegate",
+        "osmosis/superfluid-undelegate",
+    ),
+    (
+        "/osmosis.superfluid.MsgSuperfluidUnbondLock",
+        "osmosis/superfluid-unbond-lock",
+    ),
+    (
+        "/osmosis.superfluid.MsgLockAndSuperfluidDelegate",
+        "osmosis/lock-and-superfluid-delegate",
+    ),
+    (
+        "/osmosis.superfluid.MsgUnPoolWhitelistedPool",
+        "osmosis/unpool-whitelisted-pool",
+    ),
+    (
+        "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+        "osmosis/tokenfactory/create-denom",
+    ),
+    (
+        "/osmosis.tokenfactory.v1beta1.MsgMint",
+        "osmosis/tokenfactory/mint",
+    ),
+    (
+        "/osmosis.tokenfactory.v1beta1.MsgBurn",
+        "osmosis/tokenfactory/burn",
+    ),
+    (
+        "/osmosis.tokenfactory.v1beta1.MsgChangeAdmin",
+        "osmosis/tokenfactory/change-admin",
+    ),
+    (
+        "/osmosis.tokenfactory.v1beta1.MsgSetDenomMetadata",
+        "osmosis/tokenfactory/set-denom-metadata",
+    ),
+    (
+        "/osmosis.valsetpref.v1beta1.MsgSetValidatorSetPreference",
+        "osmosis/valset-pref/MsgSetValidatorSetPreference",
+    ),
+    (
+        "/osmosis.valsetpref.v1beta1.MsgDelegateToValidatorSet",
+        "osmosis/valset-pref/MsgDelegateToValidatorSet",
+    ),
+    (
+        "/osmosis.valsetpref.v1beta1.MsgUndelegateFromValidatorSet",
+        "osmosis/valset-pref/MsgUndelegateFromValidatorSet",
+    ),
+    (
+        "/osmosis.valsetpref.v1beta1.MsgWithdrawDelegationRewards",
+        "osmosis/valset-pref/MsgWithdrawDelegationRewards",
+    ),
+];
+
+/// Fields that hold the address that signs a message, in order of precedence, since a message
+/// can also hold the addresses of others, e.g. `validator_address` of `MsgWithdrawDelegatorReward`.
+/// The protos of the Cosmos SDK v0.45 don't annotate signers, so these follow the `GetSigners`
+/// of each message. Messages signed by a validator's operator, e.g. `MsgUnjail`, are left out,
+/// since a contract is no validator operator.
+pub const SIGNER_FIELDS: &[&str] = &[
+    "sender",
+    "signer",
+    "owner",
+    "delegator",
+    "delegator_address",
+    "from_address",
+    "granter",
+    "grantee",
+    "proposer",
+    "voter",
+    "depositor",
+];
+
+/// Packages whose messages get no builder, since they are submitted by relayers rather than by
+/// the account that a builder is made for.
+pub const EXCLUDED_MSG_BUILDER_PACKAGES: &[&str] = &["ibc.core"];
+
 pub fn add_derive_eq(s: &ItemStruct) -> ItemStruct {
     let mut item_struct = s.clone();
-    item_struct.attrs = item_struct
-        .attrs
+    item_struct.attrs = add_eq_to_derive(item_struct.attrs);
+    item_struct
+}
+
+fn add_eq_to_derive(attrs: Vec<Attribute>) -> Vec<Attribute> {
+    attrs
         .into_iter()
         .map(|mut attr| {
             // find derive attribute
@@ -96,19 +327,33 @@ pub fn add_derive_eq(s: &ItemStruct) -> ItemStruct {
                 attr
             }
         })
-        .collect();
-
-    item_struct
+        .collect()
 }
 
 pub fn append_attrs(src: &Path, s: &ItemStruct, descriptor: &FileDescriptorSet) -> ItemStruct {
     let mut s = s.clone();
     let query_services = extract_query_services(descriptor);
+    let msg_services = extract_msg_services(descriptor);
     let type_url = get_type_url(src, &s.ident, descriptor);
 
+    let amino_type = AMINO_TYPES
+        .iter()
+        .find(|(url, _)| *url == type_url)
+        .map(|(_, amino_type)| quote! { amino_type = #amino_type });
+    let response_type = get_msg_response_type(src, &s.ident, &msg_services)
+        .map(|response_type| quote! { response_type = #response_type });
+    let proto_message_args = [
+        Some(quote! { type_url = #type_url }),
+        amino_type,
+        response_type,
+    ]
+    .into_iter()
+    .flatten();
+
     s.attrs.append(&mut vec![
-        syn::parse_quote! { #[derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema, CosmwasmExt)] },
-        syn::parse_quote! { #[proto_message(type_url = #type_url)] },
+        syn::parse_quote! { #[derive(serde::Serialize, serde::Deserialize, CosmwasmExt)] },
+        syn::parse_quote! { #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))] },
+        syn::parse_quote! { #[proto_message(#(#proto_message_args),*)] },
     ]);
 
     if let Some(attr) = get_query_attr(src, &s.ident, &query_services) {
@@ -139,7 +384,7 @@ pub fn allow_serde_int_as_str(s: ItemStruct) -> ItemStruct {
                 parse_quote!(usize),
             ];
 
-            if int_types.contains(&field.ty) {
+            if int_types.contains(&field.ty) && get_enumeration(&field).is_none() {
                 let from_str: syn::Attribute = parse_quote! {
                     #[serde(
                         serialize_with = "crate::serde::as_str::serialize",
@@ -161,24 +406,375 @@ pub fn allow_serde_int_as_str(s: ItemStruct) -> ItemStruct {
 
     syn::ItemStruct { fields, ..s }
 }
+/// (De)serialize `i32` fields that hold an enum as the names of the enum's values,
+/// with the enum as their JSON schema.
+pub fn allow_serde_enumeration(s: ItemStruct) -> ItemStruct {
+    let fields_vec = s
+        .fields
+        .clone()
+        .into_iter()
+        .map(|mut field| match get_enumeration(&field) {
+            Some(enumeration) if field.ty == parse_quote!(i32) => {
+                let serialize_with =
+                    format!("crate::serde::enumeration::serialize::<{}, _>", enumeration);
+                let deserialize_with = format!(
+                    "crate::serde::enumeration::deserialize::<{}, _>",
+                    enumeration
+                );
+                field.attrs.append(&mut vec![
+                    parse_quote! {
+                        #[serde(
+                            serialize_with = #serialize_with,
+                            deserialize_with = #deserialize_with
+                        )]
+                    },
+                    parse_quote! {
+                        #[cfg_attr(feature = "cosmwasm", schemars(with = #enumeration))]
+                    },
+                ]);
+                field
+            }
+            _ => field,
+        })
+        .collect::<Vec<syn::Field>>();
+
+    let fields_named: syn::FieldsNamed = parse_quote! {
+        { #(#fields_vec,)* }
+    };
+    let fields = syn::Fields::Named(fields_named);
+
+    syn::ItemStruct { fields, ..s }
+}
+
+/// Names of the values of each enum in `items` by variant, which are the names in the proto file.
+/// They are read from the `as_str_name` that prost generates for each enum.
+pub fn extract_enum_value_names(items: &[Item]) -> HashMap<Ident, HashMap<Ident, String>> {
+    items
+        .iter()
+        .filter_map(|item| {
+            let item_impl = match item {
+                Item::Impl(item_impl) if item_impl.trait_.is_none() => item_impl,
+                _ => return None,
+            };
+            let enum_ident = match &*item_impl.self_ty {
+                Type::Path(ty) => ty.path.get_ident()?.clone(),
+                _ => return None,
+            };
+            let as_str_name = item_impl.items.iter().find_map(|item| match item {
+                ImplItem::Method(method) if method.sig.ident == "as_str_name" => Some(method),
+                _ => None,
+            })?;
+            let arms = match as_str_name.block.stmts.first()? {
+                Stmt::Expr(Expr::Match(expr_match)) => &expr_match.arms,
+                _ => return None,
+            };
+
+            let names = arms
+                .iter()
+                .filter_map(|arm| {
+                    let variant = match &arm.pat {
+                        Pat::Path(pat) => pat.path.segments.last()?.ident.clone(),
+                        _ => return None,
+                    };
+                    match &*arm.body {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(name),
+                            ..
+                        }) => Some((variant, name.value())),
+                        _ => None,
+                    }
+                })
+                .collect();
+
+            Some((enum_ident, names))
+        })
+        .collect()
+}
+
+/// Derive serde and JSON schema for an enum, naming each value as in the proto file.
+pub fn add_serde_to_enum(e: ItemEnum, value_names: &HashMap<Ident, String>) -> ItemEnum {
+    let mut e = e;
+
+    let derive_pos = e
+        .attrs
+        .iter()
+        .position(|attr| attr.path.is_ident("derive"))
+        .map_or(0, |pos| pos + 1);
+    e.attrs.splice(
+        derive_pos..derive_pos,
+        [
+            parse_quote! { #[derive(serde::Serialize, serde::Deserialize)] },
+            parse_quote! { #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))] },
+        ],
+    );
+
+    for variant in e.variants.iter_mut() {
+        if let Some(name) = value_names.get(&variant.ident) {
+            variant
+                .attrs
+                .push(parse_quote! { #[serde(rename = #name)] });
+        }
+    }
+
+    e
+}
+
+/// Whether `e` is the enum that prost generates for a `oneof`, rather than for a proto enum.
+pub fn is_oneof(e: &ItemEnum) -> bool {
+    e.attrs.iter().any(|attr| {
+        attr.path.is_ident("derive")
+            && attr
+                .tokens
+                .to_string()
+                .replace(' ', "")
+                .contains("::prost::Oneof")
+    })
+}
+
+/// Derive `Eq`, serde, JSON schema and `CosmwasmExt` for the enum of a `oneof`, the same as
+/// for the message holding it, with the field that is set named as in the proto file.
+pub fn add_serde_to_oneof(e: ItemEnum) -> ItemEnum {
+    let mut e = e;
+    e.attrs = add_eq_to_derive(e.attrs);
+
+    let derive_pos = e
+        .attrs
+        .iter()
+        .position(|attr| attr.path.is_ident("derive"))
+        .map_or(0, |pos| pos + 1);
+    e.attrs.splice(
+        derive_pos..derive_pos,
+        [
+            parse_quote! { #[derive(serde::Serialize, serde::Deserialize, CosmwasmExt)] },
+            parse_quote! { #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))] },
+            parse_quote! { #[serde(rename_all = "snake_case")] },
+        ],
+    );
+
+    e
+}
+
+/// `impl crate::serde::enumeration::Enumeration`, which the (de)serialization of
+/// enum fields needs to tell valid values apart.
+pub fn enumeration_impl(enum_ident: &Ident) -> Item {
+    parse_quote! {
+        impl crate::serde::enumeration::Enumeration for #enum_ident {
+            fn from_i32(value: i32) -> ::core::option::Option<Self> {
+                #enum_ident::from_i32(value)
+            }
+        }
+    }
+}
+
+/// Replace `String` fields that hold `sdk.Dec` or `sdk.Int` with their typed wrappers.
+/// The wrappers dereference to `String`, so prost keeps encoding them as strings.
+pub fn map_custom_types(
+    src: &Path,
+    ancestors: &[String],
+    s: ItemStruct,
+    custom_types: &CustomTypes,
+) -> ItemStruct {
+    let package = src.file_stem().unwrap().to_str().unwrap();
+    let message_path = [&[package.to_string()], ancestors, &[s.ident.to_string()]]
+        .concat()
+        .join(".");
+    let string_type: Type = parse_quote!(::prost::alloc::string::String);
+
+    let fields_vec = s
+        .fields
+        .clone()
+        .into_iter()
+        .map(|mut field| {
+            let custom_type = field
+                .ident
+                .as_ref()
+                .and_then(|ident| custom_types.get(&(message_path.clone(), ident.to_string())));
+
+            match custom_type {
+                Some(custom_type) if field.ty == string_type => {
+                    field.ty = custom_type.rust_type();
+                    field.attrs = field
+                        .attrs
+                        .into_iter()
+                        .map(|attr| {
+                            if attr.path.is_ident("prost") {
+                                required_string_attr(&attr)
+                            } else {
+                                attr
+                            }
+                        })
+                        .collect();
+                    field
+                }
+                _ => field,
+            }
+        })
+        .collect::<Vec<syn::Field>>();
+
+    let fields_named: syn::FieldsNamed = parse_quote! {
+        { #(#fields_vec,)* }
+    };
+    let fields = syn::Fields::Named(fields_named);
+
+    syn::ItemStruct { fields, ..s }
+}
+
+/// `#[amino(name = "...", not_omitted)]` on the fields whose amino JSON differs from their prost
+/// name, left out when empty.
+pub fn add_amino_attrs(
+    src: &Path,
+    ancestors: &[String],
+    s: ItemStruct,
+    amino_fields: &AminoFields,
+) -> ItemStruct {
+    let package = src.file_stem().unwrap().to_str().unwrap();
+    let message_path = [&[package.to_string()], ancestors, &[s.ident.to_string()]]
+        .concat()
+        .join(".");
+
+    let fields_vec = s
+        .fields
+        .clone()
+        .into_iter()
+        .map(|mut field| {
+            let amino_field = field.ident.as_ref().and_then(|ident| {
+                amino_fields.get(&(message_path.clone(), ident.unraw().to_string()))
+            });
+
+            if let Some(amino_field) = amino_field {
+                let name = amino_field.name.as_ref().map(|name| quote!(name = #name));
+                let not_omitted = amino_field.not_omitted.then(|| quote!(not_omitted));
+                let args = name.into_iter().chain(not_omitted);
+                field.attrs.push(parse_quote! { #[amino(#(#args),*)] });
+            }
+            field
+        })
+        .collect::<Vec<syn::Field>>();
+
+    let fields_named: syn::FieldsNamed = parse_quote! {
+        { #(#fields_vec,)* }
+    };
+    let fields = syn::Fields::Named(fields_named);
+
+    syn::ItemStruct { fields, ..s }
+}
+
+/// Fix up the `///` comments copied from the protos, which are plain text rather than markdown:
+/// lines that wrap a list item are indented under it, and lines starting with `>` are escaped
+/// so they don't start a quote.
+pub fn sanitize_doc_comments(contents: &str) -> String {
+    let list_item = Regex::new(r"^(\s*)([-*+]|\d+[.)])(\s+)\S").unwrap();
+
+    // indentation of the text of the list item that the current line may wrap
+    let mut item_indent: Option<usize> = None;
+    let mut in_code_block = false;
+
+    let mut lines = contents
+        .lines()
+        .map(|line| {
+            let doc = match line.trim_start().strip_prefix("///") {
+                Some(doc) => doc.strip_prefix(' ').unwrap_or(doc),
+                None => {
+                    item_indent = None;
+                    in_code_block = false;
+                    return line.to_string();
+                }
+            };
+            let prefix = &line[..line.len() - line.trim_start().len()];
+
+            if doc.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block || doc.trim().is_empty() {
+                item_indent = None;
+                return line.to_string();
+            }
+
+            let indent = doc.len() - doc.trim_start().len();
+            let mut sanitized = match list_item.captures(doc) {
+                Some(item) => {
+                    item_indent = Some(item[0].len() - 1);
+                    None
+                }
+                None => match item_indent {
+                    Some(item_indent) if indent < item_indent => {
+                        Some(format!("{}{}", " ".repeat(item_indent), doc.trim_start()))
+                    }
+                    _ => None,
+                },
+            };
+
+            let text = sanitized.as_deref().unwrap_or(doc);
+            if let Some(quoted) = text.trim_start().strip_prefix('>') {
+                let indent = text.len() - text.trim_start().len();
+                sanitized = Some(format!("{}\\>{}", &text[..indent], quoted));
+            }
+
+            match sanitized {
+                Some(doc) => format!("{}/// {}", prefix, doc),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    if contents.ends_with('\n') {
+        lines.push('\n');
+    }
+    lines
+}
+
 // ====== helpers ======
 
+/// `#[prost(string, tag = "N")]` to `#[prost(string, required, tag = "N", default = "0")]`,
+/// so that the field is always encoded like gogoproto does for non-nullable custom types.
+fn required_string_attr(attr: &Attribute) -> Attribute {
+    let tag = match attr.parse_meta() {
+        Ok(Meta::List(list)) => list.nested.into_iter().find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("tag") => match nv.lit {
+                Lit::Str(tag) => Some(tag),
+                _ => None,
+            },
+            _ => None,
+        }),
+        _ => None,
+    }
+    .expect("prost attribute must have a tag");
+
+    parse_quote! { #[prost(string, required, tag = #tag, default = "0")] }
+}
+
+/// The enum that a field holds, from `#[prost(enumeration = "...")]`.
+fn get_enumeration(field: &syn::Field) -> Option<String> {
+    field.attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("prost") {
+            return None;
+        }
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.into_iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("enumeration") => {
+                    match nv.lit {
+                        Lit::Str(enumeration) => Some(enumeration.value()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
+
 fn get_query_attr(
     src: &Path,
     ident: &Ident,
     query_services: &HashMap<String, ServiceDescriptorProto>,
 ) -> Option<Attribute> {
     let package = src.file_stem().unwrap().to_str().unwrap();
-    let service = query_services.get(package);
-
-    let method = service?.method.iter().find(|m| {
-        let input_type = m.input_type.clone().unwrap();
-        let input_type = input_type.split('.').last().unwrap();
-        *ident == input_type.to_upper_camel_case()
-    });
+    let method = find_method(src, ident, query_services)?;
 
-    let method_name = method?.name.clone().unwrap();
-    let response_type = method?.output_type.clone().unwrap();
+    let method_name = method.name.clone().unwrap();
+    let response_type = method.output_type.clone().unwrap();
     let response_type = response_type.split('.').last().unwrap();
     let response_type = format_ident!("{}", response_type.to_upper_camel_case());
 
@@ -186,6 +782,35 @@ fn get_query_attr(
     Some(syn::parse_quote! { #[proto_query(path = #path, response_type = #response_type)] })
 }
 
+/// The response of the method of the package's `Msg` service that takes the message, if any.
+fn get_msg_response_type(
+    src: &Path,
+    ident: &Ident,
+    msg_services: &HashMap<String, ServiceDescriptorProto>,
+) -> Option<Ident> {
+    let response_type = find_method(src, ident, msg_services)?
+        .output_type
+        .clone()
+        .unwrap();
+    let response_type = response_type.rsplit('.').next().unwrap();
+    Some(format_ident!("{}", response_type.to_upper_camel_case()))
+}
+
+/// The method of the package's service that takes the message.
+fn find_method<'a>(
+    src: &Path,
+    ident: &Ident,
+    services: &'a HashMap<String, ServiceDescriptorProto>,
+) -> Option<&'a MethodDescriptorProto> {
+    let package = src.file_stem().unwrap().to_str().unwrap();
+
+    services.get(package)?.method.iter().find(|m| {
+        let input_type = m.input_type.clone().unwrap();
+        let input_type = input_type.rsplit('.').next().unwrap();
+        *ident == input_type.to_upper_camel_case()
+    })
+}
+
 fn get_type_url(src: &Path, ident: &Ident, descriptor: &FileDescriptorSet) -> String {
     let type_path = src.file_stem().unwrap().to_str().unwrap();
     let init_path = "";
@@ -247,6 +872,20 @@ fn extract_type_path_from_enum(
 
 pub fn extract_query_services(
     descriptor: &FileDescriptorSet,
+) -> HashMap<String, ServiceDescriptorProto> {
+    extract_services(descriptor, "Query")
+}
+
+pub fn extract_msg_services(
+    descriptor: &FileDescriptorSet,
+) -> HashMap<String, ServiceDescriptorProto> {
+    extract_services(descriptor, "Msg")
+}
+
+/// The services named `name`, by package.
+fn extract_services(
+    descriptor: &FileDescriptorSet,
+    name: &str,
 ) -> HashMap<String, ServiceDescriptorProto> {
     descriptor
         .clone()
@@ -256,7 +895,7 @@ pub fn extract_query_services(
             let service = f
                 .service
                 .into_iter()
-                .find(|s| s.name == Some("Query".to_string()));
+                .find(|s| s.name.as_deref() == Some(name));
 
             if let Some(service) = service {
                 Some((
@@ -334,11 +973,14 @@ pub fn append_querier(
         if !nested_mod {
             vec![
                 parse_quote! {
+                  #[cfg(feature = "cosmwasm")]
+                  #[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
                   pub struct #querier_wrapper_ident<'a, Q: cosmwasm_std::CustomQuery> {
                       querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>,
                   }
                 },
                 parse_quote! {
+                  #[cfg(feature = "cosmwasm")]
                   impl<'a, Q: cosmwasm_std::CustomQuery> #querier_wrapper_ident<'a, Q> {
                       pub fn new(querier: &'a cosmwasm_std::QuerierWrapper<'a, Q>) -> Self {
                     Self { querier }
@@ -357,9 +999,104 @@ pub fn append_querier(
     vec![items, querier].concat()
 }
 
+/// `<Module>MsgBuilder`, which builds the `CosmosMsg` of each method of the `Msg` service from
+/// the fields of the message other than its signer, which is the sender of the builder.
+pub fn append_msg_builder(
+    items: Vec<Item>,
+    src: &Path,
+    nested_mod: bool,
+    descriptor: &FileDescriptorSet,
+) -> Vec<Item> {
+    let package = src.file_stem().unwrap().to_str().unwrap();
+    let re = Regex::new(r"([^.]*)(\.v\d+(beta\d+)?)?$").unwrap();
+
+    let package_stem = re.captures(package).unwrap().get(1).unwrap().as_str();
+
+    let builder_ident = format_ident!("{}MsgBuilder", &package_stem.to_upper_camel_case());
+
+    if EXCLUDED_MSG_BUILDER_PACKAGES
+        .iter()
+        .any(|excluded| package.starts_with(&format!("{}.", excluded)))
+    {
+        return items;
+    }
+
+    let msg_services = extract_msg_services(descriptor);
+    let service = match msg_services.get(package) {
+        Some(service) if !nested_mod => service,
+        _ => return items,
+    };
+
+    let msg_fns = service
+        .method
+        .iter()
+        .filter_map(|method_desc| {
+            let name = format_ident!("{}", method_desc.name().to_snake_case());
+            let input_type = method_desc.input_type().rsplit('.').next().unwrap();
+            let req_type = format_ident!("{}", input_type.to_upper_camel_case());
+
+            let fields = items.iter().find_map(|item| match item {
+                Item::Struct(s) if s.ident == req_type => match &s.fields {
+                    Fields::Named(fields_named) => Some(fields_named.named.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })?;
+
+            // messages without a signer of their own, e.g. `MsgMultiSend`, are left out
+            let signer = SIGNER_FIELDS.iter().find_map(|signer| {
+                fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .find(|ident| *ident == signer)
+            })?;
+
+            let args = fields
+                .iter()
+                .filter(|field| field.ident.as_ref() != Some(signer));
+            let arg_idents = args.clone().map(|arg| arg.ident.as_ref().unwrap());
+            let arg_ty = args.map(|arg| &arg.ty);
+            let field_idents = arg_idents.clone();
+
+            Some(quote! {
+              pub fn #name(&self, #(#arg_idents: #arg_ty,)*) -> cosmwasm_std::CosmosMsg {
+                #req_type { #signer: self.sender.to_string(), #(#field_idents,)* }.into()
+              }
+            })
+        })
+        .collect::<Vec<TokenStream2>>();
+
+    if msg_fns.is_empty() {
+        return items;
+    }
+
+    let mut items = items;
+    items.extend::<[Item; 2]>([
+        parse_quote! {
+          #[cfg(feature = "cosmwasm")]
+          #[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+          pub struct #builder_ident {
+              sender: cosmwasm_std::Addr,
+          }
+        },
+        parse_quote! {
+          #[cfg(feature = "cosmwasm")]
+          impl #builder_ident {
+              pub fn new(sender: cosmwasm_std::Addr) -> Self {
+                Self { sender }
+              }
+              #(#msg_fns)*
+          }
+        },
+    ]);
+
+    items
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::amino_fields::AminoField;
     use syn::ItemStruct;
 
     macro_rules! assert_ast_eq {
@@ -378,6 +1115,328 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_map_custom_types() {
+        let item_struct: ItemStruct = syn::parse_quote! {
+            pub struct PoolParams {
+                #[prost(string, tag = "1")]
+                pub swap_fee: ::prost::alloc::string::String,
+                #[prost(string, tag = "2")]
+                pub future_pool_governor: ::prost::alloc::string::String,
+            }
+        };
+        let custom_types = CustomTypes::from([(
+            (
+                "osmosis.gamm.v1beta1.PoolParams".to_string(),
+                "swap_fee".to_string(),
+            ),
+            crate::custom_types::CustomType::Dec,
+        )]);
+
+        let result = map_custom_types(
+            Path::new("osmosis.gamm.v1beta1.rs"),
+            &[],
+            item_struct,
+            &custom_types,
+        );
+        let expected: ItemStruct = syn::parse_quote! {
+            pub struct PoolParams {
+                #[prost(string, required, tag = "1", default = "0")]
+                pub swap_fee: crate::shim::Dec,
+                #[prost(string, tag = "2")]
+                pub future_pool_governor: ::prost::alloc::string::String,
+            }
+        };
+
+        assert_ast_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_amino_attrs() {
+        let item_struct: ItemStruct = syn::parse_quote! {
+            pub struct MsgSwapExactAmountIn {
+                #[prost(string, tag = "1")]
+                pub sender: ::prost::alloc::string::String,
+                #[prost(message, repeated, tag = "2")]
+                pub routes: ::prost::alloc::vec::Vec<SwapAmountInRoute>,
+                #[prost(message, optional, tag = "3")]
+                pub token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+                #[prost(string, required, tag = "4", default = "0")]
+                pub token_out_min_amount: crate::shim::Int,
+            }
+        };
+        let key = |field: &str| {
+            (
+                "osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string(),
+                field.to_string(),
+            )
+        };
+        let amino_fields = AminoFields::from([
+            (
+                key("routes"),
+                AminoField {
+                    name: None,
+                    not_omitted: true,
+                },
+            ),
+            (
+                key("token_in"),
+                AminoField {
+                    name: Some("tokenIn".to_string()),
+                    not_omitted: true,
+                },
+            ),
+            (
+                key("token_out_min_amount"),
+                AminoField {
+                    name: Some("tokenOutMinAmount".to_string()),
+                    not_omitted: false,
+                },
+            ),
+        ]);
+
+        let result = add_amino_attrs(
+            Path::new("osmosis.gamm.v1beta1.rs"),
+            &[],
+            item_struct,
+            &amino_fields,
+        );
+        let expected: ItemStruct = syn::parse_quote! {
+            pub struct MsgSwapExactAmountIn {
+                #[prost(string, tag = "1")]
+                pub sender: ::prost::alloc::string::String,
+                #[prost(message, repeated, tag = "2")]
+                #[amino(not_omitted)]
+                pub routes: ::prost::alloc::vec::Vec<SwapAmountInRoute>,
+                #[prost(message, optional, tag = "3")]
+                #[amino(name = "tokenIn", not_omitted)]
+                pub token_in: ::core::option::Option<super::super::super::cosmos::base::v1beta1::Coin>,
+                #[prost(string, required, tag = "4", default = "0")]
+                #[amino(name = "tokenOutMinAmount")]
+                pub token_out_min_amount: crate::shim::Int,
+            }
+        };
+
+        assert_ast_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_append_attrs_with_msg_response_type() {
+        let message = |name: &str| DescriptorProto {
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        let descriptor = FileDescriptorSet {
+            file: vec![prost_types::FileDescriptorProto {
+                package: Some("osmosis.tokenfactory.v1beta1".to_string()),
+                message_type: vec![message("MsgCreateDenom"), message("MsgCreateDenomResponse")],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Msg".to_string()),
+                    method: vec![MethodDescriptorProto {
+                        name: Some("CreateDenom".to_string()),
+                        input_type: Some(
+                            ".osmosis.tokenfactory.v1beta1.MsgCreateDenom".to_string(),
+                        ),
+                        output_type: Some(
+                            ".osmosis.tokenfactory.v1beta1.MsgCreateDenomResponse".to_string(),
+                        ),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+        let src = Path::new("osmosis.tokenfactory.v1beta1.rs");
+
+        let msg: ItemStruct = syn::parse_quote! { pub struct MsgCreateDenom {} };
+        let result = append_attrs(src, &msg, &descriptor);
+        let expected: ItemStruct = syn::parse_quote! {
+            #[derive(serde::Serialize, serde::Deserialize, CosmwasmExt)]
+            #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+            #[proto_message(
+                type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+                amino_type = "osmosis/tokenfactory/create-denom",
+                response_type = MsgCreateDenomResponse
+            )]
+            pub struct MsgCreateDenom {}
+        };
+        assert_ast_eq!(result, expected);
+
+        let response: ItemStruct = syn::parse_quote! { pub struct MsgCreateDenomResponse {} };
+        let result = append_attrs(src, &response, &descriptor);
+        let expected: ItemStruct = syn::parse_quote! {
+            #[derive(serde::Serialize, serde::Deserialize, CosmwasmExt)]
+            #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+            #[proto_message(type_url = "/osmosis.tokenfactory.v1beta1.MsgCreateDenomResponse")]
+            pub struct MsgCreateDenomResponse {}
+        };
+        assert_ast_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_append_msg_builder() {
+        let method = |name: &str| MethodDescriptorProto {
+            name: Some(name.to_string()),
+            input_type: Some(format!(".cosmos.bank.v1beta1.Msg{}", name)),
+            output_type: Some(format!(".cosmos.bank.v1beta1.Msg{}Response", name)),
+            ..Default::default()
+        };
+        let descriptor = FileDescriptorSet {
+            file: vec![prost_types::FileDescriptorProto {
+                package: Some("cosmos.bank.v1beta1".to_string()),
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Msg".to_string()),
+                    method: vec![method("Send"), method("MultiSend")],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+        let items: Vec<Item> = vec![
+            syn::parse_quote! {
+                pub struct MsgSend {
+                    pub from_address: ::prost::alloc::string::String,
+                    pub to_address: ::prost::alloc::string::String,
+                    pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+                }
+            },
+            syn::parse_quote! {
+                pub struct MsgMultiSend {
+                    pub inputs: ::prost::alloc::vec::Vec<Input>,
+                    pub outputs: ::prost::alloc::vec::Vec<Output>,
+                }
+            },
+        ];
+
+        let result = append_msg_builder(
+            items.clone(),
+            Path::new("cosmos.bank.v1beta1.rs"),
+            false,
+            &descriptor,
+        );
+        let [_, _, builder_struct, builder_impl]: [Item; 4] = result.try_into().unwrap();
+        let expected_struct: Item = syn::parse_quote! {
+                #[cfg(feature = "cosmwasm")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm")))]
+                pub struct BankMsgBuilder {
+                    sender: cosmwasm_std::Addr,
+                }
+        };
+        let expected_impl: Item = syn::parse_quote! {
+                #[cfg(feature = "cosmwasm")]
+                impl BankMsgBuilder {
+                    pub fn new(sender: cosmwasm_std::Addr) -> Self {
+                        Self { sender }
+                    }
+                    pub fn send(
+                        &self,
+                        to_address: ::prost::alloc::string::String,
+                        amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
+                    ) -> cosmwasm_std::CosmosMsg {
+                        MsgSend {
+                            from_address: self.sender.to_string(),
+                            to_address,
+                            amount,
+                        }
+                        .into()
+                    }
+                }
+        };
+        assert_ast_eq!(builder_struct, expected_struct);
+        assert_ast_eq!(builder_impl, expected_impl);
+
+        let nested = append_msg_builder(
+            items.clone(),
+            Path::new("cosmos.bank.v1beta1.rs"),
+            true,
+            &descriptor,
+        );
+        assert_eq!(nested, items);
+
+        // messages signed by a validator's operator and the ones of relayers get no builder
+        let single_msg_descriptor = |package: &str, name: &str| FileDescriptorSet {
+            file: vec![prost_types::FileDescriptorProto {
+                package: Some(package.to_string()),
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Msg".to_string()),
+                    method: vec![MethodDescriptorProto {
+                        name: Some(name.to_string()),
+                        input_type: Some(format!(".{}.Msg{}", package, name)),
+                        output_type: Some(format!(".{}.Msg{}Response", package, name)),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+
+        let unjail: Vec<Item> = vec![syn::parse_quote! {
+            pub struct MsgUnjail {
+                pub validator_addr: ::prost::alloc::string::String,
+            }
+        }];
+        let result = append_msg_builder(
+            unjail.clone(),
+            Path::new("cosmos.slashing.v1beta1.rs"),
+            false,
+            &single_msg_descriptor("cosmos.slashing.v1beta1", "Unjail"),
+        );
+        assert_eq!(result, unjail);
+
+        let update_client: Vec<Item> = vec![syn::parse_quote! {
+            pub struct MsgUpdateClient {
+                pub client_id: ::prost::alloc::string::String,
+                pub header: ::core::option::Option<crate::shim::Any>,
+                pub signer: ::prost::alloc::string::String,
+            }
+        }];
+        let result = append_msg_builder(
+            update_client.clone(),
+            Path::new("ibc.core.client.v1.rs"),
+            false,
+            &single_msg_descriptor("ibc.core.client.v1", "UpdateClient"),
+        );
+        assert_eq!(result, update_client);
+    }
+
+    #[test]
+    fn test_sanitize_doc_comments() {
+        let contents = r#"/// ReferenceCount =
+///   + number of slashes which ended the associated period (and might need to
+///   read that record)
+///   + one per validator for the zeroeth period, set on initialization
+pub struct HistoricalRewards {
+    /// Wall-clock time of epoch start may be
+    /// >> current_epoch_start_time.
+    ///
+    /// ```
+    /// > kept
+    /// ```
+    ///=============================== Pools
+    pub start_time: u64,
+}
+"#;
+        let expected = r#"/// ReferenceCount =
+///   + number of slashes which ended the associated period (and might need to
+///     read that record)
+///   + one per validator for the zeroeth period, set on initialization
+pub struct HistoricalRewards {
+    /// Wall-clock time of epoch start may be
+    /// \>> current_epoch_start_time.
+    ///
+    /// ```
+    /// > kept
+    /// ```
+    ///=============================== Pools
+    pub start_time: u64,
+}
+"#;
+
+        assert_eq!(sanitize_doc_comments(contents), expected);
+    }
+
     #[test]
     fn test_add_derive_eq_if_there_is_partial_eq() {
         let item_struct: ItemStruct = syn::parse_quote! {
@@ -432,4 +1491,83 @@ mod tests {
 
         assert_ast_eq!(result, expected);
     }
+
+    #[test]
+    fn test_allow_serde_enumeration() {
+        let item_struct: ItemStruct = syn::parse_quote! {
+            pub struct QueryCondition {
+                #[prost(enumeration = "LockQueryType", tag = "1")]
+                pub lock_query_type: i32,
+                #[prost(uint64, tag = "2")]
+                pub pool_id: u64,
+            }
+        };
+
+        let result = allow_serde_int_as_str(allow_serde_enumeration(item_struct));
+        let expected: ItemStruct = syn::parse_quote! {
+            pub struct QueryCondition {
+                #[prost(enumeration = "LockQueryType", tag = "1")]
+                #[serde(
+                    serialize_with = "crate::serde::enumeration::serialize::<LockQueryType, _>",
+                    deserialize_with = "crate::serde::enumeration::deserialize::<LockQueryType, _>"
+                )]
+                #[cfg_attr(feature = "cosmwasm", schemars(with = "LockQueryType"))]
+                pub lock_query_type: i32,
+                #[prost(uint64, tag = "2")]
+                #[serde(
+                    serialize_with = "crate::serde::as_str::serialize",
+                    deserialize_with = "crate::serde::as_str::deserialize"
+                )]
+                pub pool_id: u64,
+            }
+        };
+
+        assert_ast_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_add_serde_to_enum() {
+        let items: Vec<Item> = vec![
+            syn::parse_quote! {
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+                #[repr(i32)]
+                pub enum BondStatus {
+                    Unspecified = 0,
+                    Bonded = 3,
+                }
+            },
+            syn::parse_quote! {
+                impl BondStatus {
+                    pub fn as_str_name(&self) -> &'static str {
+                        match self {
+                            BondStatus::Unspecified => "BOND_STATUS_UNSPECIFIED",
+                            BondStatus::Bonded => "BOND_STATUS_BONDED",
+                        }
+                    }
+                }
+            },
+        ];
+
+        let value_names = extract_enum_value_names(&items);
+        let item_enum = match &items[0] {
+            Item::Enum(item_enum) => item_enum.clone(),
+            _ => unreachable!(),
+        };
+
+        let result = add_serde_to_enum(item_enum, &value_names[&format_ident!("BondStatus")]);
+        let expected: ItemEnum = syn::parse_quote! {
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+            #[derive(serde::Serialize, serde::Deserialize)]
+            #[cfg_attr(feature = "cosmwasm", derive(schemars::JsonSchema))]
+            #[repr(i32)]
+            pub enum BondStatus {
+                #[serde(rename = "BOND_STATUS_UNSPECIFIED")]
+                Unspecified = 0,
+                #[serde(rename = "BOND_STATUS_BONDED")]
+                Bonded = 3,
+            }
+        };
+
+        assert_ast_eq!(result, expected);
+    }
 }
@@ -0,0 +1,101 @@
+//! `fixtures/descriptor.bin` is a descriptor set of `cosmos/base/v1beta1/coin.proto`,
+//! `cosmos/gov/v1beta1/gov.proto`, `cosmos/bank/v1beta1/bank.proto`,
+//! `osmosis/tokenfactory/v1beta1/{tx,query}.proto`, which import `coin.proto`, and
+//! `osmosis/gamm/v1beta1/tx.proto`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proto_build::code_generator::{CodeGenerator, CosmosProject};
+use walkdir::WalkDir;
+
+fn project(name: &str, include_mods: &[&str], exclude_mods: &[&str]) -> CosmosProject {
+    CosmosProject {
+        name: name.to_string(),
+        version: "v1.0.0".to_string(),
+        project_dir: String::new(),
+        proto_dir: "proto".to_string(),
+        include_dirs: vec![],
+        include_mods: include_mods.iter().map(|m| m.to_string()).collect(),
+        exclude_mods: exclude_mods.iter().map(|m| m.to_string()).collect(),
+    }
+}
+
+/// Generate into a crate at `dir`, since the output is formatted with `cargo fmt` on the crate
+/// it goes into, and read back the files generated, by their path in `src/types`.
+fn generate(dir: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+    let _ = fs::remove_dir_all(dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"generated\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .unwrap();
+    fs::write(dir.join("src/lib.rs"), "pub mod types;\n").unwrap();
+
+    let types_dir = dir.join("src/types");
+    CodeGenerator::new(
+        types_dir.clone(),
+        dir.join("tmp"),
+        project("osmosis", &[], &["gamm"]),
+        vec![project("cosmos", &["bank"], &[])],
+    )
+    .descriptor_set(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/descriptor.bin"))
+    .generate();
+
+    WalkDir::new(&types_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| {
+            (
+                e.path().strip_prefix(&types_dir).unwrap().to_path_buf(),
+                fs::read(e.path()).unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_generate_from_descriptor_set() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("generate_from_descriptor_set");
+    let first = generate(&dir.join("first"));
+    let second = generate(&dir.join("second"));
+
+    // `coin.proto` is generated since it is imported, while gov is not in `include_mods`
+    // and gamm is in `exclude_mods`
+    assert_eq!(
+        first.keys().collect::<Vec<_>>(),
+        [
+            "OSMOSIS_COMMIT",
+            "cosmos/bank/mod.rs",
+            "cosmos/bank/v1beta1.rs",
+            "cosmos/base/mod.rs",
+            "cosmos/base/v1beta1.rs",
+            "cosmos/mod.rs",
+            "mod.rs",
+            "osmosis/mod.rs",
+            "osmosis/tokenfactory/mod.rs",
+            "osmosis/tokenfactory/v1beta1.rs",
+            "registry.rs",
+        ]
+        .map(PathBuf::from)
+        .iter()
+        .collect::<Vec<_>>()
+    );
+    // the response type of each `Query` method is registered by its path
+    let registry = String::from_utf8(first[Path::new("registry.rs")].clone()).unwrap();
+    assert!(registry.contains(
+        "\"/osmosis.tokenfactory.v1beta1.Query/Params\" => \
+         crate::types::osmosis::tokenfactory::v1beta1::QueryParamsResponse,"
+    ));
+
+    for (path, contents) in &first {
+        assert!(
+            second.get(path) == Some(contents),
+            "{} differs between runs",
+            path.display()
+        );
+    }
+}
@@ -37,32 +37,32 @@ pub fn mock_balancner_pool() -> osmosis_std::types::osmosis::gamm::v1beta1::Pool
         address: "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t".to_string(),
         id: 1,
         pool_params: Some(osmosis_std::types::osmosis::gamm::v1beta1::PoolParams {
-            swap_fee: "0.010000000000000000".to_string(),
-            exit_fee: "0.010000000000000000".to_string(),
+            swap_fee: "0.010000000000000000".parse().unwrap(),
+            exit_fee: "0.010000000000000000".parse().unwrap(),
             smooth_weight_change_params: None,
         }),
         future_pool_governor: "".to_string(),
         total_shares: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
             denom: "gamm/pool/1".to_string(),
-            amount: "100000000000000000000".to_string(),
+            amount: "100000000000000000000".parse().unwrap(),
         }),
         pool_assets: vec![
             osmosis_std::types::osmosis::gamm::v1beta1::PoolAsset {
                 token: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
                     denom: "uion".to_string(),
-                    amount: "1000".to_string(),
+                    amount: "1000".parse().unwrap(),
                 }),
-                weight: "1073741824000000".to_string(),
+                weight: "1073741824000000".parse().unwrap(),
             },
             osmosis_std::types::osmosis::gamm::v1beta1::PoolAsset {
                 token: Some(osmosis_std::types::cosmos::base::v1beta1::Coin {
                     denom: "uosmo".to_string(),
-                    amount: "1000".to_string(),
+                    amount: "1000".parse().unwrap(),
                 }),
-                weight: "1073741824000000".to_string(),
+                weight: "1073741824000000".parse().unwrap(),
             },
         ],
-        total_weight: "2147483648000000".to_string(),
+        total_weight: "2147483648000000".parse().unwrap(),
     }
 }
 